@@ -0,0 +1,162 @@
+//! Criterion benchmarks for the everyday operations: `Int` arithmetic at 10, 1 000 and
+//! 100 000 digits, `Float` arithmetic and rounding, parsing, `Display` and the
+//! transcendental functions.
+//! Criterion keeps the previous run under `target/criterion` and reports the change against
+//! it, so running this before and after a change shows any regression.
+//!
+//! Run with `cargo bench --bench core_ops`, or `cargo bench --bench core_ops -- int_mul` for
+//! one group.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use imagnum::compat::{float_to_parts, round_float_parts};
+use imagnum::{Float, Int, create_float, create_int};
+use std::hint::black_box;
+
+const INT_DIGITS: [usize; 3] = [10, 1_000, 100_000];
+
+/// A one-argument `Float` function such as `Float::sqrt`.
+type UnaryFn = fn(&Float) -> Result<Float, i8>;
+
+/// `len` deterministic decimal digits, the first one nonzero.
+fn digits(len: usize, seed: u64) -> String {
+    let mut state = seed;
+    (0..len)
+        .map(|i| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let d = (state >> 33) % 10;
+            char::from(b'0' + if i == 0 { d as u8 % 9 + 1 } else { d as u8 })
+        })
+        .collect()
+}
+
+fn int(len: usize, seed: u64) -> Int {
+    create_int(&digits(len, seed))
+}
+
+/// A float with `len` digits on each side of the point.
+fn float(len: usize, seed: u64) -> Float {
+    create_float(&format!("{}.{}", digits(len, seed), digits(len, seed + 1)))
+}
+
+fn int_ops(c: &mut Criterion) {
+    for (name, op) in [
+        ("int_add", Int::_add as fn(&Int, &Int) -> Result<Int, i8>),
+        ("int_mul", Int::_mul),
+        ("int_div", Int::_div),
+    ] {
+        let mut group = c.benchmark_group(name);
+        group.sample_size(20);
+        for len in INT_DIGITS {
+            let a = int(len, 1);
+            // divide by an operand half as long so the quotient is not trivial
+            let b = if name == "int_div" { int(len / 2 + 1, 2) } else { int(len, 2) };
+            group.bench_with_input(BenchmarkId::from_parameter(len), &(a, b), |bench, (a, b)| {
+                bench.iter(|| op(black_box(a), black_box(b)))
+            });
+        }
+        group.finish();
+    }
+}
+
+fn float_ops(c: &mut Criterion) {
+    let mut group = c.benchmark_group("float");
+    let a = float(500, 1);
+    let b = float(500, 3);
+    group.bench_function("add", |bench| bench.iter(|| black_box(&a)._add(black_box(&b))));
+    group.bench_function("sub", |bench| bench.iter(|| black_box(&a)._sub(black_box(&b))));
+    group.bench_function("mul", |bench| bench.iter(|| black_box(&a)._mul(black_box(&b))));
+    group.bench_function("div", |bench| bench.iter(|| black_box(&a)._div(black_box(&b))));
+    group.bench_function("modulo", |bench| bench.iter(|| black_box(&a)._modulo(black_box(&b))));
+    group.bench_function("compare", |bench| bench.iter(|| black_box(&a) < black_box(&b)));
+    let (x, y) = (create_float("1.5"), create_float("-0.25"));
+    group.bench_function("add small", |bench| bench.iter(|| black_box(&x)._add(black_box(&y))));
+    group.bench_function("mul small", |bench| bench.iter(|| black_box(&x)._mul(black_box(&y))));
+    group.finish();
+}
+
+/// `Float::round` on decimal values, which rounds the `BigDecimal` directly, against the
+/// digit-string path it replaced (still used for binary floats) on the same values.
+fn rounding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("round");
+    for len in [20, 200, 2_000] {
+        let x = float(len, 11);
+        let places = len / 4;
+        group.bench_with_input(BenchmarkId::new("bigdecimal", len), &x, |bench, x| {
+            bench.iter(|| black_box(x).round(places))
+        });
+        group.bench_with_input(BenchmarkId::new("digit string", len), &x, |bench, x| {
+            bench.iter(|| {
+                let (mantissa, exponent, neg, _) = float_to_parts(black_box(x));
+                round_float_parts(mantissa, exponent, neg, places)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for len in INT_DIGITS {
+        let s = digits(len, 5);
+        group.bench_with_input(BenchmarkId::new("int", len), &s, |bench, s| {
+            bench.iter(|| create_int(black_box(s)))
+        });
+    }
+    for s in ["3.14159", "-0.000125", "1.2345678901234567890123456789e-40", "0.(142857)"] {
+        group.bench_with_input(BenchmarkId::new("float", s), s, |bench, s| {
+            bench.iter(|| create_float(black_box(s)))
+        });
+    }
+    let long = format!("{}.{}", digits(1_000, 6), digits(1_000, 7));
+    group.bench_with_input(BenchmarkId::new("float", "1000.1000 digits"), &long, |bench, s| {
+        bench.iter(|| create_float(black_box(s)))
+    });
+    group.finish();
+}
+
+fn display(c: &mut Criterion) {
+    let mut group = c.benchmark_group("display");
+    group.sample_size(20);
+    for len in INT_DIGITS {
+        let n = int(len, 8);
+        group.bench_with_input(BenchmarkId::new("int", len), &n, |bench, n| {
+            bench.iter(|| black_box(n).to_string())
+        });
+    }
+    let values = [
+        ("small", create_float("3.14159")),
+        ("big", float(1_000, 9)),
+        ("recurring", create_float("0.(142857)")),
+        ("irrational", create_float("2").sqrt().unwrap()),
+    ];
+    for (name, x) in &values {
+        group.bench_with_input(BenchmarkId::new("float", name), x, |bench, x| {
+            bench.iter(|| black_box(x).to_string())
+        });
+    }
+    group.finish();
+}
+
+fn transcendental(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transcendental");
+    group.sample_size(20);
+    let x = create_float("1.2345678901234567890123456789");
+    let functions: [(&str, UnaryFn); 7] = [
+        ("sqrt", Float::sqrt),
+        ("exp", Float::exp),
+        ("ln", Float::ln),
+        ("sin", Float::sin),
+        ("cos", Float::cos),
+        ("tan", Float::tan),
+        ("log10", Float::log10),
+    ];
+    for (name, f) in functions {
+        group.bench_function(name, |bench| bench.iter(|| f(black_box(&x))));
+    }
+    let y = create_float("0.75");
+    group.bench_function("pow", |bench| bench.iter(|| black_box(&x)._pow(black_box(&y))));
+    group.finish();
+}
+
+criterion_group!(benches, int_ops, float_ops, rounding, parsing, display, transcendental);
+criterion_main!(benches);
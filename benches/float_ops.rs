@@ -0,0 +1,53 @@
+//! `Float` arithmetic on 10 000-digit operands, to track the cost of the generic decimal
+//! paths (operand conversion, normalization and kind bookkeeping) around the big-number
+//! work itself.
+//!
+//! Run with `cargo bench --bench float_ops`.
+
+use imagnum::{create_float, Float};
+use std::hint::black_box;
+use std::time::Instant;
+
+const DIGITS: usize = 10_000;
+const ITERATIONS: u32 = 200;
+
+fn bench(label: &str, mut f: impl FnMut() -> Float) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    println!("{:<36} {:>10.2?} per op", label, start.elapsed() / ITERATIONS);
+}
+
+/// `DIGITS` pseudo-random digits with `decimals` of them after the point.
+fn operand(seed: u64, decimals: usize) -> Float {
+    let mut x = seed;
+    let mut digits: String = (0..DIGITS)
+        .map(|_| {
+            x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            char::from(b'1' + (x >> 60) as u8 % 9)
+        })
+        .collect();
+    if decimals > 0 {
+        digits.insert(DIGITS - decimals, '.');
+    }
+    create_float(&digits)
+}
+
+fn main() {
+    let a = operand(1, DIGITS / 2);
+    let b = operand(2, DIGITS / 3);
+    let (i, j) = (operand(3, 0), operand(4, 0));
+    let third = create_float("1")._div(&create_float("3")).unwrap();
+
+    bench("add", || a._add(black_box(&b)).unwrap());
+    bench("sub", || a._sub(black_box(&b)).unwrap());
+    bench("mul", || a._mul(black_box(&b)).unwrap());
+    bench("div", || a._div(black_box(&b)).unwrap());
+    bench("div, integral operands", || i._div(black_box(&j)).unwrap());
+    bench("modulo", || a._modulo(black_box(&b)).unwrap());
+    bench("neg", || -black_box(&a).clone());
+    bench("abs", || black_box(&a).abs());
+    bench("add recurring", || a._add(black_box(&third)).unwrap());
+    bench("compare", || Float::from(f64::from(u8::from(black_box(&a) < &b))));
+}
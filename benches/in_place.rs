@@ -0,0 +1,88 @@
+//! Million-iteration accumulation loops comparing `_add`/`_mul`, which clone both operands
+//! into a fresh value, with the in-place `*_assign_ref` methods.
+//!
+//! Run with `cargo bench --bench in_place`.
+
+use imagnum::{create_float, create_int, Float};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: usize = 1_000_000;
+
+fn time(label: &str, f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    let elapsed = start.elapsed();
+    println!("{:<40} {:>10.2?}", label, elapsed);
+    elapsed
+}
+
+fn compare(name: &str, cloning: impl FnOnce(), in_place: impl FnOnce()) {
+    let a = time(&format!("{} (clone)", name), cloning);
+    let b = time(&format!("{} (in place)", name), in_place);
+    println!("{:<40} {:>9.2}x\n", "", a.as_secs_f64() / b.as_secs_f64());
+}
+
+fn main() {
+    // a 600-digit accumulator makes the clone of the left operand visible
+    let start = create_int(&"9".repeat(600));
+    let step = create_int("12345678901234567890");
+    compare(
+        "Int add, 600 digits",
+        || {
+            let mut acc = start.clone();
+            for _ in 0..ITERATIONS {
+                acc = acc._add(black_box(&step)).unwrap();
+            }
+            black_box(acc);
+        },
+        || {
+            let mut acc = start.clone();
+            for _ in 0..ITERATIONS {
+                acc.add_assign_ref(black_box(&step));
+            }
+            black_box(acc);
+        },
+    );
+
+    let one = create_int("1");
+    let three = create_int("3");
+    compare(
+        "Int mul, growing to 4700 digits",
+        || {
+            let mut acc = one.clone();
+            for _ in 0..ITERATIONS / 100 {
+                acc = acc._mul(black_box(&three)).unwrap();
+            }
+            black_box(acc);
+        },
+        || {
+            let mut acc = one.clone();
+            for _ in 0..ITERATIONS / 100 {
+                acc.mul_assign_ref(black_box(&three));
+            }
+            black_box(acc);
+        },
+    );
+
+    let start: Float = create_float(&format!("{}.5", "7".repeat(300)));
+    let step = create_float("0.25");
+    compare(
+        "Float add, 300 digits",
+        || {
+            let mut acc = start.clone();
+            for _ in 0..ITERATIONS {
+                acc = acc._add(black_box(&step)).unwrap();
+            }
+            black_box(acc);
+        },
+        || {
+            let mut acc = start.clone();
+            for _ in 0..ITERATIONS {
+                acc.add_assign_ref(black_box(&step)).unwrap();
+            }
+            black_box(acc);
+        },
+    );
+
+}
@@ -0,0 +1,56 @@
+//! Huge `Int` multiplication: `num-bigint` (Karatsuba / Toom-3) against the
+//! number-theoretic transform in `imagnum::ntt`, at sizes around `ntt::THRESHOLD_BITS`.
+//!
+//! Run with `cargo bench --bench mul`.
+
+use imagnum::ntt;
+use num_bigint::{BigInt, BigUint};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// A deterministic operand of `digits` 32-bit digits, every one of them nonzero.
+fn operand(digits: usize, seed: u64) -> BigInt {
+    let mut state = seed;
+    let words = (0..digits)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 32) as u32 | 1
+        })
+        .collect();
+    BigInt::from(BigUint::new(words))
+}
+
+/// Best of `runs` timings, which is steadier than the mean on a busy machine.
+fn best(runs: usize, mut f: impl FnMut()) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    println!("{:>12} {:>14} {:>14} {:>8}", "decimal", "num-bigint", "transform", "ratio");
+    for words in [1_000, 4_000, 8_000, 16_000, 20_000, 24_000, 32_000, 64_000, 128_000, 256_000] {
+        let a = operand(words, 1);
+        let b = operand(words, 2);
+        assert_eq!(ntt::mul_transform(&a, &b), &a * &b);
+        let runs = (256_000 / words).clamp(1, 20);
+        let schoolbook = best(runs, || {
+            black_box(black_box(&a) * black_box(&b));
+        });
+        let transform = best(runs, || {
+            black_box(ntt::mul_transform(black_box(&a), black_box(&b)));
+        });
+        println!(
+            "{:>12} {:>14.2?} {:>14.2?} {:>7.2}x",
+            words * 32 * 3010 / 10_000,
+            schoolbook,
+            transform,
+            schoolbook.as_secs_f64() / transform.as_secs_f64()
+        );
+    }
+}
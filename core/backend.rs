@@ -0,0 +1,116 @@
+//! The arbitrary-precision arithmetic behind `Int` and `Float`, behind a trait so a faster
+//! library can stand in for `num-bigint` / `bigdecimal` where it matters.
+//!
+//! `Int` and `Float` keep storing `BigInt` and `BigDecimal`, so the public API does not
+//! change with the backend. The operations that dominate on huge values (multiplication,
+//! division, roots and decimal conversion) go through the current `backend()`. Every method
+//! has a default built on `num-bigint` and `bigdecimal`, so a backend only overrides what it
+//! does better and converts at its boundary.
+//!
+//! The `gmp` feature adds `Gmp`, a backend on GMP through the `rug` crate, which wins for
+//! products and quotients of tens of thousands of digits:
+//!
+//! ```ignore
+//! use imagnum::backend::{set_backend, Gmp};
+//!
+//! set_backend(&Gmp);
+//! ```
+
+use crate::alloc_prelude::*;
+use crate::math::nth_root_bigint;
+use bigdecimal::{BigDecimal, Context};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use core::num::NonZeroU64;
+use core::str::FromStr;
+#[cfg(not(feature = "std"))]
+use spin::RwLock;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+/// Arbitrary-precision operations `Int` and `Float` delegate to. Implementations must give
+/// exactly the results of the defaults; only the speed may differ.
+pub trait Backend: Send + Sync {
+    /// Short name for diagnostics, such as `"num-bigint"`.
+    fn name(&self) -> &'static str;
+
+    /// `a * b`. The default switches to `ntt::mul` for huge operands.
+    fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        crate::ntt::mul(a, b)
+    }
+
+    /// `*a *= b`; the default multiplies in place below the `ntt` threshold.
+    fn mul_assign(&self, a: &mut BigInt, b: &BigInt) {
+        crate::ntt::mul_assign(a, b);
+    }
+
+    /// Quotient rounded toward zero and the remainder, which takes the sign of `a`.
+    /// `b` is never zero.
+    fn div_rem(&self, a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
+        a.div_rem(b)
+    }
+
+    /// Floor of the `n`-th root of a non-negative `a`; `n >= 1`.
+    fn root(&self, a: &BigInt, n: u32) -> BigInt {
+        nth_root_bigint(a, n)
+    }
+
+    /// Decimal digits of `a`, with a leading `-` when negative.
+    fn to_decimal(&self, a: &BigInt) -> String {
+        a.to_string()
+    }
+
+    /// Parses an optionally signed run of decimal digits; `None` for anything else. The
+    /// default splits inputs of `chunked::THRESHOLD_DIGITS` digits or more into halves.
+    fn parse_decimal(&self, s: &str) -> Option<BigInt> {
+        if s.len() < crate::chunked::THRESHOLD_DIGITS {
+            return BigInt::from_str(s).ok();
+        }
+        crate::chunked::parse_signed(s)
+    }
+
+    /// `a * b`, exactly.
+    fn decimal_mul(&self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        a * b
+    }
+
+    /// `a / b` to `digits` significant digits; `b` is never zero.
+    fn decimal_div(&self, a: &BigDecimal, b: &BigDecimal, digits: NonZeroU64) -> BigDecimal {
+        let ctx = Context::default().with_precision(digits);
+        a.mul_with_context(&b.inverse_with_context(&ctx), &ctx)
+    }
+}
+
+/// The default backend: `num-bigint` with `ntt` for huge products, and `bigdecimal`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NumBigint;
+
+impl Backend for NumBigint {
+    fn name(&self) -> &'static str {
+        "num-bigint"
+    }
+}
+
+#[cfg(feature = "gmp")]
+pub use crate::features::feature_gmp::{Gmp, GMP_THRESHOLD_BITS};
+
+static BACKEND: RwLock<&'static dyn Backend> = RwLock::new(&NumBigint);
+
+/// Makes `backend` do the arithmetic of every `Int` and `Float` from now on. Process-wide,
+/// like `set_precision`; `set_backend(&NumBigint)` restores the default.
+pub fn set_backend(backend: &'static dyn Backend) {
+    #[cfg(feature = "std")]
+    let mut current = BACKEND.write().unwrap_or_else(|e| e.into_inner());
+    #[cfg(not(feature = "std"))]
+    let mut current = BACKEND.write();
+    *current = backend;
+}
+
+/// The backend currently in use.
+pub fn backend() -> &'static dyn Backend {
+    #[cfg(feature = "std")]
+    let current = BACKEND.read().unwrap_or_else(|e| e.into_inner());
+    #[cfg(not(feature = "std"))]
+    let current = BACKEND.read();
+    *current
+}
@@ -0,0 +1,237 @@
+use crate::alloc_prelude::*;
+use crate::compat::int_to_bigint;
+use crate::foundation::{Float, Int, SmallFloat};
+use crate::math::ERR_INVALID_FORMAT;
+use bigdecimal::BigDecimal;
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
+
+// Kind tags of the `Float` frame. Stored on disk: never renumber.
+const TAG_BIG: u8 = 0;
+const TAG_IRRATIONAL: u8 = 1;
+const TAG_RECURRING: u8 = 2;
+const TAG_COMPLEX: u8 = 3;
+const TAG_NAN: u8 = 4;
+const TAG_INFINITY: u8 = 5;
+const TAG_NEG_INFINITY: u8 = 6;
+const TAG_F32: u8 = 7;
+const TAG_F64: u8 = 8;
+const TAG_SURD: u8 = 9;
+const TAG_RATIO: u8 = 10;
+
+fn write_varint(out: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<u64, i8> {
+    let mut v = 0u64;
+    for shift in (0..64).step_by(7) {
+        let b = *bytes.get(*pos).ok_or(ERR_INVALID_FORMAT)?;
+        *pos += 1;
+        v |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Ok(v);
+        }
+    }
+    Err(ERR_INVALID_FORMAT)
+}
+
+fn take<'a>(bytes: &'a [u8], pos: &mut usize, n: usize) -> Result<&'a [u8], i8> {
+    let end = pos.checked_add(n).filter(|&e| e <= bytes.len()).ok_or(ERR_INVALID_FORMAT)?;
+    let slice = &bytes[*pos..end];
+    *pos = end;
+    Ok(slice)
+}
+
+fn write_bigint(out: &mut Vec<u8>, n: &BigInt) {
+    let bytes = n.to_signed_bytes_le();
+    write_varint(out, bytes.len() as u64);
+    out.extend(bytes);
+}
+
+fn write_float(out: &mut Vec<u8>, f: &Float) {
+    let decimal = |out: &mut Vec<u8>, tag: u8, bd: &BigDecimal| {
+        let (mantissa, scale) = bd.as_bigint_and_scale();
+        let mantissa = mantissa.to_signed_bytes_le();
+        out.push(tag);
+        // zigzag so small negative scales stay one byte
+        write_varint(out, ((scale << 1) ^ (scale >> 63)) as u64);
+        write_varint(out, mantissa.len() as u64);
+        out.extend(mantissa);
+    };
+    match f {
+        Float::Big(bd) => decimal(out, TAG_BIG, bd),
+        Float::Irrational(bd) => decimal(out, TAG_IRRATIONAL, bd),
+        Float::Recurring(num, den) => {
+            out.push(TAG_RATIO);
+            write_bigint(out, num);
+            write_bigint(out, den);
+        }
+        Float::Surd(c, r) => {
+            decimal(out, TAG_SURD, c);
+            write_bigint(out, r);
+        }
+        Float::Complex(real, imag) => {
+            out.push(TAG_COMPLEX);
+            write_float(out, real);
+            write_float(out, imag);
+        }
+        Float::NaN => out.push(TAG_NAN),
+        Float::Infinity => out.push(TAG_INFINITY),
+        Float::NegInfinity => out.push(TAG_NEG_INFINITY),
+        Float::Small(SmallFloat::F32(v)) => {
+            out.push(TAG_F32);
+            out.extend(v.to_le_bytes());
+        }
+        Float::Small(SmallFloat::F64(v)) => {
+            out.push(TAG_F64);
+            out.extend(v.to_le_bytes());
+        }
+    }
+}
+
+/// `nested` is set while reading the parts of a complex value, which may not be complex.
+fn read_float(bytes: &[u8], pos: &mut usize, nested: bool) -> Result<Float, i8> {
+    let tag = *bytes.get(*pos).ok_or(ERR_INVALID_FORMAT)?;
+    *pos += 1;
+    let bigint = |pos: &mut usize| -> Result<BigInt, i8> {
+        let len = usize::try_from(read_varint(bytes, pos)?).map_err(|_| ERR_INVALID_FORMAT)?;
+        Ok(BigInt::from_signed_bytes_le(take(bytes, pos, len)?))
+    };
+    let decimal = |pos: &mut usize| -> Result<BigDecimal, i8> {
+        let zigzag = read_varint(bytes, pos)?;
+        let scale = ((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64);
+        Ok(BigDecimal::new(bigint(pos)?, scale))
+    };
+    Ok(match tag {
+        TAG_BIG => Float::Big(decimal(pos)?),
+        TAG_IRRATIONAL => Float::Irrational(decimal(pos)?),
+        // frames written before recurring values were stored as fractions
+        TAG_RECURRING => crate::recurring::from_digits(&decimal(pos)?),
+        TAG_RATIO => {
+            let (num, den) = (bigint(pos)?, bigint(pos)?);
+            if den.is_zero() {
+                return Err(ERR_INVALID_FORMAT);
+            }
+            crate::recurring::ratio_to_float(&num, &den)
+        }
+        TAG_SURD => {
+            let coefficient = decimal(pos)?;
+            let radicand = bigint(pos)?;
+            if radicand < BigInt::from(2) {
+                return Err(ERR_INVALID_FORMAT);
+            }
+            Float::Surd(coefficient, radicand)
+        }
+        TAG_COMPLEX if !nested => {
+            let real = read_float(bytes, pos, true)?;
+            let imag = read_float(bytes, pos, true)?;
+            Float::Complex(Box::new(real), Box::new(imag))
+        }
+        TAG_NAN => Float::NaN,
+        TAG_INFINITY => Float::Infinity,
+        TAG_NEG_INFINITY => Float::NegInfinity,
+        TAG_F32 => {
+            let b = take(bytes, pos, 4)?;
+            Float::Small(SmallFloat::F32(f32::from_le_bytes([b[0], b[1], b[2], b[3]])))
+        }
+        TAG_F64 => {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(take(bytes, pos, 8)?);
+            Float::Small(SmallFloat::F64(f64::from_le_bytes(b)))
+        }
+        _ => return Err(ERR_INVALID_FORMAT),
+    })
+}
+
+impl Int {
+    /// Minimal two's-complement little-endian bytes (`0` is a single zero byte).
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        int_to_bigint(self).to_signed_bytes_le()
+    }
+
+    /// Minimal two's-complement big-endian bytes.
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        int_to_bigint(self).to_signed_bytes_be()
+    }
+
+    /// Reads two's-complement little-endian bytes; an empty slice is zero.
+    pub fn from_bytes_le(bytes: &[u8]) -> Int {
+        Int::Big(BigInt::from_signed_bytes_le(bytes))
+    }
+
+    /// Reads two's-complement big-endian bytes; an empty slice is zero.
+    pub fn from_bytes_be(bytes: &[u8]) -> Int {
+        Int::Big(BigInt::from_signed_bytes_be(bytes))
+    }
+
+    /// The sign and the magnitude as little-endian 32-bit limbs, as `BigInt::to_u32_digits`
+    /// gives them: zero is `(NoSign, [])`.
+    pub fn to_u32_digits(&self) -> (Sign, Vec<u32>) {
+        int_to_bigint(self).to_u32_digits()
+    }
+
+    /// The `Int` with sign `sign` and magnitude `digits`, little-endian 32-bit limbs. A zero
+    /// magnitude is 0 whatever the sign; `NoSign` with a nonzero magnitude fails with
+    /// `INVALID_FORMAT`.
+    pub fn from_u32_digits(sign: Sign, digits: &[u32]) -> Result<Int, i8> {
+        if sign == Sign::NoSign && digits.iter().any(|&d| d != 0) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        Ok(Int::Big(BigInt::from_slice(sign, digits)))
+    }
+
+    /// The sign and the magnitude as little-endian digits in base `radix`, `2..=256`, one
+    /// per byte; zero is `(NoSign, [0])`. Fails with `INVALID_FORMAT` for other radixes.
+    pub fn to_radix_le(&self, radix: u32) -> Result<(Sign, Vec<u8>), i8> {
+        if !(2..=256).contains(&radix) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        Ok(int_to_bigint(self).to_radix_le(radix))
+    }
+
+    /// The inverse of `to_radix_le`. Fails with `INVALID_FORMAT` for a radix outside
+    /// `2..=256`, a digit not below `radix`, or `NoSign` with a nonzero magnitude.
+    pub fn from_radix_le(sign: Sign, digits: &[u8], radix: u32) -> Result<Int, i8> {
+        if !(2..=256).contains(&radix) || (sign == Sign::NoSign && digits.iter().any(|&d| d != 0)) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        BigInt::from_radix_le(sign, digits, radix).map(Int::Big).ok_or(ERR_INVALID_FORMAT)
+    }
+}
+
+impl Float {
+    /// Self-delimiting binary frame: a kind tag, then for decimal kinds the scale and the
+    /// mantissa length as varints followed by the mantissa in two's-complement little-endian.
+    /// Small floats store their IEEE bits; complex values store both parts, surds their
+    /// coefficient followed by the radicand's length and bytes, and recurring values the
+    /// length and bytes of their numerator and denominator. Kind and scale survive the
+    /// round trip exactly.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_float(&mut out, self);
+        out
+    }
+
+    /// Decodes a frame written by `to_bytes`. Fails with `INVALID_FORMAT` on truncated
+    /// input, unknown tags or trailing bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Float, i8> {
+        let (f, used) = Float::read_bytes(bytes)?;
+        if used != bytes.len() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        Ok(f)
+    }
+
+    /// Decodes the frame at the start of `bytes` and returns it with the number of bytes
+    /// consumed, for reading frames written back to back.
+    pub fn read_bytes(bytes: &[u8]) -> Result<(Float, usize), i8> {
+        let mut pos = 0;
+        let f = read_float(bytes, &mut pos, false)?;
+        Ok((f, pos))
+    }
+}
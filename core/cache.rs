@@ -0,0 +1,34 @@
+//! Values the arithmetic builds over and over, created once on first use and shared.
+//!
+//! Complex arithmetic in particular used to rebuild `0`, `2` and `ln 10` on every
+//! call; the operations now clone these instead. The integers `-128..=127` each have one
+//! shared `Int` too, which `Int::interned` lends out.
+
+#[cfg(not(feature = "std"))]
+use crate::alloc_prelude::*;
+use crate::foundation::Int;
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+#[cfg(feature = "std")]
+use once_cell::sync::Lazy;
+#[cfg(not(feature = "std"))]
+use spin::Lazy;
+
+/// Decimals of `ln 10` used by the complex `log10`.
+pub(crate) const LN_10_DECIMALS: u64 = 136;
+
+pub(crate) static ZERO: Lazy<BigDecimal> = Lazy::new(|| BigDecimal::from(0));
+pub(crate) static ONE: Lazy<BigDecimal> = Lazy::new(|| BigDecimal::from(1));
+pub(crate) static TWO: Lazy<BigDecimal> = Lazy::new(|| BigDecimal::from(2));
+
+/// `ln 10` to `LN_10_DECIMALS` decimals.
+pub(crate) static LN_10: Lazy<BigDecimal> = Lazy::new(|| crate::consts::ln10(LN_10_DECIMALS));
+
+static SMALL_INTS: Lazy<Vec<Int>> = Lazy::new(|| {
+    (i8::MIN..=i8::MAX).map(|v| Int::Big(BigInt::from(v))).collect()
+});
+
+/// The shared `Int` for `value`.
+pub(crate) fn small_int(value: i8) -> &'static Int {
+    &SMALL_INTS[(i16::from(value) - i16::from(i8::MIN)) as usize]
+}
@@ -0,0 +1,203 @@
+//! Parsing of huge decimal integers.
+//!
+//! `num-bigint` reads a decimal string by multiplying every 19-digit chunk into one
+//! accumulator, which is quadratic: ten million digits take minutes. Here the digits are
+//! split in halves until the pieces are short, the pieces are read that way, and the halves
+//! are joined as `high * 10^len(low) + low`, so the work goes into a few big multiplications
+//! that `ntt` makes fast. The default `Backend::parse_decimal` uses it from
+//! `THRESHOLD_DIGITS` digits on.
+//!
+//! `Int::from_reader` reads a number from a stream in blocks and joins the blocks the same
+//! way, so the text itself is never held in memory.
+
+use crate::alloc_prelude::*;
+use crate::backend::backend;
+#[cfg(feature = "std")]
+use crate::foundation::Int;
+#[cfg(feature = "std")]
+use crate::math::ERR_INVALID_FORMAT;
+use alloc::collections::BTreeMap;
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+/// Length, in digits, from which `parse_digits` splits instead of reading chunk by chunk.
+pub const THRESHOLD_DIGITS: usize = 2_000;
+/// Digits per chunk; `10^19` is the largest power of ten in a `u64`.
+const CHUNK_DIGITS: usize = 19;
+/// Digits `Int::from_reader` reads before turning them into a number.
+#[cfg(feature = "std")]
+const BLOCK_DIGITS: usize = 1 << 16;
+
+/// Powers of ten by exponent, computed once per parse.
+#[derive(Default)]
+struct Powers(BTreeMap<usize, BigInt>);
+
+impl Powers {
+    fn get(&mut self, k: usize) -> BigInt {
+        if let Some(p) = self.0.get(&k) {
+            return p.clone();
+        }
+        let p = if k <= CHUNK_DIGITS {
+            BigInt::from(10u64.pow(k as u32))
+        } else {
+            let half = self.get(k / 2);
+            let square = backend().mul(&half, &half);
+            if k % 2 == 1 { square * 10u32 } else { square }
+        };
+        self.0.insert(k, p.clone());
+        p
+    }
+
+    /// `high * 10^low_len + low`.
+    fn join(&mut self, high: &BigInt, low: BigInt, low_len: usize) -> BigInt {
+        if high.is_zero() {
+            return low;
+        }
+        backend().mul(high, &self.get(low_len)) + low
+    }
+}
+
+/// The value of a short run of ASCII digits, 19 at a time.
+fn parse_chunks(digits: &[u8]) -> BigInt {
+    let mut acc = BigInt::zero();
+    let head = digits.len() % CHUNK_DIGITS;
+    for chunk in core::iter::once(&digits[..head]).chain(digits[head..].chunks(CHUNK_DIGITS)) {
+        let value = chunk.iter().fold(0u64, |v, d| v * 10 + u64::from(d - b'0'));
+        acc = acc * 10u64.pow(chunk.len() as u32) + value;
+    }
+    acc
+}
+
+fn parse_split(digits: &[u8], powers: &mut Powers) -> BigInt {
+    if digits.len() < THRESHOLD_DIGITS {
+        return parse_chunks(digits);
+    }
+    let (high, low) = digits.split_at(digits.len() / 2);
+    let (high, low_value) = (parse_split(high, powers), parse_split(low, powers));
+    powers.join(&high, low_value, low.len())
+}
+
+/// The value of `digits`, which must be ASCII digits only (no sign or separators);
+/// `None` otherwise or when empty.
+pub fn parse_digits(digits: &[u8]) -> Option<BigInt> {
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(parse_split(digits, &mut Powers::default()))
+}
+
+/// What the default `Backend::parse_decimal` accepts, the same as `BigInt::from_str`: a
+/// `+` or `-`, then digits with `_` allowed anywhere but first.
+pub(crate) fn parse_signed(s: &str) -> Option<BigInt> {
+    let (negative, body) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if body.starts_with('_') {
+        return None;
+    }
+    let value = if body.contains('_') {
+        let digits: Vec<u8> = body.bytes().filter(|&b| b != b'_').collect();
+        parse_digits(&digits)?
+    } else {
+        parse_digits(body.as_bytes())?
+    };
+    Some(if negative { -value } else { value })
+}
+
+/// Blocks of digits read so far, each joined with the one before as soon as both have
+/// the same length, like the carries of a binary counter; `(value, digit count)`.
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct Blocks {
+    stack: Vec<(BigInt, usize)>,
+    powers: Powers,
+}
+
+#[cfg(feature = "std")]
+impl Blocks {
+    fn push(&mut self, digits: &[u8]) {
+        let (mut value, mut len) = (parse_split(digits, &mut self.powers), digits.len());
+        while self.stack.last().is_some_and(|&(_, top)| top == len) {
+            let (high, high_len) = self.stack.pop().unwrap();
+            value = self.powers.join(&high, value, len);
+            len += high_len;
+        }
+        self.stack.push((value, len));
+    }
+
+    fn finish(mut self) -> BigInt {
+        let Some((mut acc, mut acc_len)) = self.stack.pop() else {
+            return BigInt::zero();
+        };
+        while let Some((high, len)) = self.stack.pop() {
+            acc = self.powers.join(&high, acc, acc_len);
+            acc_len += len;
+        }
+        acc
+    }
+}
+
+#[cfg(feature = "std")]
+impl Int {
+    /// Reads a decimal integer from `reader`: optional surrounding whitespace, a `+` or
+    /// `-`, and digits with single `_` separators between them. The digits are converted
+    /// block by block as they arrive, so a file of millions of digits never has to be
+    /// loaded as text.
+    ///
+    /// Fails with `INVALID_FORMAT` for anything else (a leading, trailing or repeated `_`
+    /// included), for an empty input and when reading fails.
+    ///
+    /// ```
+    /// use imagnum::{create_int, Int};
+    ///
+    /// let text = format!("  -{}\n", "12345".repeat(1000));
+    /// assert_eq!(Int::from_reader(text.as_bytes()), Ok(create_int(text.trim())));
+    /// ```
+    pub fn from_reader<R: std::io::BufRead>(mut reader: R) -> Result<Int, i8> {
+        // 0: before the number, 1: after the sign, 2: in the digits, 3: after the number
+        let mut state = 0;
+        let mut negative = false;
+        let mut prev = 0;
+        let mut block = Vec::with_capacity(BLOCK_DIGITS);
+        let mut blocks = Blocks::default();
+        loop {
+            let buf = reader.fill_buf().map_err(|_| ERR_INVALID_FORMAT)?;
+            if buf.is_empty() {
+                break;
+            }
+            for &b in buf {
+                match (state, b) {
+                    (0 | 3, b) if b.is_ascii_whitespace() => {}
+                    (0, b'+' | b'-') => {
+                        negative = b == b'-';
+                        state = 1;
+                    }
+                    (0..=2, b'0'..=b'9') => {
+                        state = 2;
+                        block.push(b);
+                        if block.len() == BLOCK_DIGITS {
+                            blocks.push(&block);
+                            block.clear();
+                        }
+                    }
+                    (2, b'_') if prev != b'_' => {}
+                    (2, b) if b.is_ascii_whitespace() && prev != b'_' => state = 3,
+                    _ => return Err(ERR_INVALID_FORMAT),
+                }
+                prev = b;
+            }
+            let len = buf.len();
+            reader.consume(len);
+        }
+        if state < 2 || prev == b'_' {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        if !block.is_empty() {
+            blocks.push(&block);
+        }
+        let value = blocks.finish();
+        Ok(Int::Big(if negative { -value } else { value }))
+    }
+}
@@ -1,8 +1,164 @@
-use crate::foundation::{Float, FloatKind, Int, SmallFloat, SmallInt};
+use crate::alloc_prelude::*;
+use crate::foundation::{DivMode, Float, FloatKind, Int, SmallFloat, SmallInt};
 use bigdecimal::BigDecimal;
 use num_bigint::BigInt;
-use num_traits::FromPrimitive;
-use std::str::FromStr;
+use num_traits::{FromPrimitive, One, Signed, Zero};
+use core::str::FromStr;
+use core::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// Release line whose numeric semantics `Behavior::version` reproduces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BehaviorVersion {
+    /// Semantics up to 0.2.29
+    V1,
+    /// Current semantics
+    #[default]
+    V2,
+}
+
+/// What `Float` arithmetic does where IEEE 754 has a special result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArithmeticPolicy {
+    /// Fail with an error code: `INFINITE_RESULT` for `∞ - ∞`, `DIV_BY_ZERO` for `x / 0`,
+    /// `INVALID_FORMAT` for a NaN operand
+    #[default]
+    Strict,
+    /// Never fail: `x / 0` is `±Infinity` (NaN for `0 / 0`), `0` to a negative power is
+    /// `Infinity`, and every other failing `_add` / `_sub` / `_mul` / `_div` / `_modulo` /
+    /// `_pow` (`∞ - ∞`, `∞ · 0`, `x % 0`, NaN operands) gives NaN
+    Ieee,
+}
+
+/// Switches for numeric behavior that changed incompatibly between releases, so an
+/// embedder can pin old results and migrate one flag at a time.
+///
+/// ```
+/// use imagnum::compat::{self, Behavior, BehaviorVersion};
+/// use imagnum::create_int;
+///
+/// compat::set_behavior(Behavior::version(BehaviorVersion::V1));
+/// assert_eq!((create_int("7") / create_int("2")).unwrap(), create_int("4"));
+/// compat::set_behavior(Behavior::default());
+/// assert_eq!((create_int("7") / create_int("2")).unwrap(), create_int("3"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Behavior {
+    /// Rounding of `Int` `/` and `_div`. V1: `DivMode::Round`, V2: `DivMode::Trunc`
+    pub int_division: DivMode,
+    /// Exact arithmetic on two `Small(F64)` floats stays `Small(F64)`.
+    /// V1: off, every result is `Float::Big`
+    pub small_float_results: bool,
+    /// `sqrt` of an exact value that is not a perfect square gives a symbolic
+    /// `Float::Surd` instead of a rounded `Irrational`. Off in every version
+    pub symbolic_radicals: bool,
+    /// Deprecated: `&`, `|`, `^`, `!`, `<<` and `>>` on `Float` act on the IEEE bit pattern
+    /// of `Small` floats and on the unscaled digits of `Big` ones. When off they act on the
+    /// integer value and fail with `INVALID_FORMAT` for anything else; use
+    /// `Float::to_bits` for bit access. On in every version for now
+    pub float_bit_patterns: bool,
+    /// A negative base to a fractional power whose denominator is even (`(-4)^0.5`) or that
+    /// is not a small fraction gives the complex principal value instead of failing with
+    /// `INVALID_FORMAT`. V1: off
+    pub complex_powers: bool,
+    /// Errors or IEEE special values from failing `Float` arithmetic.
+    /// `ArithmeticPolicy::Strict` in every version
+    pub arithmetic_policy: ArithmeticPolicy,
+}
+
+impl Behavior {
+    pub fn version(version: BehaviorVersion) -> Self {
+        match version {
+            BehaviorVersion::V1 => Behavior {
+                int_division: DivMode::Round,
+                small_float_results: false,
+                symbolic_radicals: false,
+                float_bit_patterns: true,
+                complex_powers: false,
+                arithmetic_policy: ArithmeticPolicy::Strict,
+            },
+            BehaviorVersion::V2 => Behavior {
+                int_division: DivMode::Trunc,
+                small_float_results: true,
+                symbolic_radicals: false,
+                float_bit_patterns: true,
+                complex_powers: true,
+                arithmetic_policy: ArithmeticPolicy::Strict,
+            },
+        }
+    }
+
+    pub fn int_division(mut self, mode: DivMode) -> Self {
+        self.int_division = mode;
+        self
+    }
+
+    pub fn small_float_results(mut self, enabled: bool) -> Self {
+        self.small_float_results = enabled;
+        self
+    }
+
+    pub fn symbolic_radicals(mut self, enabled: bool) -> Self {
+        self.symbolic_radicals = enabled;
+        self
+    }
+
+    pub fn float_bit_patterns(mut self, enabled: bool) -> Self {
+        self.float_bit_patterns = enabled;
+        self
+    }
+
+    pub fn complex_powers(mut self, enabled: bool) -> Self {
+        self.complex_powers = enabled;
+        self
+    }
+
+    pub fn arithmetic_policy(mut self, policy: ArithmeticPolicy) -> Self {
+        self.arithmetic_policy = policy;
+        self
+    }
+}
+
+impl Default for Behavior {
+    fn default() -> Self {
+        Behavior::version(BehaviorVersion::default())
+    }
+}
+
+static INT_DIVISION: AtomicU8 = AtomicU8::new(0);
+static SMALL_FLOAT_RESULTS: AtomicBool = AtomicBool::new(true);
+static SYMBOLIC_RADICALS: AtomicBool = AtomicBool::new(false);
+static FLOAT_BIT_PATTERNS: AtomicBool = AtomicBool::new(true);
+static COMPLEX_POWERS: AtomicBool = AtomicBool::new(true);
+static IEEE_ARITHMETIC: AtomicBool = AtomicBool::new(false);
+
+const DIV_MODES: [DivMode; 5] = [DivMode::Trunc, DivMode::Floor, DivMode::Ceil, DivMode::Euclid, DivMode::Round];
+
+/// Selects the numeric behavior for the whole process. Defaults to `BehaviorVersion::V2`.
+pub fn set_behavior(behavior: Behavior) {
+    let div = DIV_MODES.iter().position(|m| *m == behavior.int_division).unwrap_or(0);
+    INT_DIVISION.store(div as u8, Ordering::Relaxed);
+    SMALL_FLOAT_RESULTS.store(behavior.small_float_results, Ordering::Relaxed);
+    SYMBOLIC_RADICALS.store(behavior.symbolic_radicals, Ordering::Relaxed);
+    FLOAT_BIT_PATTERNS.store(behavior.float_bit_patterns, Ordering::Relaxed);
+    COMPLEX_POWERS.store(behavior.complex_powers, Ordering::Relaxed);
+    IEEE_ARITHMETIC.store(behavior.arithmetic_policy == ArithmeticPolicy::Ieee, Ordering::Relaxed);
+}
+
+/// Returns the behavior currently in effect.
+pub fn behavior() -> Behavior {
+    Behavior {
+        int_division: DIV_MODES[INT_DIVISION.load(Ordering::Relaxed) as usize],
+        small_float_results: SMALL_FLOAT_RESULTS.load(Ordering::Relaxed),
+        symbolic_radicals: SYMBOLIC_RADICALS.load(Ordering::Relaxed),
+        float_bit_patterns: FLOAT_BIT_PATTERNS.load(Ordering::Relaxed),
+        complex_powers: COMPLEX_POWERS.load(Ordering::Relaxed),
+        arithmetic_policy: if IEEE_ARITHMETIC.load(Ordering::Relaxed) {
+            ArithmeticPolicy::Ieee
+        } else {
+            ArithmeticPolicy::Strict
+        },
+    }
+}
 
 pub fn int_to_string(i: &Int) -> String {
     match i {
@@ -28,88 +184,32 @@ pub fn int_to_string(i: &Int) -> String {
 }
 
 pub fn float_to_parts(f: &Float) -> (String, i32, bool, FloatKind) {
-    match f {
-        Float::Big(bd) => from_bigdecimal(bd),
-        Float::Irrational(bd) => {
-            let (m, e, neg, _k) = from_bigdecimal(bd);
-            (m, e, neg, FloatKind::Irrational)
-        }
-        Float::Recurring(bd) => {
-            let (m, e, neg, _k) = from_bigdecimal(bd);
-            (m, e, neg, FloatKind::Recurring)
-        }
-        Float::Small(s) => match s {
-            SmallFloat::F32(v) => {
-                let s = v.to_string();
-                match BigDecimal::from_str(&s) {
-                    Ok(bd) => from_bigdecimal(&bd),
-                    Err(_) => (String::new(), 0, v.is_sign_negative(), FloatKind::Finite),
-                }
-            }
-            SmallFloat::F64(v) => {
-                let s = v.to_string();
-                match BigDecimal::from_str(&s) {
-                    Ok(bd) => from_bigdecimal(&bd),
-                    Err(_) => (String::new(), 0, v.is_sign_negative(), FloatKind::Finite),
-                }
-            }
-        },
-        Float::NaN => (String::new(), 0, false, FloatKind::NaN),
-        Float::Infinity => (String::new(), 0, false, FloatKind::Infinity),
-        Float::NegInfinity => (String::new(), 0, true, FloatKind::NegInfinity),
-        Float::Complex(_, _) => (String::new(), 0, false, FloatKind::Complex),
-    }
-}
-
-fn from_bigdecimal(bd: &BigDecimal) -> (String, i32, bool, FloatKind) {
-    let s = bd.normalized().to_string();
-    let neg = s.starts_with('-');
-    let s = s.trim_start_matches('-');
-
-    if s == "0" || s.is_empty() {
-        return ("0".to_string(), 0, false, FloatKind::Finite);
-    }
-
-    let parts: Vec<&str> = s.split('E').collect();
-    let (base, exp_part) = if parts.len() == 2 {
-        (parts[0], parts[1])
-    } else {
-        (s, "0")
+    let (mantissa, exponent, negative, kind) = f.decompose();
+    let digits = match kind {
+        FloatKind::NaN | FloatKind::Infinity | FloatKind::NegInfinity | FloatKind::Complex => String::new(),
+        _ => crate::backend::backend().to_decimal(&mantissa.as_bigint()),
     };
-
-    let exp_from_e: i32 = exp_part.parse().unwrap_or(0);
-
-    let (mant, exp) = if let Some(dot) = base.find('.') {
-        let mantissa = base[..dot].to_string() + &base[dot + 1..];
-        let exp_decimal = -((base.len() - dot - 1) as i32);
-        (mantissa.trim_start_matches('0').to_string(), exp_decimal)
-    } else {
-        (base.trim_start_matches('0').to_string(), 0)
-    };
-
-    let final_exp = exp + exp_from_e;
-    (mant, final_exp, neg, FloatKind::Finite)
+    // saturate rather than wrap, so a huge exponent stays huge for the range checks
+    (digits, exponent.clamp(i32::MIN as i64, i32::MAX as i64) as i32, negative, kind)
 }
 
 pub fn float_is_zero(f: &Float) -> bool {
     match f {
-        Float::Big(bd) | Float::Irrational(bd) | Float::Recurring(bd) => {
-            let (m, _e, _neg, _k) = from_bigdecimal(bd);
-            m == "0"
-        }
+        Float::Big(bd) | Float::Irrational(bd) => bd.is_zero(),
+        Float::Recurring(num, _) => num.is_zero(),
         Float::Small(s) => match s {
             SmallFloat::F32(v) => *v == 0.0,
             SmallFloat::F64(v) => *v == 0.0,
         },
+        Float::Complex(re, im) => float_is_zero(re) && float_is_zero(im),
         _ => false,
     }
 }
 
 pub fn float_is_one(f: &Float) -> bool {
     match f {
-        Float::Big(bd) | Float::Irrational(bd) | Float::Recurring(bd) => {
-            bd.to_string() == "1" || bd.to_string() == "1.0"
-        }
+        Float::Big(bd) | Float::Irrational(bd) => bd.is_one(),
+        Float::Recurring(num, den) => num == den,
         Float::Small(s) => match s {
             SmallFloat::F32(v) => *v == 1.0,
             SmallFloat::F64(v) => *v == 1.0,
@@ -120,9 +220,8 @@ pub fn float_is_one(f: &Float) -> bool {
 
 pub fn float_is_neg_one(f: &Float) -> bool {
     match f {
-        Float::Big(bd) | Float::Irrational(bd) | Float::Recurring(bd) => {
-            bd.to_string() == "-1" || bd.to_string() == "-1.0"
-        }
+        Float::Big(bd) | Float::Irrational(bd) => (-bd).is_one(),
+        Float::Recurring(num, den) => -num == *den,
         Float::Small(s) => match s {
             SmallFloat::F32(v) => *v == -1.0,
             SmallFloat::F64(v) => *v == -1.0,
@@ -133,7 +232,9 @@ pub fn float_is_neg_one(f: &Float) -> bool {
 
 pub fn float_to_bigdecimal(f: &Float) -> Option<BigDecimal> {
     match f {
-        Float::Big(bd) | Float::Irrational(bd) | Float::Recurring(bd) => Some(bd.clone()),
+        Float::Big(bd) | Float::Irrational(bd) => Some(bd.clone()),
+        Float::Recurring(num, den) => Some(crate::recurring::expand(num, den)),
+        Float::Surd(c, r) => Some(crate::surd::expand(c, r)),
         Float::Small(s) => match s {
             SmallFloat::F32(v) => BigDecimal::from_f32(*v),
             SmallFloat::F64(v) => BigDecimal::from_f64(*v),
@@ -142,10 +243,32 @@ pub fn float_to_bigdecimal(f: &Float) -> Option<BigDecimal> {
     }
 }
 
+/// `bd` without trailing zeros, as `BigDecimal::normalized` gives it but without the round
+/// trip through decimal digits: only the zeros are divided out, so a value that has none
+/// costs one remainder by 10.
+pub(crate) fn normalize_decimal(bd: BigDecimal) -> BigDecimal {
+    let (mut digits, mut scale) = bd.into_bigint_and_exponent();
+    if digits.is_zero() {
+        return BigDecimal::zero();
+    }
+    for step in [16u32, 1] {
+        let chunk = BigInt::from(10u64.pow(step));
+        loop {
+            let (q, r) = num_integer::Integer::div_rem(&digits, &chunk);
+            if !r.is_zero() {
+                break;
+            }
+            digits = q;
+            scale -= i64::from(step);
+        }
+    }
+    BigDecimal::new(digits, scale)
+}
+
 pub fn int_to_parts(i: &Int) -> (String, bool, FloatKind) {
     match i {
         Int::Big(bi) => {
-            let s = bi.to_string();
+            let s = crate::backend::backend().to_decimal(bi);
             let neg = s.starts_with('-');
             let digits = s.trim_start_matches('-').to_string();
             (digits, neg, FloatKind::Finite)
@@ -199,29 +322,9 @@ pub fn int_to_bigint(i: &Int) -> BigInt {
 }
 
 pub fn make_int_from_parts(digits: String, negative: bool, _kind: FloatKind) -> Int {
-    match BigDecimal::from_str(&digits) {
-        Ok(_) => {
-            match BigInt::from_str(&digits) {
-                Ok(mut bi) => {
-                    if negative {
-                        bi = -bi
-                    };
-                    Int::Big(bi)
-                }
-                Err(_) => Int::new(),
-            }
-        }
-        Err(_) => {
-            match BigInt::from_str(&digits) {
-                Ok(mut bi) => {
-                    if negative {
-                        bi = -bi
-                    };
-                    Int::Big(bi)
-                }
-                Err(_) => Int::new(),
-            }
-        }
+    match crate::backend::backend().parse_decimal(&digits) {
+        Some(bi) => Int::Big(if negative { -bi } else { bi }),
+        None => Int::new(),
     }
 }
 
@@ -255,7 +358,7 @@ pub fn make_float_from_parts(
                 if kind == FloatKind::Irrational {
                     Float::Irrational(bd)
                 } else if kind == FloatKind::Recurring {
-                    Float::Recurring(bd)
+                    crate::recurring::from_digits(&bd)
                 } else {
                     Float::Big(bd)
                 }
@@ -270,7 +373,7 @@ pub fn make_float_from_parts(
                         if kind == FloatKind::Irrational {
                             Float::Irrational(bd)
                         } else if kind == FloatKind::Recurring {
-                            Float::Recurring(bd)
+                            crate::recurring::from_digits(&bd)
                         } else {
                             Float::Big(bd)
                         }
@@ -285,15 +388,86 @@ pub fn make_float_from_parts(
     }
 }
 
+/// Rounds the `float_to_parts` digits `mantissa · 10^exponent` to `precision` decimal
+/// places, ties away from zero, by carrying through the digit string. `Float::round` uses
+/// it for binary floats; decimal kinds round on the `BigDecimal` directly, which is faster.
+pub fn round_float_parts(mut mantissa: String, mut exponent: i32, neg: bool, precision: usize) -> Float {
+    let drop = -i64::from(exponent) - precision as i64;
+    if drop <= 0 {
+        return make_float_from_parts(mantissa, exponent, neg, FloatKind::Finite);
+    }
+    if drop as usize > mantissa.len() {
+        return make_float_from_parts("0".to_string(), 0, false, FloatKind::Finite);
+    }
+    let keep = mantissa.len() - drop as usize;
+    let round_up = mantissa.as_bytes()[keep] >= b'5';
+    mantissa.truncate(keep);
+    exponent += drop as i32;
+    if round_up {
+        let mut digits: Vec<u8> = mantissa.bytes().map(|b| b - b'0').collect();
+        let mut carry = 1;
+        for d in digits.iter_mut().rev() {
+            let sum = *d + carry;
+            *d = sum % 10;
+            carry = sum / 10;
+            if carry == 0 {
+                break;
+            }
+        }
+        if carry > 0 {
+            digits.insert(0, carry);
+        }
+        mantissa = digits.into_iter().map(|d| (d + b'0') as char).collect();
+    }
+    let trimmed = mantissa.trim_start_matches('0');
+    if trimmed.is_empty() {
+        return make_float_from_parts("0".to_string(), 0, false, FloatKind::Finite);
+    }
+    make_float_from_parts(trimmed.to_string(), exponent, neg, FloatKind::Finite)
+}
+
+#[deprecated(note = "an Int is never NaN; use IntOrSpecial for an integer that can be")]
 pub fn int_is_nan(_i: &Int) -> bool {
     false
 }
+#[deprecated(note = "an Int is never infinite; use IntOrSpecial for an integer that can be")]
 pub fn int_is_infinite(_i: &Int) -> bool {
     false
 }
 pub fn float_kind(f: &Float) -> FloatKind {
-    float_to_parts(f).3
+    match f {
+        Float::Big(_) => FloatKind::Finite,
+        Float::Irrational(_) | Float::Surd(_, _) => FloatKind::Irrational,
+        Float::Recurring(_, _) => FloatKind::Recurring,
+        Float::Small(s) => {
+            let v = match s {
+                SmallFloat::F32(v) => f64::from(*v),
+                SmallFloat::F64(v) => *v,
+            };
+            if v.is_nan() {
+                FloatKind::NaN
+            } else if v == f64::INFINITY {
+                FloatKind::Infinity
+            } else if v == f64::NEG_INFINITY {
+                FloatKind::NegInfinity
+            } else {
+                FloatKind::Finite
+            }
+        }
+        Float::NaN => FloatKind::NaN,
+        Float::Infinity => FloatKind::Infinity,
+        Float::NegInfinity => FloatKind::NegInfinity,
+        Float::Complex(_, _) => FloatKind::Complex,
+    }
 }
 pub fn float_is_negative(f: &Float) -> bool {
-    float_to_parts(f).2
+    match f {
+        Float::Big(bd) | Float::Irrational(bd) => bd.is_negative(),
+        Float::Recurring(num, _) => num.is_negative(),
+        Float::Surd(c, _) => c.is_negative(),
+        Float::Small(SmallFloat::F32(v)) => *v < 0.0,
+        Float::Small(SmallFloat::F64(v)) => *v < 0.0,
+        Float::NegInfinity => true,
+        _ => false,
+    }
 }
@@ -0,0 +1,217 @@
+use crate::alloc_prelude::*;
+use crate::compat::int_to_bigint;
+use crate::foundation::{Float, Int};
+use crate::math::{ERR_DIV_BY_ZERO, ERR_INVALID_FORMAT};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::Zero;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Complex number with unboxed parts of a single type.
+///
+/// `Complex<Int>` (alias `ComplexInt`) is the ring of Gaussian integers: every operation is
+/// exact, and `/` and `%` perform Euclidean division, so `a == (a / b)? * b + a % b` with
+/// `(a % b).norm() < b.norm()`. `Complex<Float>` (alias `ComplexFloat`) converts to and
+/// from `Float::Complex`.
+///
+/// ```
+/// use imagnum::{create_int, ComplexInt};
+///
+/// let a = ComplexInt::new(create_int("7"), create_int("2"));
+/// let b = ComplexInt::new(create_int("2"), create_int("-1"));
+/// let (q, r) = a.div_rem(&b).unwrap();
+/// assert_eq!(((q * &b).unwrap() + r).unwrap(), a);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+/// Gaussian integer
+pub type ComplexInt = Complex<Int>;
+pub type ComplexFloat = Complex<Float>;
+
+impl<T> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl<T: Display> Display for Complex<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let im = self.im.to_string();
+        match im.strip_prefix('-') {
+            Some(abs) => write!(f, "{} - {}i", self.re, abs),
+            None => write!(f, "{} + {}i", self.re, im),
+        }
+    }
+}
+
+/// Operations shared by every part type, built on the parts' `_add` / `_sub` / `_mul`.
+macro_rules! complex_ops {
+    ($t:ty) => {
+        impl Complex<$t> {
+            pub fn conj(&self) -> Self {
+                Complex::new(self.re.clone(), -self.im.clone())
+            }
+
+            /// `re² + im²`
+            pub fn norm(&self) -> Result<$t, i8> {
+                self.re._mul(&self.re)?._add(&self.im._mul(&self.im)?)
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.re.is_zero() && self.im.is_zero()
+            }
+
+            pub fn _add(&self, other: &Self) -> Result<Self, i8> {
+                Ok(Complex::new(self.re._add(&other.re)?, self.im._add(&other.im)?))
+            }
+
+            pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+                Ok(Complex::new(self.re._sub(&other.re)?, self.im._sub(&other.im)?))
+            }
+
+            pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
+                let re = self.re._mul(&other.re)?._sub(&self.im._mul(&other.im)?)?;
+                let im = self.re._mul(&other.im)?._add(&self.im._mul(&other.re)?)?;
+                Ok(Complex::new(re, im))
+            }
+        }
+
+        impl Neg for Complex<$t> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Complex::new(-self.re, -self.im)
+            }
+        }
+
+        complex_ops!(@op $t, Add, add, _add);
+        complex_ops!(@op $t, Sub, sub, _sub);
+        complex_ops!(@op $t, Mul, mul, _mul);
+        complex_ops!(@op $t, Div, div, _div);
+    };
+    (@op $t:ty, $trait:ident, $method:ident, $inner:ident) => {
+        impl $trait for Complex<$t> {
+            type Output = Result<Self, i8>;
+            fn $method(self, other: Self) -> Result<Self, i8> {
+                self.$inner(&other)
+            }
+        }
+
+        impl<'a> $trait<&'a Complex<$t>> for Complex<$t> {
+            type Output = Result<Complex<$t>, i8>;
+            fn $method(self, other: &'a Complex<$t>) -> Result<Complex<$t>, i8> {
+                self.$inner(other)
+            }
+        }
+    };
+}
+
+complex_ops!(Int);
+complex_ops!(Float);
+
+impl Complex<Int> {
+    fn as_bigints(&self) -> (BigInt, BigInt) {
+        (int_to_bigint(&self.re), int_to_bigint(&self.im))
+    }
+
+    /// Euclidean division: the quotient is `self / other` with both parts rounded to the
+    /// nearest integer (halves toward +∞), so the remainder's norm is at most half of
+    /// `other.norm()`. Fails with `DIV_BY_ZERO` when `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> Result<(Self, Self), i8> {
+        let (a, b) = self.as_bigints();
+        let (c, d) = other.as_bigints();
+        let n = &c * &c + &d * &d;
+        if n.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        // self * conj(other) / norm(other), rounded part by part
+        let two_n = &n + &n;
+        let round = |x: BigInt| (&x + &x + &n).div_floor(&two_n);
+        let q = Complex::new(
+            Int::Big(round(&a * &c + &b * &d)),
+            Int::Big(round(&b * &c - &a * &d)),
+        );
+        let r = self._sub(&q._mul(other)?)?;
+        Ok((q, r))
+    }
+
+    pub fn _div(&self, other: &Self) -> Result<Self, i8> {
+        Ok(self.div_rem(other)?.0)
+    }
+
+    pub fn _modulo(&self, other: &Self) -> Result<Self, i8> {
+        Ok(self.div_rem(other)?.1)
+    }
+
+    /// `Float::Complex` with the same parts.
+    pub fn to_float(&self) -> Result<Float, i8> {
+        Ok(Complex::new(self.re.to_float()?, self.im.to_float()?).into())
+    }
+
+    /// Reads a `Float` whose parts are both integers; a real value gets a zero imaginary
+    /// part. Fails with `INVALID_FORMAT` otherwise.
+    pub fn from_float(value: &Float) -> Result<Self, i8> {
+        let parts = Complex::<Float>::from_float(value)?;
+        Ok(Complex::new(parts.re.to_int()?, parts.im.to_int()?))
+    }
+}
+
+impl Rem for Complex<Int> {
+    type Output = Result<Self, i8>;
+    fn rem(self, other: Self) -> Result<Self, i8> {
+        self._modulo(&other)
+    }
+}
+
+impl<'a> Rem<&'a Complex<Int>> for Complex<Int> {
+    type Output = Result<Complex<Int>, i8>;
+    fn rem(self, other: &'a Complex<Int>) -> Result<Complex<Int>, i8> {
+        self._modulo(other)
+    }
+}
+
+impl Complex<Float> {
+    /// `self · conj(other) / norm(other)`. Fails with `DIV_BY_ZERO` when `other` is zero.
+    pub fn _div(&self, other: &Self) -> Result<Self, i8> {
+        let n = other.norm()?;
+        if n.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        let num = self._mul(&other.conj())?;
+        Ok(Complex::new(num.re._div(&n)?, num.im._div(&n)?))
+    }
+
+    /// Splits a `Float` into its parts; a real value gets a zero imaginary part.
+    /// Fails with `INVALID_FORMAT` for NaN.
+    pub fn from_float(value: &Float) -> Result<Self, i8> {
+        match value {
+            Float::Complex(re, im) => Ok(Complex::new(*re.clone(), *im.clone())),
+            Float::NaN => Err(ERR_INVALID_FORMAT),
+            _ => Ok(Complex::new(value.clone(), Float::Big(crate::cache::ZERO.clone()))),
+        }
+    }
+}
+
+impl From<Complex<Float>> for Float {
+    fn from(c: Complex<Float>) -> Float {
+        Float::Complex(Box::new(c.re), Box::new(c.im))
+    }
+}
+
+impl TryFrom<Float> for Complex<Float> {
+    type Error = i8;
+    fn try_from(value: Float) -> Result<Self, i8> {
+        Complex::<Float>::from_float(&value)
+    }
+}
+
+impl TryFrom<Float> for Complex<Int> {
+    type Error = i8;
+    fn try_from(value: Float) -> Result<Self, i8> {
+        Complex::<Int>::from_float(&value)
+    }
+}
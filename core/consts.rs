@@ -0,0 +1,290 @@
+#[cfg(not(feature = "std"))]
+use crate::alloc_prelude::*;
+use crate::foundation::Float;
+use bigdecimal::{BigDecimal, Context};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::Zero;
+use core::num::NonZeroU64;
+#[cfg(not(feature = "std"))]
+use spin::Mutex;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+/// Exact value of the form `(num / den) * sqrt(radicand)`.
+///
+/// `sqrt(3)/2` is `ExactValue { num: 1, den: 2, radicand: 3 }`, `-1` is
+/// `ExactValue { num: -1, den: 1, radicand: 1 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExactValue {
+    pub num: i64,
+    pub den: u64,
+    pub radicand: u64,
+}
+
+impl ExactValue {
+    pub const fn new(num: i64, den: u64, radicand: u64) -> Self {
+        ExactValue { num, den, radicand }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// `true` when the value is rational (no square root left)
+    pub fn is_rational(&self) -> bool {
+        self.num == 0 || self.radicand == 1
+    }
+
+    /// Evaluates the value as a Float. Rational values are exact `Float::Big`, values
+    /// with a square root are `Float::Irrational` with `precision` significant digits.
+    pub fn to_float(&self, precision: u64) -> Float {
+        let ratio = BigDecimal::from(self.num) / BigDecimal::from(self.den);
+        if self.is_rational() {
+            return Float::Big(ratio.normalized());
+        }
+        let prec = NonZeroU64::new(precision.max(1) + 10).unwrap();
+        let ctx = Context::default().with_precision(prec);
+        let root = BigDecimal::from(self.radicand)
+            .sqrt_with_context(&ctx)
+            .unwrap_or_else(|| BigDecimal::from(0));
+        let value = (ratio * root).with_prec(precision.max(1));
+        Float::Irrational(value.normalized())
+    }
+}
+
+/// Exact sine, cosine and tangent of the angle `(num / den) * π`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrigEntry {
+    pub num: i64,
+    pub den: u64,
+    pub sin: ExactValue,
+    pub cos: ExactValue,
+    /// `None` where the tangent is undefined (π/2, 3π/2)
+    pub tan: Option<ExactValue>,
+}
+
+const fn v(num: i64, den: u64, radicand: u64) -> ExactValue {
+    ExactValue::new(num, den, radicand)
+}
+
+const fn entry(num: i64, den: u64, sin: ExactValue, cos: ExactValue, tan: Option<ExactValue>) -> TrigEntry {
+    TrigEntry { num, den, sin, cos, tan }
+}
+
+/// Multiples of π/6 and π/4 in [0, 2π), with angles as reduced fractions of π
+static TRIG_TABLE: [TrigEntry; 16] = [
+    entry(0, 1, v(0, 1, 1), v(1, 1, 1), Some(v(0, 1, 1))),
+    entry(1, 6, v(1, 2, 1), v(1, 2, 3), Some(v(1, 3, 3))),
+    entry(1, 4, v(1, 2, 2), v(1, 2, 2), Some(v(1, 1, 1))),
+    entry(1, 3, v(1, 2, 3), v(1, 2, 1), Some(v(1, 1, 3))),
+    entry(1, 2, v(1, 1, 1), v(0, 1, 1), None),
+    entry(2, 3, v(1, 2, 3), v(-1, 2, 1), Some(v(-1, 1, 3))),
+    entry(3, 4, v(1, 2, 2), v(-1, 2, 2), Some(v(-1, 1, 1))),
+    entry(5, 6, v(1, 2, 1), v(-1, 2, 3), Some(v(-1, 3, 3))),
+    entry(1, 1, v(0, 1, 1), v(-1, 1, 1), Some(v(0, 1, 1))),
+    entry(7, 6, v(-1, 2, 1), v(-1, 2, 3), Some(v(1, 3, 3))),
+    entry(5, 4, v(-1, 2, 2), v(-1, 2, 2), Some(v(1, 1, 1))),
+    entry(4, 3, v(-1, 2, 3), v(-1, 2, 1), Some(v(1, 1, 3))),
+    entry(3, 2, v(-1, 1, 1), v(0, 1, 1), None),
+    entry(5, 3, v(-1, 2, 3), v(1, 2, 1), Some(v(-1, 1, 3))),
+    entry(7, 4, v(-1, 2, 2), v(1, 2, 2), Some(v(-1, 1, 1))),
+    entry(11, 6, v(-1, 2, 1), v(1, 2, 3), Some(v(-1, 3, 3))),
+];
+
+/// Table of exact trig values at the special angles (multiples of π/6 and π/4 in [0, 2π)).
+///
+/// Symbolic layers above the crate can use it to keep `sin(π/3)` as `√3/2` instead of
+/// a truncated decimal.
+pub fn trig_table() -> &'static [TrigEntry] {
+    &TRIG_TABLE
+}
+
+/// Looks up the exact trig values of `(num / den) * π`, reducing the angle modulo 2π.
+/// Returns `None` if the angle is not one of the special angles.
+pub fn trig_lookup(num: i64, den: u64) -> Option<&'static TrigEntry> {
+    if den == 0 {
+        return None;
+    }
+    let den_i = den as i128;
+    let num = (num as i128).mod_floor(&(2 * den_i));
+    let g = num.gcd(&den_i);
+    let (num, den) = ((num / g) as i64, (den_i / g) as u64);
+    TRIG_TABLE.iter().find(|e| e.num == num && e.den == den)
+}
+
+/// Largest precision computed so far for one constant, as `value * 10^digits`, so repeated
+/// calls (trig reduction, the CLI's named constants) do not redo the series.
+struct ConstCache(Mutex<Option<(u64, BigInt)>>);
+
+impl ConstCache {
+    const fn new() -> Self {
+        ConstCache(Mutex::new(None))
+    }
+
+    /// `compute(digits)` truncated, reusing a longer cached expansion when there is one.
+    fn get(&self, digits: u64, compute: fn(u64) -> BigInt) -> BigInt {
+        #[cfg(feature = "std")]
+        let mut cache = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        #[cfg(not(feature = "std"))]
+        let mut cache = self.0.lock();
+        if let Some((cached_digits, value)) = cache.as_ref()
+            && *cached_digits >= digits
+        {
+            return value / BigInt::from(10u32).pow((cached_digits - digits) as u32);
+        }
+        let value = compute(digits);
+        *cache = Some((digits, value.clone()));
+        value
+    }
+}
+
+static PI_CACHE: ConstCache = ConstCache::new();
+static E_CACHE: ConstCache = ConstCache::new();
+static PHI_CACHE: ConstCache = ConstCache::new();
+static LN2_CACHE: ConstCache = ConstCache::new();
+static LN10_CACHE: ConstCache = ConstCache::new();
+static SQRT2_CACHE: ConstCache = ConstCache::new();
+static EULER_GAMMA_CACHE: ConstCache = ConstCache::new();
+
+/// Extra digits carried through the series and dropped at the end.
+const GUARD: u64 = 10;
+
+fn pow10(digits: u64) -> BigInt {
+    BigInt::from(10u32).pow(digits as u32)
+}
+
+/// `arctan(1/n) * 10^(digits + guard)` as a fixed-point integer.
+fn arctan_inv(n: u32, one: &BigInt) -> BigInt {
+    let n_sq = BigInt::from(n) * n;
+    let mut power = one / n;
+    let mut sum = power.clone();
+    let mut k = 1u32;
+    while !power.is_zero() {
+        power /= &n_sq;
+        let term = &power / (2 * k + 1);
+        if k % 2 == 1 { sum -= term } else { sum += term }
+        k += 1;
+    }
+    sum
+}
+
+/// `π * 10^digits`, truncated, from Machin's formula `π = 16 atan(1/5) - 4 atan(1/239)`.
+pub(crate) fn pi_scaled(digits: u64) -> BigInt {
+    PI_CACHE.get(digits, |digits| {
+        let one = pow10(digits + GUARD);
+        (arctan_inv(5, &one) * 16u32 - arctan_inv(239, &one) * 4u32) / pow10(GUARD)
+    })
+}
+
+/// `e * 10^digits`, truncated, from `e = Σ 1/k!`.
+fn e_scaled(digits: u64) -> BigInt {
+    E_CACHE.get(digits, |digits| {
+        let mut term = pow10(digits + GUARD);
+        let mut sum = BigInt::zero();
+        let mut k = 0u32;
+        while !term.is_zero() {
+            sum += &term;
+            k += 1;
+            term /= k;
+        }
+        sum / pow10(GUARD)
+    })
+}
+
+/// `atanh(1/n) * 10^(digits + guard)` as a fixed-point integer.
+fn atanh_inv(n: u32, one: &BigInt) -> BigInt {
+    let n_sq = BigInt::from(n) * n;
+    let mut power = one / n;
+    let mut sum = BigInt::zero();
+    let mut k = 0u32;
+    while !power.is_zero() {
+        sum += &power / (2 * k + 1);
+        power /= &n_sq;
+        k += 1;
+    }
+    sum
+}
+
+/// `ln 2 * 10^digits`, truncated, from `ln 2 = 2 atanh(1/3)`.
+fn ln2_scaled(digits: u64) -> BigInt {
+    LN2_CACHE.get(digits, |digits| atanh_inv(3, &pow10(digits + GUARD)) * 2u32 / pow10(GUARD))
+}
+
+/// `ln 10 * 10^digits`, truncated, from `ln 10 = 3 ln 2 + ln(5/4) = 6 atanh(1/3) + 2 atanh(1/9)`.
+fn ln10_scaled(digits: u64) -> BigInt {
+    LN10_CACHE.get(digits, |digits| {
+        let one = pow10(digits + GUARD);
+        (atanh_inv(3, &one) * 6u32 + atanh_inv(9, &one) * 2u32) / pow10(GUARD)
+    })
+}
+
+/// `√2 * 10^digits`, truncated; exact via the integer square root.
+fn sqrt2_scaled(digits: u64) -> BigInt {
+    SQRT2_CACHE.get(digits, |digits| (pow10(2 * digits) * 2u32).sqrt())
+}
+
+/// `γ * 10^digits`, truncated, from the Brent-McMillan formula `γ ≈ A / B` with
+/// `B = Σ (N^k / k!)²` and `A = Σ (N^k / k!)² (H_k - ln N)`, whose error is about `e^(-4N)`.
+/// `N` is a power of two so `ln N` comes from `ln 2`.
+fn euler_gamma_scaled(digits: u64) -> BigInt {
+    EULER_GAMMA_CACHE.get(digits, |digits| {
+        let one = pow10(digits + GUARD);
+        let needed = ((digits + GUARD) as f64 * core::f64::consts::LN_10 / 4.0).ceil() as u64 + 1;
+        let log2_n = 64 - (needed - 1).leading_zeros();
+        let n_sq = BigInt::from(1u32) << (2 * log2_n);
+        // A_k = (A_(k-1) N² / k + B_k) / k, B_k = B_(k-1) N² / k²
+        let mut a = -(ln2_scaled(digits + GUARD) * log2_n);
+        let mut b = one.clone();
+        let (mut sum_a, mut sum_b) = (a.clone(), b.clone());
+        let mut k = 0u64;
+        while !a.is_zero() || !b.is_zero() {
+            k += 1;
+            b = &b * &n_sq / (k * k);
+            a = (&a * &n_sq / k + &b) / k;
+            sum_a += &a;
+            sum_b += &b;
+        }
+        sum_a * one / sum_b / pow10(GUARD)
+    })
+}
+
+/// `φ * 10^digits`, truncated, as `(10^digits + ⌊√5 * 10^digits⌋) / 2`, which is exact.
+fn phi_scaled(digits: u64) -> BigInt {
+    PHI_CACHE.get(digits, |digits| (pow10(digits) + (pow10(2 * digits) * 5u32).sqrt()) / 2u32)
+}
+
+/// π truncated to `decimals` decimal places.
+pub fn pi(decimals: u64) -> BigDecimal {
+    BigDecimal::new(pi_scaled(decimals), decimals as i64)
+}
+
+/// Euler's number `e` truncated to `decimals` decimal places.
+pub fn e(decimals: u64) -> BigDecimal {
+    BigDecimal::new(e_scaled(decimals), decimals as i64)
+}
+
+/// The golden ratio `(1 + √5) / 2` truncated to `decimals` decimal places.
+pub fn phi(decimals: u64) -> BigDecimal {
+    BigDecimal::new(phi_scaled(decimals), decimals as i64)
+}
+
+/// `ln 2` truncated to `decimals` decimal places.
+pub fn ln2(decimals: u64) -> BigDecimal {
+    BigDecimal::new(ln2_scaled(decimals), decimals as i64)
+}
+
+/// `ln 10` truncated to `decimals` decimal places.
+pub fn ln10(decimals: u64) -> BigDecimal {
+    BigDecimal::new(ln10_scaled(decimals), decimals as i64)
+}
+
+/// `√2` truncated to `decimals` decimal places.
+pub fn sqrt2(decimals: u64) -> BigDecimal {
+    BigDecimal::new(sqrt2_scaled(decimals), decimals as i64)
+}
+
+/// The Euler-Mascheroni constant `γ` truncated to `decimals` decimal places.
+pub fn euler_gamma(decimals: u64) -> BigDecimal {
+    BigDecimal::new(euler_gamma_scaled(decimals), decimals as i64)
+}
@@ -0,0 +1,250 @@
+use crate::alloc_prelude::*;
+use crate::foundation::{Float, Int, SmallFloat};
+use crate::math::ERR_INVALID_FORMAT;
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+use alloc::collections::VecDeque;
+use core::str::FromStr;
+
+/// Pieces at or below this many digits are converted with `to_str_radix`;
+/// larger ones are split in half first.
+const LEAF_DIGITS: u64 = 256;
+
+/// Most-significant-first iterator over the digits of an integer's magnitude,
+/// returned by `Int::digits`. Each item is a digit value in `0..radix`.
+///
+/// Power-of-two radixes are read straight from the bits. Other radixes split the
+/// value by powers of the radix on demand, so only one small chunk is ever rendered
+/// to a string at a time.
+pub struct IntDigits {
+    radix: u32,
+    inner: IntDigitsInner,
+}
+
+enum IntDigitsInner {
+    Bits {
+        value: BigInt,
+        bits_per_digit: u64,
+        remaining: u64,
+    },
+    Chunks {
+        /// Pieces still to emit, last one first. `Some(width)` pieces are zero-padded.
+        stack: Vec<(BigInt, Option<u64>)>,
+        chunk: Vec<u8>,
+        pos: usize,
+    },
+}
+
+impl IntDigits {
+    pub(crate) fn new(value: BigInt, radix: u32) -> Result<Self, i8> {
+        if !(2..=36).contains(&radix) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let value = value.abs();
+        let inner = if radix.is_power_of_two() {
+            let bits_per_digit = radix.trailing_zeros() as u64;
+            let remaining = value.bits().div_ceil(bits_per_digit).max(1);
+            IntDigitsInner::Bits { value, bits_per_digit, remaining }
+        } else {
+            IntDigitsInner::Chunks { stack: vec![(value, None)], chunk: Vec::new(), pos: 0 }
+        };
+        Ok(IntDigits { radix, inner })
+    }
+
+    /// Upper estimate of the number of base-`radix` digits of `v`.
+    fn estimate_digits(v: &BigInt, radix: u32) -> u64 {
+        ((v.bits() as f64) / (radix as f64).log2()).ceil() as u64 + 1
+    }
+
+    fn render(value: &BigInt, width: Option<u64>, radix: u32) -> Vec<u8> {
+        let digits: Vec<u8> = value
+            .to_str_radix(radix)
+            .bytes()
+            .map(|b| (b as char).to_digit(36).unwrap_or(0) as u8)
+            .collect();
+        match width {
+            Some(w) if (w as usize) > digits.len() => {
+                let mut padded = vec![0u8; w as usize - digits.len()];
+                padded.extend(digits);
+                padded
+            }
+            _ => digits,
+        }
+    }
+}
+
+impl Iterator for IntDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let radix = self.radix;
+        match &mut self.inner {
+            IntDigitsInner::Bits { value, bits_per_digit, remaining } => {
+                if *remaining == 0 {
+                    return None;
+                }
+                *remaining -= 1;
+                let start = *remaining * *bits_per_digit;
+                let mut digit = 0u8;
+                for i in (0..*bits_per_digit).rev() {
+                    digit = (digit << 1) | value.bit(start + i) as u8;
+                }
+                Some(digit)
+            }
+            IntDigitsInner::Chunks { stack, chunk, pos } => loop {
+                if *pos < chunk.len() {
+                    *pos += 1;
+                    return Some(chunk[*pos - 1]);
+                }
+                let (value, width) = stack.pop()?;
+                let size = width.unwrap_or_else(|| IntDigits::estimate_digits(&value, radix));
+                if size <= LEAF_DIGITS || value.is_zero() {
+                    *chunk = IntDigits::render(&value, width, radix);
+                    *pos = 0;
+                    continue;
+                }
+                let low_width = size / 2;
+                let (high, low) = num_integer::Integer::div_rem(&value, &BigInt::from(radix).pow(low_width as u32));
+                stack.push((low, Some(low_width)));
+                if width.is_some() || !high.is_zero() {
+                    stack.push((high, width.map(|w| w - low_width)));
+                }
+            },
+        }
+    }
+}
+
+/// Item yielded by `Float::decimal_digits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalDigit {
+    Digit(u8),
+    /// The decimal point
+    Point,
+    /// Marks where the repeating part of a `Recurring` value begins;
+    /// the repetend follows once.
+    RepetendStart,
+}
+
+enum Segment {
+    Digits(IntDigits),
+    Zeros(u64),
+    Point,
+    Tokens(alloc::vec::IntoIter<DecimalDigit>),
+}
+
+/// Iterator over the decimal digits of a `Float`'s magnitude, returned by
+/// `Float::decimal_digits`. Integer-valued floats yield no `Point`.
+pub struct DecimalDigits {
+    segments: VecDeque<Segment>,
+}
+
+impl DecimalDigits {
+    pub(crate) fn new(float: &Float) -> Result<Self, i8> {
+        let bd = match float {
+            Float::Big(bd) | Float::Irrational(bd) => bd.normalized(),
+            Float::Small(SmallFloat::F64(v)) if v.is_finite() => {
+                BigDecimal::from_str(&v.to_string()).map_err(|_| ERR_INVALID_FORMAT)?
+            }
+            Float::Small(SmallFloat::F32(v)) if v.is_finite() => {
+                BigDecimal::from_str(&v.to_string()).map_err(|_| ERR_INVALID_FORMAT)?
+            }
+            Float::Recurring(_, _) => {
+                // the repetend as Display marks it
+                let tokens: Vec<DecimalDigit> = float
+                    .to_string()
+                    .chars()
+                    .filter_map(|c| match c {
+                        '.' => Some(DecimalDigit::Point),
+                        '(' => Some(DecimalDigit::RepetendStart),
+                        c => c.to_digit(10).map(|d| DecimalDigit::Digit(d as u8)),
+                    })
+                    .collect();
+                let mut segments = VecDeque::new();
+                segments.push_back(Segment::Tokens(tokens.into_iter()));
+                return Ok(DecimalDigits { segments });
+            }
+            _ => return Err(ERR_INVALID_FORMAT),
+        };
+
+        let (unscaled, scale) = bd.as_bigint_and_scale();
+        let unscaled = unscaled.abs();
+        let mut segments = VecDeque::new();
+        if scale <= 0 || unscaled.is_zero() {
+            segments.push_back(Segment::Digits(IntDigits::new(unscaled.clone(), 10)?));
+            if scale < 0 && !unscaled.is_zero() {
+                segments.push_back(Segment::Zeros((-scale) as u64));
+            }
+            return Ok(DecimalDigits { segments });
+        }
+
+        let scale = scale as u64;
+        let divisor = BigInt::from(10u32).pow(scale as u32);
+        let (int_part, frac_part) = num_integer::Integer::div_rem(&unscaled, &divisor);
+        segments.push_back(Segment::Digits(IntDigits::new(int_part, 10)?));
+        segments.push_back(Segment::Point);
+        // normalized() stripped trailing zeros, so the fraction is non-zero here
+        segments.push_back(Segment::Zeros(scale - decimal_digit_count(&frac_part)));
+        segments.push_back(Segment::Digits(IntDigits::new(frac_part, 10)?));
+        Ok(DecimalDigits { segments })
+    }
+}
+
+/// Exact number of decimal digits of a positive `v`.
+fn decimal_digit_count(v: &BigInt) -> u64 {
+    let mut n = IntDigits::estimate_digits(v, 10);
+    let ten = BigInt::from(10u32);
+    while n > 1 && ten.pow((n - 1) as u32) > *v {
+        n -= 1;
+    }
+    n
+}
+
+impl Iterator for DecimalDigits {
+    type Item = DecimalDigit;
+
+    fn next(&mut self) -> Option<DecimalDigit> {
+        loop {
+            match self.segments.front_mut()? {
+                Segment::Digits(digits) => {
+                    if let Some(d) = digits.next() {
+                        return Some(DecimalDigit::Digit(d));
+                    }
+                }
+                Segment::Zeros(n) => {
+                    if *n > 0 {
+                        *n -= 1;
+                        return Some(DecimalDigit::Digit(0));
+                    }
+                }
+                Segment::Point => {
+                    self.segments.pop_front();
+                    return Some(DecimalDigit::Point);
+                }
+                Segment::Tokens(tokens) => {
+                    if let Some(t) = tokens.next() {
+                        return Some(t);
+                    }
+                }
+            }
+            self.segments.pop_front();
+        }
+    }
+}
+
+impl Int {
+    /// Lazily yields the digits of `|self|` in `radix` (2..=36), most significant first.
+    /// Fails with `INVALID_FORMAT` for an unsupported radix.
+    pub fn digits(&self, radix: u32) -> Result<IntDigits, i8> {
+        IntDigits::new(crate::compat::int_to_bigint(self), radix)
+    }
+}
+
+impl Float {
+    /// Lazily yields the decimal digits of `|self|`, with `DecimalDigit::Point` at the
+    /// decimal point and, for recurring values, `DecimalDigit::RepetendStart` before the
+    /// repeating digits. Fails with `INVALID_FORMAT` for NaN, infinities and complex values.
+    pub fn decimal_digits(&self) -> Result<DecimalDigits, i8> {
+        DecimalDigits::new(self)
+    }
+}
@@ -0,0 +1,715 @@
+use crate::alloc_prelude::*;
+use crate::consts;
+use crate::foundation::{Float, Int};
+use crate::functions::{create_float, create_imaginary};
+use crate::math::{ERR_INVALID_FORMAT, ERR_UNIMPLEMENTED, ERR_WRONG_SYNTAX};
+use crate::special;
+use alloc::collections::BTreeMap;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use alloc::sync::Arc;
+
+/// Decimal places of the built-in irrational constants.
+pub const CONSTANT_DECIMALS: u64 = 99;
+
+/// Result of an expression: integers stay `Int` through `+ - * ^`, comparisons give `Bool`,
+/// anything else is a `Float`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(Int),
+    Float(Float),
+    Bool(bool),
+}
+
+impl Value {
+    /// Fails with `INVALID_FORMAT` for a `Bool`.
+    pub fn to_float(&self) -> Result<Float, i8> {
+        match self {
+            Value::Int(i) => i.to_float(),
+            Value::Float(f) => Ok(f.clone()),
+            Value::Bool(_) => Err(ERR_INVALID_FORMAT),
+        }
+    }
+
+    pub fn as_int(&self) -> Option<&Int> {
+        match self {
+            Value::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<&Float> {
+        match self {
+            Value::Float(f) => Some(f),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    fn int_or_float(
+        &self,
+        other: &Value,
+        int: fn(&Int, &Int) -> Result<Int, i8>,
+        float: fn(&Float, &Float) -> Result<Float, i8>,
+    ) -> Result<Value, i8> {
+        match (self, other) {
+            (Value::Int(a), Value::Int(b)) => Ok(Value::Int(int(a, b)?)),
+            (a, b) => Ok(Value::Float(float(&a.to_float()?, &b.to_float()?)?)),
+        }
+    }
+
+    fn binary(&self, op: &str, other: &Value) -> Result<Value, i8> {
+        match op {
+            "+" => self.int_or_float(other, Int::_add, Float::_add),
+            "-" => self.int_or_float(other, Int::_sub, Float::_sub),
+            "*" => self.int_or_float(other, Int::_mul, Float::_mul),
+            "/" => Ok(Value::Float(self.to_float()?._div(&other.to_float()?)?)),
+            "%" => Ok(Value::Float(self.to_float()?._modulo(&other.to_float()?)?)),
+            // a negative integer exponent leaves the integers
+            "^" => match other {
+                Value::Int(b) if b.is_negative() => match self {
+                    Value::Int(a) => Ok(Value::Float(a.pow_signed(b)?)),
+                    _ => Ok(Value::Float(self.to_float()?.pow(&other.to_float()?)?)),
+                },
+                _ => self.int_or_float(other, Int::pow, Float::pow),
+            },
+            _ => Ok(Value::Bool(self.compare(op, other)?)),
+        }
+    }
+
+    /// `==`, `!=`, `<`, `>`, `<=` and `>=`.
+    ///
+    /// Numbers compare by value whatever their kind, so `2 == 2.0` and a complex value with
+    /// a zero imaginary part equals the real one. NaN is unequal to everything, itself
+    /// included, and every ordering involving it is false. Ordering a complex value with a
+    /// nonzero imaginary part, or a `Bool` at all, fails with `INVALID_FORMAT`; booleans
+    /// only compare with booleans.
+    fn compare(&self, op: &str, other: &Value) -> Result<bool, i8> {
+        use core::cmp::Ordering;
+
+        let ordering = match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) if matches!(op, "==" | "!=") => Some(a.cmp(b)),
+            (Value::Bool(_), _) | (_, Value::Bool(_)) => return Err(ERR_INVALID_FORMAT),
+            (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+            _ => {
+                let (a, b) = (self.to_float()?, other.to_float()?);
+                let nan = |f: &Float| [f.re(), f.im()].iter().any(|p| p.is_nan() || p.partial_cmp(p).is_none());
+                if nan(&a) || nan(&b) {
+                    None
+                } else if a.is_complex() || b.is_complex() {
+                    let (ar, ai, br, bi) = (a.re(), a.im(), b.re(), b.im());
+                    let equal = ar.partial_cmp(&br) == Some(Ordering::Equal) && ai.partial_cmp(&bi) == Some(Ordering::Equal);
+                    match op {
+                        "==" => return Ok(equal),
+                        "!=" => return Ok(!equal),
+                        _ if ai.is_zero() && bi.is_zero() => ar.partial_cmp(&br),
+                        _ => return Err(ERR_INVALID_FORMAT),
+                    }
+                } else {
+                    a.partial_cmp(&b)
+                }
+            }
+        };
+        Ok(match op {
+            "==" => ordering == Some(Ordering::Equal),
+            "!=" => ordering != Some(Ordering::Equal),
+            "<" => ordering == Some(Ordering::Less),
+            ">" => ordering == Some(Ordering::Greater),
+            "<=" => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            ">=" => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+            _ => return Err(ERR_WRONG_SYNTAX),
+        })
+    }
+
+    fn neg(self) -> Result<Value, i8> {
+        match self {
+            Value::Int(i) => Ok(Value::Int(-i)),
+            Value::Float(f) => Ok(Value::Float(-f)),
+            Value::Bool(_) => Err(ERR_INVALID_FORMAT),
+        }
+    }
+}
+
+impl From<Int> for Value {
+    fn from(i: Int) -> Self {
+        Value::Int(i)
+    }
+}
+
+impl From<Float> for Value {
+    fn from(f: Float) -> Self {
+        Value::Float(f)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Float(v) => write!(f, "{}", v),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+/// Function callable from expressions; receives the evaluated arguments.
+pub type Function = Arc<dyn Fn(&[Value]) -> Result<Value, i8> + Send + Sync>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(String),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+const OPERATORS: [&str; 12] = ["==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "^"];
+
+fn tokenize(input: &str) -> Result<Vec<Token>, i8> {
+    let chars: Vec<char> = input.chars().collect();
+    let n = chars.len();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if let Some(op) = OPERATORS.iter().find(|op| op.chars().enumerate().all(|(k, oc)| chars.get(i + k) == Some(&oc))) {
+            tokens.push(Token::Op(op));
+            i += op.len();
+            continue;
+        }
+        match c {
+            '(' => tokens.push(Token::LParen),
+            ')' => tokens.push(Token::RParen),
+            ',' => tokens.push(Token::Comma),
+            _ if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                if c == '0' && chars.get(i + 1).is_some_and(|p| "xXbBoO".contains(*p)) {
+                    i += 2;
+                    while i < n && chars[i].is_ascii_alphanumeric() {
+                        i += 1;
+                    }
+                } else {
+                    while i < n && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                        i += 1;
+                    }
+                    // recurring part, as in 0.1(6)
+                    let has_point = chars[start..i].contains(&'.');
+                    if has_point && chars.get(i) == Some(&'(') {
+                        let close = (i + 1..n).find(|&j| chars[j] == ')').ok_or(ERR_WRONG_SYNTAX)?;
+                        i = close + 1;
+                    }
+                    if chars.get(i) == Some(&'i') && !chars.get(i + 1).is_some_and(|p| p.is_alphanumeric() || *p == '_') {
+                        i += 1;
+                    }
+                }
+                tokens.push(Token::Number(chars[start..i].iter().collect()));
+                continue;
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < n && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+                continue;
+            }
+            _ => return Err(ERR_WRONG_SYNTAX),
+        }
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+/// Reads a number literal: decimal or `0x` / `0b` / `0o` integers, decimals, recurring
+/// decimals and imaginary literals ending in `i`.
+fn parse_number(token: &str) -> Result<Value, i8> {
+    let radix = match token.get(..2) {
+        Some("0x" | "0X") => Some(16),
+        Some("0b" | "0B") => Some(2),
+        Some("0o" | "0O") => Some(8),
+        _ => None,
+    };
+    if let Some(radix) = radix {
+        let digits = &token[2..];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        return Ok(Value::Int(Int::from_str_radix(digits, radix)?));
+    }
+    if token.matches('.').count() > 1 || token == "." {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    if token.contains(['.', 'i']) {
+        Ok(Value::Float(Float::from_str(token)?))
+    } else {
+        Ok(Value::Int(Int::from_str(token)?))
+    }
+}
+
+/// Precedence and right-associativity of a binary operator.
+fn binding(op: &str) -> (u8, bool) {
+    match op {
+        "==" | "!=" | "<" | ">" | "<=" | ">=" => (1, false),
+        "+" | "-" => (2, false),
+        "*" | "/" | "%" => (3, false),
+        _ => (5, true),
+    }
+}
+
+/// Precedence of unary minus: below `^`, so `-2^2` is `-4`.
+const UNARY_PRECEDENCE: u8 = 4;
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    evaluator: &'a Evaluator,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, token: Token) -> Result<(), i8> {
+        if self.next() == Some(token) { Ok(()) } else { Err(ERR_WRONG_SYNTAX) }
+    }
+
+    /// Precedence climbing: parses operators binding at least as tightly as `min`.
+    fn expression(&mut self, min: u8) -> Result<Value, i8> {
+        let mut lhs = self.unary()?;
+        while let Some(Token::Op(op)) = self.peek() {
+            let op = *op;
+            let (prec, right) = binding(op);
+            if prec < min {
+                break;
+            }
+            self.pos += 1;
+            let rhs = self.expression(if right { prec } else { prec + 1 })?;
+            lhs = lhs.binary(op, &rhs)?;
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Value, i8> {
+        match self.peek() {
+            Some(Token::Op("-")) => {
+                self.pos += 1;
+                self.expression(UNARY_PRECEDENCE)?.neg()
+            }
+            Some(Token::Op("+")) => {
+                self.pos += 1;
+                self.expression(UNARY_PRECEDENCE)
+            }
+            _ => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Value, i8> {
+        match self.next().ok_or(ERR_WRONG_SYNTAX)? {
+            Token::Number(n) => parse_number(&n),
+            Token::LParen => {
+                let value = self.expression(0)?;
+                self.expect(Token::RParen)?;
+                Ok(value)
+            }
+            Token::Ident(name) if self.peek() == Some(&Token::LParen) => {
+                self.pos += 1;
+                let mut args = Vec::new();
+                if self.peek() == Some(&Token::RParen) {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        args.push(self.expression(0)?);
+                        match self.next() {
+                            Some(Token::Comma) => continue,
+                            Some(Token::RParen) => break,
+                            _ => return Err(ERR_WRONG_SYNTAX),
+                        }
+                    }
+                }
+                self.evaluator.call(&name, &args)
+            }
+            Token::Ident(name) => self.evaluator.lookup(&name).cloned().ok_or(ERR_INVALID_FORMAT),
+            _ => Err(ERR_WRONG_SYNTAX),
+        }
+    }
+}
+
+/// Fails with `WRONG_SYNTAX` unless exactly `n` arguments were passed; for use in
+/// registered functions.
+pub fn arity(args: &[Value], n: usize) -> Result<(), i8> {
+    if args.len() == n { Ok(()) } else { Err(ERR_WRONG_SYNTAX) }
+}
+
+fn decimals(value: &Value) -> Result<usize, i8> {
+    value.as_int().ok_or(ERR_INVALID_FORMAT)?.to_usize()
+}
+
+type ConstantFn = fn(u64) -> bigdecimal::BigDecimal;
+
+const IRRATIONAL_CONSTANTS: [(&[&str], ConstantFn); 4] = [
+    (&["pi", "PI"], consts::pi),
+    (&["e", "E"], consts::e),
+    (&["phi", "PHI"], consts::phi),
+    (&["sqrt2", "SQRT2"], consts::sqrt2),
+];
+
+/// Constants and functions an `Evaluator` can refer to by name.
+///
+/// Embedders build one registry with their own functions and constants and hand clones to
+/// as many evaluators as they need; functions are shared, not copied. `new()` holds the
+/// constants `pi`, `e`, `phi`, `sqrt2`, `inf`, `nan` and `i` (each also upper-case), `true`
+/// and `false`, and the functions
+///
+/// - of one argument: `sqrt abs sin cos tan ln exp floor ceil conj gamma lgamma erf erfc
+///   ellipk ellipe`
+/// - of two: `log(x, base)`, `round(x, places)`, `trunc(x, places)`, `beta(a, b)`,
+///   `besselj(n, x)` and `bessely(n, x)`
+///
+/// ```
+/// use imagnum::create_float;
+/// use imagnum::eval::{Evaluator, Registry, Value};
+///
+/// assert_eq!(
+///     Registry::new().function_names(),
+///     [
+///         "abs", "besselj", "bessely", "beta", "ceil", "conj", "cos", "ellipe", "ellipk", "erf",
+///         "erfc", "exp", "floor", "gamma", "lgamma", "ln", "log", "round", "sin", "sqrt", "tan",
+///         "trunc",
+///     ]
+/// );
+///
+/// let mut registry = Registry::new();
+/// registry.set_const("g", Value::Float(create_float("9.81")));
+/// registry.register_unary("half", |x| x._div(&create_float("2")));
+///
+/// let ev = Evaluator::with_registry(registry);
+/// assert_eq!(ev.eval("half(g)").unwrap(), Value::Float(create_float("4.905")));
+/// ```
+#[derive(Clone)]
+pub struct Registry {
+    constants: BTreeMap<String, Value>,
+    functions: BTreeMap<String, Function>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+impl core::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Registry")
+            .field("constants", &self.constant_names())
+            .field("functions", &self.function_names())
+            .finish()
+    }
+}
+
+impl Registry {
+    /// A registry with the built-in constants and functions.
+    pub fn new() -> Self {
+        let mut reg = Registry::empty();
+        reg.set_constant_decimals(CONSTANT_DECIMALS);
+        for (names, value) in [
+            (&["inf", "INF", "infinity", "INFINITY"][..], Value::Float(create_float("inf"))),
+            (&["nan", "NaN", "NAN"], Value::Float(create_float("nan"))),
+            (&["i", "I"], Value::Float(create_imaginary())),
+            (&["true"], Value::Bool(true)),
+            (&["false"], Value::Bool(false)),
+        ] {
+            for name in names {
+                reg.set_const(name, value.clone());
+            }
+        }
+
+        for (name, f) in [
+            ("sqrt", Float::sqrt as fn(&Float) -> Result<Float, i8>),
+            ("sin", Float::sin),
+            ("cos", Float::cos),
+            ("tan", Float::tan),
+            ("ln", Float::ln),
+            ("exp", Float::exp),
+            ("floor", Float::floor),
+            ("ceil", Float::ceil),
+            ("gamma", Float::gamma),
+            ("lgamma", Float::ln_gamma),
+            ("erf", Float::erf),
+            ("erfc", Float::erfc),
+            ("ellipk", special::elliptic_k),
+            ("ellipe", special::elliptic_e),
+        ] {
+            reg.register_unary(name, f);
+        }
+        reg.register("abs", |args| {
+            arity(args, 1)?;
+            Ok(match &args[0] {
+                Value::Int(i) => Value::Int(i.abs()),
+                Value::Float(f) => Value::Float(f.abs()),
+                Value::Bool(_) => return Err(ERR_INVALID_FORMAT),
+            })
+        });
+        reg.register("conj", |args| {
+            arity(args, 1)?;
+            Ok(match &args[0] {
+                Value::Float(f) => Value::Float(f.conj()),
+                Value::Int(i) => Value::Int(i.clone()),
+                Value::Bool(_) => return Err(ERR_INVALID_FORMAT),
+            })
+        });
+        reg.register("log", |args| {
+            arity(args, 2)?;
+            Ok(Value::Float(args[0].to_float()?.log(&args[1].to_float()?)?))
+        });
+        reg.register("beta", |args| {
+            arity(args, 2)?;
+            Ok(Value::Float(special::beta(&args[0].to_float()?, &args[1].to_float()?)?))
+        });
+        for (name, f) in [
+            ("besselj", special::bessel_j as fn(i64, &Float) -> Result<Float, i8>),
+            ("bessely", special::bessel_y),
+        ] {
+            reg.register(name, move |args| {
+                arity(args, 2)?;
+                let n = args[0].as_int().ok_or(ERR_INVALID_FORMAT)?.to_i64()?;
+                Ok(Value::Float(f(n, &args[1].to_float()?)?))
+            });
+        }
+        reg.register("round", |args| {
+            arity(args, 2)?;
+            Ok(Value::Float(args[0].to_float()?.round(decimals(&args[1])?)))
+        });
+        reg.register("trunc", |args| {
+            arity(args, 2)?;
+            Ok(Value::Float(args[0].to_float()?.truncate(decimals(&args[1])?)))
+        });
+        reg
+    }
+
+    /// A registry with no constants or functions.
+    pub fn empty() -> Self {
+        Registry { constants: BTreeMap::new(), functions: BTreeMap::new() }
+    }
+
+    /// (Re)defines the built-in irrational constants `pi`, `e`, `phi` and `sqrt2` and their
+    /// upper-case names with `decimals` places.
+    pub fn set_constant_decimals(&mut self, decimals: u64) {
+        for (names, compute) in IRRATIONAL_CONSTANTS {
+            let value = Value::Float(Float::Irrational(compute(decimals)));
+            for name in names {
+                self.set_const(name, value.clone());
+            }
+        }
+    }
+
+    /// Defines or replaces a constant.
+    pub fn set_const(&mut self, name: &str, value: Value) {
+        self.constants.insert(name.to_string(), value);
+    }
+
+    pub fn constant(&self, name: &str) -> Option<&Value> {
+        self.constants.get(name)
+    }
+
+    pub fn remove_const(&mut self, name: &str) -> Option<Value> {
+        self.constants.remove(name)
+    }
+
+    /// Adds or replaces a function. It receives the evaluated arguments and checks their
+    /// count itself, e.g. with `arity`. Only identifiers can be called from expressions.
+    pub fn register(&mut self, name: &str, f: impl Fn(&[Value]) -> Result<Value, i8> + Send + Sync + 'static) {
+        self.functions.insert(name.to_string(), Arc::new(f));
+    }
+
+    /// Registers a one-argument function on `Float`; an `Int` argument is converted first.
+    pub fn register_unary(&mut self, name: &str, f: impl Fn(&Float) -> Result<Float, i8> + Send + Sync + 'static) {
+        self.register(name, move |args| {
+            arity(args, 1)?;
+            Ok(Value::Float(f(&args[0].to_float()?)?))
+        });
+    }
+
+    /// Removes a function; returns whether it existed.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.functions.remove(name).is_some()
+    }
+
+    pub fn has_function(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Calls a function. Fails with `UNIMPLEMENTED` for an unknown name.
+    pub fn call(&self, name: &str, args: &[Value]) -> Result<Value, i8> {
+        self.functions.get(name).ok_or(ERR_UNIMPLEMENTED)?(args)
+    }
+
+    /// Constant names in sorted order.
+    pub fn constant_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.constants.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Function names in sorted order.
+    pub fn function_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.functions.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+/// Evaluates arithmetic expressions over `Int` and `Float`.
+///
+/// Supports `+ - * / % ^` (`^` is right-associative and binds tighter than unary minus),
+/// the comparisons `== != < > <= >=` (giving a `Bool`), parentheses, function calls,
+/// and number literals in decimal, `0x` / `0b` / `0o`, recurring (`0.1(6)`) and
+/// imaginary (`2i`) form. Names resolve to variables first, then the `Registry`'s
+/// constants; calls go to the registry's functions.
+///
+/// Errors are the crate's usual codes: `WRONG_SYNTAX` for malformed input or a wrong
+/// argument count, `INVALID_FORMAT` for a bad literal or unknown name, `UNIMPLEMENTED` for
+/// an unknown function, and whatever the arithmetic itself reports.
+///
+/// ```
+/// use imagnum::{create_float, create_int};
+/// use imagnum::eval::{Evaluator, Value};
+///
+/// let mut ev = Evaluator::new();
+/// ev.set_var("x", Value::Int(create_int("6")));
+/// ev.register("half", |args| Ok(Value::Float(args[0].to_float()?._div(&create_float("2"))?)));
+/// assert_eq!(ev.eval("half(x) ^ 2 - 1").unwrap(), Value::Float(create_float("8")));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Evaluator {
+    variables: BTreeMap<String, Value>,
+    registry: Registry,
+}
+
+impl Evaluator {
+    /// An evaluator with the built-in constants and functions.
+    pub fn new() -> Self {
+        Evaluator::with_registry(Registry::new())
+    }
+
+    /// An evaluator with no constants, functions or variables.
+    pub fn empty() -> Self {
+        Evaluator::with_registry(Registry::empty())
+    }
+
+    pub fn with_registry(registry: Registry) -> Self {
+        Evaluator { variables: BTreeMap::new(), registry }
+    }
+
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    pub fn registry_mut(&mut self) -> &mut Registry {
+        &mut self.registry
+    }
+
+    pub fn set_var(&mut self, name: &str, value: Value) {
+        self.variables.insert(name.to_string(), value);
+    }
+
+    pub fn get_var(&self, name: &str) -> Option<&Value> {
+        self.variables.get(name)
+    }
+
+    pub fn remove_var(&mut self, name: &str) -> Option<Value> {
+        self.variables.remove(name)
+    }
+
+    pub fn clear_vars(&mut self) {
+        self.variables.clear();
+    }
+
+    /// Variables in name order.
+    pub fn vars(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.variables.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Shorthand for `registry_mut().set_const`. Variables of the same name shadow it.
+    pub fn set_const(&mut self, name: &str, value: Value) {
+        self.registry.set_const(name, value);
+    }
+
+    /// Shorthand for `registry_mut().register`.
+    pub fn register(&mut self, name: &str, f: impl Fn(&[Value]) -> Result<Value, i8> + Send + Sync + 'static) {
+        self.registry.register(name, f);
+    }
+
+    pub fn has_function(&self, name: &str) -> bool {
+        self.registry.has_function(name)
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Value> {
+        self.variables.get(name).or_else(|| self.registry.constant(name))
+    }
+
+    /// Calls a registered function. Fails with `UNIMPLEMENTED` for an unknown name.
+    pub fn call(&self, name: &str, args: &[Value]) -> Result<Value, i8> {
+        self.registry.call(name, args)
+    }
+
+    /// Evaluates one expression.
+    pub fn eval(&self, expr: &str) -> Result<Value, i8> {
+        let mut parser = Parser { tokens: tokenize(expr)?, pos: 0, evaluator: self };
+        let value = parser.expression(0)?;
+        if parser.pos != parser.tokens.len() {
+            return Err(ERR_WRONG_SYNTAX);
+        }
+        Ok(value)
+    }
+
+    /// Evaluates `name = expr` (storing the value in the variable) or a bare expression.
+    /// Returns the assigned name, if any, with the value.
+    pub fn execute(&mut self, line: &str) -> Result<(Option<String>, Value), i8> {
+        if let Some((name, expr)) = split_assignment(line) {
+            let value = self.eval(expr)?;
+            self.set_var(name, value.clone());
+            return Ok((Some(name.to_string()), value));
+        }
+        Ok((None, self.eval(line)?))
+    }
+}
+
+/// `name = expr`, where `name` is an identifier and `=` is not part of a comparison.
+fn split_assignment(line: &str) -> Option<(&str, &str)> {
+    let (name, expr) = line.split_once('=')?;
+    let name = name.trim();
+    let valid = name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+    (valid && !expr.starts_with('=')).then_some((name, expr))
+}
+
+/// Evaluates `expr` with a fresh `Evaluator::new()`.
+///
+/// ```
+/// use imagnum::create_int;
+/// use imagnum::eval::{eval, Value};
+///
+/// assert_eq!(eval("2 ^ 3 ^ 2").unwrap(), Value::Int(create_int("512")));
+/// assert_eq!(eval("-2 ^ 2").unwrap(), Value::Int(create_int("-4")));
+/// ```
+pub fn eval(expr: &str) -> Result<Value, i8> {
+    Evaluator::new().eval(expr)
+}
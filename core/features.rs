@@ -6,8 +6,10 @@ use bigdecimal::FromPrimitive;
 #[cfg(feature = "serde")]
 pub mod feature_serde {
     use serde::{Serialize, Deserialize};
-    use serde::ser::{Serializer};
-    use serde::de::{self, Deserializer, Visitor};
+    use serde::ser::{SerializeMap, Serializer};
+    use serde::de::{self, Deserializer, MapAccess, Visitor};
+    use bigdecimal::BigDecimal;
+    use core::str::FromStr;
     use super::*;
 
     impl Serialize for Int {
@@ -30,7 +32,7 @@ pub mod feature_serde {
             impl<'de> Visitor<'de> for IntVisitor {
                 type Value = Int;
 
-                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
                     formatter.write_str("a string representing an integer")
                 }
 
@@ -46,16 +48,67 @@ pub mod feature_serde {
         }
     }
 
+    /// Human-readable formats write finite decimals as a plain string (the pre-0.3 format)
+    /// and other kinds as a tagged map:
+    /// `{"kind":"recurring","value":"0.(3)"}`, `{"kind":"irrational","value":"1.41..."}`,
+    /// `{"kind":"surd","value":"2√3"}`, `{"kind":"complex","re":…,"im":…}`, `{"kind":"nan"}`, `{"kind":"infinity"}`,
+    /// `{"kind":"neg_infinity"}`. Binary formats get the bytes of `to_bytes()`, which tag
+    /// every kind, the special values included.
     impl Serialize for Float {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            let s = self.to_str();
-            serializer.serialize_str(&s)
+            if !serializer.is_human_readable() {
+                return serializer.serialize_bytes(&self.to_bytes());
+            }
+            match self {
+                Float::Small(_) | Float::Big(_) => serializer.serialize_str(&self.to_canonical_string()),
+                // written out like an irrational value, as `to_canonical_string` does
+                Float::Recurring(_, _) if self.recurring_parts().is_none() => {
+                    serializer.serialize_str(&self.to_canonical_string())
+                }
+                Float::Recurring(_, _) => {
+                    let mut map = serializer.serialize_map(Some(2))?;
+                    map.serialize_entry("kind", "recurring")?;
+                    map.serialize_entry("value", &self.to_canonical_string())?;
+                    map.end()
+                }
+                Float::Irrational(bd) => {
+                    let mut map = serializer.serialize_map(Some(2))?;
+                    map.serialize_entry("kind", "irrational")?;
+                    map.serialize_entry("value", &bd.to_string())?;
+                    map.end()
+                }
+                Float::Surd(_, _) => {
+                    let mut map = serializer.serialize_map(Some(2))?;
+                    map.serialize_entry("kind", "surd")?;
+                    map.serialize_entry("value", &self.to_string())?;
+                    map.end()
+                }
+                Float::Complex(re, im) => {
+                    let mut map = serializer.serialize_map(Some(3))?;
+                    map.serialize_entry("kind", "complex")?;
+                    map.serialize_entry("re", re.as_ref())?;
+                    map.serialize_entry("im", im.as_ref())?;
+                    map.end()
+                }
+                Float::NaN | Float::Infinity | Float::NegInfinity => {
+                    let kind = match self {
+                        Float::NaN => "nan",
+                        Float::Infinity => "infinity",
+                        _ => "neg_infinity",
+                    };
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry("kind", kind)?;
+                    map.end()
+                }
+            }
         }
     }
 
+    /// Accepts both the tagged map and a plain string, so data written by older versions
+    /// (or by `to_canonical_string()`) still loads. Binary formats read `to_bytes()` bytes.
     impl<'de> Deserialize<'de> for Float {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
@@ -66,8 +119,8 @@ pub mod feature_serde {
             impl<'de> Visitor<'de> for FloatVisitor {
                 type Value = Float;
 
-                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                    formatter.write_str("a string representing a floating-point number")
+                fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    formatter.write_str("a string representing a floating-point number, a map with a \"kind\" field or `to_bytes` bytes")
                 }
 
                 fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -76,9 +129,75 @@ pub mod feature_serde {
                 {
                     Float::from_str(v).map_err(de::Error::custom)
                 }
+
+                fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    Float::from_bytes(v).map_err(de::Error::custom)
+                }
+
+                fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    let mut kind: Option<String> = None;
+                    let mut value: Option<String> = None;
+                    let mut re: Option<Float> = None;
+                    let mut im: Option<Float> = None;
+                    while let Some(key) = map.next_key::<String>()? {
+                        match key.as_str() {
+                            "kind" => kind = Some(map.next_value()?),
+                            "value" => value = Some(map.next_value()?),
+                            "re" => re = Some(map.next_value()?),
+                            "im" => im = Some(map.next_value()?),
+                            other => return Err(de::Error::unknown_field(other, &["kind", "value", "re", "im"])),
+                        }
+                    }
+                    let kind = kind.ok_or_else(|| de::Error::missing_field("kind"))?;
+                    let value = || value.ok_or_else(|| de::Error::missing_field("value"));
+                    match kind.as_str() {
+                        "finite" => self.visit_str(&value()?),
+                        "recurring" => {
+                            let f = Float::from_str(&value()?).map_err(de::Error::custom)?;
+                            match f {
+                                Float::Recurring(_, _) => Ok(f),
+                                _ => Err(de::Error::custom("value is not a recurring decimal")),
+                            }
+                        }
+                        "irrational" => {
+                            let v = value()?;
+                            let bd = BigDecimal::from_str(&v).map_err(de::Error::custom)?;
+                            Ok(Float::Irrational(bd))
+                        }
+                        "surd" => {
+                            let f = Float::from_str(&value()?).map_err(de::Error::custom)?;
+                            match f {
+                                Float::Surd(_, _) => Ok(f),
+                                _ => Err(de::Error::custom("value is not a surd")),
+                            }
+                        }
+                        "complex" => {
+                            let re = re.ok_or_else(|| de::Error::missing_field("re"))?;
+                            let im = im.ok_or_else(|| de::Error::missing_field("im"))?;
+                            Ok(Float::Complex(Box::new(re), Box::new(im)))
+                        }
+                        "nan" => Ok(Float::NaN),
+                        "infinity" => Ok(Float::Infinity),
+                        "neg_infinity" => Ok(Float::NegInfinity),
+                        other => Err(de::Error::unknown_variant(
+                            other,
+                            &["finite", "recurring", "irrational", "surd", "complex", "nan", "infinity", "neg_infinity"],
+                        )),
+                    }
+                }
             }
 
-            deserializer.deserialize_str(FloatVisitor)
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(FloatVisitor)
+            } else {
+                deserializer.deserialize_bytes(FloatVisitor)
+            }
         }
     }
 }
@@ -86,11 +205,99 @@ pub mod feature_serde {
 #[cfg(feature = "random")]
 #[cfg(not(target_arch = "wasm32"))]
 pub mod feature_rand {
-    use rand::{Rng, RngCore};
+    //! Every function takes its randomness from `rand::rng()`; the `_with_rng` variants take any
+    //! `Rng` instead, so a seeded one gives reproducible values. `UniformInt` and
+    //! `UniformFloat` make `Int` and `Float` usable with `rand::distr::Uniform` and
+    //! `Rng::random_range`.
+    //!
+    //! ```
+    //! use imagnum::random::{rand_bits_with_rng, UniformInt};
+    //! use imagnum::create_int;
+    //! use rand::{rngs::StdRng, Rng, SeedableRng};
+    //!
+    //! let mut rng = StdRng::seed_from_u64(7);
+    //! let a = rand_bits_with_rng(&mut rng, 200);
+    //! assert_eq!(a, rand_bits_with_rng(&mut StdRng::seed_from_u64(7), 200));
+    //!
+    //! let d = rng.random_range(create_int("-5")..=create_int("5"));
+    //! assert!(d >= create_int("-5") && d <= create_int("5"));
+    //! let die = UniformInt::new_inclusive(&create_int("1"), &create_int("6")).unwrap();
+    //! let roll = rng.sample(&die);
+    //! ```
+    use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler};
+    use rand::distr::Distribution;
+    use rand::Rng;
     use super::*;
+    use crate::math::ERR_INVALID_FORMAT;
     use bigdecimal::BigDecimal;
-    use num_bigint::{BigInt, RandBigInt};
-    use std::f64::consts::PI;
+    use num_bigint::Sign;
+    use num_integer::Integer;
+    use num_traits::{One, Signed, Zero};
+    use core::f64::consts::PI;
+
+    /// Uniform in `[0, 2^bits)`.
+    fn random_bits<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> BigInt {
+        let mut bytes = vec![0u8; bits.div_ceil(8) as usize];
+        rng.fill_bytes(&mut bytes);
+        if let Some(top) = bytes.last_mut() {
+            *top >>= bits.div_ceil(8) * 8 - bits;
+        }
+        BigInt::from_bytes_le(Sign::Plus, &bytes)
+    }
+
+    /// Uniform in `[0, bound)` by rejection; `bound > 0`.
+    fn below<R: Rng + ?Sized>(rng: &mut R, bound: &BigInt) -> BigInt {
+        let bits = bound.bits();
+        loop {
+            let r = random_bits(rng, bits);
+            if &r < bound {
+                return r;
+            }
+        }
+    }
+
+    /// A uniformly random decimal with `scale` digits after the point between `min` and
+    /// `max`, both included, or the rounded bound when no such decimal lies between them.
+    fn uniform_decimal<R: Rng + ?Sized>(rng: &mut R, min: &BigDecimal, max: &BigDecimal, scale: i64) -> BigDecimal {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let (low, _) = min.with_scale_round(scale, bigdecimal::RoundingMode::Ceiling).into_bigint_and_exponent();
+        let (high, _) = max.with_scale_round(scale, bigdecimal::RoundingMode::Floor).into_bigint_and_exponent();
+        if high < low {
+            return min.with_scale_round(scale, bigdecimal::RoundingMode::HalfEven);
+        }
+        let steps = high - &low + 1u32;
+        BigDecimal::new(low + below(rng, &steps), scale)
+    }
+
+    const SMALL_PRIMES: [u32; 24] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+    /// Miller-Rabin rounds; a composite passes all of them with probability below `4^-40`.
+    const MILLER_RABIN_ROUNDS: usize = 40;
+
+    /// Whether an odd `n > 97` is probably prime.
+    fn is_probable_prime<R: Rng + ?Sized>(rng: &mut R, n: &BigInt) -> bool {
+        if SMALL_PRIMES.iter().any(|&p| (n % p).is_zero()) {
+            return false;
+        }
+        let n_minus_1 = n - 1u32;
+        let s = n_minus_1.trailing_zeros().unwrap_or(0);
+        let d = &n_minus_1 >> s;
+        let two = BigInt::from(2u32);
+        'rounds: for _ in 0..MILLER_RABIN_ROUNDS {
+            let a = below(rng, &(n - 3u32)) + 2u32;
+            let mut x = a.modpow(&d, n);
+            if x.is_one() || x == n_minus_1 {
+                continue;
+            }
+            for _ in 1..s {
+                x = x.modpow(&two, n);
+                if x == n_minus_1 {
+                    continue 'rounds;
+                }
+            }
+            return false;
+        }
+        true
+    }
 
     // -----------------------
     // Random Float in [0, 1)
@@ -98,12 +305,15 @@ pub mod feature_rand {
     /// Generates a random Float in the range [0, 1) with arbitrary precision.
     /// The precision is determined using a normal distribution centered around 12 with a standard deviation of 6.
     pub fn rand() -> Float {
-        let mut rng = rand::rng();
+        rand_with_rng(&mut rand::rng())
+    }
 
+    /// `rand` drawing from `rng`.
+    pub fn rand_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Float {
         let min_bd = BigDecimal::from(0);
         let max_bd = BigDecimal::from(1);
 
-        let frac_f64: f64 = rng.random_range(0.0..1.0) as f64;
+        let frac_f64: f64 = rng.random_range(0.0..1.0);
 
         // --- Box-Muller normal distribution for precision ---
         let mean: f64 = 12.0;
@@ -111,14 +321,14 @@ pub mod feature_rand {
         let mut prec: f64;
 
         loop {
-            let mut u1: f64 = rng.random_range(0.0..1.0) as f64;
+            let mut u1: f64 = rng.random_range(0.0..1.0);
             if u1 < 1e-10 { u1 = 1e-10; }
-            let u2: f64 = rng.random_range(0.0..1.0) as f64;
+            let u2: f64 = rng.random_range(0.0..1.0);
 
             let z0: f64 = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
             prec = mean + z0 * std_dev;
 
-            if prec >= 0.0 && prec <= 42.0 {
+            if (0.0..=42.0).contains(&prec) {
                 break;
             }
         }
@@ -138,64 +348,99 @@ pub mod feature_rand {
     // -----------------------
     /// Generates a random Int in the inclusive range [min, max].
     pub fn randint(min: &Int, max: &Int) -> Int {
-        let mut rng = rand::rng();
+        randint_with_rng(&mut rand::rng(), min, max)
+    }
 
+    /// `randint` drawing from `rng`.
+    pub fn randint_with_rng<R: Rng + ?Sized>(rng: &mut R, min: &Int, max: &Int) -> Int {
         let min_big = min.to_bigint().unwrap();
         let max_big = max.to_bigint().unwrap();
         let range = &max_big - &min_big + 1u32;
-        let bits = range.bits();
-        let mut r: BigInt;
+        Int::from(below(rng, &range) + min_big)
+    }
+
+    /// A uniformly random Int in `[0, 2^bits)`, so `bits` random bits; `rand_bits(0)` is 0.
+    pub fn rand_bits(bits: u64) -> Int {
+        rand_bits_with_rng(&mut rand::rng(), bits)
+    }
+
+    /// `rand_bits` drawing from `rng`.
+    pub fn rand_bits_with_rng<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> Int {
+        Int::from(random_bits(rng, bits))
+    }
+
+    /// A random prime of exactly `bits` bits, so in `[2^(bits-1), 2^bits)`. Primality is
+    /// checked with trial division and Miller-Rabin, so a composite slips through with
+    /// probability below `2^-80`. Fails with `INVALID_FORMAT` for `bits < 2`.
+    pub fn rand_prime(bits: u64) -> Result<Int, i8> {
+        rand_prime_with_rng(&mut rand::rng(), bits)
+    }
 
+    /// `rand_prime` drawing from `rng`.
+    pub fn rand_prime_with_rng<R: Rng + ?Sized>(rng: &mut R, bits: u64) -> Result<Int, i8> {
+        if bits < 2 {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        if bits <= 7 {
+            // few enough to list: every prime below 128
+            let primes: Vec<u32> = core::iter::once(2)
+                .chain(SMALL_PRIMES)
+                .chain([101, 103, 107, 109, 113, 127])
+                .filter(|p| u64::from(32 - p.leading_zeros()) == bits)
+                .collect();
+            return Ok(Int::from(primes[rng.random_range(0..primes.len())]));
+        }
+        let top = BigInt::one() << (bits - 1);
         loop {
-            let mut bytes = vec![0u8; (bits as usize + 7) / 8];
-            rng.fill_bytes(&mut bytes);
-            r = BigInt::from_bytes_le(num_bigint::Sign::Plus, &bytes);
-            if &r < &range {
-                break;
+            let candidate = (random_bits(rng, bits - 1) | &top) | BigInt::one();
+            if is_probable_prime(rng, &candidate) {
+                return Ok(Int::from(candidate));
             }
         }
-        r += min_big;
-        Int::Big(r)
     }
 
     // -----------------------
     // Random Float (limited precision: word * 2)
     // -----------------------
     /// Generates a random Float in the range [min, max] with limited precision based on system word size.
+    /// Every decimal with that many digits after the point in the range is equally likely.
     pub fn randfloat(min: &Float, max: &Float) -> Float {
-        let word = std::mem::size_of::<usize>() as u64;
+        randfloat_with_rng(&mut rand::rng(), min, max)
+    }
+
+    /// `randfloat` drawing from `rng`.
+    pub fn randfloat_with_rng<R: Rng + ?Sized>(rng: &mut R, min: &Float, max: &Float) -> Float {
+        let word = core::mem::size_of::<usize>() as u64;
         let precision = word * 2;
 
-        let mut rng = rand::rng();
         let (min_bd_opt, _) = min.to_bigdecimal();
         let (max_bd_opt, _) = max.to_bigdecimal();
 
         let min_bd = min_bd_opt.unwrap_or(BigDecimal::from(0));
         let max_bd = max_bd_opt.unwrap_or(BigDecimal::from(0));
 
-        let frac = BigDecimal::from_f64(rng.random_range(0.0..1.0)).unwrap_or(BigDecimal::from(0));
-        let result = min_bd.clone() + (max_bd - min_bd) * frac;
-
-        Float::Big(result.with_scale(precision as i64))
+        Float::Big(uniform_decimal(rng, &min_bd, &max_bd, precision as i64))
     }
 
     // -----------------------
     // Random Decimal (arbitrary precision)
     // -----------------------
-    /// Generates a random Float in the range [min, max] with specified precision.
+    /// Generates a random Float in the range [min, max] with specified precision: each decimal
+    /// with `precision` digits after the point in the range is equally likely, however many
+    /// digits that is.
     pub fn randdecimal(min: &Float, max: &Float, precision: u64) -> Float {
-        let mut rng = rand::rng();
+        randdecimal_with_rng(&mut rand::rng(), min, max, precision)
+    }
+
+    /// `randdecimal` drawing from `rng`.
+    pub fn randdecimal_with_rng<R: Rng + ?Sized>(rng: &mut R, min: &Float, max: &Float, precision: u64) -> Float {
         let (min_bd_opt, _) = min.to_bigdecimal();
         let (max_bd_opt, _) = max.to_bigdecimal();
 
         let min_bd = min_bd_opt.unwrap_or(BigDecimal::from(0));
         let max_bd = max_bd_opt.unwrap_or(BigDecimal::from(0));
 
-        let frac_val: f64 = rng.random_range(0.0..1.0);
-        let frac = BigDecimal::from_f64(frac_val).unwrap_or(BigDecimal::from(0));
-        let result = min_bd.clone() + (max_bd - min_bd) * frac;
-
-        Float::Big(result.with_scale(precision as i64))
+        Float::Big(uniform_decimal(rng, &min_bd, &max_bd, precision as i64))
     }
 
     // -----------------------
@@ -203,8 +448,13 @@ pub mod feature_rand {
     // -----------------------
     /// Generates a random Complex Float where both real and imaginary parts are in the range [min, max].
     pub fn randcomplex(min: &Float, max: &Float) -> Float {
-        let real = randfloat(min, max);
-        let imag = randfloat(min, max);
+        randcomplex_with_rng(&mut rand::rng(), min, max)
+    }
+
+    /// `randcomplex` drawing from `rng`.
+    pub fn randcomplex_with_rng<R: Rng + ?Sized>(rng: &mut R, min: &Float, max: &Float) -> Float {
+        let real = randfloat_with_rng(rng, min, max);
+        let imag = randfloat_with_rng(rng, min, max);
         Float::complex(real, imag)
     }
 
@@ -213,7 +463,11 @@ pub mod feature_rand {
     // -----------------------
     /// Generates a random Float in the range [min, max].
     pub fn randreal(min: &Float, max: &Float) -> Float {
-        let mut rng = rand::rng();
+        randreal_with_rng(&mut rand::rng(), min, max)
+    }
+
+    /// `randreal` drawing from `rng`.
+    pub fn randreal_with_rng<R: Rng + ?Sized>(rng: &mut R, min: &Float, max: &Float) -> Float {
         let (min_bd_opt, _) = min.to_bigdecimal();
         let (max_bd_opt, _) = max.to_bigdecimal();
         let min_bd = min_bd_opt.unwrap_or(BigDecimal::from(0));
@@ -222,13 +476,557 @@ pub mod feature_rand {
         let frac = BigDecimal::from_f64(rng.random_range(0.0..1.0)).unwrap_or(BigDecimal::from(0));
         let value = (max_bd - min_bd.clone()) * frac + min_bd;
 
-        // 80% Big, 10% Recurring, 10% Irrational
+        // 80% Big, 10% read as recurring digits (`Big` without a repetend), 10% Irrational
         let choice = rng.random_range(0..100);
         match choice {
             0..=79 => Float::Big(value),
-            80..=89 => Float::Recurring(value),
+            80..=89 => crate::recurring::from_digits(&value),
             _ => Float::Irrational(value),
         }
     }
+
+    // -----------------------
+    // Distributions
+    // -----------------------
+    /// Samples `Int`s uniformly from a range, exactly for any size. The sampler behind
+    /// `rand::distr::Uniform<Int>` and `rng.random_range(a..b)` on `Int`s.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UniformInt {
+        low: BigInt,
+        /// Number of values, at least 1.
+        count: BigInt,
+    }
+
+    impl UniformInt {
+        /// Uniform over `[low, high)`; fails with `INVALID_FORMAT` when the range is empty.
+        pub fn new(low: &Int, high: &Int) -> Result<Self, i8> {
+            Self::from_bounds(low, high, false).ok_or(ERR_INVALID_FORMAT)
+        }
+
+        /// Uniform over `[low, high]`; fails with `INVALID_FORMAT` when `low > high`.
+        pub fn new_inclusive(low: &Int, high: &Int) -> Result<Self, i8> {
+            Self::from_bounds(low, high, true).ok_or(ERR_INVALID_FORMAT)
+        }
+
+        fn from_bounds(low: &Int, high: &Int, inclusive: bool) -> Option<Self> {
+            let low = low.to_bigint().ok()?;
+            let mut count = high.to_bigint().ok()? - &low;
+            if inclusive {
+                count += 1u32;
+            }
+            count.is_positive().then_some(UniformInt { low, count })
+        }
+    }
+
+    impl Distribution<Int> for UniformInt {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Int {
+            Int::from(&self.low + below(rng, &self.count))
+        }
+    }
+
+    impl UniformSampler for UniformInt {
+        type X = Int;
+
+        fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+        where
+            B1: SampleBorrow<Int> + Sized,
+            B2: SampleBorrow<Int> + Sized,
+        {
+            Self::from_bounds(low.borrow(), high.borrow(), false).ok_or(Error::EmptyRange)
+        }
+
+        fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+        where
+            B1: SampleBorrow<Int> + Sized,
+            B2: SampleBorrow<Int> + Sized,
+        {
+            Self::from_bounds(low.borrow(), high.borrow(), true).ok_or(Error::EmptyRange)
+        }
+
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Int {
+            Distribution::sample(self, rng)
+        }
+    }
+
+    impl SampleUniform for Int {
+        type Sampler = UniformInt;
+    }
+
+    /// Digits after the point `UniformFloat` uses at the least; bounds with more use theirs.
+    pub const UNIFORM_FLOAT_DECIMALS: u64 = 16;
+
+    /// Samples `Float`s uniformly from the decimals with a fixed number of digits after the
+    /// point in a range, as exact `Float::Big` values. The sampler behind
+    /// `rand::distr::Uniform<Float>` and `rng.random_range(a..b)` on `Float`s, which use
+    /// `UNIFORM_FLOAT_DECIMALS` digits or those of the bounds, whichever is more.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct UniformFloat {
+        low: BigInt,
+        /// Number of grid points, at least 1.
+        count: BigInt,
+        scale: i64,
+    }
+
+    impl UniformFloat {
+        /// Uniform over `[low, high)` in steps of `10^-decimals`; fails with `INVALID_FORMAT`
+        /// for an empty range or a bound that is not finite and real.
+        pub fn new(low: &Float, high: &Float, decimals: u64) -> Result<Self, i8> {
+            Self::from_bounds(low, high, Some(decimals), false).ok_or(ERR_INVALID_FORMAT)
+        }
+
+        /// Uniform over `[low, high]` in steps of `10^-decimals`.
+        pub fn new_inclusive(low: &Float, high: &Float, decimals: u64) -> Result<Self, i8> {
+            Self::from_bounds(low, high, Some(decimals), true).ok_or(ERR_INVALID_FORMAT)
+        }
+
+        /// With `decimals` unset, the more of `UNIFORM_FLOAT_DECIMALS` and the bounds' own.
+        fn from_bounds(low: &Float, high: &Float, decimals: Option<u64>, inclusive: bool) -> Option<Self> {
+            let (low, high) = (low.decimal_value()?, high.decimal_value()?);
+            let scale = match decimals {
+                Some(d) => i64::try_from(d).ok()?,
+                None => (UNIFORM_FLOAT_DECIMALS as i64).max(low.fractional_digit_count()).max(high.fractional_digit_count()),
+            };
+            // the grid points inside the range: ceil(low) up to floor(high) in units of 10^-scale
+            let (low, _) = low.with_scale_round(scale, bigdecimal::RoundingMode::Ceiling).into_bigint_and_exponent();
+            let (high, _) = high.with_scale_round(scale, bigdecimal::RoundingMode::Floor).into_bigint_and_exponent();
+            let mut count = high - &low;
+            if inclusive {
+                count += 1u32;
+            }
+            count.is_positive().then_some(UniformFloat { low, count, scale })
+        }
+
+        fn sampler(low: &Float, high: &Float, inclusive: bool) -> Result<Self, Error> {
+            if low.decimal_value().is_none() || high.decimal_value().is_none() {
+                return Err(Error::NonFinite);
+            }
+            Self::from_bounds(low, high, None, inclusive).ok_or(Error::EmptyRange)
+        }
+    }
+
+    impl Distribution<Float> for UniformFloat {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float {
+            Float::Big(BigDecimal::new(&self.low + below(rng, &self.count), self.scale))
+        }
+    }
+
+    impl UniformSampler for UniformFloat {
+        type X = Float;
+
+        fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+        where
+            B1: SampleBorrow<Float> + Sized,
+            B2: SampleBorrow<Float> + Sized,
+        {
+            Self::sampler(low.borrow(), high.borrow(), false)
+        }
+
+        fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+        where
+            B1: SampleBorrow<Float> + Sized,
+            B2: SampleBorrow<Float> + Sized,
+        {
+            Self::sampler(low.borrow(), high.borrow(), true)
+        }
+
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float {
+            Distribution::sample(self, rng)
+        }
+    }
+
+    impl SampleUniform for Float {
+        type Sampler = UniformFloat;
+    }
 }
 
+
+/// `arbitrary::Arbitrary` for `Int` and `Float`, so fuzzers such as `cargo fuzz` can build
+/// values of every kind from raw bytes.
+#[cfg(feature = "arbitrary")]
+pub mod feature_arbitrary {
+    use super::*;
+    use crate::foundation::SmallFloat;
+    use crate::functions::create_float;
+    use arbitrary::{Arbitrary, Result, Unstructured};
+    use bigdecimal::BigDecimal;
+    use num_bigint::Sign;
+
+    /// A run of up to `max` decimal digits, at least one.
+    fn digits(u: &mut Unstructured<'_>, max: usize) -> Result<String> {
+        let len = u.int_in_range(1..=max)?;
+        (0..len).map(|_| Ok(char::from(b'0' + u.int_in_range(0..=9)?))).collect()
+    }
+
+    impl<'a> Arbitrary<'a> for Int {
+        /// Small values of every width as well as `Big` ones up to 512 bytes.
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(match u.int_in_range(0..=3)? {
+                0 => Int::from(i64::arbitrary(u)?),
+                1 => Int::from(u128::arbitrary(u)?),
+                2 => Int::from(i8::arbitrary(u)?),
+                _ => {
+                    let len = u.int_in_range(0..=512)?;
+                    let sign = if bool::arbitrary(u)? { Sign::Minus } else { Sign::Plus };
+                    Int::from(BigInt::from_bytes_le(sign, u.bytes(len)?))
+                }
+            })
+        }
+    }
+
+    /// A real `Float`: any kind but `Complex`, mostly finite.
+    fn real(u: &mut Unstructured<'_>) -> Result<Float> {
+        let neg = if bool::arbitrary(u)? { "-" } else { "" };
+        Ok(match u.int_in_range(0..=5)? {
+            0 => Float::from_bits(u64::arbitrary(u)?),
+            1 => match f32::arbitrary(u)? {
+                v if v.is_finite() => Float::Small(SmallFloat::F32(v + 0.0)),
+                v => Float::from_bits(f64::from(v).to_bits()),
+            },
+            2 => {
+                let scale = u.int_in_range(-400..=400)?;
+                Float::Big(BigDecimal::new(BigInt::from(i128::arbitrary(u)?), scale))
+            }
+            3 => create_float(&format!("{neg}{}.{}({})", digits(u, 6)?, digits(u, 4)?, digits(u, 4)?)),
+            4 => {
+                let mut f = create_float(&format!("{neg}{}.{}", digits(u, 4)?, digits(u, 60)?));
+                f.make_irrational()
+            }
+            _ => {
+                let coefficient = create_float(&format!("{neg}{}", digits(u, 4)?));
+                let radicand = Int::from(u.int_in_range(2u32..=1000)?);
+                Float::surd(&coefficient, &radicand).unwrap_or(coefficient)
+            }
+        })
+    }
+
+    /// A finite real `Float`, for either part of a `Complex` one.
+    fn part(u: &mut Unstructured<'_>) -> Result<Float> {
+        let f = real(u)?;
+        Ok(match f {
+            Float::NaN | Float::Infinity | Float::NegInfinity => Float::new(),
+            f => f,
+        })
+    }
+
+    impl<'a> Arbitrary<'a> for Float {
+        /// Every kind: `Small`, `Big`, `Recurring`, `Irrational`, `Surd`, `Complex` and the
+        /// special values.
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(match u.int_in_range(0..=9)? {
+                0 => Float::NaN,
+                1 => Float::Infinity,
+                2 => Float::NegInfinity,
+                3 => Float::Complex(Box::new(part(u)?), Box::new(part(u)?)),
+                _ => real(u)?,
+            })
+        }
+    }
+}
+
+/// JavaScript bindings through `wasm-bindgen`: `Int` and `Float` classes that take and
+/// give numbers as strings, so no precision is lost crossing into JavaScript. Failing
+/// operations throw an `Error` carrying the message of the error code.
+///
+/// ```js
+/// import { Int, Float } from "imagnum";
+/// new Int("12345678901234567890").mul(new Int("3")).toString(); // "37037036703703703670"
+/// new Float("1").div(new Float("3")).toString();                 // "0.(3)"
+/// ```
+#[cfg(feature = "wasm-bindgen")]
+pub mod feature_wasm {
+    use super::*;
+    use crate::functions::{get_error_message, try_create_int};
+    use core::cmp::Ordering;
+    use wasm_bindgen::prelude::*;
+
+    fn js_error(code: i8) -> JsError {
+        JsError::new(get_error_message(code))
+    }
+
+    /// An `Int`, exported to JavaScript as `Int`.
+    #[wasm_bindgen(js_name = Int)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JsInt(Int);
+
+    #[wasm_bindgen(js_class = Int)]
+    impl JsInt {
+        /// Parses a decimal integer as `try_create_int` does.
+        #[wasm_bindgen(constructor)]
+        pub fn new(value: &str) -> Result<JsInt, JsError> {
+            try_create_int(value).map(JsInt).map_err(js_error)
+        }
+
+        pub fn add(&self, other: &JsInt) -> Result<JsInt, JsError> {
+            self.0._add(&other.0).map(JsInt).map_err(js_error)
+        }
+
+        pub fn sub(&self, other: &JsInt) -> Result<JsInt, JsError> {
+            self.0._sub(&other.0).map(JsInt).map_err(js_error)
+        }
+
+        pub fn mul(&self, other: &JsInt) -> Result<JsInt, JsError> {
+            self.0._mul(&other.0).map(JsInt).map_err(js_error)
+        }
+
+        /// Quotient rounded toward zero, like `/` on `Int`.
+        pub fn div(&self, other: &JsInt) -> Result<JsInt, JsError> {
+            self.0._div(&other.0).map(JsInt).map_err(js_error)
+        }
+
+        pub fn rem(&self, other: &JsInt) -> Result<JsInt, JsError> {
+            self.0._modulo(&other.0).map(JsInt).map_err(js_error)
+        }
+
+        pub fn pow(&self, exponent: &JsInt) -> Result<JsInt, JsError> {
+            self.0.pow(&exponent.0).map(JsInt).map_err(js_error)
+        }
+
+        pub fn neg(&self) -> JsInt {
+            JsInt(-self.0.clone())
+        }
+
+        pub fn abs(&self) -> JsInt {
+            JsInt(self.0.abs())
+        }
+
+        /// `-1`, `0` or `1`.
+        #[wasm_bindgen(js_name = cmp)]
+        pub fn compare(&self, other: &JsInt) -> i32 {
+            self.0.cmp(&other.0) as i32
+        }
+
+        pub fn equals(&self, other: &JsInt) -> bool {
+            self.0 == other.0
+        }
+
+        #[wasm_bindgen(js_name = isZero)]
+        pub fn is_zero(&self) -> bool {
+            self.0.is_zero()
+        }
+
+        #[wasm_bindgen(js_name = isNegative)]
+        pub fn is_negative(&self) -> bool {
+            self.0.is_negative()
+        }
+
+        #[wasm_bindgen(js_name = toFloat)]
+        pub fn to_float(&self) -> Result<JsFloat, JsError> {
+            self.0.to_float().map(JsFloat).map_err(js_error)
+        }
+
+        #[wasm_bindgen(js_name = toString)]
+        pub fn to_js_string(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    impl From<Int> for JsInt {
+        fn from(value: Int) -> Self {
+            JsInt(value)
+        }
+    }
+
+    impl From<JsInt> for Int {
+        fn from(value: JsInt) -> Self {
+            value.0
+        }
+    }
+
+    /// A `Float`, exported to JavaScript as `Float`.
+    #[wasm_bindgen(js_name = Float)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct JsFloat(Float);
+
+    #[wasm_bindgen(js_class = Float)]
+    impl JsFloat {
+        /// Parses a decimal, recurring (`"0.(3)"`) or scientific literal as `Float::from_str`
+        /// does.
+        #[wasm_bindgen(constructor)]
+        pub fn new(value: &str) -> Result<JsFloat, JsError> {
+            Float::from_str(value).map(JsFloat).map_err(js_error)
+        }
+
+        /// The shortest decimal that reads back as `value`; `NaN` and the infinities map to
+        /// the special values.
+        #[wasm_bindgen(js_name = fromNumber)]
+        pub fn from_number(value: f64) -> JsFloat {
+            JsFloat(if value.is_finite() { Float::from_f64_shortest(value) } else { Float::from_bits(value.to_bits()) })
+        }
+
+        pub fn add(&self, other: &JsFloat) -> Result<JsFloat, JsError> {
+            self.0._add(&other.0).map(JsFloat).map_err(js_error)
+        }
+
+        pub fn sub(&self, other: &JsFloat) -> Result<JsFloat, JsError> {
+            self.0._sub(&other.0).map(JsFloat).map_err(js_error)
+        }
+
+        pub fn mul(&self, other: &JsFloat) -> Result<JsFloat, JsError> {
+            self.0._mul(&other.0).map(JsFloat).map_err(js_error)
+        }
+
+        pub fn div(&self, other: &JsFloat) -> Result<JsFloat, JsError> {
+            self.0._div(&other.0).map(JsFloat).map_err(js_error)
+        }
+
+        pub fn rem(&self, other: &JsFloat) -> Result<JsFloat, JsError> {
+            self.0._modulo(&other.0).map(JsFloat).map_err(js_error)
+        }
+
+        pub fn pow(&self, exponent: &JsFloat) -> Result<JsFloat, JsError> {
+            self.0._pow(&exponent.0).map(JsFloat).map_err(js_error)
+        }
+
+        pub fn sqrt(&self) -> Result<JsFloat, JsError> {
+            self.0.sqrt().map(JsFloat).map_err(js_error)
+        }
+
+        pub fn neg(&self) -> JsFloat {
+            JsFloat(-self.0.clone())
+        }
+
+        pub fn abs(&self) -> JsFloat {
+            JsFloat(self.0.abs())
+        }
+
+        /// `-1`, `0` or `1`; `undefined` when either side is NaN or complex.
+        #[wasm_bindgen(js_name = cmp)]
+        pub fn compare(&self, other: &JsFloat) -> Option<i32> {
+            self.0.partial_cmp(&other.0).map(|o: Ordering| o as i32)
+        }
+
+        pub fn equals(&self, other: &JsFloat) -> bool {
+            self.0 == other.0
+        }
+
+        #[wasm_bindgen(js_name = isZero)]
+        pub fn is_zero(&self) -> bool {
+            self.0.is_zero()
+        }
+
+        #[wasm_bindgen(js_name = isNaN)]
+        pub fn is_nan(&self) -> bool {
+            self.0.is_nan()
+        }
+
+        /// The nearest JavaScript number; throws for complex values.
+        #[wasm_bindgen(js_name = toNumber)]
+        pub fn to_number(&self) -> Result<f64, JsError> {
+            match self.0 {
+                Float::NaN => Ok(f64::NAN),
+                _ => self.0.to_f64().map_err(js_error),
+            }
+        }
+
+        #[wasm_bindgen(js_name = toString)]
+        pub fn to_js_string(&self) -> String {
+            self.0.to_string_full()
+        }
+    }
+
+    impl From<Float> for JsFloat {
+        fn from(value: Float) -> Self {
+            JsFloat(value)
+        }
+    }
+
+    impl From<JsFloat> for Float {
+        fn from(value: JsFloat) -> Self {
+            value.0
+        }
+    }
+}
+
+/// GMP through the `rug` crate as a `Backend` (enabled with `features = ["gmp"]`), for
+/// products, quotients, roots and decimal conversions of tens of thousands of digits.
+/// `NumBigint` stays the default; `config::set_backend(&Gmp)` switches to it.
+///
+/// Operands cross to GMP through their binary digits, which is linear but not free, so
+/// values under `GMP_THRESHOLD_BITS` stay on `NumBigint`. Results are exactly `NumBigint`'s.
+#[cfg(feature = "gmp")]
+pub mod feature_gmp {
+    use crate::alloc_prelude::*;
+    use crate::backend::{Backend, NumBigint};
+    use bigdecimal::BigDecimal;
+    use num_bigint::{BigInt, BigUint, Sign};
+    use rug::integer::Order;
+    use rug::Integer;
+
+    /// Size, in bits, from which `Gmp` hands an operation to GMP.
+    pub const GMP_THRESHOLD_BITS: u64 = 4_096;
+
+    /// The `gmp` backend.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct Gmp;
+
+    fn to_rug(x: &BigInt) -> Integer {
+        let magnitude = Integer::from_digits(&x.magnitude().to_u64_digits(), Order::Lsf);
+        if x.sign() == Sign::Minus { -magnitude } else { magnitude }
+    }
+
+    fn from_rug(x: &Integer) -> BigInt {
+        let sign = if *x < 0 { Sign::Minus } else { Sign::Plus };
+        BigInt::from_biguint(sign, BigUint::from_slice(&x.to_digits::<u32>(Order::Lsf)))
+    }
+
+    fn large(x: &BigInt) -> bool {
+        x.bits() >= GMP_THRESHOLD_BITS
+    }
+
+    impl Backend for Gmp {
+        fn name(&self) -> &'static str {
+            "gmp"
+        }
+
+        fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+            if !large(a) && !large(b) {
+                return NumBigint.mul(a, b);
+            }
+            from_rug(&Integer::from(&to_rug(a) * &to_rug(b)))
+        }
+
+        fn mul_assign(&self, a: &mut BigInt, b: &BigInt) {
+            if !large(a) && !large(b) {
+                return NumBigint.mul_assign(a, b);
+            }
+            *a = self.mul(a, b);
+        }
+
+        fn div_rem(&self, a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
+            if !large(a) {
+                return NumBigint.div_rem(a, b);
+            }
+            // rug's div_rem also rounds toward zero
+            let (q, r) = to_rug(a).div_rem(to_rug(b));
+            (from_rug(&q), from_rug(&r))
+        }
+
+        fn root(&self, a: &BigInt, n: u32) -> BigInt {
+            if !large(a) {
+                return NumBigint.root(a, n);
+            }
+            from_rug(&to_rug(a).root(n))
+        }
+
+        fn to_decimal(&self, a: &BigInt) -> String {
+            if !large(a) {
+                return NumBigint.to_decimal(a);
+            }
+            to_rug(a).to_string()
+        }
+
+        fn parse_decimal(&self, s: &str) -> Option<BigInt> {
+            // separators and malformed input stay on NumBigint, so both accept the same text
+            let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+            if s.len() < crate::chunked::THRESHOLD_DIGITS || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return NumBigint.parse_decimal(s);
+            }
+            Integer::from_str_radix(s, 10).ok().map(|x| from_rug(&x))
+        }
+
+        fn decimal_mul(&self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+            let ((a, a_scale), (b, b_scale)) = (a.as_bigint_and_exponent(), b.as_bigint_and_exponent());
+            BigDecimal::new(self.mul(&a, &b), a_scale + b_scale)
+        }
+    }
+}
@@ -0,0 +1,478 @@
+use crate::alloc_prelude::*;
+use crate::compat::int_to_bigint;
+use crate::foundation::{precision, Float, SmallFloat};
+use crate::math::bigdecimal_to_fraction;
+use bigdecimal::{BigDecimal, FromPrimitive, RoundingMode};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use core::fmt::{Binary, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult, UpperExp, UpperHex};
+use core::num::NonZeroU64;
+use core::str::FromStr;
+
+/// How `Float::format` lays out the number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// Fixed notation, switching to scientific once the decimal exponent exceeds
+    /// `FormatOptions::exponent_threshold` in magnitude
+    #[default]
+    Auto,
+    /// Plain digits with a decimal point (`12345.678`)
+    Fixed,
+    /// One digit before the point (`1.2345678e4`)
+    Scientific,
+    /// Exponent a multiple of 3, one to three digits before the point (`12.345678e3`)
+    Engineering,
+}
+
+/// How `Float::format` writes a value as a point in the complex plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComplexDisplayMode {
+    /// Real and imaginary parts (`3 + 4i`)
+    #[default]
+    Rectangular,
+    /// Magnitude and angle in radians (`5∠0.9272952180016122`)
+    Polar,
+    /// Magnitude and angle as an exponential (`5·e^{i0.9272952180016122}`)
+    Exponential,
+}
+
+/// Options for `Float::format`.
+///
+/// ```
+/// use imagnum::create_float;
+/// use imagnum::format::{FormatOptions, Notation};
+///
+/// let opts = FormatOptions::new().notation(Notation::Scientific).significant_digits(3);
+/// assert_eq!(create_float("12345").format(&opts), "1.23e4");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub notation: Notation,
+    /// Round to this many significant digits (half up), keeping trailing zeros
+    pub significant_digits: Option<u64>,
+    /// Inserted between groups of three digits in the integer part
+    pub thousands_separator: Option<char>,
+    /// Largest decimal exponent `Notation::Auto` still prints in fixed notation
+    pub exponent_threshold: i64,
+    /// Write `E` instead of `e`
+    pub uppercase_exponent: bool,
+    /// Rectangular, polar or exponential form; in the last two, real values are written
+    /// as points in the complex plane too (`-2∠3.14...`), so a column of results reads alike
+    pub complex_mode: ComplexDisplayMode,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            notation: Notation::Auto,
+            significant_digits: None,
+            thousands_separator: None,
+            exponent_threshold: 50,
+            uppercase_exponent: false,
+            complex_mode: ComplexDisplayMode::Rectangular,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    pub fn significant_digits(mut self, digits: u64) -> Self {
+        self.significant_digits = Some(digits);
+        self
+    }
+
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    pub fn exponent_threshold(mut self, threshold: i64) -> Self {
+        self.exponent_threshold = threshold;
+        self
+    }
+
+    pub fn uppercase_exponent(mut self, uppercase: bool) -> Self {
+        self.uppercase_exponent = uppercase;
+        self
+    }
+
+    pub fn complex_mode(mut self, mode: ComplexDisplayMode) -> Self {
+        self.complex_mode = mode;
+        self
+    }
+}
+
+fn group_thousands(int_part: &str, separator: Option<char>) -> String {
+    let Some(sep) = separator else {
+        return int_part.to_string();
+    };
+    let mut out = String::with_capacity(int_part.len() + int_part.len() / 3);
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i).is_multiple_of(3) {
+            out.push(sep);
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Renders `digits` (no sign, no leading zeros) with the decimal point placed so that
+/// `point` digits come before it; `point` may be `<= 0` or past the end.
+fn place_point(digits: &str, point: i64, separator: Option<char>) -> String {
+    let (int_part, frac_part) = if point <= 0 {
+        ("0".to_string(), format!("{}{}", "0".repeat((-point) as usize), digits))
+    } else if point as usize >= digits.len() {
+        (format!("{}{}", digits, "0".repeat(point as usize - digits.len())), String::new())
+    } else {
+        let (i, f) = digits.split_at(point as usize);
+        (i.to_string(), f.to_string())
+    };
+    let int_part = group_thousands(&int_part, separator);
+    if frac_part.is_empty() { int_part } else { format!("{}.{}", int_part, frac_part) }
+}
+
+fn format_decimal(bd: &BigDecimal, opts: &FormatOptions) -> String {
+    let bd = match opts.significant_digits.and_then(NonZeroU64::new) {
+        Some(n) => bd.with_precision_round(n, RoundingMode::HalfUp),
+        None => bd.normalized(),
+    };
+    let (unscaled, scale) = bd.as_bigint_and_scale();
+    let negative = unscaled.is_negative();
+    let digits = unscaled.abs().to_string();
+    // value = 0.d1d2d3... * 10^(exponent + 1)
+    let exponent = digits.len() as i64 - 1 - scale;
+    let is_zero = digits.chars().all(|c| c == '0');
+
+    let notation = match opts.notation {
+        Notation::Auto if exponent.abs() > opts.exponent_threshold && !is_zero => Notation::Scientific,
+        Notation::Auto => Notation::Fixed,
+        n => n,
+    };
+    let e = if opts.uppercase_exponent { 'E' } else { 'e' };
+    let body = match notation {
+        Notation::Fixed | Notation::Auto => place_point(&digits, digits.len() as i64 - scale, opts.thousands_separator),
+        Notation::Scientific => {
+            let exponent = if is_zero { 0 } else { exponent };
+            format!("{}{}{}", place_point(&digits, 1, None), e, exponent)
+        }
+        Notation::Engineering => {
+            let exponent = if is_zero { 0 } else { exponent };
+            let eng = exponent.div_euclid(3) * 3;
+            let lead = exponent - eng + 1;
+            format!("{}{}{}", place_point(&digits, lead, opts.thousands_separator), e, eng)
+        }
+    };
+    if negative { format!("-{}", body) } else { body }
+}
+
+/// `r∠θ` or `r·e^{iθ}` from the formatted magnitude and angle.
+fn polar_form(r: &str, theta: &str, mode: ComplexDisplayMode) -> String {
+    match (mode, theta.strip_prefix('-')) {
+        (ComplexDisplayMode::Exponential, Some(abs)) => format!("{}·e^{{-i{}}}", r, abs),
+        (ComplexDisplayMode::Exponential, None) => format!("{}·e^{{i{}}}", r, theta),
+        _ => format!("{}∠{}", r, theta),
+    }
+}
+
+/// `x` with exactly `decimals` digits after the point, rounded half up.
+fn fixed_decimals(x: &Float, decimals: usize) -> String {
+    let bd = match x {
+        Float::Small(SmallFloat::F64(v)) => BigDecimal::from_str(&v.to_string()).ok(),
+        Float::Small(SmallFloat::F32(v)) => BigDecimal::from_str(&v.to_string()).ok(),
+        _ => crate::compat::float_to_bigdecimal(x),
+    };
+    let Some(bd) = bd else {
+        return x.to_string();
+    };
+    let (unscaled, scale) = bd.with_scale_round(decimals as i64, RoundingMode::HalfUp).into_bigint_and_exponent();
+    let digits = unscaled.abs().to_string();
+    let body = place_point(&digits, digits.len() as i64 - scale, None);
+    if unscaled.is_negative() { format!("-{}", body) } else { body }
+}
+
+impl Float {
+    /// Formats the value according to `opts`. NaN and the infinities render as in `Display`;
+    /// complex values format each part, or magnitude and angle under a polar
+    /// `complex_mode`. Unlike `Display`, no `.0` is appended to integer values and
+    /// irrational values carry no `...` marker.
+    pub fn format(&self, opts: &FormatOptions) -> String {
+        let polar = Some(opts.complex_mode)
+            .filter(|&mode| mode != ComplexDisplayMode::Rectangular)
+            .and_then(|_| self.to_polar().ok())
+            .filter(|(r, _)| !r.is_infinity());
+        if let Some((r, theta)) = polar {
+            let parts = FormatOptions { complex_mode: ComplexDisplayMode::Rectangular, ..opts.clone() };
+            return polar_form(&r.format(&parts), &theta.format(&parts), opts.complex_mode);
+        }
+        let bd = match self {
+            Float::Big(bd) | Float::Irrational(bd) => bd.clone(),
+            Float::Recurring(num, den) => crate::recurring::expand(num, den),
+            // shortest round-tripping digits rather than the exact binary expansion
+            Float::Small(SmallFloat::F64(v)) if v.is_finite() => match BigDecimal::from_str(&v.to_string()) {
+                Ok(bd) => bd,
+                Err(_) => return self.to_string(),
+            },
+            Float::Small(SmallFloat::F32(v)) if v.is_finite() => match BigDecimal::from_str(&v.to_string()) {
+                Ok(bd) => bd,
+                Err(_) => return self.to_string(),
+            },
+            Float::Complex(real, imag) => {
+                let re = real.format(opts);
+                let im = imag.format(opts);
+                return match im.strip_prefix('-') {
+                    Some(abs) => format!("{} - {}i", re, abs),
+                    None => format!("{} + {}i", re, im),
+                };
+            }
+            _ => return self.to_string(),
+        };
+        format_decimal(&bd, opts)
+    }
+
+    /// The value as `r∠θ`, magnitude and angle in radians, each with exactly `precision`
+    /// decimals. The angle comes from `arg`, so only its first 16 or so digits are
+    /// meaningful. NaN and the infinities render as in `Display`.
+    ///
+    /// ```
+    /// use imagnum::create_complex;
+    ///
+    /// assert_eq!(create_complex("3", "4").to_polar_string(3), "5.000∠0.927");
+    /// ```
+    pub fn to_polar_string(&self, precision: usize) -> String {
+        match self.to_polar() {
+            Ok((r, theta)) if !r.is_infinity() => polar_form(
+                &fixed_decimals(&r, precision),
+                &fixed_decimals(&theta, precision),
+                ComplexDisplayMode::Polar,
+            ),
+            _ => self.to_string(),
+        }
+    }
+
+    fn format_exp(&self, f: &mut Formatter<'_>, uppercase: bool) -> FmtResult {
+        let mut opts = FormatOptions::new().notation(Notation::Scientific).uppercase_exponent(uppercase);
+        if let Some(precision) = f.precision() {
+            opts = opts.significant_digits(precision as u64 + 1);
+        }
+        pad(f, &self.format(&opts))
+    }
+
+    /// `{:x}`/`{:X}` write the `%a`-style hexadecimal form `1.8p+3` and `{:b}`/`{:o}` the
+    /// positional digits `1100.1`, each with its `0x`/`0b`/`0o` prefix under `{:#}`. A
+    /// precision fixes the number of fractional digits; otherwise the digits are exact
+    /// when the expansion terminates and cover `precision()` decimal digits when it does not.
+    fn format_radix(&self, f: &mut Formatter<'_>, radix: u32, uppercase: bool) -> FmtResult {
+        if let Float::Complex(real, imag) = self {
+            let (re, im) = (real.radix_string(f, radix, uppercase), imag.radix_string(f, radix, uppercase));
+            let s = match im.strip_prefix('-') {
+                Some(abs) => format!("{} - {}i", re, abs),
+                None => format!("{} + {}i", re, im),
+            };
+            return pad(f, &s);
+        }
+        pad(f, &self.radix_string(f, radix, uppercase))
+    }
+
+    fn radix_string(&self, f: &Formatter<'_>, radix: u32, uppercase: bool) -> String {
+        // a small float shows its exact binary value, not its shortest decimal
+        let exact = match self {
+            Float::Small(SmallFloat::F64(v)) => BigDecimal::from_f64(*v).map(|bd| bigdecimal_to_fraction(&bd)),
+            Float::Small(SmallFloat::F32(v)) => BigDecimal::from_f32(*v).map(|bd| bigdecimal_to_fraction(&bd)),
+            _ => self.to_fraction(None).ok().map(|(n, d)| (int_to_bigint(&n), int_to_bigint(&d))),
+        };
+        let Some((num, den)) = exact else {
+            return self.to_string();
+        };
+        let sign = if num.is_negative() { "-" } else { "" };
+        let prefix = match (f.alternate(), radix) {
+            (false, _) => "",
+            (true, 2) => "0b",
+            (true, 8) => "0o",
+            (true, _) => "0x",
+        };
+        let (body, exponent) = if radix == 16 {
+            let (int_part, frac, exponent) = binary_scientific(&num.abs(), &den, f.precision());
+            (radix_body(&int_part, &frac, 16, false), Some(exponent))
+        } else {
+            let (int_part, frac) = radix_digits(&num.abs(), &den, radix, f.precision());
+            (radix_body(&int_part, &frac, radix, true), None)
+        };
+        let body = if uppercase { body.to_uppercase() } else { body };
+        match exponent {
+            Some(e) => format!("{}{}{}{}{:+}", sign, prefix, body, if uppercase { 'P' } else { 'p' }, e),
+            None => format!("{}{}{}", sign, prefix, body),
+        }
+    }
+}
+
+/// Writes `s` padded to the formatter's width; `Formatter::pad` would treat the precision
+/// as a maximum length, so this pads by hand.
+fn pad(f: &mut Formatter<'_>, s: &str) -> FmtResult {
+    let len = s.chars().count();
+    let width = f.width().unwrap_or(0);
+    if len >= width {
+        return f.write_str(s);
+    }
+    let fill = f.fill().to_string();
+    let (before, after) = match f.align() {
+        Some(core::fmt::Alignment::Left) => (0, width - len),
+        Some(core::fmt::Alignment::Center) => ((width - len) / 2, width - len - (width - len) / 2),
+        _ => (width - len, 0),
+    };
+    write!(f, "{}{}{}", fill.repeat(before), s, fill.repeat(after))
+}
+
+/// `bd` rounded half-even to exactly `places` decimals, as `{:.N}` does for `f64`.
+pub(crate) fn fixed_places(bd: &BigDecimal, places: usize) -> String {
+    bd.with_scale_round(places as i64, RoundingMode::HalfEven).to_plain_string()
+}
+
+/// `num / den` (`den > 0`) rounded half-even to exactly `places` decimals.
+pub(crate) fn ratio_places(num: &BigInt, den: &BigInt, places: usize) -> String {
+    let (mut q, rem) = (num.abs() * num_traits::pow(BigInt::from(10), places)).div_rem(den);
+    let twice = rem * 2u32;
+    if twice > *den || (twice == *den && q.is_odd()) {
+        q += 1u32;
+    }
+    let q = if num.is_negative() { -q } else { q };
+    fixed_places(&BigDecimal::new(q, places as i64), places)
+}
+
+/// Writes `s` with the `+` flag, zero padding (`{:08}`, after the sign and only when
+/// `zero_pad` allows it) and then the width, fill and alignment of `pad`.
+pub(crate) fn pad_number(f: &mut Formatter<'_>, s: &str, zero_pad: bool) -> FmtResult {
+    let signed;
+    let s = if f.sign_plus() && !s.starts_with('-') && s != "NaN" {
+        signed = format!("+{}", s);
+        signed.as_str()
+    } else {
+        s
+    };
+    let len = s.chars().count();
+    match f.width() {
+        Some(width) if zero_pad && f.sign_aware_zero_pad() && len < width => {
+            let (sign, digits) = s.split_at(if s.starts_with(['+', '-']) { 1 } else { 0 });
+            write!(f, "{}{}{}", sign, "0".repeat(width - len), digits)
+        }
+        _ => pad(f, s),
+    }
+}
+
+/// `int.frac` in `radix`; an empty fraction is written as `.0` when `keep_point` is set
+/// (as `Display` does) and dropped otherwise (as `%a` does).
+fn radix_body(int_part: &BigInt, frac: &str, radix: u32, keep_point: bool) -> String {
+    let int_part = int_part.to_str_radix(radix);
+    match (frac.is_empty(), keep_point) {
+        (false, _) => format!("{}.{}", int_part, frac),
+        (true, true) => format!("{}.0", int_part),
+        (true, false) => int_part,
+    }
+}
+
+/// Digits of `num / den` (`num >= 0`, `den > 0`, in lowest terms) in a power-of-two
+/// `radix` as the integer part and the fractional digits. With `places` the fraction is
+/// rounded half-even to exactly that many digits; otherwise it is exact when `den` is a
+/// power of two and rounded to cover `precision()` decimal digits when it is not, with
+/// trailing zeros dropped.
+fn radix_digits(num: &BigInt, den: &BigInt, radix: u32, places: Option<usize>) -> (BigInt, String) {
+    let bits = radix.trailing_zeros() as u64;
+    let twos = den.trailing_zeros().unwrap_or(0);
+    let terminating = (den >> twos).is_one();
+    let count = match places {
+        Some(p) => p,
+        None if terminating => twos.div_ceil(bits) as usize,
+        // log2(10) ≈ 3.3219 bits per decimal digit
+        None => (precision() * 33_219 / 10_000).div_ceil(bits) as usize,
+    };
+    let scale = num_traits::pow(BigInt::from(radix), count);
+    let (mut scaled, rem) = (num * &scale).div_rem(den);
+    let twice = rem * 2u32;
+    if twice > *den || (twice == *den && scaled.is_odd()) {
+        scaled += 1u32;
+    }
+    let (int_part, frac) = scaled.div_rem(&scale);
+    let mut frac = format!("{:0>width$}", frac.to_str_radix(radix), width = count);
+    if count == 0 {
+        frac.clear();
+    }
+    if places.is_none() {
+        frac.truncate(frac.trim_end_matches('0').len());
+    }
+    (int_part, frac)
+}
+
+/// `num / den` (`num >= 0`, `den > 0`) as `m * 2^e` with `1 <= m < 2`, giving the integer
+/// and hexadecimal fractional digits of `m` and `e`; zero is `0p+0`.
+fn binary_scientific(num: &BigInt, den: &BigInt, places: Option<usize>) -> (BigInt, String, i64) {
+    if num.is_zero() {
+        let (int_part, frac) = radix_digits(num, den, 16, places);
+        return (int_part, frac, 0);
+    }
+    let mut e = num.bits() as i64 - den.bits() as i64;
+    let scaled = |e: i64| if e >= 0 { (num.clone(), den << e as usize) } else { (num << (-e) as usize, den.clone()) };
+    let (mut n, mut d) = scaled(e);
+    if n < d {
+        e -= 1;
+        (n, d) = scaled(e);
+    }
+    let g = n.gcd(&d);
+    let (int_part, frac) = radix_digits(&(n / &g), &(d / g), 16, places);
+    if int_part > BigInt::one() {
+        // rounded up to 2: 1.000…p(e+1)
+        let frac = if places.is_some() { "0".repeat(frac.len()) } else { String::new() };
+        return (BigInt::one(), frac, e + 1);
+    }
+    (int_part, frac, e)
+}
+
+
+/// `{:e}`: scientific notation; `{:.3e}` keeps three digits after the point.
+impl LowerExp for Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.format_exp(f, false)
+    }
+}
+
+/// `{:E}`: scientific notation with an uppercase exponent marker.
+impl UpperExp for Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.format_exp(f, true)
+    }
+}
+
+/// `{:b}`: positional binary digits (`1100.1`); see `Float::format_radix`.
+impl Binary for Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.format_radix(f, 2, false)
+    }
+}
+
+/// `{:o}`: positional octal digits (`14.4`).
+impl Octal for Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.format_radix(f, 8, false)
+    }
+}
+
+/// `{:x}`: `%a`-style hexadecimal (`1.9p+3`).
+impl LowerHex for Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.format_radix(f, 16, false)
+    }
+}
+
+/// `{:X}`: `%A`-style hexadecimal (`1.9P+3`).
+impl UpperHex for Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.format_radix(f, 16, true)
+    }
+}
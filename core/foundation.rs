@@ -1,7 +1,12 @@
+use crate::alloc_prelude::*;
 use bigdecimal::BigDecimal;
 use num_bigint::BigInt;
+#[cfg(not(feature = "std"))]
+use once_cell::unsync::Lazy;
+#[cfg(feature = "std")]
 use once_cell::sync::Lazy;
-use std::str::FromStr;
+use core::str::FromStr;
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use crate::impls::{IntoSmallInt, IntoSmallFloat};
 
@@ -17,6 +22,57 @@ pub enum FloatKind {
     Complex,
 }
 
+/// Rounding mode for `Int::div_mode`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Default)]
+pub enum DivMode {
+    /// Round toward zero (the `/` operator)
+    #[default]
+    Trunc,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceil,
+    /// Round so the remainder is never negative
+    Euclid,
+    /// Round to nearest, ties away from zero (what `/` used to do)
+    Round,
+}
+
+/// Rounding mode for `Float::round_with` and `Int::div_round`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Default)]
+pub enum RoundingMode {
+    /// To nearest, ties away from zero (what `Float::round` does)
+    #[default]
+    HalfUp,
+    /// To nearest, ties to the even neighbour
+    HalfEven,
+    /// To nearest, ties toward zero
+    HalfDown,
+    /// Toward negative infinity
+    Floor,
+    /// Toward positive infinity
+    Ceiling,
+    /// Drop the discarded digits (what `Float::truncate` does)
+    TowardZero,
+    /// Away from zero whenever anything is discarded
+    AwayFromZero,
+}
+
+/// Fixed-width two's-complement integer type emulated by the `*_width` methods on `Int`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+pub enum BitWidth {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    I128,
+    U128,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub enum SmallInt {
     I8(i8),
@@ -33,13 +89,50 @@ pub enum SmallInt {
     ISize(isize),
 }
 
+/// Variant tag of `SmallInt`, used to request a specific representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+pub enum SmallIntKind {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    I128,
+    U128,
+    USize,
+    ISize,
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Copy)]
 pub enum SmallFloat {
     F32(f32),
     F64(f64),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Structured description of a number, as returned by `Int::describe()` and `Float::describe()`.
+///
+/// Frontends (the REPL, Lucia's `info` builtin) render this instead of matching on enum variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberInfo {
+    /// `true` for `Int`, `false` for `Float`
+    pub is_int: bool,
+    /// Kind of the value (`Finite` for every `Int`)
+    pub kind: FloatKind,
+    pub negative: bool,
+    /// Number of significant decimal digits (0 for NaN/Infinity/Complex)
+    pub digits: usize,
+    /// Number of digits after the decimal point (0 for integers)
+    pub scale: i64,
+    /// `false` when the stored value is a truncated approximation (irrational results, NaN)
+    pub exact: bool,
+    /// Rough number of bytes used by the value, heap included
+    pub memory: usize,
+}
+
+#[derive(Debug, Clone)]
 pub enum Int {
     Big(BigInt),
     Small(SmallInt),
@@ -50,13 +143,69 @@ pub enum Float {
     Small(SmallFloat),
     Big(BigDecimal),
     Irrational(BigDecimal),
-    Recurring(BigDecimal),
+    /// `numerator / denominator` whose decimal never terminates, in lowest terms with a
+    /// positive denominator.
+    Recurring(BigInt, BigInt),
+    /// `coefficient * sqrt(radicand)` kept symbolically, with a square-free radicand >= 2.
+    Surd(BigDecimal, BigInt),
     Complex(Box<Float>, Box<Float>),
     NaN,
     Infinity,
     NegInfinity,
 }
 
+static AUTO_SHRINK: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables automatic `Int::shrink()` on the results of `Int` arithmetic
+/// (add, sub, mul, div, modulo, pow). Off by default; the setting is process-wide.
+pub fn set_auto_shrink(enabled: bool) {
+    AUTO_SHRINK.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether automatic shrinking of `Int` results is enabled.
+pub fn auto_shrink() -> bool {
+    AUTO_SHRINK.load(Ordering::Relaxed)
+}
+
+/// Default of `precision()`.
+pub const DEFAULT_PRECISION: u64 = 137;
+
+/// Largest value `set_precision` accepts; larger requests are clamped to it, which also
+/// keeps the guard digits added on top of `precision()` from overflowing.
+pub const MAX_PRECISION: u64 = 1_000_000;
+
+static PRECISION: AtomicU64 = AtomicU64::new(DEFAULT_PRECISION);
+
+/// Sets the precision context: the significant digits kept wherever a result has to be
+/// rounded (`Float::pow_int` past its exact range, `hypot`, `exp_m1`, `ln_1p`, and the fused
+/// `mul_add` / `dot` / `sum` on irrational inputs). `0` counts as `1` and anything above
+/// `MAX_PRECISION` as `MAX_PRECISION`. Process-wide, like `set_auto_shrink`.
+pub fn set_precision(digits: u64) {
+    PRECISION.store(digits.clamp(1, MAX_PRECISION), Ordering::Relaxed);
+}
+
+/// Returns the significant digits of the precision context.
+pub fn precision() -> u64 {
+    PRECISION.load(Ordering::Relaxed)
+}
+
+/// Default of `display_digits()`.
+pub const DEFAULT_DISPLAY_DIGITS: u64 = 20;
+
+static DISPLAY_DIGITS: AtomicU64 = AtomicU64::new(DEFAULT_DISPLAY_DIGITS);
+
+/// Sets the significant digits `Display` shows of an irrational value before cutting it
+/// short with `…`; `0` shows every stored digit, as `Float::to_string_full` always does.
+/// Process-wide, like `set_precision`.
+pub fn set_display_digits(digits: u64) {
+    DISPLAY_DIGITS.store(digits, Ordering::Relaxed);
+}
+
+/// Returns the significant digits `Display` shows of an irrational value; `0` for all.
+pub fn display_digits() -> u64 {
+    DISPLAY_DIGITS.load(Ordering::Relaxed)
+}
+
 impl Int {
     pub const ZERO: Lazy<Int> = Lazy::new(|| Int::new());
     pub const ONE: Lazy<Int> = Lazy::new(|| Int::from(1));
@@ -66,6 +215,12 @@ impl Int {
         Self::Big(BigInt::from(0))
     }
 
+    /// A shared, lazily built `Int` for a value in `-128..=127`, for comparisons and other
+    /// borrows that would otherwise build a throwaway value.
+    pub fn interned(value: i8) -> &'static Int {
+        crate::cache::small_int(value)
+    }
+
     pub fn new_small<T: IntoSmallInt>(value: T) -> Self {
         value.into_small_int()
     }
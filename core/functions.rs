@@ -1,3 +1,4 @@
+use crate::alloc_prelude::*;
 use crate::foundation::{Float, Int};
 use crate::math::{
     ERR_DIV_BY_ZERO, ERR_INFINITE_RESULT, ERR_INVALID_FORMAT, ERR_NEGATIVE_RESULT,
@@ -7,49 +8,83 @@ use crate::foundation::SmallFloat;
 use bigdecimal::BigDecimal;
 use bigdecimal::FromPrimitive;
 use num_bigint::BigInt;
-use num_traits::{Signed, Zero, ToPrimitive};
-use std::str::FromStr;
+use num_traits::Zero;
+use alloc::borrow::Cow;
+use core::str::FromStr;
 
+/// Parses an optionally signed decimal integer. Anything it cannot read, such as `"abc"`,
+/// `"1.5"` or `"Infinity"`, gives zero; `try_create_int` reports those instead.
 pub fn create_int(int: &str) -> Int {
-    let s = int.trim();
-    if s.is_empty() {
-        return Int::new();
-    }
+    try_create_int(int).unwrap_or_else(|_| Int::new())
+}
 
+/// Parses an optionally signed decimal integer, ignoring surrounding whitespace. Fails
+/// with `INFINITE_RESULT` for `inf` / `infinity`, which no `Int` can hold, and with
+/// `INVALID_FORMAT` for everything else that is not an integer, including empty input,
+/// `nan` and decimals such as `"1.5"`.
+///
+/// ```
+/// use imagnum::{errors, try_create_int, create_int};
+///
+/// assert_eq!(try_create_int(" -42 "), Ok(create_int("-42")));
+/// assert_eq!(try_create_int("1.5"), Err(errors::INVALID_FORMAT));
+/// assert_eq!(try_create_int("-Infinity"), Err(errors::INFINITE_RESULT));
+/// ```
+pub fn try_create_int(int: &str) -> Result<Int, i8> {
+    let s = int.trim();
     let low = s.to_ascii_lowercase();
-    if low == "nan" || low == "inf" || low == "infinity" || low == "-inf" || low == "-infinity" {
-        return Int::new();
-    }
-
-    if s.contains('.') {
-        return Int::new();
-    }
-
-    match BigInt::from_str(s) {
-        Ok(b) => Int::Big(b),
-        Err(_) => Int::new(),
+    if matches!(low.trim_start_matches(['+', '-']), "inf" | "infinity") {
+        return Err(ERR_INFINITE_RESULT);
     }
+    crate::backend::backend().parse_decimal(s).map(Int::Big).ok_or(ERR_INVALID_FORMAT)
 }
 
+/// Parses a float literal as `try_create_float` does, giving NaN for anything it cannot
+/// read and zero for an empty string.
 pub fn create_float(float: &str) -> Float {
-    let s = float.trim();
-    if s.is_empty() {
+    if float.trim().is_empty() {
         return Float::Big(BigDecimal::from(0));
     }
+    try_create_float(float).unwrap_or(Float::NaN)
+}
 
+/// Parses a float literal, ignoring surrounding whitespace: a decimal such as `-1_000.5e-3`,
+/// `.5` or `5.` (underscores may stand between digits and the exponent may be signed), a
+/// recurring decimal `0.1(6)`, a hexadecimal float `0x1.8p3`, `nan` and `inf`, an
+/// imaginary or complex value `2i` / `1.5-2i`, a surd `3√2` and the `digits...` form of
+/// irrational values.
+///
+/// Fails with `NUMBER_TOO_LARGE` when the exponent puts the value out of the range of an
+/// `i64` scale and with `INVALID_FORMAT` for anything else it cannot read.
+///
+/// ```
+/// use imagnum::{create_float, errors, try_create_float};
+///
+/// assert_eq!(try_create_float("1_000.5e-3"), Ok(create_float("1.0005")));
+/// assert_eq!(try_create_float("1e+99999999999999999999"), Err(errors::NUMBER_TOO_LARGE));
+/// assert_eq!(try_create_float("1._5"), Err(errors::INVALID_FORMAT));
+/// ```
+pub fn try_create_float(float: &str) -> Result<Float, i8> {
+    let s = float.trim();
     let lower = s.to_ascii_lowercase();
     if lower == "nan" {
-        return Float::NaN;
+        return Ok(Float::NaN);
     }
     if lower == "inf" || lower == "infinity" {
-        return Float::Infinity;
+        return Ok(Float::Infinity);
     }
     if lower == "-inf" || lower == "-infinity" {
-        return Float::NegInfinity;
+        return Ok(Float::NegInfinity);
     }
 
+    if lower.trim_start_matches(['+', '-']).starts_with("0x") {
+        return not_nan(create_hex_float_literal(s));
+    }
     if lower.ends_with('i') {
         let without_i = &s[..s.len() - 1];
+        if let Some(split) = complex_split(without_i) {
+            return not_nan(create_complex_literal(&without_i[..split], &without_i[split..]));
+        }
         let coeff = if without_i.is_empty() || without_i == "+" {
             "1"
         } else if without_i == "-" {
@@ -57,125 +92,775 @@ pub fn create_float(float: &str) -> Float {
         } else {
             without_i
         };
-        let bd = BigDecimal::from_str(coeff).unwrap_or_else(|_| BigDecimal::from(0));
+        let bd = parse_decimal_literal(coeff)?;
         let zero = Float::Big(BigDecimal::from(0));
         let imag = Float::Big(bd);
-        return Float::Complex(Box::new(zero), Box::new(imag));
-    }
-
-    if let Some(lp) = s.find('(') {
-        if s.ends_with(')') {
-            let repeat = &s[lp + 1..s.len() - 1];
-            let before = &s[..lp];
-            let sign = if before.starts_with('-') { -1 } else { 1 };
-            let before_nosign = if before.starts_with('+') || before.starts_with('-') {
-                &before[1..]
-            } else {
-                before
-            };
-            let (int_part_str, nonrep_str) = if let Some(dot) = before_nosign.find('.') {
-                (&before_nosign[..dot], &before_nosign[dot + 1..])
-            } else {
-                (before_nosign, "")
-            };
+        return Ok(Float::Complex(Box::new(zero), Box::new(imag)));
+    }
 
-            if !int_part_str.chars().all(|c| c.is_ascii_digit())
-                || !nonrep_str.chars().all(|c| c.is_ascii_digit())
-                || !repeat.chars().all(|c| c.is_ascii_digit())
-            {
-                return Float::NaN;
-            }
+    if let Some(dots) = s.find("...") {
+        return not_nan(create_irrational_literal(&s[..dots], &s[dots + 3..]));
+    }
+    if let Some(dots) = s.find('…') {
+        return not_nan(create_irrational_literal(&s[..dots], &s[dots + '…'.len_utf8()..]));
+    }
 
-            let ip = BigInt::from_str(if int_part_str.is_empty() {
-                "0"
-            } else {
-                int_part_str
-            })
-            .unwrap_or_else(|_| BigInt::from(0));
-            let nonrep = if nonrep_str.is_empty() {
-                BigInt::from(0)
-            } else {
-                BigInt::from_str(nonrep_str).unwrap_or_else(|_| BigInt::from(0))
-            };
-            let rep = BigInt::from_str(repeat).unwrap_or_else(|_| BigInt::from(0));
+    if let Some(root) = s.find('√') {
+        return not_nan(create_surd_literal(&s[..root], &s[root + '√'.len_utf8()..]));
+    }
 
-            let len_nonrep = nonrep_str.len() as u32;
-            let len_rep = repeat.len() as u32;
+    if let Some(lp) = s.find('(')
+        && s.ends_with(')')
+    {
+        let repeat = strip_separators(&s[lp + 1..s.len() - 1])?;
+        let before = &s[..lp];
+        let (int_part, nonrep) = before.split_once('.').unwrap_or((before, ""));
+        let sign = &int_part[..int_part.len() - int_part.trim_start_matches(['+', '-']).len()];
+        let int_part = format!("{}{}", sign, strip_separators(&int_part[sign.len()..])?);
+        return Float::from_recurring_parts(&int_part, &strip_separators(nonrep)?, &repeat);
+    }
 
-            let ten = BigInt::from(10u32);
-            let pow_nr = ten.pow(len_nonrep);
-            let pow_r = ten.pow(len_rep);
-            let denom = &pow_nr * (&pow_r - BigInt::from(1u32));
+    parse_decimal_literal(s).map(Float::Big)
+}
 
-            let numer_frac = &nonrep * (&pow_r - BigInt::from(1u32)) + &rep;
+fn not_nan(f: Float) -> Result<Float, i8> {
+    if f.is_nan() { Err(ERR_INVALID_FORMAT) } else { Ok(f) }
+}
 
-            let mut total_num = &ip * &denom + numer_frac;
-            if sign < 0 {
-                total_num = -total_num;
-            }
+/// `digits` without its `_` separators, which may only stand between two digits.
+fn strip_separators(digits: &str) -> Result<Cow<'_, str>, i8> {
+    if !digits.contains('_') {
+        return Ok(Cow::Borrowed(digits));
+    }
+    if scan_digits(digits.as_bytes(), 0, 10) != digits.len() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    Ok(Cow::Owned(digits.replace('_', "")))
+}
 
-            use std::collections::HashMap;
-            let mut num_abs = total_num.clone();
-            let den_abs = denom.clone().abs();
-            let neg = total_num.sign() == num_bigint::Sign::Minus;
-            if neg { num_abs = -num_abs.clone(); }
-            let int_part = (&num_abs / &den_abs).to_string();
-            let mut rem = num_abs % &den_abs;
-            let mut seen: HashMap<BigInt, usize> = HashMap::new();
-            let mut digits: Vec<char> = Vec::new();
-            let max_digits = 10000usize;
-            while !rem.is_zero() && !seen.contains_key(&rem) && digits.len() < max_digits {
-                seen.insert(rem.clone(), digits.len());
-                rem = rem * BigInt::from(10u32);
-                let q = (&rem / &den_abs).to_i32().unwrap_or(0);
-                digits.push(std::char::from_digit(q as u32, 10).unwrap_or('0'));
-                rem = rem % &den_abs;
-            }
+/// Parses `[sign] digits [. digits] [e [sign] digits]`, where either run of mantissa digits
+/// may be empty but not both. Reads the digits itself rather than through
+/// `BigDecimal::from_str`, so separators follow one rule everywhere and an exponent
+/// too large for the scale fails with `NUMBER_TOO_LARGE` instead of wrapping around.
+fn parse_decimal_literal(s: &str) -> Result<BigDecimal, i8> {
+    let bytes = s.as_bytes();
+    let sign = usize::from(matches!(bytes.first(), Some(b'-') | Some(b'+')));
+    let int_end = scan_digits(bytes, sign, 10);
+    let mut pos = int_end;
+    let mut frac = "";
+    if bytes.get(pos) == Some(&b'.') {
+        pos = scan_digits(bytes, pos + 1, 10);
+        frac = &s[int_end + 1..pos];
+    }
+    if int_end == sign && frac.is_empty() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    let mut exponent = 0i64;
+    if matches!(bytes.get(pos), Some(b'e') | Some(b'E')) {
+        let digits_start = pos + 1 + usize::from(matches!(bytes.get(pos + 1), Some(b'-') | Some(b'+')));
+        let exp_end = scan_digits(bytes, digits_start, 10);
+        if exp_end == digits_start {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        exponent = s[pos + 1..exp_end].replace('_', "").parse().map_err(|_| ERR_NUMBER_TOO_LARGE)?;
+        pos = exp_end;
+    }
+    if pos != bytes.len() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    let frac = frac.replace('_', "");
+    let scale = (frac.len() as i64).checked_sub(exponent).ok_or(ERR_NUMBER_TOO_LARGE)?;
+    let digits = format!("{}{}", s[..int_end].replace('_', ""), frac);
+    // the decimal exponent of the leading digit has to fit as well
+    (digits.len() as i64).checked_sub(scale).ok_or(ERR_NUMBER_TOO_LARGE)?;
+    let value = crate::backend::backend().parse_decimal(&digits).ok_or(ERR_INVALID_FORMAT)?;
+    Ok(BigDecimal::new(value, scale))
+}
 
-            let mut frac_str = String::new();
-            if digits.is_empty() {
-                let s_out = if neg { format!("-{}.0", int_part) } else { format!("{}.0", int_part) };
-                let bd = BigDecimal::from_str(&s_out).unwrap_or_else(|_| BigDecimal::from(0));
-                return Float::Big(bd);
-            } else {
-                if let Some(start) = seen.get(&rem) {
-                    let start = *start;
-                    let nonrep: String = digits[..start].iter().collect();
-                    let rep: String = digits[start..].iter().collect();
-                    let min_repeats = 4usize;
-                    let repeat_count = min_repeats;
-                    frac_str.push_str(&nonrep);
-                    for _ in 0..repeat_count {
-                        frac_str.push_str(&rep);
-                    }
-                } else {
-                    for d in digits.iter() { frac_str.push(*d); }
-                }
+
+/// Largest binary exponent a hexadecimal float literal may carry.
+const MAX_HEX_EXPONENT: i64 = 100_000;
+
+/// Parses a `%a`-style hexadecimal float such as `0x1.8p3` or `-0x.4p-2`: hex digits with
+/// an optional fractional part, scaled by the power of two after `p`. Hex floats are
+/// dyadic, so the value is an exact `Float::Big`.
+fn create_hex_float_literal(s: &str) -> Float {
+    let (negative, body) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let body = &body[2..];
+    let (digits, exponent) = match body.find(['p', 'P']) {
+        Some(p) => (&body[..p], body[p + 1..].parse::<i64>().ok()),
+        None => (body, Some(0)),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    let Some(exponent) = exponent.filter(|e| e.abs() <= MAX_HEX_EXPONENT) else {
+        return Float::NaN;
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Float::NaN;
+    }
+    let all = format!("{}{}", int_part, frac_part);
+    if !all.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Float::NaN;
+    }
+    let Some(mut mantissa) = BigInt::parse_bytes(all.as_bytes(), 16) else {
+        return Float::NaN;
+    };
+    if negative {
+        mantissa = -mantissa;
+    }
+    // value = mantissa * 2^shift, and 2^-k = 5^k / 10^k
+    let shift = exponent - 4 * frac_part.len() as i64;
+    if shift >= 0 {
+        Float::Big(BigDecimal::from(mantissa << shift as usize))
+    } else {
+        let fives = num_traits::pow(BigInt::from(5u32), (-shift) as usize);
+        Float::Big(BigDecimal::new(mantissa * fives, -shift).normalized())
+    }
+}
+
+/// Parses the `digits...` / `digits...{scale}` form written by `Float::to_canonical_string`.
+fn create_irrational_literal(digits: &str, suffix: &str) -> Float {
+    let Ok(bd) = BigDecimal::from_str(digits) else {
+        return Float::NaN;
+    };
+    if suffix.is_empty() {
+        return Float::Irrational(bd);
+    }
+    match suffix.strip_prefix('{').and_then(|s| s.strip_suffix('}')).map(i64::from_str) {
+        Some(Ok(scale)) if scale >= bd.fractional_digit_count() => Float::Irrational(bd.with_scale(scale)),
+        _ => Float::NaN,
+    }
+}
+
+/// Parses the `c√r` form written by `Display` for `Float::Surd`; a bare `√r` or `-√r`
+/// has coefficient ±1.
+fn create_surd_literal(coefficient: &str, radicand: &str) -> Float {
+    let coefficient = match coefficient.trim() {
+        "" | "+" => BigDecimal::from(1),
+        "-" => BigDecimal::from(-1),
+        c => match BigDecimal::from_str(c) {
+            Ok(bd) => bd,
+            Err(_) => return Float::NaN,
+        },
+    };
+    match BigInt::from_str(radicand.trim()) {
+        Ok(r) => Float::surd(&Float::Big(coefficient), &Int::Big(r)).unwrap_or(Float::NaN),
+        Err(_) => Float::NaN,
+    }
+}
+
+/// Finds the sign separating the real and imaginary parts of `a+b` / `a-b`
+/// (the trailing `i` already removed). Signs that start the literal or an exponent
+/// do not count.
+fn complex_split(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    (1..bytes.len()).rev().find(|&i| {
+        (bytes[i] == b'+' || bytes[i] == b'-')
+            && !matches!(bytes[..i].iter().rev().find(|b| !b.is_ascii_whitespace()), Some(b'e') | Some(b'E') | None)
+    })
+}
+
+fn create_complex_literal(real: &str, imag: &str) -> Float {
+    let imag: String = imag.chars().filter(|c| !c.is_whitespace()).collect();
+    let imag = match imag.as_str() {
+        "+" => "1",
+        "-" => "-1",
+        s => s,
+    };
+    let real = create_float(real);
+    let imag = create_float(imag);
+    if real.is_nan() || imag.is_nan() || real.is_complex() || imag.is_complex() {
+        return Float::NaN;
+    }
+    Float::Complex(Box::new(real), Box::new(imag))
+}
+
+/// Parses the integer literal at the start of `bytes` and returns it together with
+/// the number of bytes consumed, so tokenizers can read numbers straight out of
+/// their input buffer.
+///
+/// Accepts an optional sign, `0x`/`0b`/`0o` prefixes and `_` separators between digits.
+/// Parsing stops at the first byte that cannot continue the literal.
+pub fn parse_int_bytes(bytes: &[u8]) -> Result<(Int, usize), i8> {
+    let mut pos = 0;
+    let negative = match bytes.first() {
+        Some(b'-') => {
+            pos += 1;
+            true
+        }
+        Some(b'+') => {
+            pos += 1;
+            false
+        }
+        _ => false,
+    };
+
+    let mut radix = 10;
+    if bytes.len() > pos + 2 && bytes[pos] == b'0' {
+        let prefixed = match bytes[pos + 1] {
+            b'x' | b'X' => 16,
+            b'b' | b'B' => 2,
+            b'o' | b'O' => 8,
+            _ => 10,
+        };
+        if prefixed != 10 && (bytes[pos + 2] as char).is_digit(prefixed) {
+            radix = prefixed;
+            pos += 2;
+        }
+    }
+
+    let start = pos;
+    let end = scan_digits(bytes, pos, radix);
+    if end == start {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    let mut value = BigInt::parse_bytes(&bytes[start..end], radix).ok_or(ERR_INVALID_FORMAT)?;
+    if negative {
+        value = -value;
+    }
+    Ok((Int::Big(value), end))
+}
+
+/// Parses the decimal float literal at the start of `bytes` and returns it together
+/// with the number of bytes consumed.
+///
+/// Accepts an optional sign, `_` separators, a fractional part, a recurring part in
+/// parentheses (`0.1(6)`) and an exponent (`e-5`). An exponent marker not followed by
+/// digits is left unconsumed.
+pub fn parse_float_bytes(bytes: &[u8]) -> Result<(Float, usize), i8> {
+    let mut pos = 0;
+    if matches!(bytes.first(), Some(b'-') | Some(b'+')) {
+        pos += 1;
+    }
+    let int_start = pos;
+    pos = scan_digits(bytes, pos, 10);
+    let mut has_digits = pos > int_start;
+
+    if pos < bytes.len() && bytes[pos] == b'.' {
+        let frac_start = pos + 1;
+        let frac_end = scan_digits(bytes, frac_start, 10);
+        let mut end = frac_end;
+        if end < bytes.len() && bytes[end] == b'(' {
+            let rep_end = scan_digits(bytes, end + 1, 10);
+            if rep_end > end + 1 && rep_end < bytes.len() && bytes[rep_end] == b')' {
+                end = rep_end + 1;
             }
+        }
+        if end > frac_start && (has_digits || frac_end > frac_start) {
+            has_digits = true;
+            pos = end;
+        }
+    }
+    if !has_digits {
+        return Err(ERR_INVALID_FORMAT);
+    }
+
+    if pos < bytes.len() && (bytes[pos] == b'e' || bytes[pos] == b'E') {
+        let mut exp_pos = pos + 1;
+        if exp_pos < bytes.len() && (bytes[exp_pos] == b'-' || bytes[exp_pos] == b'+') {
+            exp_pos += 1;
+        }
+        let exp_end = scan_digits(bytes, exp_pos, 10);
+        if exp_end > exp_pos {
+            pos = exp_end;
+        }
+    }
+
+    let text = core::str::from_utf8(&bytes[..pos]).map_err(|_| ERR_INVALID_FORMAT)?;
+    let value = if text.contains('_') {
+        create_float(&text.replace('_', ""))
+    } else {
+        create_float(text)
+    };
+    if value.is_nan() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    Ok((value, pos))
+}
+
+/// Returns the end of the run of `radix` digits starting at `pos`. Underscores are
+/// allowed between digits but never at the start or end of the run.
+fn scan_digits(bytes: &[u8], pos: usize, radix: u32) -> usize {
+    let mut end = pos;
+    let mut i = pos;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if (b as char).is_digit(radix) {
+            i += 1;
+            end = i;
+        } else if b == b'_' && end > pos {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+/// What the parser was looking for where a `ParseError` points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expected {
+    /// A digit, such as after a sign, a decimal point, `e` or `(`.
+    Digit,
+    /// The `)` closing a recurring part.
+    ClosingParen,
+    /// The `i` ending the imaginary part of `a+bi`.
+    ImaginaryUnit,
+    /// The end of the input: the number was complete before it.
+    End,
+    /// A finite value rather than NaN or an infinity.
+    FiniteValue,
+    /// A number the value types can hold; the input reads as one but could not be built.
+    Number,
+}
+
+impl core::fmt::Display for Expected {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Expected::Digit => "a digit",
+            Expected::ClosingParen => "')'",
+            Expected::ImaginaryUnit => "'i'",
+            Expected::End => "end of input",
+            Expected::FiniteValue => "a finite number",
+            Expected::Number => "a number",
+        })
+    }
+}
+
+/// Why and where `str::parse::<Int>()` or `str::parse::<Float>()` failed.
+///
+/// `code` is what `Int::from_str` / `Float::from_str` return for the same input, and
+/// `offset` the byte offset of the first character that could not be read (the input
+/// length when the input ended too early), so a REPL can point at it:
+///
+/// ```
+/// use imagnum::{errors, Float};
+///
+/// let err = "1.25e+x".parse::<Float>().unwrap_err();
+/// assert_eq!((err.code, err.offset, err.expected), (errors::INVALID_FORMAT, 6, errors::Expected::Digit));
+/// assert_eq!(err.underline("1.25e+x"), "1.25e+x\n      ^");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub code: i8,
+    pub offset: usize,
+    pub expected: Expected,
+}
 
-            let digits_concat = format!("{}{}", int_part.trim_start_matches('-'), frac_str);
-            match BigInt::from_str(&digits_concat) {
-                Ok(mut bi) => {
-                    if neg {
-                        bi = -bi;
+impl ParseError {
+    /// `input` with a caret under the character at `offset` on the next line.
+    pub fn underline(&self, input: &str) -> String {
+        let column = input.get(..self.offset).map_or(0, |s| s.chars().count());
+        format!("{}\n{}^", input, " ".repeat(column))
+    }
+
+    /// The error for `s`, which `try_create_int` rejected with `code`.
+    pub(crate) fn locate_int(s: &str, code: i8) -> Self {
+        let start = s.len() - s.trim_start().len();
+        let bytes = s.trim().as_bytes();
+        let at = |offset: usize, expected| ParseError { code, offset: start + offset, expected };
+        if code == ERR_INFINITE_RESULT {
+            return at(0, Expected::FiniteValue);
+        }
+        let sign = usize::from(matches!(bytes.first(), Some(b'-') | Some(b'+')));
+        let end = scan_digits(bytes, sign, 10);
+        if end == sign {
+            at(sign, Expected::Digit)
+        } else if end < bytes.len() {
+            at(end, Expected::End)
+        } else {
+            at(0, Expected::Number)
+        }
+    }
+
+    /// The error for `s`, which `Float::from_str` rejected with `code`.
+    pub(crate) fn locate_float(s: &str, code: i8) -> Self {
+        let start = s.len() - s.trim_start().len();
+        let bytes = s.trim().as_bytes();
+        let at = |offset: usize, expected| ParseError { code, offset: start + offset, expected };
+        let low = s.trim().to_ascii_lowercase();
+        if matches!(low.trim_start_matches(['+', '-']), "nan" | "inf" | "infinity") {
+            return at(0, Expected::FiniteValue);
+        }
+        let sign = usize::from(matches!(bytes.first(), Some(b'-') | Some(b'+')));
+        // a bare `√r` has no coefficient to scan
+        let real = if s.trim()[sign..].starts_with('√') { Ok(sign) } else { scan_real(bytes, 0) };
+        match real {
+            Err(pos) => at(pos, Expected::Digit),
+            // the syntax is fine, so the exponent is what is out of range
+            Ok(end) if end == bytes.len() && code == ERR_NUMBER_TOO_LARGE => {
+                at(bytes.iter().rposition(|b| matches!(b, b'e' | b'E')).map_or(0, |e| e + 1), Expected::Number)
+            }
+            Ok(end) if end == bytes.len() => at(0, Expected::Number),
+            Ok(end) => match bytes[end] {
+                b'i' if end + 1 < bytes.len() => at(end + 1, Expected::End),
+                b'(' => match scan_digits(bytes, end + 1, 10) {
+                    rep if rep == end + 1 => at(rep, Expected::Digit),
+                    rep => at(rep, Expected::ClosingParen),
+                },
+                b'+' | b'-' => match scan_real(bytes, end + 1) {
+                    Err(pos) => at(pos, Expected::Digit),
+                    Ok(imag) if bytes.get(imag) != Some(&b'i') => at(imag, Expected::ImaginaryUnit),
+                    Ok(imag) if imag + 1 < bytes.len() => at(imag + 1, Expected::End),
+                    Ok(_) => at(0, Expected::Number),
+                },
+                _ if s.trim()[end..].starts_with('√') => {
+                    let radicand = end + '√'.len_utf8();
+                    match scan_digits(bytes, radicand, 10) {
+                        r if r == radicand => at(r, Expected::Digit),
+                        r if r < bytes.len() => at(r, Expected::End),
+                        _ => at(0, Expected::Number),
                     }
-                    let scale = frac_str.len() as i64;
-                    let bd = BigDecimal::new(bi, scale);
-                    return Float::Recurring(bd);
-                }
-                Err(_) => {
-                    let s_out = if neg { format!("-{}.{}", int_part, frac_str) } else { format!("{}.{}", int_part, frac_str) };
-                    let bd = BigDecimal::from_str(&s_out).unwrap_or_else(|_| BigDecimal::from(0));
-                    return Float::Recurring(bd);
                 }
+                _ => at(end, Expected::End),
+            },
+        }
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at byte {}: expected {}", get_error_message(self.code), self.offset, self.expected)
+    }
+}
+
+impl From<ParseError> for i8 {
+    fn from(err: ParseError) -> i8 {
+        err.code
+    }
+}
+
+/// `s.parse::<Int>()`: `Int::from_str` with the position of the error.
+impl FromStr for Int {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        try_create_int(s).map_err(|code| ParseError::locate_int(s, code))
+    }
+}
+
+/// `s.parse::<Float>()`: `Float::from_str` with the position of the error.
+impl FromStr for Float {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Float::from_str(s).map_err(|code| ParseError::locate_float(s, code))
+    }
+}
+
+/// Scans an optionally signed real literal starting at `pos`: decimal digits with a
+/// fractional part and exponent, or a `0x` hexadecimal float with a `p` exponent. Returns
+/// the end of what was read, or the position of the missing digit.
+fn scan_real(bytes: &[u8], mut pos: usize) -> Result<usize, usize> {
+    if matches!(bytes.get(pos), Some(b'-') | Some(b'+')) {
+        pos += 1;
+    }
+    let hex = bytes.get(pos) == Some(&b'0') && matches!(bytes.get(pos + 1), Some(b'x') | Some(b'X'));
+    let (radix, markers) = if hex { (16, [b'p', b'P']) } else { (10, [b'e', b'E']) };
+    if hex {
+        pos += 2;
+    }
+    let int_end = scan_digits(bytes, pos, radix);
+    let mut end = int_end;
+    if bytes.get(end) == Some(&b'.') {
+        end = scan_digits(bytes, end + 1, radix);
+        if end == int_end + 1 && int_end == pos {
+            return Err(end);
+        }
+    } else if end == pos {
+        return Err(pos);
+    }
+    if bytes.get(end).is_some_and(|b| markers.contains(b)) {
+        let mut exp = end + 1;
+        if matches!(bytes.get(exp), Some(b'-') | Some(b'+')) {
+            exp += 1;
+        }
+        end = scan_digits(bytes, exp, 10);
+        if end == exp {
+            return Err(exp);
+        }
+    }
+    Ok(end)
+}
+
+/// Options for `Float::parse_with` and `Int::parse_with`.
+///
+/// ```
+/// use imagnum::{Float, ParseOptions};
+///
+/// let opts = ParseOptions::new().decimal_separator(',').grouping('.');
+/// assert_eq!(Float::parse_with("1.234,5", &opts).unwrap().to_string(), "1234.5");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub decimal_separator: char,
+    /// Accepted between digits of the integer part (`1,234,567`)
+    pub grouping: Option<char>,
+    /// Accept `_` between digits
+    pub allow_underscores: bool,
+    /// Accept an exponent (`1.5e3`)
+    pub allow_scientific: bool,
+    /// Accept a parenthesised repetend after the decimal separator (`0.1(6)`)
+    pub allow_recurring: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            decimal_separator: '.',
+            grouping: None,
+            allow_underscores: false,
+            allow_scientific: true,
+            allow_recurring: true,
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    pub fn grouping(mut self, separator: char) -> Self {
+        self.grouping = Some(separator);
+        self
+    }
+
+    pub fn allow_underscores(mut self, allow: bool) -> Self {
+        self.allow_underscores = allow;
+        self
+    }
+
+    pub fn allow_scientific(mut self, allow: bool) -> Self {
+        self.allow_scientific = allow;
+        self
+    }
+
+    pub fn allow_recurring(mut self, allow: bool) -> Self {
+        self.allow_recurring = allow;
+        self
+    }
+}
+
+/// A literal split up by `scan_literal`, with separators already removed.
+struct LiteralParts {
+    negative: bool,
+    int_digits: String,
+    frac_digits: Option<String>,
+    repetend: Option<String>,
+    exponent: Option<String>,
+}
+
+/// Reads a run of ASCII digits starting at `pos` into `out`, skipping `separators` that
+/// sit between two digits. Returns the position after the run.
+fn scan_digit_run(chars: &[char], mut pos: usize, separators: &[char], out: &mut String) -> usize {
+    let start = out.len();
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c.is_ascii_digit() {
+            out.push(c);
+            pos += 1;
+        } else if separators.contains(&c)
+            && out.len() > start
+            && chars.get(pos + 1).is_some_and(|n| n.is_ascii_digit())
+        {
+            pos += 1;
+        } else {
+            break;
+        }
+    }
+    pos
+}
+
+fn scan_literal(s: &str, opts: &ParseOptions) -> Result<LiteralParts, i8> {
+    if opts.grouping == Some(opts.decimal_separator)
+        || opts.decimal_separator.is_ascii_digit()
+        || opts.grouping.is_some_and(|g| g.is_ascii_digit())
+    {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    let chars: Vec<char> = s.trim().chars().collect();
+    let mut int_separators = Vec::new();
+    let mut separators = Vec::new();
+    if opts.allow_underscores {
+        int_separators.push('_');
+        separators.push('_');
+    }
+    if let Some(g) = opts.grouping {
+        int_separators.push(g);
+    }
+
+    let mut pos = 0;
+    let negative = chars.first() == Some(&'-');
+    if matches!(chars.first(), Some('-') | Some('+')) {
+        pos += 1;
+    }
+    let mut int_digits = String::new();
+    pos = scan_digit_run(&chars, pos, &int_separators, &mut int_digits);
+
+    let mut frac_digits = None;
+    let mut repetend = None;
+    if chars.get(pos) == Some(&opts.decimal_separator) {
+        let mut frac = String::new();
+        pos = scan_digit_run(&chars, pos + 1, &separators, &mut frac);
+        if chars.get(pos) == Some(&'(') {
+            if !opts.allow_recurring {
+                return Err(ERR_INVALID_FORMAT);
             }
+            let mut rep = String::new();
+            pos = scan_digit_run(&chars, pos + 1, &separators, &mut rep);
+            if rep.is_empty() || chars.get(pos) != Some(&')') {
+                return Err(ERR_INVALID_FORMAT);
+            }
+            pos += 1;
+            repetend = Some(rep);
         }
+        frac_digits = Some(frac);
+    }
+    let frac_len = frac_digits.as_ref().map_or(0, |f| f.len());
+    if int_digits.is_empty() && frac_len == 0 && repetend.is_none() {
+        return Err(ERR_INVALID_FORMAT);
     }
 
-    match BigDecimal::from_str(s) {
-        Ok(bd) => Float::Big(bd),
-        Err(_) => Float::NaN,
+    let mut exponent = None;
+    if matches!(chars.get(pos), Some('e') | Some('E')) {
+        if !opts.allow_scientific || repetend.is_some() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        pos += 1;
+        let mut exp = String::new();
+        if let Some(&c) = chars.get(pos).filter(|c| **c == '-' || **c == '+') {
+            exp.push(c);
+            pos += 1;
+        }
+        let sign_len = exp.len();
+        pos = scan_digit_run(&chars, pos, &separators, &mut exp);
+        if exp.len() == sign_len {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        exponent = Some(exp);
+    }
+
+    if pos != chars.len() {
+        return Err(ERR_INVALID_FORMAT);
     }
+    Ok(LiteralParts { negative, int_digits, frac_digits, repetend, exponent })
+}
+
+impl Float {
+    /// Parses a real literal using the separators and syntax allowed by `opts`.
+    /// NaN, infinities and imaginary literals are rejected with `INVALID_FORMAT`.
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Float, i8> {
+        let parts = scan_literal(s, opts)?;
+        let mut canonical = String::new();
+        if parts.negative {
+            canonical.push('-');
+        }
+        canonical.push_str(if parts.int_digits.is_empty() { "0" } else { &parts.int_digits });
+        if let Some(frac) = &parts.frac_digits {
+            canonical.push('.');
+            canonical.push_str(frac);
+        }
+        if let Some(rep) = &parts.repetend {
+            canonical.push('(');
+            canonical.push_str(rep);
+            canonical.push(')');
+        }
+        if let Some(exp) = &parts.exponent {
+            canonical.push('e');
+            canonical.push_str(exp);
+        }
+        Float::from_str(&canonical)
+    }
+}
+
+/// Largest decimal exponent `Int::parse_with` applies, so a literal like `1e999999999`
+/// fails instead of building a billion-digit integer.
+const MAX_INT_EXPONENT: i64 = 1_000_000;
+
+impl Int {
+    /// Parses an integer literal using the separators and syntax allowed by `opts`.
+    ///
+    /// With `allow_scientific`, a non-negative exponent is accepted as long as the
+    /// value stays integral (`1.5e3` is `1500`); a fractional result or a recurring
+    /// part fails with `INVALID_FORMAT`. A zero mantissa gives 0 whatever the exponent;
+    /// otherwise a shift past a million digits fails with `NUMBER_TOO_LARGE`.
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Int, i8> {
+        let parts = scan_literal(s, opts)?;
+        if parts.repetend.is_some() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let frac = parts.frac_digits.unwrap_or_default();
+        let frac = frac.trim_end_matches('0');
+        let digits = format!("{}{}", parts.int_digits, frac);
+        let mut value = BigInt::from_str(if digits.is_empty() { "0" } else { &digits }).map_err(|_| ERR_INVALID_FORMAT)?;
+        if value.is_zero() {
+            return Ok(Int::Big(value));
+        }
+        let exponent: i64 = match &parts.exponent {
+            Some(exp) => exp.parse().map_err(|_| ERR_NUMBER_TOO_LARGE)?,
+            None => 0,
+        };
+        let shift = exponent.saturating_sub(frac.len() as i64);
+        if shift < 0 {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        if shift > MAX_INT_EXPONENT {
+            return Err(ERR_NUMBER_TOO_LARGE);
+        }
+        value *= BigInt::from(10u32).pow(shift as u32);
+        if parts.negative {
+            value = -value;
+        }
+        Ok(Int::Big(value))
+    }
+}
+
+/// Converts a slice of `i64` into `Int`s in one preallocated pass.
+pub fn ints_from_i64(values: &[i64]) -> Vec<Int> {
+    let mut out = Vec::with_capacity(values.len());
+    out.extend(values.iter().map(|v| Int::Big(BigInt::from(*v))));
+    out
+}
+
+/// Converts a slice of `Int`s into `i64`, failing on the first value that does not fit.
+pub fn ints_to_i64(values: &[Int]) -> Result<Vec<i64>, i8> {
+    let mut out = Vec::with_capacity(values.len());
+    for v in values {
+        out.push(v.to_i64()?);
+    }
+    Ok(out)
+}
+
+/// Converts a slice of `f64` into `Float::Small` values in one preallocated pass.
+/// NaN and the infinities become `Float::NaN`, `Float::Infinity` and `Float::NegInfinity`.
+pub fn floats_from_f64(values: &[f64]) -> Vec<Float> {
+    let mut out = Vec::with_capacity(values.len());
+    out.extend(values.iter().map(|v| match *v {
+        v if v.is_nan() => Float::NaN,
+        f64::INFINITY => Float::Infinity,
+        f64::NEG_INFINITY => Float::NegInfinity,
+        v => Float::Small(SmallFloat::F64(v + 0.0)),
+    }));
+    out
+}
+
+/// Converts a slice of `Float`s into `f64` (rounding as `Float::to_f64` does),
+/// failing on the first `NaN` or complex value.
+pub fn floats_to_f64(values: &[Float]) -> Result<Vec<f64>, i8> {
+    let mut out = Vec::with_capacity(values.len());
+    for v in values {
+        out.push(v.to_f64()?);
+    }
+    Ok(out)
 }
 
 pub fn create_irrational(float: &str) -> Float {
@@ -232,19 +917,3 @@ pub fn get_error_code(message: &str) -> i8 {
         _ => 0, // Unknown error
     }
 }
-
-/// Macro to create an Int from a string
-#[macro_export]
-macro_rules! int {
-    ($val:expr) => {
-        create_int($val)
-    };
-}
-
-/// Macro to create a Float from a string
-#[macro_export]
-macro_rules! float {
-    ($val:expr) => {
-        create_float($val)
-    };
-}
@@ -1,64 +1,192 @@
+use crate::alloc_prelude::*;
 use crate::compat::{
-    float_is_negative, float_is_zero, float_kind, float_to_parts,
-    int_is_infinite, int_is_nan, int_to_parts, int_to_string, make_float_from_parts,
-    make_int_from_parts,
+    float_is_negative, ArithmeticPolicy, float_is_one, float_is_zero, float_kind, float_to_parts,
+    int_to_parts, make_float_from_parts,
+    make_int_from_parts, normalize_decimal,
 };
-use crate::foundation::{Float, FloatKind, Int, SmallFloat, SmallInt};
-use crate::functions::{create_float, create_int};
+use crate::foundation::{
+    auto_shrink, precision, BitWidth, DivMode, Float, FloatKind, Int, NumberInfo, RoundingMode, SmallFloat,
+    SmallInt, SmallIntKind,
+};
+use crate::backend::backend;
+use crate::cache;
+use crate::functions::create_float;
+use crate::recurring::{ratio_to_float, RatioOp};
 use crate::math::{
     ERR_DIV_BY_ZERO, ERR_INFINITE_RESULT, ERR_INVALID_FORMAT, ERR_NEGATIVE_RESULT,
-    ERR_NEGATIVE_SQRT, ERR_UNIMPLEMENTED, add_float, ceil_float, ceil_int, cos_float,
-    cos_int, div_float, exp_float, exp_int, floor_float, floor_int, is_string_odd,
-    ln_float, ln_int, log10_float, mod_float, mul_float, pow_strings,
-    bigdecimal_pow_integer,
-    sin_float, sin_int, sqrt_float, sqrt_int, sub_float, tan_float, tan_int,
-    LN_10,
+    ERR_NEGATIVE_SQRT, ERR_NUMBER_TOO_LARGE, ERR_UNIMPLEMENTED, fnv1a_128, ceil_float, ceil_int, cos_float,
+    cos_int, div_bigdecimal, exp_float, exp_int, floor_float, floor_int, is_string_odd,
+    exp_m1_bigdecimal, ln_1p_bigdecimal, ln_float, ln_int, log10_float, sin_cos_float, pow_strings,
+    sin_float, sin_int, sqrt_float, sqrt_int, tan_float, tan_int,
 };
 use bigdecimal::BigDecimal;
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 use num_integer::Integer;
-use num_traits::{FromPrimitive, Signed, ToPrimitive, Zero};
-use std::collections::HashMap;
-use std::fmt::{Binary, LowerHex, Octal};
-use std::str::FromStr;
-use std::hash::{Hash, Hasher};
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+use alloc::borrow::Cow;
+use core::fmt::{Binary, LowerHex, Octal, UpperHex};
+use core::str::FromStr;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 use pastey::paste;
 
-fn normalize_recurring_decimal(float: Float) -> Float {
-    if let Float::Recurring(ref bd) = float {
-        let n = bd.normalized();
-        
-        let int_candidate = n.with_scale(0);
-        if n == int_candidate {
-            return Float::Big(int_candidate);
+/// Largest number of digits `Float::pow_int` computes exactly.
+pub(crate) const EXACT_POW_DIGITS: u64 = 10_000;
+
+/// Largest decimal exponent, either way, of a `Float::pow_int` result; past it the power
+/// overflows (or underflows) the exponent range.
+const MAX_POW_EXPONENT: f64 = 1e15;
+
+/// `log10 |v|` to about 17 digits, for a non-zero `v` of any size.
+fn log10_abs(v: &BigDecimal) -> f64 {
+    let (digits, scale) = v.abs().with_prec(17).into_bigint_and_exponent();
+    digits.to_f64().unwrap_or(f64::MAX).log10() - scale as f64
+}
+
+/// The parts of a complex `z` rounded to `digits` significant digits, as irrationals.
+pub(crate) fn round_parts(z: Float, digits: core::num::NonZeroU64) -> Float {
+    let round = |part: &Float| match part.decimal_value() {
+        Some(d) if !d.is_zero() => Float::Irrational(d.with_precision_round(digits, bigdecimal::RoundingMode::HalfEven)),
+        _ => part.clone(),
+    };
+    match &z {
+        Float::Complex(re, im) => Float::complex(round(re), round(im)),
+        _ => round(&z),
+    }
+}
+
+/// Digits of the radixes above 36, in order: the usual base62 alphabet.
+const BASE62_ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// A leading `+` or `-` split off.
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
+
+/// The symbols of a custom alphabet: 2 to 256 of them, all different.
+fn check_alphabet(alphabet: &str) -> Result<Vec<char>, i8> {
+    let symbols: Vec<char> = alphabet.chars().collect();
+    if !(2..=256).contains(&symbols.len()) {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    if symbols.iter().enumerate().any(|(i, c)| symbols[..i].contains(c)) {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    Ok(symbols)
+}
+
+/// The integer with base-`radix` `digits`, most significant first.
+fn from_digits(negative: bool, digits: &[u8], radix: u32) -> Result<Int, i8> {
+    if digits.is_empty() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    BigInt::from_radix_be(sign, digits, radix).map(Int::Big).ok_or(ERR_INVALID_FORMAT)
+}
+
+/// `value` written with `alphabet`, whose length is the radix.
+fn to_alphabet(value: &BigInt, alphabet: &[char]) -> String {
+    let (sign, digits) = value.to_radix_be(alphabet.len() as u32);
+    let mut out = String::with_capacity(digits.len() + 1);
+    if sign == Sign::Minus {
+        out.push('-');
+    }
+    out.extend(digits.iter().map(|&d| alphabet[d as usize]));
+    out
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SmallOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BitWidth {
+    pub fn bits(&self) -> u32 {
+        match self {
+            BitWidth::I8 | BitWidth::U8 => 8,
+            BitWidth::I16 | BitWidth::U16 => 16,
+            BitWidth::I32 | BitWidth::U32 => 32,
+            BitWidth::I64 | BitWidth::U64 => 64,
+            BitWidth::I128 | BitWidth::U128 => 128,
         }
-        
-        let s_norm = n.normalized().to_string();
-        if s_norm.contains('.') {
-            let parts: Vec<&str> = s_norm.split('.').collect();
-            if parts.len() == 2 {
-                let frac = parts[1];
-                if frac.chars().all(|c| c == '9') && !frac.is_empty() {
-                    let int_part: i64 = parts[0].parse().unwrap_or(0);
-                    return Float::Big(BigDecimal::from(int_part + 1));
-                }
-                if frac.len() > 1 && frac.chars().skip(1).all(|c| c == '9') {
-                    let first_digit = frac.chars().next().unwrap_or('0');
-                    if let Some(digit_val) = first_digit.to_digit(10) {
-                        let new_digit = digit_val + 1;
-                        if new_digit < 10 {
-                            let new_frac = format!("{}", new_digit);
-                            let new_val = format!("{}.{}", parts[0], new_frac);
-                            if let Ok(new_bd) = BigDecimal::from_str(&new_val) {
-                                return Float::Big(new_bd);
-                            }
-                        }
-                    }
-                }
-            }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, BitWidth::I8 | BitWidth::I16 | BitWidth::I32 | BitWidth::I64 | BitWidth::I128)
+    }
+
+    /// Smallest and largest representable values.
+    fn bounds(&self) -> (BigInt, BigInt) {
+        let bits = self.bits();
+        if self.is_signed() {
+            let half = BigInt::from(1u32) << (bits - 1);
+            (-&half, half - 1u32)
+        } else {
+            (BigInt::zero(), (BigInt::from(1u32) << bits) - 1u32)
+        }
+    }
+
+    fn wrap(&self, v: BigInt) -> BigInt {
+        let bits = self.bits();
+        let modulus = BigInt::from(1u32) << bits;
+        let r = v.mod_floor(&modulus);
+        if self.is_signed() && r.bit((bits - 1) as u64) { r - modulus } else { r }
+    }
+
+    /// The low `bits` bits of `v` as a non-negative pattern.
+    fn pattern(&self, v: &BigInt) -> BigInt {
+        v.mod_floor(&(BigInt::from(1u32) << self.bits()))
+    }
+
+    fn clamp(&self, v: BigInt) -> BigInt {
+        let (min, max) = self.bounds();
+        v.clamp(min, max)
+    }
+
+    fn check(&self, v: BigInt) -> Option<Int> {
+        let (min, max) = self.bounds();
+        if v < min || v > max { None } else { Some(Int::Big(v)) }
+    }
+}
+
+impl SmallIntKind {
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            SmallIntKind::I8
+                | SmallIntKind::I16
+                | SmallIntKind::I32
+                | SmallIntKind::I64
+                | SmallIntKind::I128
+                | SmallIntKind::ISize
+        )
+    }
+}
+
+impl SmallInt {
+    pub fn kind(&self) -> SmallIntKind {
+        match self {
+            SmallInt::I8(_) => SmallIntKind::I8,
+            SmallInt::U8(_) => SmallIntKind::U8,
+            SmallInt::I16(_) => SmallIntKind::I16,
+            SmallInt::U16(_) => SmallIntKind::U16,
+            SmallInt::I32(_) => SmallIntKind::I32,
+            SmallInt::U32(_) => SmallIntKind::U32,
+            SmallInt::I64(_) => SmallIntKind::I64,
+            SmallInt::U64(_) => SmallIntKind::U64,
+            SmallInt::I128(_) => SmallIntKind::I128,
+            SmallInt::U128(_) => SmallIntKind::U128,
+            SmallInt::USize(_) => SmallIntKind::USize,
+            SmallInt::ISize(_) => SmallIntKind::ISize,
         }
     }
-    float
 }
 
 impl Int {
@@ -79,6 +207,77 @@ impl Int {
         }
     }
 
+    fn owned_bigint(&self) -> BigInt {
+        match self {
+            Int::Big(bi) => bi.clone(),
+            Int::Small(si) => Int::smallint_to_bigint(si),
+        }
+    }
+
+    /// The value as a `BigInt`, borrowed from a `Big` int and converted for a `Small` one.
+    pub fn as_bigint(&self) -> Cow<'_, BigInt> {
+        match self {
+            Int::Big(bi) => Cow::Borrowed(bi),
+            Int::Small(si) => Cow::Owned(Int::smallint_to_bigint(si)),
+        }
+    }
+
+    /// Wraps an arithmetic result, shrinking it when auto-shrink is enabled.
+    fn from_op(v: BigInt) -> Self {
+        let result = Int::Big(v);
+        if auto_shrink() { result.shrink() } else { result }
+    }
+
+    /// Demotes the value to `SmallInt::I64` or `SmallInt::I128` when it fits,
+    /// otherwise returns it as `Int::Big`.
+    pub fn shrink(&self) -> Self {
+        let v = Int::owned_bigint(self);
+        if let Some(x) = v.to_i64() {
+            Int::Small(SmallInt::I64(x))
+        } else if let Some(x) = v.to_i128() {
+            Int::Small(SmallInt::I128(x))
+        } else {
+            Int::Big(v)
+        }
+    }
+
+    /// Converts to `Int::Small` with the requested variant. Fails with `NEGATIVE_RESULT`
+    /// for negative values and unsigned kinds, and `NUMBER_TOO_LARGE` when the value does not fit.
+    pub fn to_small(&self, kind: SmallIntKind) -> Result<Self, i8> {
+        let v = Int::owned_bigint(self);
+        if v.is_negative() && !kind.is_signed() {
+            return Err(ERR_NEGATIVE_RESULT);
+        }
+        let small = match kind {
+            SmallIntKind::I8 => v.to_i8().map(SmallInt::I8),
+            SmallIntKind::U8 => v.to_u8().map(SmallInt::U8),
+            SmallIntKind::I16 => v.to_i16().map(SmallInt::I16),
+            SmallIntKind::U16 => v.to_u16().map(SmallInt::U16),
+            SmallIntKind::I32 => v.to_i32().map(SmallInt::I32),
+            SmallIntKind::U32 => v.to_u32().map(SmallInt::U32),
+            SmallIntKind::I64 => v.to_i64().map(SmallInt::I64),
+            SmallIntKind::U64 => v.to_u64().map(SmallInt::U64),
+            SmallIntKind::I128 => v.to_i128().map(SmallInt::I128),
+            SmallIntKind::U128 => v.to_u128().map(SmallInt::U128),
+            SmallIntKind::USize => v.to_usize().map(SmallInt::USize),
+            SmallIntKind::ISize => v.to_isize().map(SmallInt::ISize),
+        };
+        small.map(Int::Small).ok_or(ERR_NUMBER_TOO_LARGE)
+    }
+
+    /// Narrowest fixed-size variant that holds the value: unsigned kinds for non-negative
+    /// values, signed kinds for negative ones. `None` if it needs more than 128 bits.
+    /// `USize`/`ISize` are never chosen since their width depends on the platform.
+    pub fn smallest_fitting_variant(&self) -> Option<SmallIntKind> {
+        let v = Int::owned_bigint(self);
+        let kinds: &[SmallIntKind] = if v.is_negative() {
+            &[SmallIntKind::I8, SmallIntKind::I16, SmallIntKind::I32, SmallIntKind::I64, SmallIntKind::I128]
+        } else {
+            &[SmallIntKind::U8, SmallIntKind::U16, SmallIntKind::U32, SmallIntKind::U64, SmallIntKind::U128]
+        };
+        kinds.iter().copied().find(|k| self.to_small(*k).is_ok())
+    }
+
     pub fn to_bigint(&self) -> Result<BigInt, i8> {
         match self {
             Int::Big(bi) => Ok(bi.clone()),
@@ -92,19 +291,19 @@ impl Int {
     }
 
     pub fn to_float(&self) -> Result<Float, i8> {
-        match self {
-            Int::Big(bi) => {
-                let bd = BigDecimal::from(bi.clone());
-                Ok(Float::Big(bd))
-            }
-            Int::Small(_) => {
-                let s = int_to_string(self);
-                match BigDecimal::from_str(&s) {
-                    Ok(bd) => Ok(Float::Big(bd)),
-                    Err(_) => Err(ERR_INVALID_FORMAT),
-                }
-            }
-        }
+        Ok(Float::Big(BigDecimal::from(Int::owned_bigint(self))))
+    }
+
+    /// `self * 10^k`: the digits followed by `k` zeros.
+    pub fn mul_pow10(&self, k: u32) -> Self {
+        let pow = num_traits::pow(BigInt::from(10u32), k as usize);
+        Int::from_op(backend().mul(&Int::owned_bigint(self), &pow))
+    }
+
+    /// `self / 10^k` rounded toward zero: the digits without the last `k`.
+    pub fn div_pow10(&self, k: u32) -> Self {
+        let pow = num_traits::pow(BigInt::from(10u32), k as usize);
+        Int::from_op(backend().div_rem(&Int::owned_bigint(self), &pow).0)
     }
     pub fn _add(&self, other: &Self) -> Result<Self, i8> {
         let a = match self {
@@ -115,7 +314,7 @@ impl Int {
             Int::Big(bi) => bi.clone(),
             Int::Small(si) => Int::smallint_to_bigint(si),
         };
-        Ok(Int::Big(a + b))
+        Ok(Int::from_op(a + b))
     }
     pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
         let a = match self {
@@ -126,7 +325,7 @@ impl Int {
             Int::Big(bi) => bi.clone(),
             Int::Small(si) => Int::smallint_to_bigint(si),
         };
-        Ok(Int::Big(a - b))
+        Ok(Int::from_op(a - b))
     }
     pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
         let a = match self {
@@ -137,28 +336,215 @@ impl Int {
             Int::Big(bi) => bi.clone(),
             Int::Small(si) => Int::smallint_to_bigint(si),
         };
-        Ok(Int::Big(a * b))
+        Ok(Int::from_op(backend().mul(&a, &b)))
     }
+    /// Integer division, truncating toward zero unless `compat::Behavior::int_division`
+    /// says otherwise. This is what the `/` operator uses; see `div_mode` for the other
+    /// rounding modes.
     pub fn _div(&self, other: &Self) -> Result<Self, i8> {
-        let a = match self {
-            Int::Big(bi) => bi.clone(),
-            Int::Small(si) => Int::smallint_to_bigint(si),
-        };
-        let b = match other {
-            Int::Big(bi) => bi.clone(),
-            Int::Small(si) => Int::smallint_to_bigint(si),
+        self.div_mode(other, crate::compat::behavior().int_division)
+    }
+
+    /// Integer division with an explicit rounding mode. Fails with `DIV_BY_ZERO`
+    /// when `other` is zero.
+    pub fn div_mode(&self, other: &Self, mode: DivMode) -> Result<Self, i8> {
+        let mode = match mode {
+            DivMode::Trunc => RoundingMode::TowardZero,
+            DivMode::Floor => RoundingMode::Floor,
+            DivMode::Ceil => RoundingMode::Ceiling,
+            // the remainder takes the dividend's sign under floor for b > 0, ceiling for b < 0
+            DivMode::Euclid if other.is_negative() => RoundingMode::Ceiling,
+            DivMode::Euclid => RoundingMode::Floor,
+            DivMode::Round => RoundingMode::HalfUp,
         };
+        self.div_round(other, mode)
+    }
+
+    /// Integer division rounding the exact quotient with `mode`. Fails with `DIV_BY_ZERO`
+    /// when `other` is zero.
+    pub fn div_round(&self, other: &Self, mode: RoundingMode) -> Result<Self, i8> {
+        let a = self.to_bigint()?;
+        let b = other.to_bigint()?;
         if b.is_zero() { return Err(ERR_DIV_BY_ZERO); }
-        let (quot, rem) = (a.clone() / b.clone(), a.clone() % b.clone());
-        if rem.is_zero() { return Ok(Int::Big(quot)); }
-        let two = BigInt::from(2);
-        let abs_rem_times_two = rem.abs() * &two;
-        let abs_b = b.abs();
+        let (quot, rem) = backend().div_rem(&a, &b);
+        if rem.is_zero() { return Ok(Int::from_op(quot)); }
         let same_sign = a.is_negative() == b.is_negative();
-        let rounded = if abs_rem_times_two >= abs_b {
-            if same_sign { quot + BigInt::from(1) } else { quot - BigInt::from(1) }
-        } else { quot };
-        Ok(Int::Big(rounded))
+        let away = if same_sign { &quot + 1u32 } else { &quot - 1u32 };
+        // discarded fraction against one half
+        let half = (rem.abs() * 2u32).cmp(&b.abs());
+        let round_away = match mode {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => !same_sign,
+            RoundingMode::Ceiling => same_sign,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && quot.is_odd()),
+        };
+        Ok(Int::from_op(if round_away { away } else { quot }))
+    }
+
+    /// Quotient rounded toward zero (same as `/` under the default behavior).
+    pub fn div_trunc(&self, other: &Self) -> Result<Self, i8> {
+        self.div_mode(other, DivMode::Trunc)
+    }
+
+    /// Quotient rounded toward negative infinity.
+    pub fn div_floor(&self, other: &Self) -> Result<Self, i8> {
+        self.div_mode(other, DivMode::Floor)
+    }
+
+    /// Quotient rounded toward positive infinity.
+    pub fn div_ceil(&self, other: &Self) -> Result<Self, i8> {
+        self.div_mode(other, DivMode::Ceil)
+    }
+
+    /// Euclidean quotient: the `q` for which `self == q * other + r` with `0 <= r < |other|`.
+    pub fn div_euclid(&self, other: &Self) -> Result<Self, i8> {
+        self.div_mode(other, DivMode::Euclid)
+    }
+
+    /// Euclidean remainder, always in `0..|other|`.
+    pub fn rem_euclid(&self, other: &Self) -> Result<Self, i8> {
+        let a = self.to_bigint()?;
+        let b = other.to_bigint()?;
+        if b.is_zero() { return Err(ERR_DIV_BY_ZERO); }
+        let r = a % &b;
+        Ok(Int::from_op(if r.is_negative() { r + b.abs() } else { r }))
+    }
+
+    /// Returns `true` if the value is representable in `width`.
+    pub fn fits_width(&self, width: BitWidth) -> bool {
+        let (min, max) = width.bounds();
+        let v = Int::owned_bigint(self);
+        v >= min && v <= max
+    }
+
+    /// Reduces the value modulo `2^bits` into the range of `width`, like an `as` cast.
+    pub fn wrap_to_width(&self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(Int::owned_bigint(self)))
+    }
+
+    /// Addition that returns `None` if the result does not fit in `width`.
+    pub fn checked_add_width(&self, other: &Self, width: BitWidth) -> Option<Self> {
+        width.check(Int::owned_bigint(self) + Int::owned_bigint(other))
+    }
+
+    /// Subtraction that returns `None` if the result does not fit in `width`.
+    pub fn checked_sub_width(&self, other: &Self, width: BitWidth) -> Option<Self> {
+        width.check(Int::owned_bigint(self) - Int::owned_bigint(other))
+    }
+
+    /// Multiplication that returns `None` if the result does not fit in `width`.
+    pub fn checked_mul_width(&self, other: &Self, width: BitWidth) -> Option<Self> {
+        width.check(Int::owned_bigint(self) * Int::owned_bigint(other))
+    }
+
+    /// Truncating division that returns `None` on division by zero or overflow (`MIN / -1`).
+    pub fn checked_div_width(&self, other: &Self, width: BitWidth) -> Option<Self> {
+        let b = Int::owned_bigint(other);
+        if b.is_zero() {
+            return None;
+        }
+        width.check(Int::owned_bigint(self) / b)
+    }
+
+    /// Addition wrapping around at the boundary of `width`.
+    pub fn wrapping_add_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(Int::owned_bigint(self) + Int::owned_bigint(other)))
+    }
+
+    /// Subtraction wrapping around at the boundary of `width`.
+    pub fn wrapping_sub_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(Int::owned_bigint(self) - Int::owned_bigint(other)))
+    }
+
+    /// Multiplication wrapping around at the boundary of `width`.
+    pub fn wrapping_mul_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(Int::owned_bigint(self) * Int::owned_bigint(other)))
+    }
+
+    /// Truncating division wrapping around at the boundary of `width` (`MIN / -1 == MIN`).
+    /// Fails with `DIV_BY_ZERO`.
+    pub fn wrapping_div_width(&self, other: &Self, width: BitWidth) -> Result<Self, i8> {
+        let b = Int::owned_bigint(other);
+        if b.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        Ok(Int::Big(width.wrap(Int::owned_bigint(self) / b)))
+    }
+
+    /// Addition clamped to the range of `width`.
+    pub fn saturating_add_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.clamp(Int::owned_bigint(self) + Int::owned_bigint(other)))
+    }
+
+    /// Subtraction clamped to the range of `width`.
+    pub fn saturating_sub_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.clamp(Int::owned_bigint(self) - Int::owned_bigint(other)))
+    }
+
+    /// Multiplication clamped to the range of `width`.
+    pub fn saturating_mul_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.clamp(Int::owned_bigint(self) * Int::owned_bigint(other)))
+    }
+
+    /// Truncating division clamped to the range of `width` (`MIN / -1 == MAX`).
+    /// Fails with `DIV_BY_ZERO`.
+    pub fn saturating_div_width(&self, other: &Self, width: BitWidth) -> Result<Self, i8> {
+        let b = Int::owned_bigint(other);
+        if b.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        Ok(Int::Big(width.clamp(Int::owned_bigint(self) / b)))
+    }
+
+    /// Bitwise NOT within `width`: `!0` is `-1` for `I32` and `4294967295` for `U32`.
+    pub fn not_width(&self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(!Int::owned_bigint(self)))
+    }
+
+    /// Bitwise AND of both operands wrapped to `width`.
+    pub fn and_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(Int::owned_bigint(self) & Int::owned_bigint(other)))
+    }
+
+    /// Bitwise OR of both operands wrapped to `width`.
+    pub fn or_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(Int::owned_bigint(self) | Int::owned_bigint(other)))
+    }
+
+    /// Bitwise XOR of both operands wrapped to `width`.
+    pub fn xor_width(&self, other: &Self, width: BitWidth) -> Self {
+        Int::Big(width.wrap(Int::owned_bigint(self) ^ Int::owned_bigint(other)))
+    }
+
+    /// Left shift within `width`: bits moved past the top are dropped, so a shift of
+    /// `width.bits()` or more gives `0`.
+    pub fn shl_width(&self, shift: u32, width: BitWidth) -> Self {
+        let v = width.pattern(&Int::owned_bigint(self));
+        Int::Big(width.wrap(v << shift.min(width.bits())))
+    }
+
+    /// Right shift within `width`: arithmetic (copying the sign bit) for signed widths and
+    /// logical for unsigned ones, as Rust's `>>` on the primitive types.
+    pub fn shr_width(&self, shift: u32, width: BitWidth) -> Self {
+        let v = width.wrap(Int::owned_bigint(self));
+        Int::Big(v >> shift.min(width.bits()))
+    }
+
+    /// Rotates the `width.bits()`-bit pattern left by `n` (modulo the width).
+    pub fn rotate_left_width(&self, n: u32, width: BitWidth) -> Self {
+        let bits = width.bits();
+        let n = n % bits;
+        let v = width.pattern(&Int::owned_bigint(self));
+        Int::Big(width.wrap((&v << n) | (v >> (bits - n))))
+    }
+
+    /// Rotates the `width.bits()`-bit pattern right by `n` (modulo the width).
+    pub fn rotate_right_width(&self, n: u32, width: BitWidth) -> Self {
+        let bits = width.bits();
+        self.rotate_left_width(bits - n % bits, width)
     }
     pub fn _modulo(&self, other: &Self) -> Result<Self, i8> {
         let a = match self {
@@ -170,7 +556,7 @@ impl Int {
             Int::Small(si) => Int::smallint_to_bigint(si),
         };
         if b.is_zero() { return Err(ERR_DIV_BY_ZERO); }
-        Ok(Int::Big(a % b))
+        Ok(Int::from_op(backend().div_rem(&a, &b).1))
     }
     pub fn pow(&self, exponent: &Self) -> Result<Self, i8> {
         let (ed, eneg, _ek) = int_to_parts(exponent);
@@ -185,9 +571,34 @@ impl Int {
         } else {
             sign_flipped
         };
-        Ok(make_int_from_parts(digits, negative, FloatKind::Finite))
+        let result = make_int_from_parts(digits, negative, FloatKind::Finite);
+        if auto_shrink() { Ok(result.shrink()) } else { Ok(result) }
+    }
+
+    /// `self^exponent` for any integer exponent, as a `Float`. A negative exponent gives the
+    /// exact reciprocal, `Big` when it terminates (`2^-3 = 0.125`) and `Recurring`
+    /// otherwise (`3^-2 = 0.(1)`); `0` to a negative power fails with `DIV_BY_ZERO`.
+    pub fn pow_signed(&self, exponent: &Int) -> Result<Float, i8> {
+        if !exponent.is_negative() {
+            return self.pow(exponent)?.to_float();
+        }
+        if self.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        let den = self.pow(&exponent.abs())?;
+        Ok(ratio_to_float(&BigInt::one(), &Int::owned_bigint(&den)))
     }
     pub fn sqrt(&self) -> Result<Float, i8> {
+        let n = self.to_bigint()?;
+        if !n.is_negative() {
+            let root = backend().root(&n, 2);
+            if &root * &root == n {
+                return Ok(Float::Big(BigDecimal::from(root)));
+            }
+            if let Some(surd) = crate::surd::symbolic_sqrt(&BigDecimal::from(n)) {
+                return Ok(surd);
+            }
+        }
         let (mant, neg, _k) = int_to_parts(self);
         let (m2, e2, neg2, is_irr) = sqrt_int(mant, neg)?;
         if is_irr {
@@ -201,6 +612,17 @@ impl Int {
         make_int_from_parts(digits, false, FloatKind::Finite)
     }
 
+    /// `-1`, `0` or `1` by sign. `min`, `max` and `clamp` come from `Ord`.
+    pub fn signum(&self) -> Self {
+        Int::Big(Int::owned_bigint(self).signum())
+    }
+
+    /// The magnitude of `self` with the sign of `sign`; zero counts as positive.
+    pub fn copysign(&self, sign: &Int) -> Self {
+        let abs = Int::owned_bigint(self).abs();
+        if sign.is_negative() { Int::Big(-abs) } else { Int::Big(abs) }
+    }
+
     pub fn sin(&self) -> Result<Float, i8> {
         let (digits, neg, _k) = int_to_parts(self);
         let (m, e, neg2, is_irr) = sin_int(digits, neg)?;
@@ -261,87 +683,6 @@ impl Int {
         let (digits, _neg, _k) = int_to_parts(self);
         digits.is_empty() || digits == "0"
     }
-    // pub fn to_usize(&self) -> Result<usize, i8> {
-    //     if int_is_nan(self) {
-    //         return Err(ERR_INVALID_FORMAT);
-    //     }
-    //     if int_is_infinite(self) {
-    //         return Err(ERR_INFINITE_RESULT);
-    //     }
-    //     match self {
-    //         Int::Big(bi) => {
-    //             if bi.is_negative() {
-    //                 return Err(ERR_NEGATIVE_RESULT);
-    //             }
-    //             bi.to_usize().ok_or(ERR_INVALID_FORMAT)
-    //         }
-    //         Int::Small(si) => {
-    //             match si {
-    //                 SmallInt::USize(v) => Ok(*v),
-    //                 SmallInt::U64(v) => Ok(*v as usize),
-    //                 SmallInt::U32(v) => Ok(*v as usize),
-    //                 SmallInt::U16(v) => Ok(*v as usize),
-    //                 SmallInt::U8(v) => Ok(*v as usize),
-    //                 SmallInt::ISize(v) if *v >= 0 => Ok(*v as usize),
-    //                 SmallInt::I64(v) if *v >= 0 => Ok(*v as usize),
-    //                 SmallInt::I32(v) if *v >= 0 => Ok(*v as usize),
-    //                 SmallInt::i8(v) if *v >= 0 => Ok(*v as usize),
-    //                 SmallInt::I8(v) if *v >= 0 => Ok(*v as usize),
-    //                 _ => Err(ERR_NEGATIVE_RESULT),
-    //             }
-    //         }
-    //     }
-    // }
-    // pub fn to_i64(&self) -> Result<i64, i8> {
-    //     if int_is_nan(self) {
-    //         return Err(ERR_INVALID_FORMAT);
-    //     }
-    //     if int_is_infinite(self) {
-    //         return Err(ERR_INFINITE_RESULT);
-    //     }
-    //     match self {
-    //         Int::Big(bi) => bi.to_i64().ok_or(ERR_INVALID_FORMAT),
-    //         Int::Small(si) => match si {
-    //             SmallInt::I64(v) => Ok(*v),
-    //             SmallInt::U64(v) => Ok(*v as i64),
-    //             SmallInt::I32(v) => Ok(*v as i64),
-    //             SmallInt::U32(v) => Ok(*v as i64),
-    //             SmallInt::i8(v) => Ok(*v as i64),
-    //             SmallInt::U16(v) => Ok(*v as i64),
-    //             SmallInt::I8(v) => Ok(*v as i64),
-    //             SmallInt::U8(v) => Ok(*v as i64),
-    //             SmallInt::I128(v) => (*v).try_into().map_err(|_| ERR_INVALID_FORMAT),
-    //             SmallInt::U128(v) => (*v).try_into().map_err(|_| ERR_INVALID_FORMAT),
-    //             SmallInt::ISize(v) => Ok(*v as i64),
-    //             SmallInt::USize(v) => Ok(*v as i64),
-    //         },
-    //     }
-    // }
-    // pub fn to_i128(&self) -> Result<i128, i8> {
-    //     if int_is_nan(self) {
-    //         return Err(ERR_INVALID_FORMAT);
-    //     }
-    //     if int_is_infinite(self) {
-    //         return Err(ERR_INFINITE_RESULT);
-    //     }
-    //     match self {
-    //         Int::Big(bi) => bi.to_i128().ok_or(ERR_INVALID_FORMAT),
-    //         Int::Small(si) => match si {
-    //             SmallInt::I128(v) => Ok(*v),
-    //             SmallInt::U128(v) => Ok(*v as i128),
-    //             SmallInt::I64(v) => Ok(*v as i128),
-    //             SmallInt::U64(v) => Ok(*v as i128),
-    //             SmallInt::I32(v) => Ok(*v as i128),
-    //             SmallInt::U32(v) => Ok(*v as i128),
-    //             SmallInt::i8(v) => Ok(*v as i128),
-    //             SmallInt::U16(v) => Ok(*v as i128),
-    //             SmallInt::I8(v) => Ok(*v as i128),
-    //             SmallInt::U8(v) => Ok(*v as i128),
-    //             SmallInt::ISize(v) => Ok(*v as i128),
-    //             SmallInt::USize(v) => Ok(*v as i128),
-    //         },
-    //     }
-    // }
     pub fn from_i64(value: i64) -> Self {
         if value < 0 {
             make_int_from_parts(value.abs().to_string(), true, FloatKind::Finite)
@@ -393,69 +734,267 @@ impl Int {
         Ok(Int::Big(acc))
     }
 
+    /// Reads `value` in base `radix`, from 2 to 62, with an optional sign and `_`
+    /// separators between digits. Up to base 36 the digits are `0-9` then `a-z` in either
+    /// case; above that they are the base62 alphabet `0-9A-Za-z` and case matters.
     pub fn from_str_radix(value: &str, radix: u32) -> Result<Self, i8> {
-        if radix < 2 || radix > 36 {
+        if !(2..=62).contains(&radix) {
             return Err(ERR_INVALID_FORMAT);
         }
-        if value.is_empty() {
-            return Err(ERR_INVALID_FORMAT);
-        }
-        let mut s = value.trim();
-        let mut negative = false;
-        if s.starts_with('-') {
-            negative = true;
-            s = &s[1..];
-        } else if s.starts_with('+') {
-            s = &s[1..];
-        }
+        let (negative, s) = split_sign(value.trim());
         if s.is_empty() {
             return Err(ERR_INVALID_FORMAT);
         }
-        let mut acc = BigInt::from(0u32);
-        let base = BigInt::from(radix);
+        let alphabet: Vec<char> = BASE62_ALPHABET[..radix as usize].chars().collect();
+        let mut digits = Vec::with_capacity(s.len());
         for c in s.chars() {
-            if c == '_' { continue; }
-            let digit = c.to_digit(radix);
-            if digit.is_none() {
-                return Err(ERR_INVALID_FORMAT);
+            if c == '_' {
+                continue;
             }
-            acc = &acc * &base + BigInt::from(digit.unwrap());
+            let digit = if radix <= 36 {
+                c.to_digit(radix).map(|d| d as u8)
+            } else {
+                alphabet.iter().position(|&a| a == c).map(|d| d as u8)
+            };
+            digits.push(digit.ok_or(ERR_INVALID_FORMAT)?);
         }
-        if negative { acc = -acc; }
-        Ok(Int::Big(acc))
+        from_digits(negative, &digits, radix)
     }
 
+    /// Writes the value in base `radix`, from 2 to 62: lowercase `0-9a-z` up to base 36,
+    /// the base62 alphabet `0-9A-Za-z` above.
     pub fn to_str_radix(&self, radix: u32) -> Result<String, i8> {
-        if radix < 2 || radix > 36 {
+        if !(2..=62).contains(&radix) {
             return Err(ERR_INVALID_FORMAT);
         }
-        match self {
-            Int::Big(bi) => Ok(bi.to_str_radix(radix)),
-            Int::Small(si) => {
-                let bi = Int::smallint_to_bigint(si);
-                Ok(bi.to_str_radix(radix))
-            }
-        }
-    }
-    pub fn from_str(value: &str) -> Result<Self, i8> {
-        if value.is_empty() {
+        let bi = self.to_bigint()?;
+        if radix <= 36 {
+            return Ok(bi.to_str_radix(radix));
+        }
+        let alphabet: Vec<char> = BASE62_ALPHABET[..radix as usize].chars().collect();
+        Ok(to_alphabet(&bi, &alphabet))
+    }
+
+    /// Reads `value` written with a custom `alphabet`, whose symbols are the digits `0`,
+    /// `1`, ... in order, so the base is the number of symbols (2 to 256). A leading `-`
+    /// is a sign unless the alphabet itself uses `-`; there are no separators.
+    ///
+    /// Fails with `INVALID_FORMAT` for a symbol outside the alphabet, an empty number, and
+    /// an alphabet that is too short, too long or repeats a symbol.
+    ///
+    /// ```
+    /// use imagnum::create_int;
+    /// use imagnum::Int;
+    ///
+    /// let dna = "ACGT";
+    /// assert_eq!(Int::from_str_alphabet("GATTACA", dna), Ok(create_int("9156")));
+    /// assert_eq!(create_int("9156").to_str_alphabet(dna).unwrap(), "GATTACA");
+    /// ```
+    pub fn from_str_alphabet(value: &str, alphabet: &str) -> Result<Self, i8> {
+        let alphabet = check_alphabet(alphabet)?;
+        let s = value.trim();
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) if !alphabet.contains(&'-') => (true, rest),
+            _ => (false, s),
+        };
+        if s.is_empty() {
             return Err(ERR_INVALID_FORMAT);
         }
-        let int = create_int(value);
-        if int_is_nan(&int) || int_is_infinite(&int) {
+        let digits = s
+            .chars()
+            .map(|c| alphabet.iter().position(|&a| a == c).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(ERR_INVALID_FORMAT)?;
+        from_digits(negative, &digits, alphabet.len() as u32)
+    }
+
+    /// Writes the value with a custom `alphabet`, the inverse of `from_str_alphabet`.
+    /// Negative values get a leading `-`, which fails with `INVALID_FORMAT` when the
+    /// alphabet uses `-` as a digit.
+    pub fn to_str_alphabet(&self, alphabet: &str) -> Result<String, i8> {
+        let alphabet = check_alphabet(alphabet)?;
+        let bi = self.to_bigint()?;
+        if bi.is_negative() && alphabet.contains(&'-') {
             return Err(ERR_INVALID_FORMAT);
         }
-        Ok(int)
+        Ok(to_alphabet(&bi, &alphabet))
     }
+    /// Same as `try_create_int`.
+    pub fn from_str(value: &str) -> Result<Self, i8> {
+        crate::functions::try_create_int(value)
+    }
+    /// Always `false`: an `Int` is always finite. `IntOrSpecial` adds NaN and infinities.
+    #[deprecated(note = "an Int is never NaN; use IntOrSpecial for an integer that can be")]
     pub fn is_nan(&self) -> bool {
-        int_is_nan(self)
+        false
     }
+    /// Always `false`; see `is_nan`.
+    #[deprecated(note = "an Int is never infinite; use IntOrSpecial for an integer that can be")]
     pub fn is_infinity(&self) -> bool {
-        int_is_infinite(self)
+        false
     }
     pub fn to_str(&self) -> String {
         format!("{}", self)
     }
+
+    /// Exact floor square root. Fails with `NEGATIVE_SQRT` for negative values.
+    pub fn isqrt(&self) -> Result<Self, i8> {
+        let n = self.to_bigint()?;
+        if n.is_negative() {
+            return Err(ERR_NEGATIVE_SQRT);
+        }
+        Ok(Int::Big(backend().root(&n, 2)))
+    }
+
+    /// Exact `n`-th root, rounded toward zero. Odd roots of negative values are negative,
+    /// even roots of negative values fail with `NEGATIVE_SQRT`.
+    pub fn nth_root(&self, n: u32) -> Result<Self, i8> {
+        if n == 0 {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let a = self.to_bigint()?;
+        if a.is_negative() {
+            if n.is_multiple_of(2) {
+                return Err(ERR_NEGATIVE_SQRT);
+            }
+            return Ok(Int::Big(-backend().root(&-a, n)));
+        }
+        Ok(Int::Big(backend().root(&a, n)))
+    }
+
+    /// If the value is `base^exp` for some `exp >= 2`, returns the base and the largest
+    /// such exponent. Negative values only match odd exponents; 0 and 1 give `(self, 2)`.
+    pub fn is_perfect_power(&self) -> Option<(Self, u32)> {
+        let a = self.to_bigint().ok()?;
+        let negative = a.is_negative();
+        let abs = a.abs();
+        if abs <= BigInt::from(1u32) {
+            return if negative { Some((self.clone(), 3)) } else { Some((self.clone(), 2)) };
+        }
+        let max_exp = abs.bits() as u32;
+        for exp in (2..=max_exp).rev() {
+            if negative && exp % 2 == 0 {
+                continue;
+            }
+            let root = backend().root(&abs, exp);
+            if root.pow(exp) == abs {
+                let base = if negative { -root } else { root };
+                return Some((Int::Big(base), exp));
+            }
+        }
+        None
+    }
+
+    /// Returns `n!`. Fails with `NEGATIVE_RESULT` for negative values and
+    /// `NUMBER_TOO_LARGE` when `n` does not fit in a `u64`.
+    pub fn factorial(&self) -> Result<Self, i8> {
+        let n = self.to_bigint()?;
+        if n.is_negative() {
+            return Err(ERR_NEGATIVE_RESULT);
+        }
+        let n = n.to_u64().ok_or(ERR_NUMBER_TOO_LARGE)?;
+        Ok(Int::Big(product_range(&BigInt::from(1u32), n)))
+    }
+
+    /// Rising factorial `x (x+1) ... (x+n-1)`; `n = 0` gives 1.
+    pub fn rising_factorial(&self, n: &Int) -> Result<Self, i8> {
+        let x = self.to_bigint()?;
+        let n = n.to_bigint()?;
+        if n.is_negative() {
+            return Err(ERR_NEGATIVE_RESULT);
+        }
+        let n = n.to_u64().ok_or(ERR_NUMBER_TOO_LARGE)?;
+        Ok(Int::Big(product_range(&x, n)))
+    }
+
+    /// Falling factorial `x (x-1) ... (x-n+1)`; `n = 0` gives 1.
+    pub fn falling_factorial(&self, n: &Int) -> Result<Self, i8> {
+        let x = self.to_bigint()?;
+        let n = n.to_bigint()?;
+        if n.is_negative() {
+            return Err(ERR_NEGATIVE_RESULT);
+        }
+        let n_u64 = n.to_u64().ok_or(ERR_NUMBER_TOO_LARGE)?;
+        if n_u64 == 0 {
+            return Ok(Int::Big(BigInt::from(1u32)));
+        }
+        // x (x-1) ... (x-n+1) == (x-n+1) (x-n+2) ... x
+        let start = &x - &n + 1u32;
+        Ok(Int::Big(product_range(&start, n_u64)))
+    }
+
+    /// Binomial coefficient `C(self, k)`. Negative `self` uses the generalized
+    /// definition `x (x-1) ... (x-k+1) / k!`; negative `k` gives 0.
+    pub fn binomial(&self, k: &Int) -> Result<Self, i8> {
+        let n = self.to_bigint()?;
+        let mut k = k.to_bigint()?;
+        if k.is_negative() {
+            return Ok(Int::Big(BigInt::zero()));
+        }
+        if !n.is_negative() {
+            if k > n {
+                return Ok(Int::Big(BigInt::zero()));
+            }
+            let other = &n - &k;
+            if other < k {
+                k = other;
+            }
+        }
+        let k = k.to_u64().ok_or(ERR_NUMBER_TOO_LARGE)?;
+        let mut result = BigInt::from(1u32);
+        for i in 0..k {
+            result = result * (&n - i) / (i + 1);
+        }
+        Ok(Int::Big(result))
+    }
+
+    /// Multinomial coefficient `(k1 + k2 + ...)! / (k1! k2! ...)`.
+    pub fn multinomial(ks: &[Int]) -> Result<Self, i8> {
+        let mut result = BigInt::from(1u32);
+        let mut total = BigInt::zero();
+        for k in ks {
+            let k_big = k.to_bigint()?;
+            if k_big.is_negative() {
+                return Err(ERR_NEGATIVE_RESULT);
+            }
+            total += &k_big;
+            match Int::Big(total.clone()).binomial(k)? {
+                Int::Big(c) => result *= c,
+                small => result *= small.to_bigint()?,
+            }
+        }
+        Ok(Int::Big(result))
+    }
+
+    /// Stable 128-bit hash of the value, for interning literals across runs and crate versions.
+    /// `Small` and `Big` forms of the same number share a fingerprint.
+    pub fn fingerprint(&self) -> u128 {
+        fnv1a_128(&self.canonical_bytes())
+    }
+
+    fn canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![b'i'];
+        bytes.extend(Int::owned_bigint(self).to_signed_bytes_le());
+        bytes
+    }
+
+    /// Describes the value (sign, digit count, memory usage, ...) without exposing the variant.
+    pub fn describe(&self) -> NumberInfo {
+        let (digits, negative, _k) = int_to_parts(self);
+        let memory = match self {
+            Int::Big(bi) => core::mem::size_of::<Int>() + bigint_heap_bytes(bi),
+            Int::Small(_) => core::mem::size_of::<Int>(),
+        };
+        NumberInfo {
+            is_int: true,
+            kind: FloatKind::Finite,
+            negative: negative && digits != "0",
+            digits: digits.len(),
+            scale: 0,
+            exact: true,
+            memory,
+        }
+    }
 }
 
 impl Float {
@@ -472,19 +1011,105 @@ impl Float {
         float_kind(self) == FloatKind::Irrational
     }
 
+    /// Whether the value is held exactly: `false` for irrational (rounded) values and NaN,
+    /// and for a complex number when either part is inexact. Arithmetic with an irrational
+    /// operand stays irrational, so an exact result means no digits were dropped.
+    pub fn is_exact(&self) -> bool {
+        match self {
+            Float::Complex(re, im) => re.is_exact() && im.is_exact(),
+            Float::Surd(_, _) => true,
+            _ => !matches!(float_kind(self), FloatKind::Irrational | FloatKind::NaN),
+        }
+    }
+
     pub fn is_complex(&self) -> bool {
         matches!(self, Float::Complex(_, _))
     }
 
     pub fn conj(&self) -> Self {
         if let Float::Complex(real, imag) = self {
-            let neg_imag = Float::Big(BigDecimal::from(0))._sub(imag).unwrap_or_else(|_| Float::NaN);
+            let neg_imag = Float::Big(cache::ZERO.clone())._sub(imag).unwrap_or(Float::NaN);
             Float::Complex(real.clone(), Box::new(neg_imag))
         } else {
             self.clone()
         }
     }
 
+    /// Real part; the value itself when it is not complex.
+    pub fn re(&self) -> Float {
+        match self {
+            Float::Complex(real, _) => *real.clone(),
+            _ => self.clone(),
+        }
+    }
+
+    /// Imaginary part; zero when the value is not complex.
+    pub fn im(&self) -> Float {
+        match self {
+            Float::Complex(_, imag) => *imag.clone(),
+            _ => Float::Big(cache::ZERO.clone()),
+        }
+    }
+
+    /// `re² + im²`, the squared magnitude, computed without a square root.
+    pub fn norm_sqr(&self) -> Result<Float, i8> {
+        let (re, im) = (self.re(), self.im());
+        re._mul(&re)?._add(&im._mul(&im)?)
+    }
+
+    /// Angle to the positive real axis in `(-π, π]`, to `precision()` significant digits.
+    /// Non-negative reals (including zero) give exactly `0`; an infinite part outweighs a
+    /// finite one. Fails with `INVALID_FORMAT` for NaN parts.
+    pub fn arg(&self) -> Result<Float, i8> {
+        let (re, im) = (self.re(), self.im());
+        if re.is_nan() || im.is_nan() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        if im.is_zero() && !re.is_negative() {
+            return Ok(Float::Big(cache::ZERO.clone()));
+        }
+        let (x, y) = match (re.to_bigdecimal().0, im.to_bigdecimal().0) {
+            (Some(x), Some(y)) => (x, y),
+            // only the infinite parts' signs count: ±1 for those, 0 for a finite one
+            _ => {
+                let unit = |v: &Float| match float_kind(v) {
+                    FloatKind::Infinity => BigDecimal::from(1),
+                    FloatKind::NegInfinity => BigDecimal::from(-1),
+                    _ => BigDecimal::zero(),
+                };
+                (unit(&re), unit(&im))
+            }
+        };
+        Ok(Float::fused_result(crate::math::atan2_bigdecimal(&y, &x, precision()), true))
+    }
+
+    /// `(|z|, arg(z))`.
+    pub fn to_polar(&self) -> Result<(Float, Float), i8> {
+        let r = self.abs();
+        if r.is_nan() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        Ok((r, self.arg()?))
+    }
+
+    /// The complex value `r·(cos θ + i·sin θ)`. Both arguments must be real.
+    pub fn from_polar(r: &Float, theta: &Float) -> Result<Float, i8> {
+        if r.is_complex() || theta.is_complex() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let (sin, cos) = theta.sin_cos()?;
+        Ok(Float::Complex(Box::new(r._mul(&cos)?), Box::new(r._mul(&sin)?)))
+    }
+
+    /// Borrows the decimal held by a `Big` or `Irrational` value; `None` for every other
+    /// kind, recurring values included since they hold a fraction.
+    pub fn as_bigdecimal(&self) -> Option<&BigDecimal> {
+        match self {
+            Float::Big(bd) | Float::Irrational(bd) => Some(bd),
+            _ => None,
+        }
+    }
+
     pub fn to_bigdecimal(&self) -> (Option<BigDecimal>, Option<BigDecimal>) {
         match self {
             Float::Big(bd) => (Some(bd.clone()), None),
@@ -500,39 +1125,37 @@ impl Float {
                 (r_bd_opt, i_bd_opt)
             }
             Float::Irrational(irr) => (Some(irr.clone()), None),
-            Float::Recurring(rec) => (Some(rec.clone()), None),
+            Float::Recurring(num, den) => (Some(crate::recurring::expand(num, den)), None),
+            Float::Surd(c, r) => (Some(crate::surd::expand(c, r)), None),
             Float::Infinity | Float::NegInfinity | Float::NaN => (None, None),
         }
     }
 
     pub fn sqrt(&self) -> Result<Self, i8> {
-        // Complex sqrt: sqrt(a + bi) = sqrt(r) * (cos(θ/2) + i*sin(θ/2))
-        // where r = |a + bi| and θ = atan2(b, a)
         if let Float::Complex(real, imag) = self {
-            // sqrt(a+bi) = ±(sqrt((r+a)/2) + i*sign(b)*sqrt((r-a)/2))
-            // where r = sqrt(a² + b²)
-            let a_sq = real._mul(real)?;
-            let b_sq = imag._mul(imag)?;
-            let r_sq = a_sq._add(&b_sq)?;
-            let r = r_sq.sqrt()?;
-            
-            let r_plus_a = r._add(real)?;
-            let r_minus_a = r._sub(real)?;
-            
-            let two = Float::Big(BigDecimal::from(2));
-            let half_r_plus_a = r_plus_a._div(&two)?;
-            let half_r_minus_a = r_minus_a._div(&two)?;
-            
-            let new_real = half_r_plus_a.sqrt()?;
-            let new_imag_abs = half_r_minus_a.sqrt()?;
-            
-            // Sign of new imaginary part matches sign of old imaginary part
-            let new_imag = if float_is_negative(imag) {
-                Float::Big(BigDecimal::from(0))._sub(&new_imag_abs)?
+            // t = sqrt((|a| + r)/2) with r = |a + bi|; then sqrt(a + bi) is t + b/(2t) i for
+            // a >= 0 and |b|/(2t) ± t i otherwise, with the sign of b. Unlike
+            // sqrt((r - a)/2), nothing cancels when b is tiny next to a.
+            if float_is_zero(imag) {
+                let root = real.abs().sqrt()?;
+                let zero = Float::Big(cache::ZERO.clone());
+                return Ok(if float_is_negative(real) {
+                    Float::Complex(Box::new(zero), Box::new(root))
+                } else {
+                    Float::Complex(Box::new(root), Box::new(zero))
+                });
+            }
+            let r = real._mul(real)?._add(&imag._mul(imag)?)?.sqrt()?;
+            let two = Float::Big(cache::TWO.clone());
+            let t = real.abs()._add(&r)?._div(&two)?.sqrt()?;
+            let other = imag._div(&t._mul(&two)?)?;
+            let (new_real, new_imag) = if !float_is_negative(real) {
+                (t, other)
+            } else if float_is_negative(imag) {
+                (other.abs(), -t)
             } else {
-                new_imag_abs
+                (other.abs(), t)
             };
-            
             return Ok(Float::Complex(Box::new(new_real), Box::new(new_imag)));
         }
         
@@ -549,11 +1172,18 @@ impl Float {
         if self.is_negative() {
             // Handle sqrt of negative real numbers by returning complex result
             // sqrt(-x) = i * sqrt(x)
-            let pos_self = Float::Big(BigDecimal::from(0))._sub(self)?;
+            let pos_self = Float::Big(cache::ZERO.clone())._sub(self)?;
             let pos_sqrt = pos_self.sqrt()?;
-            let zero = Float::Big(BigDecimal::from(0));
+            let zero = Float::Big(cache::ZERO.clone());
             return Ok(Float::Complex(Box::new(zero), Box::new(pos_sqrt)));
         }
+        if let Float::Surd(c, r) = self {
+            // a fourth root has no surd form
+            return Float::Irrational(crate::surd::expand(c, r)).sqrt();
+        }
+        if let Some(surd) = crate::surd::exact_value(self).and_then(|v| crate::surd::symbolic_sqrt(&v)) {
+            return Ok(surd);
+        }
         let (m, e, neg, _k) = float_to_parts(self);
         let (m, e, neg, is_irr) = sqrt_float(m, e, neg)?;
         if is_irr {
@@ -561,10 +1191,195 @@ impl Float {
         }
         Ok(make_float_from_parts(m, e, neg, FloatKind::Finite))
     }
+    /// `op` on the decimal values when either operand is irrational, rounded once to
+    /// `precision()` significant digits so the result stays `Irrational`. `None` when
+    /// neither operand is irrational, leaving the exact paths to the caller.
+    fn irrational_op(&self, other: &Self, op: impl FnOnce(BigDecimal, BigDecimal) -> BigDecimal) -> Option<Self> {
+        if !self.is_irrational() && !other.is_irrational() {
+            return None;
+        }
+        let (a, b) = (self.decimal_value()?, other.decimal_value()?);
+        Some(Self::fused_result(op(a, b), true))
+    }
+
+    /// The canonical form of the value: a recurring value whose expansion terminates
+    /// (`0.(9)`, `1.2(9)`) becomes the exact decimal, a negative zero loses its sign, and a
+    /// `Big` value an `f64` holds exactly (`1.50`, `42`) becomes `Small`. Complex values
+    /// canonicalize each part. Arithmetic results get the first two always and the
+    /// demotion when `auto_shrink()` is on, as `Int` results are shrunk.
+    pub fn canonicalize(&self) -> Self {
+        match self.clone().collapse() {
+            Float::Complex(real, imag) => Float::Complex(Box::new(real.canonicalize()), Box::new(imag.canonicalize())),
+            Float::Big(bd) => {
+                let v = crate::math::bigdecimal_to_f64(&bd);
+                if v.is_finite() && BigDecimal::from_f64(v).is_some_and(|exact| exact == bd) {
+                    Float::Small(SmallFloat::F64(v + 0.0))
+                } else {
+                    Float::Big(bd)
+                }
+            }
+            other => other,
+        }
+    }
+
+    /// `canonicalize` without the demotion to `Small`.
+    fn collapse(self) -> Self {
+        match self {
+            Float::Recurring(ref num, ref den) if !den.is_zero() => ratio_to_float(num, den),
+            // `-0.0 + 0.0` is `+0.0`; every other value is unchanged
+            Float::Small(SmallFloat::F64(v)) => Float::Small(SmallFloat::F64(v + 0.0)),
+            Float::Small(SmallFloat::F32(v)) => Float::Small(SmallFloat::F32(v + 0.0)),
+            Float::Complex(real, imag) => Float::Complex(Box::new(real.collapse()), Box::new(imag.collapse())),
+            other => other,
+        }
+    }
+
+    /// Normalizes an arithmetic result; see `canonicalize`.
+    fn from_op(result: Self) -> Self {
+        if auto_shrink() { result.canonicalize() } else { result.collapse() }
+    }
+
+    /// Normalizes an arithmetic result, or under `ArithmeticPolicy::Ieee` turns its error
+    /// into the special value `ieee` gives.
+    fn finish(result: Result<Self, i8>, ieee: impl FnOnce(i8) -> Self) -> Result<Self, i8> {
+        match result {
+            Ok(v) => Ok(Self::from_op(v)),
+            Err(code) if Self::ieee_policy() => Ok(ieee(code)),
+            Err(code) => Err(code),
+        }
+    }
+
+    /// Both operands as exact decimals for the generic arithmetic paths, small floats by
+    /// their shortest digits and surds by their expansion.
+    fn decimal_operands(&self, other: &Self) -> Result<(BigDecimal, BigDecimal), i8> {
+        match (self.decimal_value(), other.decimal_value()) {
+            (Some(a), Some(b)) => Ok((a, b)),
+            _ => Err(ERR_INVALID_FORMAT),
+        }
+    }
+
+    /// `value`, the result of a generic path, rounded as `Irrational` when a recurring
+    /// operand only took part through its expansion.
+    fn generic_result(&self, other: &Self, value: BigDecimal) -> Self {
+        if matches!(self, Float::Recurring(..)) || matches!(other, Float::Recurring(..)) {
+            Self::fused_result(value, true)
+        } else {
+            Float::Big(normalize_decimal(value))
+        }
+    }
+
+    fn ieee_policy() -> bool {
+        crate::compat::behavior().arithmetic_policy == ArithmeticPolicy::Ieee
+    }
+
+    fn is_infinite_kind(&self) -> bool {
+        matches!(float_kind(self), FloatKind::Infinity | FloatKind::NegInfinity)
+    }
+
     pub fn _add(&self, other: &Self) -> Result<Self, i8> {
+        Self::finish(self.add_raw(other), |_| Float::NaN)
+    }
+
+    pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+        if self.is_infinite_kind() && float_kind(self) == float_kind(other) {
+            return if Self::ieee_policy() { Ok(Float::NaN) } else { Err(ERR_INFINITE_RESULT) };
+        }
+        Self::finish(self.sub_raw(other), |_| Float::NaN)
+    }
+
+    pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
+        // `Strict` keeps the old `∞ · 0 = ∞`
+        let inf_times_zero = (self.is_infinite_kind() && float_is_zero(other))
+            || (other.is_infinite_kind() && float_is_zero(self));
+        if inf_times_zero && Self::ieee_policy() {
+            return Ok(Float::NaN);
+        }
+        Self::finish(self.mul_raw(other), |_| Float::NaN)
+    }
+
+    pub fn _div(&self, other: &Self) -> Result<Self, i8> {
+        Self::finish(self.div_raw(other), |_| {
+            let real = !self.is_complex() && !other.is_complex();
+            if real && other.is_zero() && !self.is_zero() && !self.is_nan() {
+                if self.is_negative() { Float::NegInfinity } else { Float::Infinity }
+            } else {
+                Float::NaN
+            }
+        })
+    }
+
+    pub fn _modulo(&self, other: &Self) -> Result<Self, i8> {
+        Self::finish(self.modulo_raw(other), |_| Float::NaN)
+    }
+
+    /// `self` to the power `exponent`.
+    ///
+    /// - `x^0 = 1` for every `x` but NaN, so `0^0 = 1` and `∞^0 = 1`; a NaN operand fails
+    ///   with `INVALID_FORMAT`.
+    /// - `0^p` is 0 for `p > 0` and fails with `DIV_BY_ZERO` for `p < 0`.
+    /// - `∞^p` is `∞` for `p > 0` and 0 for `p < 0` (`(-∞)^n` keeps the sign for odd `n`);
+    ///   `x^∞` is `∞` for `|x| > 1`, 1 for `|x| = 1` and 0 for `|x| < 1`, reversed for `x^-∞`.
+    /// - A negative base to a fraction `p/q` in lowest terms is real when `q` is odd, so
+    ///   `(-8)^(1/3) = -2`. Any other fractional power gives the complex principal value,
+    ///   `(-4)^0.5 = 2i`, or fails with `INVALID_FORMAT` when `Behavior::complex_powers` is
+    ///   off.
+    pub fn _pow(&self, exponent: &Self) -> Result<Self, i8> {
+        Self::finish(self.pow_raw(exponent), |code| {
+            if code == ERR_DIV_BY_ZERO && !self.is_nan() && !exponent.is_nan() { Float::Infinity } else { Float::NaN }
+        })
+    }
+
+    /// Hardware fast path for two `Small(F64)` operands. Returns `None` (so the caller takes
+    /// the `BigDecimal` path) unless the `f64` result is finite and exact.
+    fn small_f64_op(&self, other: &Self, op: SmallOp) -> Option<Self> {
+        let (Float::Small(SmallFloat::F64(a)), Float::Small(SmallFloat::F64(b))) = (self, other) else {
+            return None;
+        };
+        if !crate::compat::behavior().small_float_results {
+            return None;
+        }
+        let (a, b) = (*a, *b);
+        if !a.is_finite() || !b.is_finite() {
+            return None;
+        }
+        let (r, exact) = match op {
+            SmallOp::Add | SmallOp::Sub => {
+                let b = if op == SmallOp::Sub { -b } else { b };
+                // TwoSum: the rounding error of a + b is exactly representable
+                let r = a + b;
+                let bb = r - a;
+                (r, (a - (r - bb)) + (b - bb) == 0.0)
+            }
+            SmallOp::Mul => {
+                let r = a * b;
+                (r, a.mul_add(b, -r) == 0.0)
+            }
+            SmallOp::Div => {
+                if b == 0.0 {
+                    return None;
+                }
+                let r = a / b;
+                (r, (-r).mul_add(b, a) == 0.0)
+            }
+        };
+        if !r.is_finite() || !exact {
+            return None;
+        }
+        // the residual check is unreliable once the product or quotient underflows
+        let underflow = (r == 0.0 && a != 0.0 && b != 0.0) || r.is_subnormal();
+        if matches!(op, SmallOp::Mul | SmallOp::Div) && underflow {
+            return None;
+        }
+        Some(Float::Small(SmallFloat::F64(r)))
+    }
+
+    fn add_raw(&self, other: &Self) -> Result<Self, i8> {
         if float_kind(self) == FloatKind::NaN || float_kind(other) == FloatKind::NaN {
             return Err(ERR_INVALID_FORMAT);
         }
+        if let Some(res) = self.small_f64_op(other, SmallOp::Add) {
+            return Ok(res);
+        }
 
         // (a + bi) + (c + di) = (a+c) + (b+d)i
         match (self, other) {
@@ -605,28 +1420,34 @@ impl Float {
         {
             return Err(ERR_INFINITE_RESULT);
         }
+        // a finite value does not move an infinity
+        if matches!(k1, FloatKind::Infinity | FloatKind::NegInfinity) {
+            return Ok(self.clone());
+        }
+        if matches!(k2, FloatKind::Infinity | FloatKind::NegInfinity) {
+            return Ok(other.clone());
+        }
 
-        let (m1, e1, n1, _k1) = float_to_parts(self);
-        let (m2, e2, n2, _k2) = float_to_parts(other);
-        let (mantissa, exponent, negative) = add_float(m1.clone(), e1, n1, m2.clone(), e2, n2)?;
-        let result_kind = if float_kind(self) == FloatKind::Recurring
-            || float_kind(other) == FloatKind::Recurring
-        {
-            FloatKind::Recurring
-        } else {
-            FloatKind::Finite
-        };
-        Ok(make_float_from_parts(
-            mantissa,
-            exponent,
-            negative,
-            result_kind,
-        ))
+        if let Some(res) = self.surd_add(other, false) {
+            return Ok(res);
+        }
+        if let Some(res) = self.irrational_op(other, |a, b| a + b) {
+            return Ok(res);
+        }
+        if let Some(res) = self.recurring_op(other, RatioOp::Add) {
+            return Ok(res);
+        }
+
+        let (a, b) = self.decimal_operands(other)?;
+        Ok(self.generic_result(other, &a + &b))
     }
-    pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+    fn sub_raw(&self, other: &Self) -> Result<Self, i8> {
         if float_kind(self) == FloatKind::NaN || float_kind(other) == FloatKind::NaN {
             return Err(ERR_INVALID_FORMAT);
         }
+        if let Some(res) = self.small_f64_op(other, SmallOp::Sub) {
+            return Ok(res);
+        }
 
         // (a + bi) - (c + di) = (a-c) + (b-d)i
         match (self, other) {
@@ -641,7 +1462,7 @@ impl Float {
             }
             (other_val, Float::Complex(r, i)) => {
                 let real = other_val._sub(r)?;
-                let neg_imag = Float::Big(BigDecimal::from(0))._sub(i)?;
+                let neg_imag = Float::Big(cache::ZERO.clone())._sub(i)?;
                 return Ok(Float::Complex(Box::new(real), Box::new(neg_imag)));
             }
             _ => {}
@@ -659,22 +1480,11 @@ impl Float {
             }
         }
 
-        if float_kind(self) == FloatKind::Infinity && float_kind(other) == FloatKind::Infinity {
-            return Ok(make_float_from_parts(
-                "0".to_string(),
-                0,
-                false,
-                FloatKind::Finite,
-            ));
-        }
-        if float_kind(self) == FloatKind::NegInfinity && float_kind(other) == FloatKind::NegInfinity
+        // zeros carry no sign, so `-∞ - -∞` is the same `0` as `∞ - ∞`
+        if (float_kind(self) == FloatKind::Infinity && float_kind(other) == FloatKind::Infinity)
+            || (float_kind(self) == FloatKind::NegInfinity && float_kind(other) == FloatKind::NegInfinity)
         {
-            return Ok(make_float_from_parts(
-                "0".to_string(),
-                0,
-                true,
-                FloatKind::Finite,
-            ));
+            return Ok(Float::Big(cache::ZERO.clone()));
         }
         if (float_kind(self) == FloatKind::Infinity && float_kind(other) == FloatKind::NegInfinity)
             || (float_kind(self) == FloatKind::NegInfinity
@@ -683,27 +1493,26 @@ impl Float {
             return Err(ERR_INFINITE_RESULT);
         }
 
-        let (m1, e1, n1, _k1) = float_to_parts(self);
-        let (m2, e2, n2, _k2) = float_to_parts(other);
-        let (mantissa, exponent, negative) = sub_float(m1.clone(), e1, n1, m2.clone(), e2, n2)?;
-        let result_kind = if float_kind(self) == FloatKind::Recurring
-            || float_kind(other) == FloatKind::Recurring
-        {
-            FloatKind::Recurring
-        } else {
-            FloatKind::Finite
-        };
-        Ok(make_float_from_parts(
-            mantissa,
-            exponent,
-            negative,
-            result_kind,
-        ))
+        if let Some(res) = self.surd_add(other, true) {
+            return Ok(res);
+        }
+        if let Some(res) = self.irrational_op(other, |a, b| a - b) {
+            return Ok(res);
+        }
+        if let Some(res) = self.recurring_op(other, RatioOp::Sub) {
+            return Ok(res);
+        }
+
+        let (a, b) = self.decimal_operands(other)?;
+        Ok(self.generic_result(other, &a - &b))
     }
-    pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
+    fn mul_raw(&self, other: &Self) -> Result<Self, i8> {
         if float_kind(self) == FloatKind::NaN || float_kind(other) == FloatKind::NaN {
             return Err(ERR_INVALID_FORMAT);
         }
+        if let Some(res) = self.small_f64_op(other, SmallOp::Mul) {
+            return Ok(res);
+        }
 
         // (a + bi)(c + di) = (ac - bd) + (ad + bc)i
         match (self, other) {
@@ -731,7 +1540,7 @@ impl Float {
                 crate::compat::float_to_bigdecimal(self),
                 crate::compat::float_to_bigdecimal(other),
             ) {
-                let res = a_bd * b_bd;
+                let res = backend().decimal_mul(&a_bd, &b_bd);
                 return Ok(Float::Big(res));
             }
         }
@@ -754,33 +1563,30 @@ impl Float {
             });
         }
 
-        let (m1, e1, n1, _k1) = float_to_parts(self);
-        let (m2, e2, n2, _k2) = float_to_parts(other);
-        let (mantissa, exponent, negative) = mul_float(m1.clone(), e1, n1, m2.clone(), e2, n2)?;
-        let result_kind = if float_kind(self) == FloatKind::Recurring
-            || float_kind(other) == FloatKind::Recurring
-        {
-            FloatKind::Recurring
-        } else {
-            FloatKind::Finite
-        };
-        let mut result = make_float_from_parts(
-            mantissa,
-            exponent,
-            negative,
-            result_kind,
-        );
-        
-        if result_kind == FloatKind::Recurring {
-            result = normalize_recurring_decimal(result);
+        // an exact zero factor wipes out any rounding in the other one
+        if (float_is_zero(self) && self.is_exact()) || (float_is_zero(other) && other.is_exact()) {
+            return Ok(Float::Big(BigDecimal::zero()));
         }
-        
-        Ok(result)
+        if let Some(res) = self.surd_mul(other) {
+            return Ok(res);
+        }
+        if let Some(res) = self.irrational_op(other, |a, b| a * b) {
+            return Ok(res);
+        }
+        if let Some(res) = self.recurring_op(other, RatioOp::Mul) {
+            return Ok(res);
+        }
+
+        let (a, b) = self.decimal_operands(other)?;
+        Ok(self.generic_result(other, backend().decimal_mul(&a, &b)))
     }
-    pub fn _div(&self, other: &Self) -> Result<Self, i8> {
+    fn div_raw(&self, other: &Self) -> Result<Self, i8> {
         if float_kind(self) == FloatKind::NaN || float_kind(other) == FloatKind::NaN {
             return Err(ERR_INVALID_FORMAT);
         }
+        if let Some(res) = self.small_f64_op(other, SmallOp::Div) {
+            return Ok(res);
+        }
         if float_is_zero(other) {
             return Err(ERR_DIV_BY_ZERO);
         }
@@ -824,7 +1630,7 @@ impl Float {
                 let ac = other_val._mul(c)?;
                 let ad = other_val._mul(d)?;
                 let real = ac._div(&denom)?;
-                let neg_ad = Float::Big(BigDecimal::from(0))._sub(&ad)?;
+                let neg_ad = Float::Big(cache::ZERO.clone())._sub(&ad)?;
                 let imag = neg_ad._div(&denom)?;
                 return Ok(Float::Complex(Box::new(real), Box::new(imag)));
             }
@@ -851,105 +1657,32 @@ impl Float {
             ));
         }
 
-        let (m1, e1, n1, _) = float_to_parts(self);
-        let (m2, e2, n2, _) = float_to_parts(other);
-
-        let self_is_int_like =
-            e1 >= 0 || (e1 < 0 && (-(e1) as usize) <= m1.len() && m1.chars().all(|c| c == '0'));
-        let other_is_int_like =
-            e2 >= 0 || (e2 < 0 && (-(e2) as usize) <= m2.len() && m2.chars().all(|c| c == '0'));
-
-        if self_is_int_like && other_is_int_like {
-            let mut num_str = m1.clone();
-            if e1 < 0 {
-                num_str.push_str(&"0".repeat((-e1) as usize));
-            }
-            let mut den_str = m2.clone();
-            if e2 < 0 {
-                den_str.push_str(&"0".repeat((-e2) as usize));
-            }
-            let mut num = BigInt::from_str(&num_str).unwrap_or_else(|_| BigInt::from(0));
-            let mut den = BigInt::from_str(&den_str).unwrap_or_else(|_| BigInt::from(1));
-            if den.is_zero() {
-                return Err(ERR_DIV_BY_ZERO);
-            }
-            let g = num.clone().abs().gcd(&den.clone().abs());
-            if !g.is_zero() {
-                num = num / &g;
-                den = den / &g;
-            }
-            let mut d = den.clone().abs();
-            while (&d % BigInt::from(2u32)).is_zero() {
-                d = d / BigInt::from(2u32);
-            }
-            while (&d % BigInt::from(5u32)).is_zero() {
-                d = d / BigInt::from(5u32);
-            }
-
-            let den_abs = den.clone().abs();
-            let num_abs = num.clone().abs();
-            let neg = n1 ^ n2;
-            let int_part = (&num_abs / &den_abs).to_string();
-            let mut rem = num_abs % &den_abs;
-            let mut seen: HashMap<BigInt, usize> = HashMap::new();
-            let mut digits: Vec<char> = Vec::new();
-            let max_digits = 10000usize;
-            while !rem.is_zero() && !seen.contains_key(&rem) && digits.len() < max_digits {
-                seen.insert(rem.clone(), digits.len());
-                rem = rem * BigInt::from(10u32);
-                let q = (&rem / &den_abs).to_i32().unwrap_or(0);
-                digits.push(std::char::from_digit(q as u32, 10).unwrap_or('0'));
-                rem = rem % &den_abs;
-            }
-
-            let mut frac_str = String::new();
-            if digits.is_empty() {
-                let s_out = if neg { format!("-{}.0", int_part) } else { format!("{}.0", int_part) };
-                let bd = BigDecimal::from_str(&s_out).unwrap_or_else(|_| BigDecimal::from(0));
-                return Ok(Float::Big(bd));
-            } else {
-                if let Some(start) = seen.get(&rem) {
-                    let start = *start;
-                    let nonrep: String = digits[..start].iter().collect();
-                    let rep: String = digits[start..].iter().collect();
-                    let min_repeats = 4usize;
-                    let repeat_count = min_repeats;
-                    frac_str.push_str(&nonrep);
-                    for _ in 0..repeat_count {
-                        frac_str.push_str(&rep);
-                    }
-                } else {
-                    for d in digits.iter() { frac_str.push(*d); }
-                }
-            }
-
-            let digits_concat = format!("{}{}", int_part.trim_start_matches('-'), frac_str);
-            match BigInt::from_str(&digits_concat) {
-                Ok(mut bi) => {
-                    if neg {
-                        bi = -bi;
-                    }
-                    let scale = frac_str.len() as i64;
-                    let bd = BigDecimal::new(bi, scale);
-                    return Ok(Float::Recurring(bd));
-                }
-                Err(_) => {
-                    let s_out = if neg { format!("-{}.{}", int_part, frac_str) } else { format!("{}.{}", int_part, frac_str) };
-                    let bd = BigDecimal::from_str(&s_out).unwrap_or_else(|_| BigDecimal::from(0));
-                    return Ok(Float::Recurring(bd));
-                }
-            }
+        if let Some(res) = self.surd_div(other) {
+            return Ok(res);
+        }
+        let working = core::num::NonZeroU64::new(precision() + 10).unwrap();
+        if let Some(res) = self.irrational_op(other, |a, b| div_bigdecimal(&a, &b, working)) {
+            return Ok(res);
+        }
+        if let Some(res) = self.recurring_op(other, RatioOp::Div) {
+            return Ok(res);
         }
 
-        let (mantissa, exponent, negative) = div_float(m1, e1, n1, m2, e2, n2)?;
-        Ok(make_float_from_parts(
-            mantissa,
-            exponent,
-            negative,
-            FloatKind::Finite,
-        ))
+        let (a, b) = self.decimal_operands(other)?;
+        let (a, b) = (normalize_decimal(a), normalize_decimal(b));
+        if b.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        // integers divide exactly, into a terminating or recurring decimal
+        if a.fractional_digit_count() <= 0 && b.fractional_digit_count() <= 0 {
+            let (num, _) = a.with_scale(0).into_bigint_and_exponent();
+            let (den, _) = b.with_scale(0).into_bigint_and_exponent();
+            return Ok(ratio_to_float(&num, &den));
+        }
+        let scale = (a.digits() + b.digits() + 20).max(50) as i64;
+        Ok(Float::Big(normalize_decimal((a / b).with_scale(scale))))
     }
-    pub fn _modulo(&self, other: &Self) -> Result<Self, i8> {
+    fn modulo_raw(&self, other: &Self) -> Result<Self, i8> {
         if self.is_complex() || other.is_complex() {
             return Err(ERR_INVALID_FORMAT);
         }
@@ -964,17 +1697,23 @@ impl Float {
             return Ok(Float::NaN);
         }
 
-        let (m1, e1, n1, _) = float_to_parts(self);
-        let (m2, e2, n2, _) = float_to_parts(other);
-        let (mantissa, exponent, negative) = mod_float(m1, e1, n1, m2, e2, n2)?;
-        Ok(make_float_from_parts(
-            mantissa,
-            exponent,
-            negative,
-            FloatKind::Finite,
-        ))
+        let (a, b) = self.decimal_operands(other)?;
+        // truncated quotient, computed on integers at a common scale so fractional divisors work
+        let scale = a.fractional_digit_count().max(b.fractional_digit_count());
+        let (a_int, _) = a.with_scale(scale).into_bigint_and_exponent();
+        let (b_int, _) = b.with_scale(scale).into_bigint_and_exponent();
+        let remainder = normalize_decimal(BigDecimal::new(a_int % b_int, scale));
+        Ok(if self.is_irrational() || other.is_irrational() {
+            Float::Irrational(remainder)
+        } else {
+            Float::Big(remainder)
+        })
     }
-    pub fn _pow(&self, exponent: &Self) -> Result<Self, i8> {
+    fn pow_raw(&self, exponent: &Self) -> Result<Self, i8> {
+        // integer exponents never need ln/exp or f64
+        if !exponent.is_complex() && exponent.is_integer_like() {
+            return self.pow_int(&exponent.to_int()?);
+        }
         // Complex power: z^w = exp(w * ln(z))
         if self.is_complex() || exponent.is_complex() {
             let ln_z = self.ln()?;
@@ -986,8 +1725,7 @@ impl Float {
             return Err(ERR_INVALID_FORMAT);
         }
         if float_is_zero(exponent) {
-            // x^0 == 1
-            let (_m, _e, _, _) = float_to_parts(self);
+            // x^0 == 1, 0^0 and ∞^0 included
             return Ok(make_float_from_parts(
                 "1".to_string(),
                 0,
@@ -995,67 +1733,51 @@ impl Float {
                 FloatKind::Finite,
             ));
         }
-        if float_kind(self) == FloatKind::Infinity || float_kind(self) == FloatKind::NegInfinity {
-            let neg = self.is_negative() ^ exponent.is_negative();
-            return Ok(if neg {
-                Float::NegInfinity
-            } else {
-                Float::Infinity
-            });
-        }
-
-        if let Float::Recurring(exp_bd) = exponent {
-            let (num, den) = crate::math::bigdecimal_to_fraction(&exp_bd);
-            if den != num_bigint::BigInt::from(1u32) {
-                if let Some(den_u64) = den.to_u64() {
-                    if den_u64 > 0 && den_u64 <= 200 {
-                        if let Some(base_bd) = crate::compat::float_to_bigdecimal(self) {
-                            if let Ok((res_bd, _exact)) = crate::math::pow_bigdecimal_rational(&base_bd, &num, &den, 137) {
-                                return Ok(Float::Big(res_bd));
-                            }
-                        }
-                    }
-                }
+        let zero = Float::Big(cache::ZERO.clone());
+        if exponent.is_infinite_kind() {
+            // as for f64: |x| = 1 stays 1, larger bases run off to ∞ toward +∞ and smaller
+            // ones vanish, and the other way round toward -∞
+            if !self.is_infinite_kind() && float_is_one(&self.abs()) {
+                return Ok(Float::Big(cache::ONE.clone()));
             }
+            let grows = self.is_infinite_kind() || self.abs() > Float::Big(cache::ONE.clone());
+            return Ok(if grows == (float_kind(exponent) == FloatKind::Infinity) { Float::Infinity } else { zero });
+        }
+        if self.is_infinite_kind() {
+            return Ok(if exponent.is_negative() { zero } else { Float::Infinity });
+        }
+        if float_is_zero(self) {
+            return if exponent.is_negative() { Err(ERR_DIV_BY_ZERO) } else { Ok(zero) };
+        }
+        if self.is_negative() {
+            return self.negative_base_pow(exponent);
+        }
 
-            if let Some(exp_f64) = exp_bd.to_f64() {
-                if let Some((p_u64, q_u64)) = approx_rational_from_f64(exp_f64, 200) {
-                    if q_u64 > 0 && q_u64 <= 200 {
-                        if p_u64 == 0 {
-                            return Ok(make_float_from_parts("1".to_string(), 0, false, FloatKind::Finite));
-                        }
-                        if let Some(base_bd) = crate::compat::float_to_bigdecimal(self) {
-                            let mut pow_bd = BigDecimal::from(1u32);
-                            for _ in 0..p_u64 { pow_bd = pow_bd * base_bd.clone(); }
-                            if let Some(root_bd) = bigdecimal_nth_root(pow_bd, q_u64 as u32, 100) {
-                                return Ok(Float::Big(root_bd));
-                            }
-                        }
-                    }
-                }
+        if let Some((num, den)) = crate::recurring::ratio(exponent).filter(|_| exponent.is_recurring()) {
+            if den != num_bigint::BigInt::from(1u32)
+                && let Some(den_u64) = den.to_u64()
+                && den_u64 > 0
+                && den_u64 <= 200
+                && let Some(base_bd) = self.decimal_value().filter(|_| matches!(self, Float::Big(_) | Float::Small(_)))
+                && let Some(res) = exact_rational_pow(&base_bd, &num, den_u64 as u32)
+            {
+                return Ok(res);
             }
-        }
 
-        if exponent.is_integer_like() {
-            if let Some(exp_bd) = crate::compat::float_to_bigdecimal(exponent) {
-                let (mant, exp_i32, neg) = crate::math::from_bigdecimal(&exp_bd);
-                let mut digits = mant;
-                if exp_i32 > 0 {
-                    digits.push_str(&"0".repeat(exp_i32 as usize));
-                }
-                let digits = digits.trim_start_matches('0').to_string();
-                if digits.is_empty() {
+            if let Ok(exp_f64) = exponent.to_f64()
+                && let Some((p_u64, q_u64)) = approx_rational_from_f64(exp_f64, 200)
+                && q_u64 > 0
+                && q_u64 <= 200
+            {
+                if p_u64 == 0 {
                     return Ok(make_float_from_parts("1".to_string(), 0, false, FloatKind::Finite));
                 }
-                match BigInt::from_str(&digits) {
-                    Ok(mut bi) => {
-                        if neg { bi = -bi; }
-                        if let Some(base_bd) = crate::compat::float_to_bigdecimal(self) {
-                            let res_bd = bigdecimal_pow_integer(base_bd.clone(), bi);
-                            return Ok(Float::Big(res_bd));
-                        }
+                if let Some(base_bd) = crate::compat::float_to_bigdecimal(self) {
+                    let mut pow_bd = BigDecimal::from(1u32);
+                    for _ in 0..p_u64 { pow_bd = pow_bd * base_bd.clone(); }
+                    if let Some(root_bd) = bigdecimal_nth_root(pow_bd, q_u64 as u32, 100) {
+                        return Ok(Float::Big(root_bd));
                     }
-                    Err(_) => {}
                 }
             }
         }
@@ -1130,6 +1852,27 @@ impl Float {
         ))
     }
 
+    /// `self^exponent` for a negative real base and a finite, non-integer real exponent.
+    /// An exponent `p/q` in lowest terms with `q` odd has a real root, `|x|^(p/q)` negated
+    /// when `p` is odd. Otherwise the result is the principal value, `sqrt(x)^p` for `q = 2`
+    /// and `exp(exponent · ln x)` in general, unless `complex_powers` is off.
+    fn negative_base_pow(&self, exponent: &Self) -> Result<Self, i8> {
+        let magnitude = self.abs();
+        let ratio = crate::recurring::ratio(exponent).filter(|(_, q)| *q <= BigInt::from(200u32));
+        if let Some((p, _)) = ratio.as_ref().filter(|(_, q)| q.is_odd()) {
+            let root = magnitude.pow_raw(exponent)?;
+            return Ok(if p.is_odd() { -root } else { root });
+        }
+        if !crate::compat::behavior().complex_powers {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        match ratio {
+            // the square root is exact or symbolic where possible, unlike exp(w · ln z)
+            Some((p, q)) if q == BigInt::from(2u32) => self.sqrt()?.pow_int(&Int::from(p)),
+            _ => Float::complex(self.clone(), Float::Big(cache::ZERO.clone())).pow_raw(exponent),
+        }
+    }
+
     pub fn pow(&self, exponent: &Self) -> Result<Self, i8> {
         self._pow(exponent).or_else(|_| {
             if float_kind(self) == FloatKind::NaN || float_kind(exponent) == FloatKind::NaN {
@@ -1143,6 +1886,189 @@ impl Float {
             }
         })
     }
+    /// The finite real value as a decimal, taking the shortest round-tripping digits of a
+    /// small float (so `1.1` is `1.1`, not its binary expansion) and the expansion of a
+    /// surd or recurring value. `None` for other kinds.
+    pub(crate) fn decimal_value(&self) -> Option<BigDecimal> {
+        match self {
+            Float::Small(SmallFloat::F64(v)) if v.is_finite() => BigDecimal::from_str(&v.to_string()).ok(),
+            Float::Small(SmallFloat::F32(v)) if v.is_finite() => BigDecimal::from_str(&v.to_string()).ok(),
+            Float::Big(bd) | Float::Irrational(bd) => Some(bd.clone()),
+            Float::Recurring(num, den) => Some(crate::recurring::expand(num, den)),
+            Float::Surd(c, r) => Some(crate::surd::expand(c, r)),
+            _ => None,
+        }
+    }
+
+    /// The value as `(mantissa, exponent, negative, kind)` with `|self| = mantissa · 10^exponent`,
+    /// the typed form of `float_to_parts`. The mantissa is non-negative without trailing
+    /// zeros, so zero is `(0, 0, false, kind)`. Small floats give their shortest round-tripping
+    /// digits, a surd its expansion, as `Irrational`, and a recurring value its first
+    /// `precision()` digits. NaN, the infinities and complex
+    /// values have a zero mantissa; only the kind and the sign of `-∞` tell them apart.
+    pub fn decompose(&self) -> (Int, i64, bool, FloatKind) {
+        let kind = float_kind(self);
+        let value = match self {
+            Float::Big(bd) | Float::Irrational(bd) => Cow::Borrowed(bd),
+            _ => match self.decimal_value() {
+                Some(bd) => Cow::Owned(bd),
+                None => return (Int::Big(BigInt::zero()), 0, kind == FloatKind::NegInfinity, kind),
+            },
+        };
+        let (digits, scale) = value.normalized().into_bigint_and_exponent();
+        if digits.is_zero() {
+            return (Int::Big(digits), 0, false, kind);
+        }
+        let negative = digits.is_negative();
+        (Int::Big(digits.abs()), -scale, negative, kind)
+    }
+
+    /// The inverse of `decompose`: `±mantissa · 10^exponent` of the decimal kind `kind`.
+    /// A negative `mantissa` gives a negative value as well. The special kinds ignore the
+    /// mantissa, with `negative` turning `Infinity` into `-∞`; `Complex` and `Imaginary`
+    /// have no such form and give NaN.
+    pub fn recompose(mantissa: &Int, exponent: i64, negative: bool, kind: FloatKind) -> Float {
+        let digits = Int::owned_bigint(mantissa);
+        let digits = if negative { -digits.abs() } else { digits };
+        let bd = || BigDecimal::new(digits.clone(), -exponent);
+        match kind {
+            FloatKind::Finite => Float::Big(bd()),
+            FloatKind::Irrational => Float::Irrational(bd()),
+            FloatKind::Recurring => crate::recurring::from_digits(&bd()),
+            FloatKind::Infinity if !negative => Float::Infinity,
+            FloatKind::Infinity | FloatKind::NegInfinity => Float::NegInfinity,
+            FloatKind::NaN | FloatKind::Complex | FloatKind::Imaginary => Float::NaN,
+        }
+    }
+
+    /// `self` raised to an integer power; see `pow_int`.
+    pub fn powi(&self, exponent: i64) -> Result<Self, i8> {
+        self.pow_int(&Int::from(exponent))
+    }
+
+    /// `self` raised to an integer power by repeated squaring, never going through `f64`.
+    ///
+    /// Exact for `Big` and small-float bases while the result stays under
+    /// `EXACT_POW_DIGITS` digits, with negative powers divided out like `1 / x^n`. Past
+    /// that, and for irrational or recurring bases, intermediates are rounded to
+    /// `precision()` significant digits (plus guard digits) and the result is
+    /// `Irrational`. Complex bases are multiplied out part by part.
+    ///
+    /// Fails with `INVALID_FORMAT` for NaN, `DIV_BY_ZERO` for zero to a negative power and
+    /// `NUMBER_TOO_LARGE` when `|exponent|` exceeds `u64` for a base other than 0 or ±1, or
+    /// when the result's decimal exponent would pass `±10^15`. Under
+    /// `ArithmeticPolicy::Ieee` such a result is `±Infinity` or `0` instead.
+    pub fn pow_int(&self, exponent: &Int) -> Result<Self, i8> {
+        let n = exponent.as_bigint();
+        let one = Float::Big(cache::ONE.clone());
+        // a result whose `log10 |self^n|` is past `MAX_POW_EXPONENT`
+        let out_of_range = |log10: f64, negative: bool| {
+            if Self::ieee_policy() {
+                Ok(match (log10 > 0.0, negative) {
+                    (true, false) => Float::Infinity,
+                    (true, true) => Float::NegInfinity,
+                    (false, _) => Float::Big(cache::ZERO.clone()),
+                })
+            } else {
+                Err(ERR_NUMBER_TOO_LARGE)
+            }
+        };
+        match self {
+            Float::NaN => return Err(ERR_INVALID_FORMAT),
+            _ if n.is_zero() => return Ok(one),
+            Float::Infinity | Float::NegInfinity => {
+                return Ok(if n.is_negative() {
+                    Float::Big(cache::ZERO.clone())
+                } else if matches!(self, Float::NegInfinity) && n.is_odd() {
+                    Float::NegInfinity
+                } else {
+                    Float::Infinity
+                });
+            }
+            Float::Complex(re, im) => {
+                let mut e = n.abs().to_u64().ok_or(ERR_NUMBER_TOO_LARGE)?;
+                let parts = [re.decimal_value(), im.decimal_value()];
+                let parts: Vec<&BigDecimal> = parts.iter().flatten().filter(|d| !d.is_zero()).collect();
+                // |z| lies within a factor √2 of its larger part
+                if let Some(largest) = parts.iter().map(|d| log10_abs(d)).reduce(f64::max) {
+                    let log10 = (largest + if largest > 0.0 { 0.16 } else { 0.0 }) * e as f64;
+                    if log10.abs() > MAX_POW_EXPONENT {
+                        return out_of_range(if n.is_negative() { -log10 } else { log10 }, false);
+                    }
+                }
+                let exact = parts.iter().map(|d| d.digits()).max().unwrap_or(1).saturating_mul(e) <= EXACT_POW_DIGITS;
+                let working = core::num::NonZeroU64::new(precision() + 10).unwrap();
+                let round = |z: Float| if exact { z } else { round_parts(z, working) };
+                let mut base = self.clone();
+                let mut result = one.clone();
+                while e > 0 {
+                    if e & 1 == 1 {
+                        result = round(result._mul(&base)?);
+                    }
+                    e >>= 1;
+                    if e > 0 {
+                        base = round(base._mul(&base)?);
+                    }
+                }
+                let result = if n.is_negative() { one._div(&result)? } else { result };
+                return Ok(if exact { result } else { round_parts(result, core::num::NonZeroU64::new(precision()).unwrap()) });
+            }
+            Float::Surd(_, _) => {
+                if let Some(result) = n.abs().to_u64().and_then(|e| self.surd_pow(e)) {
+                    return if n.is_negative() { one._div(&result) } else { Ok(result) };
+                }
+            }
+            Float::Recurring(_, _) => {
+                if let Some(result) = n.abs().to_u64().and_then(|e| self.recurring_pow(e)) {
+                    return if n.is_negative() { one._div(&result) } else { Ok(result) };
+                }
+            }
+            _ => {}
+        }
+
+        let base = self.decimal_value().ok_or(ERR_INVALID_FORMAT)?;
+        if base.is_zero() {
+            return if n.is_negative() { Err(ERR_DIV_BY_ZERO) } else { Ok(Float::Big(base)) };
+        }
+        if base.abs().is_one() {
+            let negative = base.is_negative() && n.is_odd();
+            return Ok(Float::Big(BigDecimal::from(if negative { -1 } else { 1 })));
+        }
+        let mut e = n.abs().to_u64().ok_or(ERR_NUMBER_TOO_LARGE)?;
+        let log10 = log10_abs(&base) * e as f64;
+        if log10.abs() > MAX_POW_EXPONENT {
+            let log10 = if n.is_negative() { -log10 } else { log10 };
+            return out_of_range(log10, base.is_negative() && n.is_odd());
+        }
+        let exact = matches!(self, Float::Big(_) | Float::Small(_))
+            && base.digits().saturating_mul(e) <= EXACT_POW_DIGITS;
+        let working = core::num::NonZeroU64::new(precision() + 10).unwrap();
+        let round = |v: BigDecimal| if exact { v } else { v.with_precision_round(working, bigdecimal::RoundingMode::HalfEven) };
+
+        let mut base = base;
+        let mut result = BigDecimal::from(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = round(result * &base);
+            }
+            e >>= 1;
+            if e > 0 {
+                base = round(&base * &base);
+            }
+        }
+
+        if exact {
+            let result = Float::Big(result);
+            return if n.is_negative() { one._div(&result) } else { Ok(result) };
+        }
+        if n.is_negative() {
+            let ctx = bigdecimal::Context::default().with_precision(working);
+            result = result.inverse_with_context(&ctx);
+        }
+        let digits = core::num::NonZeroU64::new(precision()).unwrap();
+        Ok(Float::Irrational(result.with_precision_round(digits, bigdecimal::RoundingMode::HalfEven)))
+    }
+
     pub fn abs(&self) -> Self {
         // Complex abs: |a + bi| = sqrt(a² + b²)
         if let Float::Complex(real, imag) = self {
@@ -1151,29 +2077,139 @@ impl Float {
             let sum = a_sq._add(&b_sq).unwrap_or_else(|_| Float::NaN);
             return sum.sqrt().unwrap_or(Float::NaN);
         }
-        
-        let (_m, _e, _, k) = float_to_parts(self);
-        make_float_from_parts(_m, _e, false, k)
+        match self {
+            Float::Surd(c, r) => Float::Surd(c.abs(), r.clone()),
+            Float::Big(bd) => Float::Big(normalize_decimal(bd.abs())),
+            Float::Irrational(bd) => Float::Irrational(normalize_decimal(bd.abs())),
+            Float::Recurring(num, den) => Float::Recurring(num.abs(), den.clone()),
+            Float::Small(_) => Float::Big(normalize_decimal(self.decimal_value().unwrap_or_default().abs())),
+            Float::NaN => Float::NaN,
+            _ => Float::Infinity,
+        }
+    }
+
+    /// `self * 10^k`, exactly, by moving the decimal point: only the scale changes, so no
+    /// multiplication happens. A `Small` float becomes `Big` from its exact binary value,
+    /// a recurring value is shifted as a fraction, a surd shifts its coefficient and a
+    /// complex value both parts. NaN and the
+    /// infinities are returned unchanged.
+    pub fn shift_decimal(&self, k: i32) -> Self {
+        let shift = |bd: &BigDecimal| {
+            let (digits, scale) = bd.as_bigint_and_exponent();
+            BigDecimal::new(digits, scale - i64::from(k))
+        };
+        match self {
+            Float::Big(bd) => Float::Big(shift(bd)),
+            Float::Irrational(bd) => Float::Irrational(shift(bd)),
+            Float::Recurring(num, den) => {
+                let pow = num_traits::pow(BigInt::from(10u32), k.unsigned_abs() as usize);
+                if k >= 0 { ratio_to_float(&(num * pow), den) } else { ratio_to_float(num, &(den * pow)) }
+            }
+            Float::Surd(c, r) => Float::Surd(shift(c), r.clone()),
+            Float::Complex(real, imag) => Float::Complex(Box::new(real.shift_decimal(k)), Box::new(imag.shift_decimal(k))),
+            Float::Small(_) => match crate::compat::float_to_bigdecimal(self) {
+                Some(bd) => Float::Big(shift(&bd)),
+                None => self.clone(),
+            },
+            Float::NaN | Float::Infinity | Float::NegInfinity => self.clone(),
+        }
+    }
+
+    /// The smaller of two values. Like `f64::min`, a NaN operand is ignored and only two
+    /// NaNs give NaN; complex values are unordered and give NaN.
+    pub fn min(&self, other: &Float) -> Float {
+        self.pick(other, Ordering::Less)
+    }
+
+    /// The larger of two values, with the same NaN rules as `min`.
+    pub fn max(&self, other: &Float) -> Float {
+        self.pick(other, Ordering::Greater)
+    }
+
+    fn pick(&self, other: &Float, want: Ordering) -> Float {
+        if self.is_complex() || other.is_complex() {
+            return Float::NaN;
+        }
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => Float::NaN,
+            (true, false) => other.clone(),
+            (false, true) => self.clone(),
+            _ => match other.partial_cmp(self) {
+                Some(ord) if ord == want => other.clone(),
+                _ => self.clone(),
+            },
+        }
+    }
+
+    /// Restricts the value to `[lo, hi]`. A NaN value stays NaN. Fails with `INVALID_FORMAT`
+    /// if a bound is NaN or complex, the value is complex, or `lo > hi`.
+    pub fn clamp(&self, lo: &Float, hi: &Float) -> Result<Float, i8> {
+        if lo.is_nan() || hi.is_nan() || lo.is_complex() || hi.is_complex() || self.is_complex() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        if lo > hi {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        if self.is_nan() {
+            return Ok(Float::NaN);
+        }
+        Ok(if self < lo {
+            lo.clone()
+        } else if self > hi {
+            hi.clone()
+        } else {
+            self.clone()
+        })
+    }
+
+    /// The magnitude of `self` with the sign of `sign`; zero counts as positive. NaN if either
+    /// value is NaN or complex.
+    pub fn copysign(&self, sign: &Float) -> Float {
+        if self.is_nan() || sign.is_nan() || self.is_complex() || sign.is_complex() {
+            return Float::NaN;
+        }
+        let abs = match self {
+            Float::NegInfinity => Float::Infinity,
+            _ => self.abs(),
+        };
+        if sign.is_negative() { -abs } else { abs }
+    }
+
+    /// `-1`, `0` or `1` by sign; there is no negative zero, so `0` maps to `0`. Infinities give
+    /// `±1`, NaN gives NaN and a non-zero complex value gives `z / |z|`.
+    pub fn signum(&self) -> Float {
+        match self {
+            Float::NaN => Float::NaN,
+            Float::Complex(_, _) if self.is_zero() => Float::Big(cache::ZERO.clone()),
+            Float::Complex(_, _) => self._div(&self.abs()).unwrap_or(Float::NaN),
+            _ if self.is_zero() => Float::Big(cache::ZERO.clone()),
+            Float::Infinity => Float::Big(cache::ONE.clone()),
+            Float::NegInfinity => Float::Big(BigDecimal::from(-1)),
+            _ if self.is_negative() => Float::Big(BigDecimal::from(-1)),
+            _ => Float::Big(cache::ONE.clone()),
+        }
+    }
+
+    /// Total order for sorting: `NegInfinity` < finite < `Infinity` < complex < NaN.
+    /// Complex values compare by real part, then imaginary part; all NaNs are equal.
+    pub fn total_cmp(&self, other: &Float) -> Ordering {
+        fn rank(f: &Float) -> u8 {
+            match f {
+                Float::NaN => 2,
+                Float::Complex(_, _) => 1,
+                _ => 0,
+            }
+        }
+        match (self, other) {
+            (Float::Complex(a, b), Float::Complex(c, d)) => a.total_cmp(c).then_with(|| b.total_cmp(d)),
+            _ if rank(self) != rank(other) || rank(self) == 2 => rank(self).cmp(&rank(other)),
+            _ => self.partial_cmp(other).unwrap_or(Ordering::Equal),
+        }
     }
 
     pub fn sin(&self) -> Result<Self, i8> {
-        // Complex sin: sin(a + bi) = sin(a)cosh(b) + i*cos(a)sinh(b)
-        if let Float::Complex(real, imag) = self {
-            let sin_a = real.sin()?;
-            let cos_a = real.cos()?;
-            let exp_b = imag.exp()?;
-            let neg_b = Float::Big(BigDecimal::from(0))._sub(imag)?;
-            let exp_neg_b = neg_b.exp()?;
-            
-            // cosh(b) = (e^b + e^(-b))/2
-            let cosh_b = exp_b._add(&exp_neg_b)?._div(&Float::Big(BigDecimal::from(2)))?;
-            // sinh(b) = (e^b - e^(-b))/2
-            let sinh_b = exp_b._sub(&exp_neg_b)?._div(&Float::Big(BigDecimal::from(2)))?;
-            
-            let new_real = sin_a._mul(&cosh_b)?;
-            let new_imag = cos_a._mul(&sinh_b)?;
-            
-            return Ok(Float::Complex(Box::new(new_real), Box::new(new_imag)));
+        if let Float::Complex(_, _) = self {
+            return Ok(self.sin_cos()?.0);
         }
         
         let (m, e, neg, _k) = float_to_parts(self);
@@ -1185,24 +2221,8 @@ impl Float {
         }
     }
     pub fn cos(&self) -> Result<Self, i8> {
-        // Complex cos: cos(a + bi) = cos(a)cosh(b) - i*sin(a)sinh(b)
-        if let Float::Complex(real, imag) = self {
-            let sin_a = real.sin()?;
-            let cos_a = real.cos()?;
-            let exp_b = imag.exp()?;
-            let neg_b = Float::Big(BigDecimal::from(0))._sub(imag)?;
-            let exp_neg_b = neg_b.exp()?;
-            
-            // cosh(b) = (e^b + e^(-b))/2
-            let cosh_b = exp_b._add(&exp_neg_b)?._div(&Float::Big(BigDecimal::from(2)))?;
-            // sinh(b) = (e^b - e^(-b))/2
-            let sinh_b = exp_b._sub(&exp_neg_b)?._div(&Float::Big(BigDecimal::from(2)))?;
-            
-            let new_real = cos_a._mul(&cosh_b)?;
-            let neg_sin_a = Float::Big(BigDecimal::from(0))._sub(&sin_a)?;
-            let new_imag = neg_sin_a._mul(&sinh_b)?;
-            
-            return Ok(Float::Complex(Box::new(new_real), Box::new(new_imag)));
+        if let Float::Complex(_, _) = self {
+            return Ok(self.sin_cos()?.1);
         }
         
         let (m, e, neg, _k) = float_to_parts(self);
@@ -1213,11 +2233,44 @@ impl Float {
             Ok(make_float_from_parts(rm, re, rneg, FloatKind::Finite))
         }
     }
+    /// Returns `(sin(self), cos(self))`, computing both from a single argument reduction.
+    pub fn sin_cos(&self) -> Result<(Self, Self), i8> {
+        if let Float::Complex(real, imag) = self {
+            // sin(a + bi) = sin(a)cosh(b) + i*cos(a)sinh(b)
+            // cos(a + bi) = cos(a)cosh(b) - i*sin(a)sinh(b)
+            let (sin_a, cos_a) = real.sin_cos()?;
+            let exp_b = imag.exp()?;
+            let neg_b = Float::Big(cache::ZERO.clone())._sub(imag)?;
+            let exp_neg_b = neg_b.exp()?;
+            
+            // cosh(b) = (e^b + e^(-b))/2
+            let cosh_b = exp_b._add(&exp_neg_b)?._div(&Float::Big(cache::TWO.clone()))?;
+            // sinh(b) = (e^b - e^(-b))/2
+            let sinh_b = exp_b._sub(&exp_neg_b)?._div(&Float::Big(cache::TWO.clone()))?;
+            
+            let sin_z = Float::Complex(
+                Box::new(sin_a._mul(&cosh_b)?),
+                Box::new(cos_a._mul(&sinh_b)?),
+            );
+            let neg_sin_a = Float::Big(cache::ZERO.clone())._sub(&sin_a)?;
+            let cos_z = Float::Complex(
+                Box::new(cos_a._mul(&cosh_b)?),
+                Box::new(neg_sin_a._mul(&sinh_b)?),
+            );
+            return Ok((sin_z, cos_z));
+        }
+
+        let (m, e, neg, _k) = float_to_parts(self);
+        let ((sm, se, sneg, _), (cm, ce, cneg, _)) = sin_cos_float(m, e, neg)?;
+        Ok((
+            make_float_from_parts(sm, se, sneg, FloatKind::Irrational),
+            make_float_from_parts(cm, ce, cneg, FloatKind::Irrational),
+        ))
+    }
     pub fn tan(&self) -> Result<Self, i8> {
         // Complex tan: tan(z) = sin(z) / cos(z)
         if let Float::Complex(_, _) = self {
-            let sin_z = self.sin()?;
-            let cos_z = self.cos()?;
+            let (sin_z, cos_z) = self.sin_cos()?;
             return sin_z._div(&cos_z);
         }
         
@@ -1231,45 +2284,9 @@ impl Float {
     }
     pub fn ln(&self) -> Result<Self, i8> {
         // Complex ln: ln(a + bi) = ln(|a + bi|) + i*arg(a + bi)
-        // where arg(a + bi) = atan2(b, a)
-        if let Float::Complex(real, imag) = self {
-            let abs_val = self.abs();
-            let ln_abs = abs_val.ln()?;
-            
-            let arg = if float_is_zero(real) {
-                // Pure imaginary
-                if float_is_negative(imag) {
-                    Float::Big(BigDecimal::from_str("-1.5707963267948966").unwrap()) // -π/2
-                } else {
-                    Float::Big(BigDecimal::from_str("1.5707963267948966").unwrap()) // π/2
-                }
-            } else if float_is_zero(imag) {
-                if float_is_negative(real) {
-                    Float::Big(BigDecimal::from_str("3.1415926535897932").unwrap()) // π
-                } else {
-                    Float::Big(BigDecimal::from(0))
-                }
-            } else {
-                // General case: use atan(b/a) then adjust for quadrant
-                let ratio = imag._div(real)?;
-                let atan = if let Ok(f64_val) = ratio.to_f64() {
-                    Float::Big(BigDecimal::from_f64(f64_val.atan()).unwrap())
-                } else {
-                    return Err(ERR_INVALID_FORMAT);
-                };
-                
-                if float_is_negative(real) {
-                    if float_is_negative(imag) {
-                        atan._sub(&Float::Big(BigDecimal::from_str("3.1415926535897932").unwrap()))?
-                    } else {
-                        atan._add(&Float::Big(BigDecimal::from_str("3.1415926535897932").unwrap()))?
-                    }
-                } else {
-                    atan
-                }
-            };
-            
-            return Ok(Float::Complex(Box::new(ln_abs), Box::new(arg)));
+        if let Float::Complex(_, _) = self {
+            let ln_abs = self.abs().ln()?;
+            return Ok(Float::Complex(Box::new(ln_abs), Box::new(self.arg()?)));
         }
         
         let (m, e, neg, _k) = float_to_parts(self);
@@ -1284,8 +2301,7 @@ impl Float {
         // Complex exp: exp(a + bi) = e^a * (cos(b) + i*sin(b))
         if let Float::Complex(real, imag) = self {
             let exp_a = real.exp()?;
-            let cos_b = imag.cos()?;
-            let sin_b = imag.sin()?;
+            let (sin_b, cos_b) = imag.sin_cos()?;
             
             let new_real = exp_a._mul(&cos_b)?;
             let new_imag = exp_a._mul(&sin_b)?;
@@ -1301,6 +2317,130 @@ impl Float {
             Ok(make_float_from_parts(rm, re, rneg, FloatKind::Finite))
         }
     }
+    /// `sqrt(self² + other²)` without overflow or underflow: the squares are summed exactly
+    /// and a single square root is taken to `precision()` significant digits (exact when
+    /// the sum is a perfect square). Infinite if either side is infinite; fails with
+    /// `INVALID_FORMAT` for NaN or complex operands.
+    pub fn hypot(&self, other: &Self) -> Result<Self, i8> {
+        if [self, other].iter().any(|f| matches!(float_kind(f), FloatKind::Infinity | FloatKind::NegInfinity)) {
+            return Ok(Float::Infinity);
+        }
+        let a = self.decimal_value().ok_or(ERR_INVALID_FORMAT)?;
+        let b = other.decimal_value().ok_or(ERR_INVALID_FORMAT)?;
+        let sum = &a * &a + &b * &b;
+        let prec = core::num::NonZeroU64::new(precision()).unwrap();
+        let root = sum
+            .sqrt_with_context(&bigdecimal::Context::default().with_precision(prec))
+            .ok_or(ERR_INVALID_FORMAT)?
+            .normalized();
+        Ok(if &root * &root == sum { Float::Big(root) } else { Float::Irrational(root) })
+    }
+
+    /// `e^self - 1`, accurate for `self` near zero where `exp() - 1` would cancel: for
+    /// `|self| < 1` it is summed from its own series to `precision()` significant digits.
+    pub fn exp_m1(&self) -> Result<Self, i8> {
+        let one = Float::Big(cache::ONE.clone());
+        let unit = BigDecimal::from(1);
+        match self.decimal_value() {
+            Some(x) if x.is_zero() => Ok(Float::Big(x)),
+            Some(x) if x.abs() < unit => {
+                Ok(Float::Irrational(exp_m1_bigdecimal(&x, precision()).normalized()))
+            }
+            Some(_) => self.exp()?._sub(&one),
+            None => match self {
+                Float::Infinity => Ok(Float::Infinity),
+                Float::NegInfinity => Ok(Float::Big(BigDecimal::from(-1))),
+                Float::Complex(_, _) => self.exp()?._sub(&one),
+                _ => Err(ERR_INVALID_FORMAT),
+            },
+        }
+    }
+
+    /// `ln(1 + self)`, accurate for `self` near zero where forming `1 + self` would drop
+    /// digits: for `-0.5 <= self < 1` it is summed from a series to `precision()`
+    /// significant digits. Fails with `INVALID_FORMAT` for `self <= -1` and NaN.
+    pub fn ln_1p(&self) -> Result<Self, i8> {
+        let one = Float::Big(cache::ONE.clone());
+        let (low, high) = (BigDecimal::new(BigInt::from(-5), 1), BigDecimal::from(1));
+        match self.decimal_value() {
+            Some(x) if x.is_zero() => Ok(Float::Big(x)),
+            Some(x) if x >= low && x < high => {
+                Ok(Float::Irrational(ln_1p_bigdecimal(&x, precision()).normalized()))
+            }
+            Some(_) => self._add(&one)?.ln(),
+            None => match self {
+                Float::Infinity => Ok(Float::Infinity),
+                Float::Complex(_, _) => self._add(&one)?.ln(),
+                _ => Err(ERR_INVALID_FORMAT),
+            },
+        }
+    }
+
+    /// Decimal values of `values` for the fused operations, with whether any is irrational.
+    /// `None` when one is NaN, infinite, complex or recurring; those take the ordinary ops.
+    fn fused_operands(values: &[&Float]) -> Option<(Vec<BigDecimal>, bool)> {
+        let mut irrational = false;
+        let mut out = Vec::with_capacity(values.len());
+        for v in values {
+            match v {
+                Float::Big(_) | Float::Small(_) => out.push(v.decimal_value()?),
+                Float::Irrational(bd) => {
+                    irrational = true;
+                    out.push(bd.clone());
+                }
+                _ => return None,
+            }
+        }
+        Some((out, irrational))
+    }
+
+    /// Exact results stay `Big`; anything touched by an irrational operand is rounded once.
+    fn fused_result(value: BigDecimal, irrational: bool) -> Float {
+        if irrational {
+            let digits = core::num::NonZeroU64::new(precision()).unwrap();
+            Float::Irrational(value.with_precision_round(digits, bigdecimal::RoundingMode::HalfEven).normalized())
+        } else {
+            Float::Big(value)
+        }
+    }
+
+    /// `self * a + b` with a single rounding: exact for exact operands, rounded once to
+    /// `precision()` significant digits when one is irrational. NaN, infinite,
+    /// complex and recurring operands go through the ordinary `_mul` and `_add`.
+    pub fn mul_add(&self, a: &Float, b: &Float) -> Result<Self, i8> {
+        match Float::fused_operands(&[self, a, b]) {
+            Some((v, irrational)) => Ok(Float::fused_result(&v[0] * &v[1] + &v[2], irrational)),
+            None => self._mul(a)?._add(b),
+        }
+    }
+
+    /// `Σ xs[i] * ys[i]` with a single rounding, like `mul_add`. Fails with `INVALID_FORMAT`
+    /// when the slices differ in length.
+    pub fn dot(xs: &[Float], ys: &[Float]) -> Result<Float, i8> {
+        if xs.len() != ys.len() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let operands: Vec<&Float> = xs.iter().chain(ys).collect();
+        match Float::fused_operands(&operands) {
+            Some((v, irrational)) => {
+                let (x, y) = v.split_at(xs.len());
+                let total = x.iter().zip(y).fold(BigDecimal::zero(), |acc, (a, b)| acc + a * b);
+                Ok(Float::fused_result(total, irrational))
+            }
+            None => xs.iter().zip(ys).try_fold(Float::Big(BigDecimal::zero()), |acc, (a, b)| acc._add(&a._mul(b)?)),
+        }
+    }
+
+    /// `Σ values` with a single rounding, so the result does not depend on the order of the
+    /// terms (no compensated summation needed). The empty sum is `0`.
+    pub fn sum(values: &[Float]) -> Result<Float, i8> {
+        let operands: Vec<&Float> = values.iter().collect();
+        match Float::fused_operands(&operands) {
+            Some((v, irrational)) => Ok(Float::fused_result(v.into_iter().sum(), irrational)),
+            None => values.iter().try_fold(Float::Big(BigDecimal::zero()), |acc, v| acc._add(v)),
+        }
+    }
+
     pub fn log(&self, base: &Float) -> Result<Self, i8> {
         // Complex log with base: log_base(z) = ln(z) / ln(base)
         if self.is_complex() || base.is_complex() {
@@ -1319,7 +2459,7 @@ impl Float {
         // Complex log base 10: log10(z) = ln(z) / ln(10)
         if let Float::Complex(_, _) = self {
             let ln_z = self.ln()?;
-            let ln_10_complex = Float::Complex(Box::new(Float::from_str(LN_10).unwrap()), Box::new(Float::Big(BigDecimal::from(0))));
+            let ln_10_complex = Float::Complex(Box::new(Float::Big(cache::LN_10.clone())), Box::new(Float::Big(cache::ZERO.clone())));
             return ln_z._div(&ln_10_complex);
         }
         
@@ -1351,17 +2491,6 @@ impl Float {
     }
 
     pub fn from_int(int: &Int) -> Result<Self, i8> {
-        if int_is_nan(int) {
-            return Err(ERR_INVALID_FORMAT);
-        }
-        if int_is_infinite(int) {
-            let (_d, neg, _k) = int_to_parts(int);
-            return Ok(if neg {
-                Float::NegInfinity
-            } else {
-                Float::Infinity
-            });
-        }
         let (mantissa, neg, _k) = int_to_parts(int);
         if mantissa.is_empty() || mantissa == "0" {
             return Ok(make_float_from_parts(
@@ -1376,6 +2505,8 @@ impl Float {
     pub fn is_zero(&self) -> bool {
         float_is_zero(self)
     }
+    /// Rounds to `precision` decimal places, ties away from zero. See `round_with` for
+    /// other rounding modes.
     pub fn round(&self, precision: usize) -> Self {
         // Round each component of complex number separately
         if let Float::Complex(real, imag) = self {
@@ -1391,62 +2522,59 @@ impl Float {
         if self.is_zero() {
             return make_float_from_parts("0".to_string(), 0, false, FloatKind::Finite);
         }
-
-        let (mut mantissa, mut exponent, neg, _k) = {
-            let (m, e, n, k1) = float_to_parts(self);
-            (m, e, n, k1)
-        };
-
-        let old_len = mantissa.len();
-        let mantissa_len = old_len as i32;
-        let point_pos = mantissa_len + exponent;
-
-        let digits_to_keep = if point_pos > 0 {
-            (point_pos as usize) + precision
-        } else {
-            precision
-        };
-
-        if mantissa.len() > digits_to_keep {
-            let round_digit = mantissa
-                .chars()
-                .nth(digits_to_keep)
-                .unwrap_or('0')
-                .to_digit(10)
-                .unwrap_or(0);
-            mantissa.truncate(digits_to_keep);
-            if round_digit >= 5 {
-                let mut digits: Vec<u8> = mantissa.bytes().map(|b| b - b'0').collect();
-                let mut carry = 1;
-                for d in digits.iter_mut().rev() {
-                    let sum = *d + carry;
-                    *d = sum % 10;
-                    carry = sum / 10;
-                    if carry == 0 {
-                        break;
-                    }
-                }
-                if carry > 0 {
-                    digits.insert(0, carry);
-                    exponent += 1;
-                }
-                mantissa = digits.into_iter().map(|d| (d + b'0') as char).collect();
-            }
-            exponent += old_len as i32 - mantissa.len() as i32;
+        if let Float::Big(_) | Float::Irrational(_) | Float::Recurring(_, _) = self {
+            return self.round_to_multiple_of_power_of_ten(-(precision as i64));
         }
 
-        while mantissa.len() > 1 && mantissa.starts_with('0') {
-            mantissa.remove(0);
-            exponent -= 1;
+        let (mantissa, exponent, neg, _k) = float_to_parts(self);
+        crate::compat::round_float_parts(mantissa, exponent, neg, precision)
+    }
+
+    /// Rounds to the nearest multiple of `10^exp` (half away from zero), working directly
+    /// on the `BigDecimal`. `exp = -2` keeps two decimal places, `exp = 3` rounds to thousands.
+    /// Values that already have no digits below `10^exp` are returned unchanged.
+    pub fn round_to_multiple_of_power_of_ten(&self, exp: i64) -> Self {
+        if let Float::Complex(real, imag) = self {
+            return Float::Complex(
+                Box::new(real.round_to_multiple_of_power_of_ten(exp)),
+                Box::new(imag.round_to_multiple_of_power_of_ten(exp)),
+            );
         }
-        if mantissa.is_empty() {
-            mantissa = "0".to_string();
-            exponent = 0;
+        match crate::compat::float_to_bigdecimal(self) {
+            Some(bd) if bd.fractional_digit_count() <= -exp => Float::Big(bd),
+            Some(bd) => Float::Big(bd.with_scale_round(-exp, bigdecimal::RoundingMode::HalfUp)),
+            None => self.clone(),
         }
+    }
 
-        make_float_from_parts(mantissa, exponent, neg, FloatKind::Finite)
+    /// Rounds to `precision` decimal places with the given rounding mode, each part of a
+    /// complex value separately. The result is a `Big` value; NaN and the infinities are
+    /// returned unchanged, as are values with no more than `precision` decimal places.
+    pub fn round_with(&self, precision: usize, mode: RoundingMode) -> Self {
+        if let Float::Complex(real, imag) = self {
+            return Float::Complex(
+                Box::new(real.round_with(precision, mode)),
+                Box::new(imag.round_with(precision, mode)),
+            );
+        }
+        let mode = match mode {
+            RoundingMode::HalfUp => bigdecimal::RoundingMode::HalfUp,
+            RoundingMode::HalfEven => bigdecimal::RoundingMode::HalfEven,
+            RoundingMode::HalfDown => bigdecimal::RoundingMode::HalfDown,
+            RoundingMode::Floor => bigdecimal::RoundingMode::Floor,
+            RoundingMode::Ceiling => bigdecimal::RoundingMode::Ceiling,
+            RoundingMode::TowardZero => bigdecimal::RoundingMode::Down,
+            RoundingMode::AwayFromZero => bigdecimal::RoundingMode::Up,
+        };
+        match crate::compat::float_to_bigdecimal(self) {
+            Some(bd) if bd.fractional_digit_count() <= precision as i64 => Float::Big(bd),
+            Some(bd) => Float::Big(bd.with_scale_round(precision as i64, mode)),
+            None => self.clone(),
+        }
     }
 
+    /// Drops everything past `decimal_places` decimal places; the same as
+    /// `round_with(decimal_places, RoundingMode::TowardZero)`.
     pub fn truncate(&self, decimal_places: usize) -> Self {
         let k = float_kind(self);
         if k == FloatKind::NaN || k == FloatKind::Infinity || k == FloatKind::NegInfinity {
@@ -1482,20 +2610,189 @@ impl Float {
 
         make_float_from_parts(mantissa, exponent, neg, FloatKind::Finite)
     }
+    /// Creates a Float from a decimal literal carrying exactly `significant_digits`
+    /// significant digits, padding with trailing zeros or rounding as needed.
+    ///
+    /// `Float::with_precision("1.5", 4)` stores `1.500`, which compares equal to `1.5`
+    /// but reports `declared_precision() == Some(4)`.
+    pub fn with_precision(value: &str, significant_digits: u64) -> Result<Self, i8> {
+        if significant_digits == 0 {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        match Float::from_str(value)? {
+            f @ Float::Big(_) => Ok(f.round_sig(significant_digits)),
+            _ => Err(ERR_INVALID_FORMAT),
+        }
+    }
+
+    /// Number of significant digits the value was declared with, trailing zeros included
+    /// (`"1.500"` -> 4, `"1.5"` -> 2). Returns `None` for kinds that carry no declared
+    /// precision (small floats, recurring values, NaN, infinities, complex numbers).
+    pub fn declared_precision(&self) -> Option<u64> {
+        match self {
+            Float::Big(bd) | Float::Irrational(bd) => Some(bd.digits()),
+            _ => None,
+        }
+    }
+
+    /// Rounds to `significant_digits` significant digits (round half up) and records
+    /// that as the declared precision.
+    pub fn round_sig(&self, significant_digits: u64) -> Self {
+        if significant_digits == 0 {
+            return self.clone();
+        }
+        if let Float::Complex(real, imag) = self {
+            return Float::Complex(
+                Box::new(real.round_sig(significant_digits)),
+                Box::new(imag.round_sig(significant_digits)),
+            );
+        }
+        match crate::compat::float_to_bigdecimal(self) {
+            Some(bd) => Float::Big(bd.with_precision_round(
+                core::num::NonZeroU64::new(significant_digits).unwrap(),
+                bigdecimal::RoundingMode::HalfUp,
+            )),
+            None => self.clone(),
+        }
+    }
+
+    #[deprecated(note = "use from_f64_shortest, or from_f64_exact for the exact binary value")]
     pub fn from_f64(value: f64) -> Self {
+        Float::from_f64_shortest(value)
+    }
+
+    /// The shortest decimal that reads back as `value`, as `f64::to_string` prints it:
+    /// `0.1` gives `0.1`, not the binary value actually stored. Lossy for diagnosing float
+    /// error; see `from_f64_exact`.
+    pub fn from_f64_shortest(value: f64) -> Self {
         create_float(&value.to_string())
     }
+
+    /// The exact value of `value` as a `Big` float, every digit of its binary expansion,
+    /// like Python's `Decimal(0.1)`: `0.1` gives
+    /// `0.1000000000000000055511151231257827021181583404541015625`. NaN and the infinities
+    /// map to `Float::NaN`, `Float::Infinity` and `Float::NegInfinity`; `-0.0` gives 0.
+    pub fn from_f64_exact(value: f64) -> Self {
+        if value.is_nan() {
+            return Float::NaN;
+        }
+        if value.is_infinite() {
+            return if value > 0.0 { Float::Infinity } else { Float::NegInfinity };
+        }
+        let bits = value.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mut m, mut e) = if biased == 0 { (fraction, -1074) } else { (fraction | (1 << 52), biased - 1075) };
+        if m == 0 {
+            return Float::Big(BigDecimal::zero());
+        }
+        let zeros = m.trailing_zeros();
+        m >>= zeros;
+        e += i64::from(zeros);
+        // m / 2^k = m * 5^k / 10^k; with `m` odd no trailing zeros are left over.
+        let mut digits = BigInt::from(m);
+        let scale = if e >= 0 {
+            digits <<= e as usize;
+            0
+        } else {
+            digits *= BigInt::from(5u8).pow((-e) as u32);
+            -e
+        };
+        if value < 0.0 {
+            digits = -digits;
+        }
+        Float::Big(BigDecimal::new(digits, scale))
+    }
+
+    /// `from_f64_exact` for an `f32`, which widens to `f64` without loss.
+    pub fn from_f32_exact(value: f32) -> Self {
+        Float::from_f64_exact(f64::from(value))
+    }
+
+    /// The IEEE 754 bit pattern of the value rounded to the nearest `f64` (exact for
+    /// `Small` floats), so magnitudes beyond the `f64` range give an infinity. NaN gives
+    /// the canonical quiet NaN; complex values fail with `INVALID_FORMAT`.
+    pub fn to_bits(&self) -> Result<u64, i8> {
+        match self {
+            Float::NaN => Ok(f64::NAN.to_bits()),
+            Float::Small(SmallFloat::F64(v)) => Ok(v.to_bits()),
+            Float::Small(SmallFloat::F32(v)) => Ok((*v as f64).to_bits()),
+            _ => self.to_f64().map(f64::to_bits),
+        }
+    }
+
+    /// The `f64` with IEEE 754 bit pattern `bits` as a `Small` float; NaN and the
+    /// infinities map to `Float::NaN`, `Float::Infinity` and `Float::NegInfinity`, and
+    /// negative zero to `+0.0`.
+    pub fn from_bits(bits: u64) -> Self {
+        let v = f64::from_bits(bits);
+        if v.is_nan() {
+            Float::NaN
+        } else if v.is_infinite() {
+            if v > 0.0 { Float::Infinity } else { Float::NegInfinity }
+        } else {
+            Float::Small(SmallFloat::F64(v + 0.0))
+        }
+    }
+    /// `try_create_float`, rejecting NaN and the infinities (and empty input) with
+    /// `INVALID_FORMAT`.
     pub fn from_str(value: &str) -> Result<Self, i8> {
-        if value.is_empty() {
+        if value.trim().is_empty() {
             return Err(ERR_INVALID_FORMAT);
         }
-        let float = create_float(value);
+        let float = crate::functions::try_create_float(value)?;
         let k = float_kind(&float);
         if k == FloatKind::NaN || k == FloatKind::Infinity || k == FloatKind::NegInfinity {
             return Err(ERR_INVALID_FORMAT);
         }
         Ok(float)
     }
+    /// Returns `true` if converting to `f64` and back gives exactly this value.
+    /// NaN and the infinities count as exact; irrational, recurring and complex values never do.
+    pub fn is_exact_f64(&self) -> bool {
+        match self {
+            Float::NaN | Float::Infinity | Float::NegInfinity | Float::Small(_) => true,
+            Float::Big(bd) => bd
+                .to_f64()
+                .filter(|v| v.is_finite())
+                .and_then(BigDecimal::from_f64)
+                .is_some_and(|back| back == *bd),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if converting to `f32` and back gives exactly this value.
+    /// Same rules as `is_exact_f64`.
+    pub fn is_exact_f32(&self) -> bool {
+        match self {
+            Float::NaN | Float::Infinity | Float::NegInfinity => true,
+            Float::Small(SmallFloat::F32(_)) => true,
+            Float::Small(SmallFloat::F64(v)) => !v.is_finite() || (*v as f32) as f64 == *v,
+            Float::Big(bd) => bd
+                .to_f32()
+                .filter(|v| v.is_finite())
+                .and_then(BigDecimal::from_f32)
+                .is_some_and(|back| back == *bd),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the value lands in the subnormal range when converted to `f64`
+    /// (non-zero, but smaller in magnitude than `f64::MIN_POSITIVE`).
+    pub fn is_subnormal_f64(&self) -> bool {
+        match self {
+            Float::Small(SmallFloat::F64(v)) => v.is_subnormal(),
+            Float::Small(SmallFloat::F32(v)) => (*v as f64).is_subnormal(),
+            Float::Big(bd) | Float::Irrational(bd) => {
+                !bd.is_zero() && bd.abs() < BigDecimal::from_f64(f64::MIN_POSITIVE).unwrap()
+            }
+            Float::Recurring(num, den) => {
+                crate::recurring::expand(num, den).abs() < BigDecimal::from_f64(f64::MIN_POSITIVE).unwrap()
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_integer_like(&self) -> bool {
         let k = float_kind(self);
         if k == FloatKind::NaN || k == FloatKind::Infinity || k == FloatKind::NegInfinity {
@@ -1558,6 +2855,9 @@ impl Float {
         float_to_parts(self).3 == FloatKind::Infinity
     }
     pub fn to_str(&self) -> String {
+        if self.is_surd() {
+            return self.to_string();
+        }
         let k = float_kind(self);
         if k == FloatKind::NaN {
             return "NaN".to_string();
@@ -1570,7 +2870,7 @@ impl Float {
         }
 
         if k == FloatKind::Recurring {
-            if let Float::Recurring(ref bd) = *self {
+            if let Some(ref bd) = self.decimal_value() {
                 let n = bd.normalized();
                 let int_candidate = n.with_scale(0);
                 if n == int_candidate {
@@ -1611,6 +2911,36 @@ impl Float {
         }
         format!("{}", self)
     }
+
+    /// Text form that `create_float` parses back to the same kind and digits: decimals keep
+    /// their scale (`1.50`), recurring values use the repetend notation (`0.(3)`), irrational
+    /// values list every stored digit followed by `...` and the stored scale in braces
+    /// (`1.4142...{52}`), and the special values are `NaN`, `Infinity` and `-Infinity`.
+    /// `Float::from_str` parses it too but rejects the special values.
+    ///
+    /// Not every value survives: `Small` floats come back as `Float::Big`, a recurring value
+    /// whose repetend passes 10 000 digits is written as its first `precision()` digits and
+    /// comes back irrational, and a complex value with a NaN or infinite part does not parse.
+    /// `to_bytes` is lossless for every kind.
+    pub fn to_canonical_string(&self) -> String {
+        match self {
+            Float::Big(bd) => bd.to_string(),
+            Float::Irrational(bd) => format!("{}...{{{}}}", bd.normalized(), bd.fractional_digit_count()),
+            Float::Complex(real, imag) => {
+                let im = imag.to_canonical_string();
+                if im.starts_with('-') {
+                    format!("{}{}i", real.to_canonical_string(), im)
+                } else {
+                    format!("{}+{}i", real.to_canonical_string(), im)
+                }
+            }
+            Float::Recurring(num, den) if self.recurring_parts().is_none() => {
+                Float::Irrational(crate::recurring::expand(num, den)).to_canonical_string()
+            }
+            Float::Recurring(_, _) => format!("{}", self),
+            _ => self.to_str(),
+        }
+    }
     pub fn make_irrational(&mut self) -> Self {
         let k = float_kind(self);
         if k == FloatKind::NaN || k == FloatKind::Infinity || k == FloatKind::NegInfinity {
@@ -1645,6 +2975,124 @@ impl Float {
         *self = newf;
         self
     }
+
+    /// Stable 128-bit hash of the value, for interning literals across runs and crate versions.
+    ///
+    /// The representation is hashed, not just the value: `1.5` and `1.50` (different declared
+    /// precision) or a finite and an irrational value with the same digits get different
+    /// fingerprints. NaN has a fingerprint like any other value.
+    pub fn fingerprint(&self) -> u128 {
+        fnv1a_128(&self.canonical_bytes())
+    }
+
+    fn canonical_bytes(&self) -> Vec<u8> {
+        fn decimal(tag: u8, bd: &BigDecimal) -> Vec<u8> {
+            let (digits, scale) = bd.as_bigint_and_scale();
+            let digits = digits.to_signed_bytes_le();
+            let mut bytes = vec![tag];
+            bytes.extend(scale.to_le_bytes());
+            bytes.extend((digits.len() as u64).to_le_bytes());
+            bytes.extend(digits);
+            bytes
+        }
+        match self {
+            Float::Big(bd) => decimal(b'f', bd),
+            Float::Irrational(bd) => decimal(b'r', bd),
+            // the digits recurring values were stored as, so their fingerprints stay put
+            Float::Recurring(num, den) => match crate::recurring::stored_digits(num, den) {
+                Some(bd) => decimal(b'p', &bd),
+                None => [&[b'/'][..], &num.to_signed_bytes_le(), &[b'/'][..], &den.to_signed_bytes_le()].concat(),
+            },
+            Float::Surd(c, r) => {
+                let mut bytes = decimal(b'q', c);
+                bytes.extend(r.to_signed_bytes_le());
+                bytes
+            }
+            Float::Small(SmallFloat::F32(v)) => [&[b's'][..], &v.to_bits().to_le_bytes()].concat(),
+            Float::Small(SmallFloat::F64(v)) => [&[b'd'][..], &v.to_bits().to_le_bytes()].concat(),
+            Float::NaN => vec![b'n'],
+            Float::Infinity => vec![b'+'],
+            Float::NegInfinity => vec![b'-'],
+            Float::Complex(real, imag) => {
+                let real = real.canonical_bytes();
+                let mut bytes = vec![b'c'];
+                bytes.extend((real.len() as u64).to_le_bytes());
+                bytes.extend(real);
+                bytes.extend(imag.canonical_bytes());
+                bytes
+            }
+        }
+    }
+
+    /// Describes the value (kind, sign, digit count, scale, exactness, memory usage)
+    /// without exposing the variant.
+    pub fn describe(&self) -> NumberInfo {
+        if let Float::Complex(real, imag) = self {
+            let r = real.describe();
+            let i = imag.describe();
+            return NumberInfo {
+                is_int: false,
+                kind: FloatKind::Complex,
+                negative: false,
+                digits: 0,
+                scale: r.scale.max(i.scale),
+                exact: r.exact && i.exact,
+                memory: core::mem::size_of::<Float>() + r.memory + i.memory,
+            };
+        }
+
+        let heap = match self {
+            Float::Big(bd) | Float::Irrational(bd) => {
+                bigint_heap_bytes(&bd.as_bigint_and_scale().0)
+            }
+            Float::Recurring(num, den) => bigint_heap_bytes(num) + bigint_heap_bytes(den),
+            _ => 0,
+        };
+        let memory = core::mem::size_of::<Float>() + heap;
+
+        let (mant, exp, neg, kind) = float_to_parts(self);
+        match kind {
+            FloatKind::NaN | FloatKind::Infinity | FloatKind::NegInfinity => NumberInfo {
+                is_int: false,
+                kind,
+                negative: kind == FloatKind::NegInfinity,
+                digits: 0,
+                scale: 0,
+                exact: kind != FloatKind::NaN,
+                memory,
+            },
+            _ => {
+                let mant = mant.trim_start_matches('0');
+                NumberInfo {
+                    is_int: false,
+                    kind,
+                    negative: neg && !mant.is_empty(),
+                    digits: mant.len().max(1),
+                    scale: if exp < 0 { -(exp as i64) } else { 0 },
+                    exact: kind != FloatKind::Irrational || self.is_surd(),
+                    memory,
+                }
+            }
+        }
+    }
+}
+
+/// Product `start (start+1) ... (start+count-1)`, split recursively so the
+/// multiplications stay balanced for large factorials.
+fn product_range(start: &BigInt, count: u64) -> BigInt {
+    match count {
+        0 => BigInt::from(1u32),
+        1 => start.clone(),
+        2 => start * (start + 1u32),
+        _ => {
+            let half = count / 2;
+            product_range(start, half) * product_range(&(start + half), count - half)
+        }
+    }
+}
+
+fn bigint_heap_bytes(bi: &BigInt) -> usize {
+    (bi.bits() as usize).div_ceil(64) * 8
 }
 
 fn normalize_int_digits(digits: &str) -> String {
@@ -1664,46 +3112,33 @@ macro_rules! impl_from_for_int {
         $(
             impl From<$t> for Int {
                 fn from(value: $t) -> Self {
-                    create_int(&value.to_string())
+                    Int::Big(BigInt::from(value))
                 }
             }
 
             paste! {
                 impl Int {
                     pub fn [<to_ $t>](&self) -> Result<$t, i8> {
-                        if int_is_nan(self) {
-                            return Err(ERR_INVALID_FORMAT);
-                        }
-                        if int_is_infinite(self) {
-                            return Err(ERR_INFINITE_RESULT);
-                        }
 
-                        match self {
-                            Int::Big(bi) => {
-                                let val = bi.[<to_ $t>]().ok_or(ERR_INVALID_FORMAT)?;
-                                Ok(val)
-                            }
-                            Int::Small(si) => {
-                                let val: i128 = match si {
-                                    SmallInt::I64(v) => *v as i128,
-                                    SmallInt::U64(v) => *v as i128,
-                                    SmallInt::I32(v) => *v as i128,
-                                    SmallInt::U32(v) => *v as i128,
-                                    SmallInt::I16(v) => *v as i128,
-                                    SmallInt::U16(v) => *v as i128,
-                                    SmallInt::I8(v) => *v as i128,
-                                    SmallInt::U8(v) => *v as i128,
-                                    SmallInt::I128(v) => *v,
-                                    SmallInt::U128(v) => *v as i128,
-                                    SmallInt::ISize(v) => *v as i128,
-                                    SmallInt::USize(v) => *v as i128,
-                                };
-                                if val < 0 && is_unsigned!($t) {
-                                    return Err(ERR_NEGATIVE_RESULT);
-                                }
-                                val.try_into().map_err(|_| ERR_INVALID_FORMAT)
-                            }
+                        // via BigInt: casting a small u128 through i128 would wrap
+                        let bi = Int::owned_bigint(self);
+                        if bi.is_negative() && is_unsigned!($t) {
+                            return Err(ERR_NEGATIVE_RESULT);
                         }
+                        bi.[<to_ $t>]().ok_or(ERR_INVALID_FORMAT)
+                    }
+                }
+
+                impl From<$t> for Float {
+                    fn from(value: $t) -> Self {
+                        Float::Big(BigDecimal::from(BigInt::from(value)))
+                    }
+                }
+
+                impl Float {
+                    /// Fails with `INVALID_FORMAT` unless the value is an integer in range.
+                    pub fn [<to_ $t>](&self) -> Result<$t, i8> {
+                        self.to_int()?.[<to_ $t>]()
                     }
                 }
             }
@@ -1729,35 +3164,19 @@ macro_rules! impl_from_for_float {
                             Float::NegInfinity => Ok($t::NEG_INFINITY),
                             Float::Complex(_, _) => Err(ERR_INVALID_FORMAT),
 
-                            Float::Big(bd) | Float::Recurring(bd) | Float::Irrational(bd) => {
-                                let val = bd.[<to_ $t>]().ok_or(ERR_INVALID_FORMAT)?;
-                                if val < 0.0 && is_unsigned!($t) {
-                                    return Err(ERR_NEGATIVE_RESULT);
-                                }
-                                Ok(val)
-                            }
+                            Float::Surd(_, _) => self.expand().[<to_ $t>](),
+
+                            // Correctly rounded, ties to even, from the exact stored value;
+                            // out of range gives an infinity or a (signed) zero.
+                            Float::Big(bd) | Float::Irrational(bd) => Ok(crate::math::[<bigdecimal_to_ $t>](bd)),
+                            Float::Recurring(num, den) => Ok(crate::math::[<ratio_to_ $t>](num, den)),
 
+                            // `as` between float types rounds to nearest, ties to even.
                             Float::Small(sf) => match sf {
-                                SmallFloat::F64(v) => {
-                                    if *v < 0.0 && is_unsigned!($t) {
-                                        return Err(ERR_NEGATIVE_RESULT);
-                                    }
-                                    if <$t>::MIN as f64 <= *v && *v <= <$t>::MAX as f64 {
-                                        Ok(*v as $t)
-                                    } else {
-                                        Err(ERR_INVALID_FORMAT)
-                                    }
-                                }
-                                SmallFloat::F32(v) => {
-                                    if *v < 0.0 && is_unsigned!($t) {
-                                        return Err(ERR_NEGATIVE_RESULT);
-                                    }
-                                    if <$t>::MIN as f32 <= *v && *v <= <$t>::MAX as f32 {
-                                        Ok(*v as $t)
-                                    } else {
-                                        Err(ERR_INVALID_FORMAT)
-                                    }
-                                }
+                                SmallFloat::F64(v) if v.is_nan() => Err(ERR_INVALID_FORMAT),
+                                SmallFloat::F32(v) if v.is_nan() => Err(ERR_INVALID_FORMAT),
+                                SmallFloat::F64(v) => Ok(*v as $t),
+                                SmallFloat::F32(v) => Ok(*v as $t),
                             },
                         }
                     }
@@ -1780,6 +3199,121 @@ macro_rules! is_unsigned {
 impl_from_for_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
 impl_from_for_float!(f32, f64);
 
+macro_rules! impl_int_to_primitive_float {
+    ($($t:ty),+) => {
+        $(
+            paste! {
+                impl Int {
+                    /// Nearest float; fails with `INVALID_FORMAT` if the magnitude overflows it.
+                    pub fn [<to_ $t>](&self) -> Result<$t, i8> {
+                        Int::owned_bigint(self).[<to_ $t>]().filter(|v| v.is_finite()).ok_or(ERR_INVALID_FORMAT)
+                    }
+                }
+
+                /// Exact when the float holds an integer; fails with `INVALID_FORMAT` for a
+                /// fractional or NaN value and `INFINITE_RESULT` for an infinity.
+                impl TryFrom<$t> for Int {
+                    type Error = i8;
+                    fn try_from(value: $t) -> Result<Int, i8> {
+                        if value.is_nan() {
+                            return Err(ERR_INVALID_FORMAT);
+                        }
+                        if value.is_infinite() {
+                            return Err(ERR_INFINITE_RESULT);
+                        }
+                        Float::from(value).to_int()
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_int_to_primitive_float!(f32, f64);
+
+/// `TryFrom<Int>`, `TryFrom<Float>` and their by-reference forms for a primitive, all
+/// going through the range-checked `to_*` methods.
+macro_rules! impl_try_into_primitive {
+    ($($t:ty),+) => {
+        $(
+            paste! {
+                impl TryFrom<Int> for $t {
+                    type Error = i8;
+                    fn try_from(value: Int) -> Result<$t, i8> {
+                        value.[<to_ $t>]()
+                    }
+                }
+
+                impl TryFrom<&Int> for $t {
+                    type Error = i8;
+                    fn try_from(value: &Int) -> Result<$t, i8> {
+                        value.[<to_ $t>]()
+                    }
+                }
+
+                impl TryFrom<Float> for $t {
+                    type Error = i8;
+                    fn try_from(value: Float) -> Result<$t, i8> {
+                        value.[<to_ $t>]()
+                    }
+                }
+
+                impl TryFrom<&Float> for $t {
+                    type Error = i8;
+                    fn try_from(value: &Float) -> Result<$t, i8> {
+                        value.[<to_ $t>]()
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_try_into_primitive!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64);
+
+impl From<BigInt> for Int {
+    fn from(value: BigInt) -> Self {
+        Int::Big(value)
+    }
+}
+
+impl From<Int> for BigInt {
+    fn from(value: Int) -> Self {
+        match value {
+            Int::Big(bi) => bi,
+            Int::Small(si) => Int::smallint_to_bigint(&si),
+        }
+    }
+}
+
+impl From<&Int> for BigInt {
+    fn from(value: &Int) -> Self {
+        value.as_bigint().into_owned()
+    }
+}
+
+impl From<BigDecimal> for Float {
+    fn from(value: BigDecimal) -> Self {
+        Float::Big(value)
+    }
+}
+
+/// The exact decimal of any finite real value (small floats expand their binary value); a
+/// recurring value gives its first `precision()` digits. Fails with `INVALID_FORMAT` for
+/// NaN, infinities and complex values.
+impl TryFrom<Float> for BigDecimal {
+    type Error = i8;
+    fn try_from(value: Float) -> Result<Self, i8> {
+        match value {
+            Float::Big(bd) | Float::Irrational(bd) => Ok(bd),
+            Float::Recurring(num, den) => Ok(crate::recurring::expand(&num, &den)),
+            Float::Small(SmallFloat::F64(v)) => BigDecimal::from_f64(v).ok_or(ERR_INVALID_FORMAT),
+            Float::Small(SmallFloat::F32(v)) => BigDecimal::from_f32(v).ok_or(ERR_INVALID_FORMAT),
+            _ => Err(ERR_INVALID_FORMAT),
+        }
+    }
+}
+
 impl From<Int> for Float {
     fn from(value: Int) -> Self {
         Float::from_int(&value).unwrap_or(Float::NaN)
@@ -1793,65 +3327,40 @@ impl From<Float> for Int {
 }
 
 
+/// `{:b}` at any size, with the formatter's sign, `#` prefix, width and fill (via `BigInt`).
 impl Binary for Int {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (digits, negative, _k) = int_to_parts(self);
-        let prefix = if negative { "-" } else { "" };
-        if let Ok(num) = digits.parse::<i128>() {
-            write!(f, "{}{:b}", prefix, num)
-        } else {
-            Err(std::fmt::Error)
-        }
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Binary::fmt(self.as_bigint().as_ref(), f)
     }
 }
+/// `{:o}`; see `Binary`.
 impl Octal for Int {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (digits, negative, _k) = int_to_parts(self);
-        let prefix = if negative { "-" } else { "" };
-        if let Ok(num) = digits.parse::<i128>() {
-            write!(f, "{}{:o}", prefix, num)
-        } else {
-            Err(std::fmt::Error)
-        }
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Octal::fmt(self.as_bigint().as_ref(), f)
     }
 }
+/// `{:x}`; see `Binary`.
 impl LowerHex for Int {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (digits, negative, _k) = int_to_parts(self);
-        let prefix = if negative { "-" } else { "" };
-        if let Ok(num) = digits.parse::<i128>() {
-            write!(f, "{}{:x}", prefix, num)
-        } else {
-            Err(std::fmt::Error)
-        }
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        LowerHex::fmt(self.as_bigint().as_ref(), f)
+    }
+}
+/// `{:X}`; see `Binary`.
+impl UpperHex for Int {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        UpperHex::fmt(self.as_bigint().as_ref(), f)
     }
 }
+/// Hashes the value, so `Small` and `Big` forms of the same number hash alike (matching `Eq`).
 impl Hash for Int {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let (digits, negative, _k) = int_to_parts(self);
-        digits.hash(state);
-        negative.hash(state);
+        Int::owned_bigint(self).hash(state);
     }
 }
 
-impl Binary for Float {
-    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Err(std::fmt::Error)
-    }
-}
-impl Octal for Float {
-    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Err(std::fmt::Error)
-    }
-}
-impl LowerHex for Float {
-    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Err(std::fmt::Error)
-    }
-}
 impl Hash for Float {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let (mant, exp, neg, k) = float_to_parts(self);
+        let (mant, exp, neg, k) = self.decompose();
         mant.hash(state);
         exp.hash(state);
         neg.hash(state);
@@ -1887,6 +3396,9 @@ impl PartialEq for Float {
             _ => {}
         }
 
+        if let Some(ord) = crate::recurring::cmp_exact(self, other) {
+            return ord == Ordering::Equal;
+        }
         if let (Some(a), Some(b)) = (float_to_bigdecimal(self), float_to_bigdecimal(other)) {
             return a.normalized() == b.normalized();
         }
@@ -1895,26 +3407,22 @@ impl PartialEq for Float {
     }
 }
 
-impl PartialEq<Int> for Float {
-    fn eq(&self, other: &Int) -> bool {
-        use crate::compat::{float_to_bigdecimal, int_to_parts};
-        use std::str::FromStr;
+/// Orders a real `Float` against an `Int`; `None` for NaN and complex values.
+fn cmp_float_int(f: &Float, i: &Int) -> Option<Ordering> {
+    use crate::compat::{float_to_bigdecimal, int_to_bigint};
 
-        if let Float::NaN = self {
-            return false;
-        }
+    match f {
+        Float::NaN | Float::Complex(_, _) => None,
+        Float::Infinity => Some(Ordering::Greater),
+        Float::NegInfinity => Some(Ordering::Less),
+        Float::Recurring(_, _) => crate::recurring::cmp_exact(f, &Float::Big(BigDecimal::from(int_to_bigint(i)))),
+        _ => Some(float_to_bigdecimal(f)?.cmp(&BigDecimal::from(int_to_bigint(i)))),
+    }
+}
 
-        if let Some(a) = float_to_bigdecimal(self) {
-            let (digits, negative, _k) = int_to_parts(other);
-            if let Ok(mut bi) = BigInt::from_str(&digits) {
-                if negative {
-                    bi = -bi;
-                }
-                let bdec = BigDecimal::new(bi, 0);
-                return a.normalized() == bdec.normalized();
-            }
-        }
-        false
+impl PartialEq<Int> for Float {
+    fn eq(&self, other: &Int) -> bool {
+        cmp_float_int(self, other) == Some(Ordering::Equal)
     }
 }
 
@@ -1924,6 +3432,18 @@ impl PartialEq<Float> for Int {
     }
 }
 
+impl PartialOrd<Int> for Float {
+    fn partial_cmp(&self, other: &Int) -> Option<Ordering> {
+        cmp_float_int(self, other)
+    }
+}
+
+impl PartialOrd<Float> for Int {
+    fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
+        cmp_float_int(other, self).map(Ordering::reverse)
+    }
+}
+
 pub trait IntoSmallInt {
     fn into_small_int(self) -> Int;
 }
@@ -1963,8 +3483,9 @@ macro_rules! impl_small_float {
     ($($t:ty => $variant:ident),*) => {
         $(
             impl IntoSmallFloat for $t {
+                /// Negative zero becomes `+0.0`: `Float` zeros carry no sign.
                 fn into_small_float(self) -> Float {
-                    Float::Small(SmallFloat::$variant(self))
+                    Float::Small(SmallFloat::$variant(self + 0.0))
                 }
             }
         )*
@@ -1998,6 +3519,34 @@ fn approx_rational_from_f64(x: f64, max_den: u64) -> Option<(u64, u64)> {
     Some((num1 as u64, den1 as u64))
 }
 
+/// `base^(num/den)` for a non-negative `base` when the result is exact, as for
+/// `64^(2/3) = 16` or `4^(-1/2) = 0.5`; `None` when the root is irrational or the power
+/// would pass `EXACT_POW_DIGITS` digits.
+fn exact_rational_pow(base: &BigDecimal, num: &BigInt, den: u32) -> Option<Float> {
+    let p = num.abs().to_u32()?;
+    if den == 0 || base.is_negative() || base.digits().saturating_mul(p as u64) > EXACT_POW_DIGITS {
+        return None;
+    }
+    let (mantissa, scale) = base.normalized().as_bigint_and_exponent();
+    // mantissa^p · 10^-(scale·p), padded so the power of ten divides evenly by den
+    let shift = scale.checked_mul(p as i64)?;
+    let pad = (-shift).rem_euclid(den as i64);
+    let power = num_traits::pow(mantissa, p as usize) * num_traits::pow(BigInt::from(10u32), pad as usize);
+    let root = backend().root(&power, den);
+    if num_traits::pow(root.clone(), den as usize) != power {
+        return None;
+    }
+    let exp = (shift + pad) / den as i64;
+    if !num.is_negative() {
+        return Some(Float::Big(BigDecimal::new(root, exp)));
+    }
+    let ten = |n: i64| num_traits::pow(BigInt::from(10u32), n.unsigned_abs() as usize);
+    Some(match exp >= 0 {
+        true => crate::recurring::ratio_to_float(&ten(exp), &root),
+        false => crate::recurring::ratio_to_float(&BigInt::from(1u32), &(root * ten(exp))),
+    })
+}
+
 fn bigdecimal_nth_root(a: BigDecimal, n: u32, prec: usize) -> Option<BigDecimal> {
     if n == 0 { return None; }
     if a.is_zero() { return Some(BigDecimal::from(0u32)); }
@@ -2023,64 +3572,69 @@ fn bigdecimal_nth_root(a: BigDecimal, n: u32, prec: usize) -> Option<BigDecimal>
 
 pub trait ApproxEq {
     fn approx_eq(&self, n: &Self, epsilon: f64) -> bool;
+
+    /// `|self - n| <= max(rel_tol * max(|self|, |n|), abs_tol)`, like Python's
+    /// `math.isclose`, computed exactly.
+    fn approx_eq_rel(&self, n: &Self, rel_tol: &Float, abs_tol: &Float) -> bool;
+
+    /// Whether `self` and `n` are at most `ulps` units in the last place apart, the unit
+    /// being that of the less precise operand: the binary ULP of a `Small` float, the last
+    /// stored decimal digit of a `Big` or `Irrational` one, the last of the `precision()`
+    /// digits of a `Recurring` one, and 1 for an `Int`.
+    fn approx_eq_ulps(&self, n: &Self, ulps: u64) -> bool;
 }
 
 impl ApproxEq for Int {
+    /// `|self - n| <= epsilon`, exactly for any magnitude; a NaN `epsilon` matches nothing.
     fn approx_eq(&self, n: &Self, epsilon: f64) -> bool {
         if self == n {
             return true;
         }
-        
-        let a_bigint = match self {
-            Int::Big(b) => b.clone(),
-            Int::Small(s) => {
-                match s {
-                    SmallInt::I8(v) => BigInt::from(*v),
-                    SmallInt::U8(v) => BigInt::from(*v),
-                    SmallInt::I16(v) => BigInt::from(*v),
-                    SmallInt::U16(v) => BigInt::from(*v),
-                    SmallInt::I32(v) => BigInt::from(*v),
-                    SmallInt::U32(v) => BigInt::from(*v),
-                    SmallInt::I64(v) => BigInt::from(*v),
-                    SmallInt::U64(v) => BigInt::from(*v),
-                    SmallInt::I128(v) => BigInt::from(*v),
-                    SmallInt::U128(v) => BigInt::from(*v),
-                    SmallInt::ISize(v) => BigInt::from(*v),
-                    SmallInt::USize(v) => BigInt::from(*v),
-                }
-            }
-        };
-        
-        let b_bigint = match n {
-            Int::Big(b) => b.clone(),
-            Int::Small(s) => {
-                match s {
-                    SmallInt::I8(v) => BigInt::from(*v),
-                    SmallInt::U8(v) => BigInt::from(*v),
-                    SmallInt::I16(v) => BigInt::from(*v),
-                    SmallInt::U16(v) => BigInt::from(*v),
-                    SmallInt::I32(v) => BigInt::from(*v),
-                    SmallInt::U32(v) => BigInt::from(*v),
-                    SmallInt::I64(v) => BigInt::from(*v),
-                    SmallInt::U64(v) => BigInt::from(*v),
-                    SmallInt::I128(v) => BigInt::from(*v),
-                    SmallInt::U128(v) => BigInt::from(*v),
-                    SmallInt::ISize(v) => BigInt::from(*v),
-                    SmallInt::USize(v) => BigInt::from(*v),
-                }
-            }
+        // The difference is an integer, so only the integer part of `epsilon` counts.
+        let Some(tolerance) = BigInt::from_f64(epsilon.abs().floor()) else {
+            return epsilon.abs() == f64::INFINITY;
         };
-        
-        let diff = (a_bigint - b_bigint).abs();
-        
-        let epsilon_bigint = BigInt::from(epsilon.abs() as i64);
-        
-        diff <= epsilon_bigint
+        (&*self.as_bigint() - &*n.as_bigint()).abs() <= tolerance
+    }
+
+    fn approx_eq_rel(&self, n: &Self, rel_tol: &Float, abs_tol: &Float) -> bool {
+        match (self.to_float(), n.to_float()) {
+            (Ok(a), Ok(b)) => a.approx_eq_rel(&b, rel_tol, abs_tol),
+            _ => false,
+        }
+    }
+
+    fn approx_eq_ulps(&self, n: &Self, ulps: u64) -> bool {
+        (&*self.as_bigint() - &*n.as_bigint()).abs() <= BigInt::from(ulps)
     }
 }
 
-impl ApproxEq for Float {
-    fn approx_eq(&self, n: &Self, epsilon: f64) -> bool {
+/// The unit in the last place of a finite, real `f`: `2^e` for a `Small` float and
+/// `10^-scale` for a decimal one. `None` for the other kinds.
+fn last_place(f: &Float) -> Option<Float> {
+    match f {
+        Float::Small(SmallFloat::F64(v)) if v.is_finite() => {
+            let biased = (v.to_bits() >> 52) & 0x7ff;
+            let ulp = if biased <= 52 { f64::from_bits(1 << (biased.max(1) - 1)) } else { f64::from_bits((biased - 52) << 52) };
+            Some(Float::from_f64_exact(ulp))
+        }
+        Float::Small(SmallFloat::F32(v)) if v.is_finite() => {
+            let biased = (v.to_bits() >> 23) & 0xff;
+            let ulp = if biased <= 23 { f32::from_bits(1 << (biased.max(1) - 1)) } else { f32::from_bits((biased - 23) << 23) };
+            Some(Float::from_f32_exact(ulp))
+        }
+        Float::Big(bd) | Float::Irrational(bd) => Some(Float::Big(BigDecimal::new(BigInt::one(), bd.fractional_digit_count()))),
+        Float::Recurring(_, _) => last_place(&Float::Big(f.decimal_value()?)),
+        Float::Surd(_, _) => last_place(&f.expand()),
+        _ => None,
+    }
+}
+
+impl Float {
+    /// Shared by the `ApproxEq` methods: NaN is never close, an infinity only to itself,
+    /// complex values compare part by part and never to real ones, and `close` decides
+    /// for two finite real values.
+    fn approx_eq_by(&self, n: &Self, close: &dyn Fn(&Float, &Float) -> bool) -> bool {
         match (self, n) {
             (Float::NaN, _) | (_, Float::NaN) => return false,
             (Float::Infinity, Float::Infinity) => return true,
@@ -2089,25 +3643,48 @@ impl ApproxEq for Float {
             (Float::NegInfinity, _) | (_, Float::NegInfinity) => return false,
             _ => {}
         }
-        
-        if self.is_complex() && n.is_complex() {
-            if let (Float::Complex(r1, i1), Float::Complex(r2, i2)) = (self, n) {
-                return r1.approx_eq(r2, epsilon) && i1.approx_eq(i2, epsilon);
-            }
+
+        if let (Float::Complex(r1, i1), Float::Complex(r2, i2)) = (self, n) {
+            return r1.approx_eq_by(r2, close) && i1.approx_eq_by(i2, close);
         }
-        
+
         if self.is_complex() != n.is_complex() {
             return false;
         }
-        
-        if let Ok(diff) = self - n {
-            let abs_diff = diff.abs();
-            
-            if let Ok(diff_val) = abs_diff.to_f64() {
-                return diff_val.abs() <= epsilon;
+
+        close(self, n)
+    }
+}
+
+impl ApproxEq for Float {
+    fn approx_eq(&self, n: &Self, epsilon: f64) -> bool {
+        self.approx_eq_by(n, &|a, b| match (a - b).map(|d| d.abs().to_f64()) {
+            Ok(Ok(diff)) => diff.abs() <= epsilon,
+            _ => false,
+        })
+    }
+
+    fn approx_eq_rel(&self, n: &Self, rel_tol: &Float, abs_tol: &Float) -> bool {
+        self.approx_eq_by(n, &|a, b| {
+            let Ok(diff) = a._sub(b) else {
+                return false;
+            };
+            let diff = diff.abs();
+            if diff <= *abs_tol {
+                return true;
             }
-        }
-        
-        false
+            let larger = if a.abs() >= b.abs() { a.abs() } else { b.abs() };
+            matches!(larger._mul(rel_tol), Ok(bound) if diff <= bound)
+        })
+    }
+
+    fn approx_eq_ulps(&self, n: &Self, ulps: u64) -> bool {
+        self.approx_eq_by(n, &|a, b| {
+            let (Some(ua), Some(ub), Ok(diff)) = (last_place(a), last_place(b), a._sub(b)) else {
+                return false;
+            };
+            let unit = if ua >= ub { ua } else { ub };
+            matches!(unit._mul(&Float::from(ulps)), Ok(bound) if diff.abs() <= bound)
+        })
     }
 }
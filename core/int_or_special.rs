@@ -0,0 +1,234 @@
+//! `IntOrSpecial`: an `Int` that can also be NaN or infinite.
+//!
+//! `Int` is always finite, which keeps integer arithmetic exact and total. Embedders whose
+//! language has an "integer NaN" (or lets an integer overflow to infinity) can hold values
+//! in this wrapper instead; the special values propagate through arithmetic the way they
+//! do for `Float`.
+
+use crate::foundation::{Float, Int};
+use crate::math::{ERR_DIV_BY_ZERO, ERR_INFINITE_RESULT, ERR_INVALID_FORMAT};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::Neg;
+
+/// An `Int`, or one of the special values an `Int` cannot hold.
+#[derive(Debug, Clone)]
+pub enum IntOrSpecial {
+    Int(Int),
+    NaN,
+    Infinity,
+    NegInfinity,
+}
+
+impl IntOrSpecial {
+    /// Parses `nan`, `inf` / `infinity` with an optional `-` (any case), or an integer as
+    /// `try_create_int` does.
+    pub fn parse(s: &str) -> Result<Self, i8> {
+        let low = s.trim().to_ascii_lowercase();
+        match low.as_str() {
+            "nan" => Ok(IntOrSpecial::NaN),
+            "inf" | "infinity" => Ok(IntOrSpecial::Infinity),
+            "-inf" | "-infinity" => Ok(IntOrSpecial::NegInfinity),
+            _ => crate::functions::try_create_int(s).map(IntOrSpecial::Int),
+        }
+    }
+
+    /// The integer value of a float: NaN and the infinities carry over and an integral
+    /// value converts exactly. Fails with `INVALID_FORMAT` for a fractional or complex
+    /// value.
+    pub fn from_float(f: &Float) -> Result<Self, i8> {
+        match f {
+            Float::NaN => Ok(IntOrSpecial::NaN),
+            Float::Infinity => Ok(IntOrSpecial::Infinity),
+            Float::NegInfinity => Ok(IntOrSpecial::NegInfinity),
+            _ => f.to_int().map(IntOrSpecial::Int),
+        }
+    }
+
+    pub fn to_float(&self) -> Result<Float, i8> {
+        match self {
+            IntOrSpecial::Int(i) => i.to_float(),
+            IntOrSpecial::NaN => Ok(Float::NaN),
+            IntOrSpecial::Infinity => Ok(Float::Infinity),
+            IntOrSpecial::NegInfinity => Ok(Float::NegInfinity),
+        }
+    }
+
+    /// The `Int`; fails with `INVALID_FORMAT` for NaN and `INFINITE_RESULT` for an
+    /// infinity, like `Float::to_int`.
+    pub fn into_int(self) -> Result<Int, i8> {
+        match self {
+            IntOrSpecial::Int(i) => Ok(i),
+            IntOrSpecial::NaN => Err(ERR_INVALID_FORMAT),
+            IntOrSpecial::Infinity | IntOrSpecial::NegInfinity => Err(ERR_INFINITE_RESULT),
+        }
+    }
+
+    pub fn as_int(&self) -> Option<&Int> {
+        match self {
+            IntOrSpecial::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    pub fn is_nan(&self) -> bool {
+        matches!(self, IntOrSpecial::NaN)
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        matches!(self, IntOrSpecial::Infinity | IntOrSpecial::NegInfinity)
+    }
+
+    pub fn is_finite(&self) -> bool {
+        matches!(self, IntOrSpecial::Int(_))
+    }
+
+    /// `-1`, `0` or `1` for an `Int`, `±1` for an infinity; `None` for NaN.
+    fn signum(&self) -> Option<i8> {
+        match self {
+            IntOrSpecial::Int(i) if i.is_zero() => Some(0),
+            IntOrSpecial::Int(i) => Some(if i.is_negative() { -1 } else { 1 }),
+            IntOrSpecial::Infinity => Some(1),
+            IntOrSpecial::NegInfinity => Some(-1),
+            IntOrSpecial::NaN => None,
+        }
+    }
+
+    /// The infinity with sign `sign`, or NaN for sign 0 (`∞ · 0`).
+    fn infinity(sign: i8) -> Self {
+        match sign.cmp(&0) {
+            Ordering::Greater => IntOrSpecial::Infinity,
+            Ordering::Less => IntOrSpecial::NegInfinity,
+            Ordering::Equal => IntOrSpecial::NaN,
+        }
+    }
+
+    /// `∞ + ∞ = ∞`, `∞ - ∞` is NaN, and an infinity absorbs any integer.
+    pub fn _add(&self, other: &Self) -> Result<Self, i8> {
+        match (self, other) {
+            (IntOrSpecial::Int(a), IntOrSpecial::Int(b)) => a._add(b).map(IntOrSpecial::Int),
+            (IntOrSpecial::NaN, _) | (_, IntOrSpecial::NaN) => Ok(IntOrSpecial::NaN),
+            (IntOrSpecial::Infinity, IntOrSpecial::NegInfinity)
+            | (IntOrSpecial::NegInfinity, IntOrSpecial::Infinity) => Ok(IntOrSpecial::NaN),
+            (IntOrSpecial::Int(_), inf) | (inf, _) => Ok(inf.clone()),
+        }
+    }
+
+    pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+        self._add(&-other.clone())
+    }
+
+    /// Signs multiply; `∞ · 0` is NaN.
+    pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
+        match (self, other) {
+            (IntOrSpecial::Int(a), IntOrSpecial::Int(b)) => a._mul(b).map(IntOrSpecial::Int),
+            _ => match (self.signum(), other.signum()) {
+                (Some(a), Some(b)) => Ok(IntOrSpecial::infinity(a * b)),
+                _ => Ok(IntOrSpecial::NaN),
+            },
+        }
+    }
+
+    /// Integer division as `Int::_div` does it; an integer over an infinity is 0, an
+    /// infinity over a nonzero integer keeps the combined sign, and `∞ / ∞` is NaN.
+    /// Dividing by zero fails with `DIV_BY_ZERO` like it does for `Int`.
+    pub fn _div(&self, other: &Self) -> Result<Self, i8> {
+        match (self, other) {
+            (IntOrSpecial::Int(a), IntOrSpecial::Int(b)) => a._div(b).map(IntOrSpecial::Int),
+            (IntOrSpecial::NaN, _) | (_, IntOrSpecial::NaN) => Ok(IntOrSpecial::NaN),
+            (_, IntOrSpecial::Int(b)) if b.is_zero() => Err(ERR_DIV_BY_ZERO),
+            (IntOrSpecial::Int(_), _) => Ok(IntOrSpecial::Int(Int::new())),
+            (_, IntOrSpecial::Int(_)) => Ok(IntOrSpecial::infinity(self.signum().unwrap_or(0) * other.signum().unwrap_or(0))),
+            _ => Ok(IntOrSpecial::NaN),
+        }
+    }
+
+    /// Remainder as `Int::_modulo` does it; `x mod ∞` is `x`, as for `f64`, and an
+    /// infinite dividend gives NaN.
+    pub fn _modulo(&self, other: &Self) -> Result<Self, i8> {
+        match (self, other) {
+            (IntOrSpecial::Int(a), IntOrSpecial::Int(b)) => a._modulo(b).map(IntOrSpecial::Int),
+            (IntOrSpecial::NaN, _) | (_, IntOrSpecial::NaN) => Ok(IntOrSpecial::NaN),
+            (_, IntOrSpecial::Int(b)) if b.is_zero() => Err(ERR_DIV_BY_ZERO),
+            (IntOrSpecial::Int(a), _) => Ok(IntOrSpecial::Int(a.clone())),
+            _ => Ok(IntOrSpecial::NaN),
+        }
+    }
+
+    /// `self` to the power `exponent`, a non-negative integer or `+∞`. Infinite bases
+    /// follow the sign rules (`(-∞)^3 = -∞`, `∞^0 = 1`); `x^∞` is 0 for `x = 0`, 1 for
+    /// `x = 1`, `∞` for `x > 1` and NaN for `x <= -1`. Negative exponents fail with
+    /// `INVALID_FORMAT`, as they do for `Int::pow`.
+    pub fn pow(&self, exponent: &Self) -> Result<Self, i8> {
+        match (self, exponent) {
+            (IntOrSpecial::Int(a), IntOrSpecial::Int(e)) => a.pow(e).map(IntOrSpecial::Int),
+            (IntOrSpecial::NaN, _) | (_, IntOrSpecial::NaN) => Ok(IntOrSpecial::NaN),
+            (_, IntOrSpecial::NegInfinity) => Err(ERR_INVALID_FORMAT),
+            (_, IntOrSpecial::Int(e)) if e.is_negative() => Err(ERR_INVALID_FORMAT),
+            (_, IntOrSpecial::Int(e)) if e.is_zero() => Ok(IntOrSpecial::Int(Int::from(1))),
+            (IntOrSpecial::NegInfinity, IntOrSpecial::Int(e)) if e.bit(0) => Ok(IntOrSpecial::NegInfinity),
+            (_, IntOrSpecial::Int(_)) => Ok(IntOrSpecial::Infinity),
+            (IntOrSpecial::Int(a), IntOrSpecial::Infinity) => Ok(match a.cmp(Int::interned(1)) {
+                Ordering::Equal => IntOrSpecial::Int(Int::from(1)),
+                Ordering::Greater => IntOrSpecial::Infinity,
+                Ordering::Less if a.is_zero() => IntOrSpecial::Int(Int::new()),
+                Ordering::Less => IntOrSpecial::NaN,
+            }),
+            (IntOrSpecial::Infinity, IntOrSpecial::Infinity) => Ok(IntOrSpecial::Infinity),
+            (IntOrSpecial::NegInfinity, IntOrSpecial::Infinity) => Ok(IntOrSpecial::NaN),
+        }
+    }
+}
+
+impl From<Int> for IntOrSpecial {
+    fn from(value: Int) -> Self {
+        IntOrSpecial::Int(value)
+    }
+}
+
+impl Neg for IntOrSpecial {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self {
+            IntOrSpecial::Int(i) => IntOrSpecial::Int(-i),
+            IntOrSpecial::NaN => IntOrSpecial::NaN,
+            IntOrSpecial::Infinity => IntOrSpecial::NegInfinity,
+            IntOrSpecial::NegInfinity => IntOrSpecial::Infinity,
+        }
+    }
+}
+
+/// NaN equals nothing, itself included, as for `Float`.
+impl PartialEq for IntOrSpecial {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// `-∞` < every `Int` < `∞`; NaN is unordered.
+impl PartialOrd for IntOrSpecial {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let rank = |v: &Self| match v {
+            IntOrSpecial::NegInfinity => Some(0),
+            IntOrSpecial::Int(_) => Some(1),
+            IntOrSpecial::Infinity => Some(2),
+            IntOrSpecial::NaN => None,
+        };
+        match (self, other) {
+            (IntOrSpecial::Int(a), IntOrSpecial::Int(b)) => Some(a.cmp(b)),
+            _ => Some(rank(self)?.cmp(&rank(other)?)),
+        }
+    }
+}
+
+impl Display for IntOrSpecial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            IntOrSpecial::Int(i) => Display::fmt(i, f),
+            IntOrSpecial::NaN => write!(f, "NaN"),
+            IntOrSpecial::Infinity => write!(f, "Infinity"),
+            IntOrSpecial::NegInfinity => write!(f, "-Infinity"),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Inverse trigonometric and hyperbolic functions, for real and complex values.
+//!
+//! Each is written with the complex `ln` and `sqrt`, e.g. `asin z = -i ln(iz + √(1 - z²))`,
+//! so results carry the `precision()` significant digits those give. A real value inside
+//! the real domain gives a real result; outside it, the complex principal value.
+//!
+//! A `Float` zero has no sign, so a value on a branch cut is taken as if its zero part were
+//! `+0`, as C's `casin` family and `numpy` do: on a cut along the real axis the result is
+//! the limit from above, on one along the imaginary axis the limit from the right. So
+//! `asin(2) = π/2 + 1.3169…i` and `atan(-2i) = π/2 - 0.5493…i`.
+
+use crate::cache;
+use crate::compat::float_kind;
+use crate::foundation::{precision, Float, FloatKind};
+use crate::math::ERR_INVALID_FORMAT;
+use bigdecimal::RoundingMode;
+use core::num::NonZeroU64;
+
+/// The real and imaginary parts of a finite `z`.
+fn parts(z: &Float) -> Result<(Float, Float), i8> {
+    let (re, im) = (z.re(), z.im());
+    for part in [&re, &im] {
+        if matches!(float_kind(part), FloatKind::NaN | FloatKind::Infinity | FloatKind::NegInfinity) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+    }
+    Ok((re, im))
+}
+
+fn one() -> Float {
+    Float::Big(cache::ONE.clone())
+}
+
+/// π/2 rounded to `precision()` significant digits, as `arg` gives it, so `acos 1` is `0`.
+fn half_pi() -> Float {
+    let digits = NonZeroU64::new(precision()).unwrap();
+    Float::Irrational(crate::consts::pi(precision() + 10).half().with_precision_round(digits, RoundingMode::HalfEven))
+}
+
+/// `i·z`.
+fn mul_i(z: &Float) -> Float {
+    Float::complex(-z.im(), z.re())
+}
+
+/// `-i·z`.
+fn mul_neg_i(z: &Float) -> Float {
+    Float::complex(z.im(), -z.re())
+}
+
+/// `z` with a non-negative imaginary part, for a real argument on a real-axis cut.
+fn upper(z: Float) -> Float {
+    Float::complex(z.re(), z.im().abs())
+}
+
+/// `z` with a non-negative real part, for an argument on an imaginary-axis cut.
+fn right(z: Float) -> Float {
+    Float::complex(z.re().abs(), z.im())
+}
+
+/// `-i ln(iz + √(1 - z²))` for `z` with `Im z <= 0`, where neither sum cancels.
+fn asin_lower(z: &Float) -> Result<Float, i8> {
+    let root = one()._sub(&z._mul(z)?)?.sqrt()?;
+    Ok(mul_neg_i(&mul_i(z)._add(&root)?.ln()?))
+}
+
+impl Float {
+    /// Arcsine, the inverse of `sin`. Real in `[-1, 1]`, complex elsewhere, with cuts along
+    /// the real axis beyond `±1`. Fails with `INVALID_FORMAT` for NaN and infinities.
+    ///
+    /// ```
+    /// use imagnum::create_float;
+    ///
+    /// assert_eq!(create_float("0").asin(), Ok(create_float("0")));
+    /// assert!(create_float("2").asin().unwrap().is_complex());
+    /// ```
+    pub fn asin(&self) -> Result<Float, i8> {
+        let (re, im) = parts(self)?;
+        let z = Float::complex(re.clone(), im.clone());
+        // asin is odd; reflecting keeps iz + √(1 - z²) free of cancellation
+        let w = if im > 0 || (im.is_zero() && re < 0) { -asin_lower(&-z)? } else { asin_lower(&z)? };
+        Ok(match im.is_zero() {
+            true if re.abs() <= 1 => w.re(),
+            true => upper(w),
+            false => w,
+        })
+    }
+
+    /// Arccosine, `π/2 - asin`. Real in `[-1, 1]`, complex elsewhere, with cuts along the
+    /// real axis beyond `±1`.
+    pub fn acos(&self) -> Result<Float, i8> {
+        let w = half_pi()._sub(&self.asin()?)?;
+        Ok(if self.im().is_zero() && self.re().abs() <= 1 { w.re() } else { w })
+    }
+
+    /// Arctangent, `(i/2)(ln(1 - iz) - ln(1 + iz))`. Real for every real value, with cuts
+    /// along the imaginary axis beyond `±i`; fails at `±i` themselves.
+    pub fn atan(&self) -> Result<Float, i8> {
+        let (re, im) = parts(self)?;
+        let iz = mul_i(&Float::complex(re.clone(), im.clone()));
+        let diff = one()._sub(&iz)?.ln()?._sub(&one()._add(&iz)?.ln()?)?;
+        let w = mul_i(&diff)._div(&Float::Big(cache::TWO.clone()))?;
+        Ok(if im.is_zero() {
+            w.re()
+        } else if re.is_zero() && im.abs() > 1 {
+            right(w)
+        } else {
+            w
+        })
+    }
+
+    /// Inverse hyperbolic sine, `-i asin(iz)`. Real for every real value, with cuts along
+    /// the imaginary axis beyond `±i`.
+    pub fn asinh(&self) -> Result<Float, i8> {
+        let (re, im) = parts(self)?;
+        let w = mul_neg_i(&mul_i(&Float::complex(re, im.clone())).asin()?);
+        Ok(if im.is_zero() { w.re() } else { w })
+    }
+
+    /// Inverse hyperbolic cosine, `ln(z + √(z + 1)·√(z - 1))`. Real from `1` up, complex
+    /// elsewhere, with a cut along the real axis below `1`.
+    pub fn acosh(&self) -> Result<Float, i8> {
+        let (re, im) = parts(self)?;
+        let z = Float::complex(re.clone(), im.clone());
+        let root = z._add(&one())?.sqrt()?._mul(&z._sub(&one())?.sqrt()?)?;
+        let w = z._add(&root)?.ln()?;
+        Ok(match im.is_zero() {
+            true if re >= 1 => w.re(),
+            true => upper(w),
+            false => w,
+        })
+    }
+
+    /// Inverse hyperbolic tangent, `(ln(1 + z) - ln(1 - z)) / 2`. Real in `(-1, 1)`,
+    /// complex elsewhere, with cuts along the real axis beyond `±1`; fails at `±1`.
+    pub fn atanh(&self) -> Result<Float, i8> {
+        let (re, im) = parts(self)?;
+        let z = Float::complex(re.clone(), im.clone());
+        let diff = one()._add(&z)?.ln()?._sub(&one()._sub(&z)?.ln()?)?;
+        let w = diff._div(&Float::Big(cache::TWO.clone()))?;
+        Ok(match im.is_zero() {
+            true if re.abs() < 1 => w.re(),
+            true => upper(w),
+            false => w,
+        })
+    }
+}
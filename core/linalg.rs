@@ -0,0 +1,458 @@
+use crate::alloc_prelude::*;
+use crate::foundation::{Float, SmallFloat};
+use crate::math::{ERR_DIV_BY_ZERO, ERR_INVALID_FORMAT};
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+fn zero() -> Float {
+    Float::Big(BigDecimal::zero())
+}
+
+fn one() -> Float {
+    Float::Big(BigDecimal::one())
+}
+
+/// Column vector of `Float`s.
+///
+/// Arithmetic returns `Result<Vector, i8>` like the scalar types and fails with
+/// `INVALID_FORMAT` when the lengths differ.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector {
+    items: Vec<Float>,
+}
+
+impl Vector {
+    pub fn new(items: Vec<Float>) -> Self {
+        Vector { items }
+    }
+
+    pub fn zeros(len: usize) -> Self {
+        Vector::new(vec![zero(); len])
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get(&self, i: usize) -> Option<&Float> {
+        self.items.get(i)
+    }
+
+    pub fn as_slice(&self) -> &[Float] {
+        &self.items
+    }
+
+    pub fn into_vec(self) -> Vec<Float> {
+        self.items
+    }
+
+    fn zip(&self, other: &Self, f: impl Fn(&Float, &Float) -> Result<Float, i8>) -> Result<Self, i8> {
+        if self.len() != other.len() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let items = self.items.iter().zip(&other.items).map(|(a, b)| f(a, b)).collect::<Result<_, _>>()?;
+        Ok(Vector::new(items))
+    }
+
+    pub fn _add(&self, other: &Self) -> Result<Self, i8> {
+        self.zip(other, Float::_add)
+    }
+
+    pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+        self.zip(other, Float::_sub)
+    }
+
+    /// Multiplies every component by `k`.
+    pub fn scale(&self, k: &Float) -> Result<Self, i8> {
+        Ok(Vector::new(self.items.iter().map(|x| x._mul(k)).collect::<Result<_, _>>()?))
+    }
+
+    /// Dot product with a single rounding; see `Float::dot`.
+    pub fn dot(&self, other: &Self) -> Result<Float, i8> {
+        Float::dot(&self.items, &other.items)
+    }
+
+    /// Euclidean length.
+    pub fn norm(&self) -> Result<Float, i8> {
+        self.dot(self)?.sqrt()
+    }
+}
+
+impl From<Vec<Float>> for Vector {
+    fn from(items: Vec<Float>) -> Self {
+        Vector::new(items)
+    }
+}
+
+impl Index<usize> for Vector {
+    type Output = Float;
+    fn index(&self, i: usize) -> &Float {
+        &self.items[i]
+    }
+}
+
+impl IndexMut<usize> for Vector {
+    fn index_mut(&mut self, i: usize) -> &mut Float {
+        &mut self.items[i]
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Vector::new(self.items.into_iter().map(|x| -x).collect())
+    }
+}
+
+impl Display for Vector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "[")?;
+        for (i, x) in self.items.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", x)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Dense row-major matrix of `Float`s.
+///
+/// Arithmetic returns `Result<_, i8>` and fails with `INVALID_FORMAT` on mismatched shapes.
+/// `determinant` and `solve` are exact when every entry is a finite `Big` or small float:
+/// they run fraction-free (Bareiss) elimination over integers, so the only rounding is the
+/// final division of each unknown. Other entries go through LU elimination with partial
+/// pivoting on the ordinary `Float` operations.
+///
+/// ```
+/// use imagnum::{create_float, Matrix, Vector};
+///
+/// let f = |s| create_float(s);
+/// let a = Matrix::from_rows(vec![vec![f("2"), f("1")], vec![f("1"), f("3")]]).unwrap();
+/// assert_eq!(a.determinant().unwrap(), f("5"));
+///
+/// let x = a.solve(&Vector::new(vec![f("3"), f("5")])).unwrap();
+/// assert_eq!(x, Vector::new(vec![f("0.8"), f("1.4")]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<Float>,
+}
+
+impl Matrix {
+    /// `rows × cols` matrix from row-major `data`. Fails with `INVALID_FORMAT` when
+    /// `data.len() != rows * cols`.
+    pub fn new(rows: usize, cols: usize, data: Vec<Float>) -> Result<Self, i8> {
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        Ok(Matrix { rows, cols, data })
+    }
+
+    /// Fails with `INVALID_FORMAT` when the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<Float>>) -> Result<Self, i8> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|r| r.len() != cols) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let n = rows.len();
+        Matrix::new(n, cols, rows.into_iter().flatten().collect())
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix { rows, cols, data: vec![zero(); rows * cols] }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut m = Matrix::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = one();
+        }
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&Float> {
+        if row < self.rows && col < self.cols { self.data.get(row * self.cols + col) } else { None }
+    }
+
+    pub fn row(&self, row: usize) -> &[Float] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> Vector {
+        Vector::new((0..self.rows).map(|r| self[(r, col)].clone()).collect())
+    }
+
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.cols).flat_map(|c| (0..self.rows).map(move |r| (r, c))).map(|(r, c)| self[(r, c)].clone()).collect();
+        Matrix { rows: self.cols, cols: self.rows, data }
+    }
+
+    fn zip(&self, other: &Self, f: impl Fn(&Float, &Float) -> Result<Float, i8>) -> Result<Self, i8> {
+        if (self.rows, self.cols) != (other.rows, other.cols) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let data = self.data.iter().zip(&other.data).map(|(a, b)| f(a, b)).collect::<Result<_, _>>()?;
+        Matrix::new(self.rows, self.cols, data)
+    }
+
+    pub fn _add(&self, other: &Self) -> Result<Self, i8> {
+        self.zip(other, Float::_add)
+    }
+
+    pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+        self.zip(other, Float::_sub)
+    }
+
+    /// Multiplies every entry by `k`.
+    pub fn scale(&self, k: &Float) -> Result<Self, i8> {
+        let data = self.data.iter().map(|x| x._mul(k)).collect::<Result<_, _>>()?;
+        Matrix::new(self.rows, self.cols, data)
+    }
+
+    /// Matrix product; every entry is a `Float::dot`, so it is rounded once.
+    pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
+        if self.cols != other.rows {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let columns: Vec<Vector> = (0..other.cols).map(|c| other.column(c)).collect();
+        let mut data = Vec::with_capacity(self.rows * other.cols);
+        for r in 0..self.rows {
+            for col in &columns {
+                data.push(Float::dot(self.row(r), col.as_slice())?);
+            }
+        }
+        Matrix::new(self.rows, other.cols, data)
+    }
+
+    /// `self · v`
+    pub fn mul_vector(&self, v: &Vector) -> Result<Vector, i8> {
+        if self.cols != v.len() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        Ok(Vector::new((0..self.rows).map(|r| Float::dot(self.row(r), v.as_slice())).collect::<Result<_, _>>()?))
+    }
+
+    fn rows_vec(&self) -> Vec<Vec<Float>> {
+        (0..self.rows).map(|r| self.row(r).to_vec()).collect()
+    }
+
+    /// Fails with `INVALID_FORMAT` for a non-square matrix. The empty matrix has
+    /// determinant `1`.
+    pub fn determinant(&self) -> Result<Float, i8> {
+        if !self.is_square() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let n = self.rows;
+        if let Some((mut m, scale)) = integer_rows(&self.rows_vec()) {
+            let det = match bareiss(&mut m, n) {
+                Some(det) => det,
+                None => return Ok(zero()),
+            };
+            // every row was multiplied by 10^scale
+            return Ok(Float::Big(BigDecimal::new(det, scale * n as i64)));
+        }
+        let mut m = self.rows_vec();
+        match lu_eliminate(&mut m, n)? {
+            Some(negate) => {
+                let mut det = one();
+                for (i, row) in m.iter().enumerate() {
+                    det = det._mul(&row[i])?;
+                }
+                Ok(if negate { -det } else { det })
+            }
+            None => Ok(zero()),
+        }
+    }
+
+    /// Solves `self · x = b` for a square `self`. Fails with `INVALID_FORMAT` on mismatched
+    /// shapes and `DIV_BY_ZERO` when `self` is singular.
+    pub fn solve(&self, b: &Vector) -> Result<Vector, i8> {
+        if !self.is_square() || b.len() != self.rows {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let n = self.rows;
+        let mut augmented = self.rows_vec();
+        for (row, x) in augmented.iter_mut().zip(b.as_slice()) {
+            row.push(x.clone());
+        }
+        if let Some((mut m, _)) = integer_rows(&augmented) {
+            bareiss(&mut m, n).ok_or(ERR_DIV_BY_ZERO)?;
+            // every diagonal entry now holds the (scaled) determinant
+            let x = m
+                .into_iter()
+                .enumerate()
+                .map(|(i, row)| Float::Big(BigDecimal::from(row[n].clone()))._div(&Float::Big(BigDecimal::from(row[i].clone()))))
+                .collect::<Result<_, _>>()?;
+            return Ok(Vector::new(x));
+        }
+        let mut m = augmented;
+        lu_eliminate(&mut m, n)?.ok_or(ERR_DIV_BY_ZERO)?;
+        let mut x = vec![zero(); n];
+        for i in (0..n).rev() {
+            let mut acc = m[i][n].clone();
+            for j in i + 1..n {
+                acc = acc._sub(&m[i][j]._mul(&x[j])?)?;
+            }
+            x[i] = acc._div(&m[i][i])?;
+        }
+        Ok(Vector::new(x))
+    }
+}
+
+/// Scales every entry by a common power of ten so all become integers. `None` unless each
+/// entry is a finite `Big` or small float.
+fn integer_rows(rows: &[Vec<Float>]) -> Option<(Vec<Vec<BigInt>>, i64)> {
+    let mut decimals = Vec::with_capacity(rows.len());
+    let mut scale = 0i64;
+    for row in rows {
+        let mut out = Vec::with_capacity(row.len());
+        for x in row {
+            let (mantissa, s) = match x {
+                Float::Big(_) | Float::Small(SmallFloat::F32(_) | SmallFloat::F64(_)) => x.decimal_value()?.into_bigint_and_exponent(),
+                _ => return None,
+            };
+            scale = scale.max(s);
+            out.push((mantissa, s));
+        }
+        decimals.push(out);
+    }
+    let ten = BigInt::from(10);
+    let rows = decimals
+        .into_iter()
+        .map(|row| row.into_iter().map(|(m, s)| m * num_traits::pow(ten.clone(), (scale - s) as usize)).collect())
+        .collect();
+    Some((rows, scale))
+}
+
+/// Fraction-free Gauss-Jordan elimination (Bareiss) of the leading `n` columns, in place.
+/// Every division is exact. Afterwards the leading block is diagonal with the determinant
+/// on every diagonal entry; that determinant is returned, or `None` for a singular block.
+fn bareiss(m: &mut [Vec<BigInt>], n: usize) -> Option<BigInt> {
+    let mut prev = BigInt::one();
+    let mut negate = false;
+    for k in 0..n {
+        if m[k][k].is_zero() {
+            let p = (k + 1..n).find(|&i| !m[i][k].is_zero())?;
+            m.swap(k, p);
+            negate = !negate;
+        }
+        for i in (0..m.len()).filter(|&i| i != k) {
+            for j in (0..m[i].len()).filter(|&j| j != k) {
+                m[i][j] = (&m[k][k] * &m[i][j] - &m[i][k] * &m[k][j]) / &prev;
+            }
+            m[i][k] = BigInt::zero();
+        }
+        prev = m[k][k].clone();
+    }
+    Some(if negate { -prev } else { prev })
+}
+
+/// Gaussian elimination with partial pivoting on the leading `n` columns, in place, leaving
+/// `m` upper triangular (the `U` of `PA = LU`, with the multipliers applied to any extra
+/// columns). Returns whether the rows were swapped an odd number of times, or `None` for a
+/// singular block.
+fn lu_eliminate(m: &mut [Vec<Float>], n: usize) -> Result<Option<bool>, i8> {
+    let mut negate = false;
+    for k in 0..n {
+        let p = (k..n)
+            .max_by(|&a, &b| m[a][k].abs().partial_cmp(&m[b][k].abs()).unwrap_or(Ordering::Equal))
+            .filter(|&p| !m[p][k].is_zero());
+        let Some(p) = p else {
+            return Ok(None);
+        };
+        if p != k {
+            m.swap(k, p);
+            negate = !negate;
+        }
+        for i in k + 1..n {
+            let factor = m[i][k]._div(&m[k][k])?;
+            for j in k..m[i].len() {
+                m[i][j] = m[i][j]._sub(&factor._mul(&m[k][j])?)?;
+            }
+        }
+    }
+    Ok(Some(negate))
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = Float;
+    fn index(&self, (row, col): (usize, usize)) -> &Float {
+        assert!(row < self.rows && col < self.cols, "matrix index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Float {
+        assert!(row < self.rows && col < self.cols, "matrix index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl Neg for Matrix {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Matrix { rows: self.rows, cols: self.cols, data: self.data.into_iter().map(|x| -x).collect() }
+    }
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for r in 0..self.rows {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", Vector::new(self.row(r).to_vec()))?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! linalg_op {
+    ($lhs:ty, $rhs:ty, $out:ty, $trait:ident, $method:ident, $inner:ident) => {
+        impl $trait<$rhs> for $lhs {
+            type Output = Result<$out, i8>;
+            fn $method(self, other: $rhs) -> Result<$out, i8> {
+                self.$inner(&other)
+            }
+        }
+
+        impl<'a> $trait<&'a $rhs> for $lhs {
+            type Output = Result<$out, i8>;
+            fn $method(self, other: &'a $rhs) -> Result<$out, i8> {
+                self.$inner(other)
+            }
+        }
+    };
+}
+
+linalg_op!(Vector, Vector, Vector, Add, add, _add);
+linalg_op!(Vector, Vector, Vector, Sub, sub, _sub);
+linalg_op!(Matrix, Matrix, Matrix, Add, add, _add);
+linalg_op!(Matrix, Matrix, Matrix, Sub, sub, _sub);
+linalg_op!(Matrix, Matrix, Matrix, Mul, mul, _mul);
+linalg_op!(Matrix, Vector, Vector, Mul, mul, mul_vector);
@@ -0,0 +1,356 @@
+//! Support for the `int!`, `float!` and `complex!` macros: `const fn` checks that reject
+//! malformed literals while compiling, and the parsers the expansions call at run time.
+//!
+//! The checks see the literal's source text from `stringify!`, so a number token such as
+//! `0xFF` or `1.5e10` and a string such as `"0.(3)"` are both validated before the program
+//! runs. Nothing here is meant to be called directly.
+
+use crate::alloc_prelude::*;
+use crate::foundation::{Float, Int};
+use crate::functions::create_float;
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+
+const INT_SUFFIXES: [&str; 12] =
+    ["i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize"];
+const FLOAT_SUFFIXES: [&str; 2] = ["f32", "f64"];
+
+const fn is_digit(c: u8, radix: u32) -> bool {
+    match c {
+        b'0'..=b'9' => ((c - b'0') as u32) < radix,
+        b'a'..=b'f' | b'A'..=b'F' => radix == 16,
+        _ => false,
+    }
+}
+
+/// Index past the digits (and `_` separators when `underscores`) from `i`, and how many
+/// digits there were.
+const fn digit_run(b: &[u8], mut i: usize, end: usize, radix: u32, underscores: bool) -> (usize, usize) {
+    let mut count = 0;
+    while i < end {
+        if is_digit(b[i], radix) {
+            count += 1;
+        } else if !(underscores && b[i] == b'_') {
+            break;
+        }
+        i += 1;
+    }
+    (i, count)
+}
+
+const fn eq_ignore_case(b: &[u8], start: usize, end: usize, word: &str) -> bool {
+    let w = word.as_bytes();
+    if end - start != w.len() {
+        return false;
+    }
+    let mut k = 0;
+    while k < w.len() {
+        if b[start + k].to_ascii_lowercase() != w[k] {
+            return false;
+        }
+        k += 1;
+    }
+    true
+}
+
+/// Where a trailing type suffix out of `suffixes` starts in `b[start..end]`; `end`
+/// without one.
+const fn strip_suffix(b: &[u8], start: usize, end: usize, suffixes: &[&str]) -> usize {
+    let mut k = 0;
+    while k < suffixes.len() {
+        let suffix = suffixes[k].as_bytes();
+        if end - start > suffix.len() {
+            let at = end - suffix.len();
+            let mut j = 0;
+            while j < suffix.len() && b[at + j] == suffix[j] {
+                j += 1;
+            }
+            if j == suffix.len() {
+                return at;
+            }
+        }
+        k += 1;
+    }
+    end
+}
+
+/// `b[start..end]` without surrounding spaces.
+const fn trim(b: &[u8], mut start: usize, mut end: usize) -> (usize, usize) {
+    while start < end && b[start] == b' ' {
+        start += 1;
+    }
+    while end > start && b[end - 1] == b' ' {
+        end -= 1;
+    }
+    (start, end)
+}
+
+const fn skip_sign(b: &[u8], i: usize, end: usize) -> usize {
+    if i < end && (b[i] == b'+' || b[i] == b'-') { i + 1 } else { i }
+}
+
+/// Radix of a `0x` / `0o` / `0b` prefix at `i`, 10 without one.
+const fn radix_at(b: &[u8], i: usize, end: usize) -> u32 {
+    if i + 1 >= end || b[i] != b'0' {
+        return 10;
+    }
+    match b[i + 1] {
+        b'x' | b'X' => 16,
+        b'o' => 8,
+        b'b' => 2,
+        _ => 10,
+    }
+}
+
+/// `[0x|0o|0b]digits` spanning exactly `b[i..end]`.
+const fn is_radix_int(b: &[u8], i: usize, end: usize, underscores: bool) -> bool {
+    let radix = radix_at(b, i, end);
+    let i = if radix == 10 { i } else { i + 2 };
+    let (j, count) = digit_run(b, i, end, radix, underscores);
+    j == end && count > 0
+}
+
+/// Index past `digits[.digits][e[+-]digits]` from `i`, or `None` without a digit.
+const fn decimal_end(b: &[u8], i: usize, end: usize, underscores: bool) -> Option<usize> {
+    let (mut i, mut count) = digit_run(b, i, end, 10, underscores);
+    if i < end && b[i] == b'.' {
+        let (j, frac) = digit_run(b, i + 1, end, 10, underscores);
+        i = j;
+        count += frac;
+    }
+    if count == 0 {
+        return None;
+    }
+    if i < end && (b[i] == b'e' || b[i] == b'E') {
+        let (j, exp) = digit_run(b, skip_sign(b, i + 1, end), end, 10, underscores);
+        if exp == 0 {
+            return None;
+        }
+        i = j;
+    }
+    Some(i)
+}
+
+const fn is_decimal(b: &[u8], i: usize, end: usize) -> bool {
+    matches!(decimal_end(b, skip_sign(b, i, end), end, false), Some(j) if j == end)
+}
+
+/// The text between the quotes of a string literal token, or the whole token.
+const fn contents(b: &[u8]) -> (usize, usize, bool) {
+    let n = b.len();
+    if n >= 2 && b[0] == b'"' && b[n - 1] == b'"' { (1, n - 1, true) } else { (0, n, false) }
+}
+
+/// Whether `token` (as `stringify!` gives it) is an integer literal, optionally with a
+/// type suffix, or a string holding an optionally signed integer in any of those radixes.
+#[doc(hidden)]
+pub const fn is_int_literal(token: &str) -> bool {
+    let b = token.as_bytes();
+    let (start, end, quoted) = contents(b);
+    if quoted {
+        let (start, end) = trim(b, start, end);
+        let i = skip_sign(b, start, end);
+        return i < end && is_radix_int(b, i, end, true);
+    }
+    // integer suffixes start with `i` or `u`, which are not hex digits
+    let end = strip_suffix(b, start, end, &INT_SUFFIXES);
+    is_radix_int(b, start, end, true)
+}
+
+/// Whether `token` is an integer or float literal, optionally with a type suffix, or a
+/// string in one of the forms `create_float` reads: decimal with exponent, `nan` / `inf`,
+/// hexadecimal `0x1.8p3`, recurring `0.1(6)`, imaginary `2i` or `1-2i`, `3.14...` and
+/// `2√3`.
+#[doc(hidden)]
+pub const fn is_float_literal(token: &str) -> bool {
+    if is_int_literal(token) {
+        return true;
+    }
+    let b = token.as_bytes();
+    let (start, end, quoted) = contents(b);
+    if !quoted {
+        let end = strip_suffix(b, start, end, &FLOAT_SUFFIXES);
+        return matches!(decimal_end(b, start, end, true), Some(j) if j == end);
+    }
+    let (start, end) = trim(b, start, end);
+    if start == end {
+        return false;
+    }
+    let body = skip_sign(b, start, end);
+    if eq_ignore_case(b, start, end, "nan")
+        || eq_ignore_case(b, body, end, "inf")
+        || eq_ignore_case(b, body, end, "infinity")
+    {
+        return b[start] != b'+';
+    }
+    if radix_at(b, body, end) == 16 {
+        return is_hex_float(b, body + 2, end);
+    }
+    if b[end - 1] == b'i' || b[end - 1] == b'I' {
+        return is_imaginary(b, start, end - 1);
+    }
+    if end - start > 3 && b[end - 1] == b')' {
+        return is_recurring(b, body, end - 1);
+    }
+    let mut k = start;
+    while k < end {
+        if k + 3 <= end && b[k] == b'.' && b[k + 1] == b'.' && b[k + 2] == b'.' {
+            return is_decimal(b, start, k) && is_scale_suffix(b, k + 3, end);
+        }
+        // `√` is the UTF-8 bytes E2 88 9A
+        if k + 3 <= end && b[k] == 0xE2 && b[k + 1] == 0x88 && b[k + 2] == 0x9A {
+            let (r, digits) = digit_run(b, k + 3, end, 10, false);
+            return (skip_sign(b, start, k) == k || is_decimal(b, start, k)) && r == end && digits > 0;
+        }
+        k += 1;
+    }
+    is_decimal(b, start, end)
+}
+
+/// `hex[.hex][p[+-]digits]` spanning `b[i..end]`.
+const fn is_hex_float(b: &[u8], i: usize, end: usize) -> bool {
+    let (mut i, mut count) = digit_run(b, i, end, 16, false);
+    if i < end && b[i] == b'.' {
+        let (j, frac) = digit_run(b, i + 1, end, 16, false);
+        i = j;
+        count += frac;
+    }
+    if count == 0 {
+        return false;
+    }
+    if i < end && (b[i] == b'p' || b[i] == b'P') {
+        let (j, exp) = digit_run(b, skip_sign(b, i + 1, end), end, 10, false);
+        return j == end && exp > 0;
+    }
+    i == end
+}
+
+/// `b[start..end]` as what precedes the `i` of an imaginary literal: nothing, a sign, a
+/// decimal, or a decimal real part followed by a signed (possibly bare) imaginary part.
+const fn is_imaginary(b: &[u8], start: usize, end: usize) -> bool {
+    if skip_sign(b, start, end) == end || is_decimal(b, start, end) {
+        return true;
+    }
+    let Some(split) = decimal_end(b, skip_sign(b, start, end), end, false) else {
+        return false;
+    };
+    split < end && (b[split] == b'+' || b[split] == b'-') && (split + 1 == end || is_decimal(b, split + 1, end))
+}
+
+/// `digits[.digits](digits` spanning `b[i..end]`; the caller strips the `)`.
+const fn is_recurring(b: &[u8], i: usize, end: usize) -> bool {
+    let (mut i, count) = digit_run(b, i, end, 10, false);
+    if i < end && b[i] == b'.' {
+        i = digit_run(b, i + 1, end, 10, false).0;
+    }
+    if count == 0 || i >= end || b[i] != b'(' {
+        return false;
+    }
+    let (j, repetend) = digit_run(b, i + 1, end, 10, false);
+    j == end && repetend > 0
+}
+
+/// Nothing, or `{digits}`, after the `...` of an irrational literal.
+const fn is_scale_suffix(b: &[u8], i: usize, end: usize) -> bool {
+    if i == end {
+        return true;
+    }
+    let (j, digits) = digit_run(b, i + 1, end, 10, false);
+    b[i] == b'{' && digits > 0 && j + 1 == end && b[j] == b'}'
+}
+
+/// The string's contents without surrounding spaces, or the token without its type
+/// suffix and `_` separators.
+fn clean(token: &str, suffixes: &[&str]) -> String {
+    if let Some(inner) = token.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        return inner.trim().replace('_', "");
+    }
+    let end = strip_suffix(token.as_bytes(), 0, token.len(), suffixes);
+    token[..end].replace('_', "")
+}
+
+/// The `Int` of a literal `is_int_literal` accepted, negated when `negative`.
+#[doc(hidden)]
+pub fn int_literal(token: &str, negative: bool) -> Int {
+    let body = clean(token, &INT_SUFFIXES);
+    let (sign, digits) = match body.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, body.strip_prefix('+').unwrap_or(&body)),
+    };
+    let radix = radix_at(digits.as_bytes(), 0, digits.len());
+    let digits = if radix == 10 { digits } else { &digits[2..] };
+    let value = BigInt::parse_bytes(digits.as_bytes(), radix).unwrap_or_default();
+    Int::Big(if sign != negative { -value } else { value })
+}
+
+/// The `Float` of a literal `is_float_literal` accepted, negated when `negative`.
+#[doc(hidden)]
+pub fn float_literal(token: &str, negative: bool) -> Float {
+    let value = if token.starts_with('"') || !is_int_literal(token) {
+        create_float(&clean(token, &FLOAT_SUFFIXES))
+    } else {
+        let int = int_literal(token, false);
+        Float::Big(BigDecimal::from(int.to_bigint().unwrap_or_default()))
+    };
+    if negative { -value } else { value }
+}
+
+/// Creates an `Int` from an integer literal such as `int!(255)`, `int!(-0xFF)` or
+/// `int!(1_000u64)`, or from a string, `int!("-123")`. Literals are checked while
+/// compiling, so `int!(1.5)` or `int!("12x")` does not build; any other expression is
+/// parsed at run time by `create_int`. Needs no imports besides the macro.
+#[macro_export]
+macro_rules! int {
+    (- $lit:literal) => {{
+        const _: () = assert!($crate::literal::is_int_literal(stringify!($lit)), "invalid integer literal");
+        $crate::literal::int_literal(stringify!($lit), true)
+    }};
+    ($lit:literal) => {{
+        const _: () = assert!($crate::literal::is_int_literal(stringify!($lit)), "invalid integer literal");
+        $crate::literal::int_literal(stringify!($lit), false)
+    }};
+    ($val:expr) => {
+        $crate::create_int($val)
+    };
+}
+
+/// Creates a `Float` from a number literal such as `float!(1.5e10)`, `float!(-2)` or
+/// `float!(0xFF)`, or from a string in any form `create_float` reads, `float!("0.(3)")`.
+/// Literals are checked while compiling, so `float!("1.2.3")` does not build; any other
+/// expression is parsed at run time by `create_float`. Needs no imports besides the macro.
+#[macro_export]
+macro_rules! float {
+    (- $lit:literal) => {{
+        const _: () = assert!($crate::literal::is_float_literal(stringify!($lit)), "invalid float literal");
+        $crate::literal::float_literal(stringify!($lit), true)
+    }};
+    ($lit:literal) => {{
+        const _: () = assert!($crate::literal::is_float_literal(stringify!($lit)), "invalid float literal");
+        $crate::literal::float_literal(stringify!($lit), false)
+    }};
+    ($val:expr) => {
+        $crate::create_float($val)
+    };
+}
+
+/// Creates a complex `Float` from its real and imaginary parts, each a literal `float!`
+/// takes: `complex!(3, 4)` is `3 + 4i`, `complex!(-0.5, "1e-3")` is `-0.5 + 0.001i`.
+/// Other expressions are strings parsed at run time by `create_complex`.
+#[macro_export]
+macro_rules! complex {
+    (- $re:literal, - $im:literal) => {
+        $crate::Float::complex($crate::float!(-$re), $crate::float!(-$im))
+    };
+    (- $re:literal, $im:literal) => {
+        $crate::Float::complex($crate::float!(-$re), $crate::float!($im))
+    };
+    ($re:literal, - $im:literal) => {
+        $crate::Float::complex($crate::float!($re), $crate::float!(-$im))
+    };
+    ($re:literal, $im:literal) => {
+        $crate::Float::complex($crate::float!($re), $crate::float!($im))
+    };
+    ($re:expr, $im:expr) => {
+        $crate::create_complex($re, $im)
+    };
+}
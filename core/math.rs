@@ -1,8 +1,11 @@
+use crate::alloc_prelude::*;
 use bigdecimal::num_bigint::BigInt;
 use bigdecimal::num_bigint::ToBigInt;
 use bigdecimal::{BigDecimal, Zero};
 use num_traits::{FromPrimitive, Signed, ToPrimitive};
-use std::str::FromStr;
+use core::str::FromStr;
+
+use crate::foundation::precision;
 
 use num_integer::Integer;
 
@@ -16,10 +19,11 @@ pub const ERR_NUMBER_TOO_LARGE: i8 = 5;
 pub const ERR_INFINITE_RESULT: i8 = 6;
 pub const ERR_WRONG_SYNTAX: i8 = 7;
 
+/// `ln 10` to 136 decimals (last digit rounded). `consts::ln10` computes it to any precision.
 pub const LN_10: &str = "2.3025850929940456840179914546843642076011014886287729760333279009675726096773524802359972050895982983419677840422862486334095254650828068";
 
-type IntResult<T> = std::result::Result<(T, bool), i8>;
-type FloatResult<T> = std::result::Result<(T, i32, bool), i8>;
+type IntResult<T> = core::result::Result<(T, bool), i8>;
+type FloatResult<T> = core::result::Result<(T, i32, bool), i8>;
 
 fn parse_positive_digits(s: &str) -> Result<BigInt, i8> {
     if s.is_empty() {
@@ -114,37 +118,47 @@ pub fn pow_strings(base: &str, exponent: &str) -> IntResult<String> {
 
 pub fn sqrt_string(a: &str) -> IntResult<String> {
     let a = parse_positive_digits(a)?;
-    if a.is_zero() {
-        return Ok(("0".to_string(), false));
-    }
-    let mut low = BigInt::from(0);
-    let mut high = a.clone();
-    while &low < &high {
-        let mid = (&low + &high + 1u32) >> 1u32;
-        let sq = &mid * &mid;
-        if sq <= a {
+    Ok((nth_root_bigint(&a, 2).to_string(), false))
+}
+
+/// Floor of the `n`-th root of a non-negative BigInt, found by binary search between
+/// bounds derived from the bit length. `n` must be at least 1.
+pub fn nth_root_bigint(a: &BigInt, n: u32) -> BigInt {
+    if a.is_zero() || n == 1 {
+        return a.clone();
+    }
+    let bits = a.bits();
+    let mut low = BigInt::from(1u32) << ((bits - 1) / n as u64);
+    let mut high = BigInt::from(1u32) << ((bits - 1) / n as u64 + 1);
+    while low < high {
+        let mid: BigInt = (&low + &high + 1u32) >> 1u32;
+        if mid.pow(n) <= *a {
             low = mid;
         } else {
             high = mid - 1u32;
         }
     }
-    Ok((low.to_string(), false))
+    low
+}
+
+/// 128-bit FNV-1a. Used for `fingerprint()`, so the constants and byte order must never change.
+pub fn fnv1a_128(bytes: &[u8]) -> u128 {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    let mut hash = OFFSET;
+    for b in bytes {
+        hash ^= *b as u128;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
 }
 
 fn to_bigdecimal(mant: &str, exp: i32, neg: bool) -> BigDecimal {
-    let mant_len = mant.len() as i32;
-    let decimal_pos = mant_len + exp;
-    let s = if decimal_pos <= 0 {
-        let zeros = "0".repeat((-decimal_pos) as usize);
-        format!("0.{}{}", zeros, mant)
-    } else if decimal_pos >= mant_len {
-        let zeros = "0".repeat((decimal_pos - mant_len) as usize);
-        format!("{}{}", mant, zeros)
-    } else {
-        let (int_part, frac_part) = mant.split_at(decimal_pos as usize);
-        format!("{}.{}", int_part, frac_part)
+    // mant · 10^exp, without writing out the zeros of a large exponent
+    let bd = match BigInt::from_str(mant) {
+        Ok(m) => BigDecimal::new(m, -(exp as i64)),
+        Err(_) => BigDecimal::zero(),
     };
-    let bd = BigDecimal::from_str(&s).unwrap_or_else(|_| BigDecimal::zero());
     if neg { -bd } else { bd }
 }
 
@@ -155,7 +169,7 @@ pub fn from_bigdecimal(bd: &BigDecimal) -> (String, i32, bool) {
     if s == "0" || s.is_empty() {
         return ("0".to_string(), 0, false);
     }
-    let parts: Vec<&str> = s.split('E').collect();
+    let parts: Vec<&str> = s.split(['e', 'E']).collect();
     let (base, exp_part) = if parts.len() == 2 {
         (parts[0], parts[1])
     } else {
@@ -173,11 +187,13 @@ pub fn from_bigdecimal(bd: &BigDecimal) -> (String, i32, bool) {
     (mant, final_exp, neg)
 }
 
+/// Cuts `bd` to `decimals` places after the point, counted from the first significant
+/// digit for values below 0.1 so tiny results (`exp(-500)`) do not truncate to zero.
 fn truncate_bd_to_decimals(bd: &BigDecimal, decimals: usize) -> BigDecimal {
-    bd.with_scale(decimals as i64)
+    let int_digits = bd.digits() as i64 - bd.fractional_digit_count();
+    bd.with_scale(decimals as i64 + (-int_digits).max(0))
 }
 
-#[allow(dead_code)]
 pub fn bigdecimal_to_fraction(bd: &BigDecimal) -> (BigInt, BigInt) {
     let s = bd.normalized().to_string();
     let mut lower = s;
@@ -185,7 +201,7 @@ pub fn bigdecimal_to_fraction(bd: &BigDecimal) -> (BigInt, BigInt) {
     if neg {
         lower = lower.trim_start_matches('-').to_string();
     }
-    let parts: Vec<&str> = lower.split('E').collect();
+    let parts: Vec<&str> = lower.split(['e', 'E']).collect();
     let (base, exp_part) = if parts.len() == 2 {
         (parts[0], parts[1])
     } else {
@@ -227,6 +243,103 @@ pub fn bigdecimal_to_fraction(bd: &BigDecimal) -> (BigInt, BigInt) {
     }
 }
 
+/// Bounds of an IEEE 754 binary format, with every value written as `q * 2^e` for an
+/// integer `q < 2^significand_bits`.
+struct BinaryFormat {
+    significand_bits: u32,
+    /// `e` of the subnormals.
+    min_exp: i64,
+    /// `e` of the largest finite values.
+    max_exp: i64,
+}
+
+const BINARY64: BinaryFormat = BinaryFormat { significand_bits: 53, min_exp: -1074, max_exp: 971 };
+const BINARY32: BinaryFormat = BinaryFormat { significand_bits: 24, min_exp: -149, max_exp: 104 };
+
+/// `|num| / den` (`den > 0`) rounded half to even into `fmt`, as the magnitude bits, or
+/// `None` when it rounds past the largest finite value. Too small a value gives `0` or a
+/// subnormal.
+fn ratio_to_bits(num: &BigInt, den: &BigInt, fmt: &BinaryFormat) -> Option<u64> {
+    let n = num.abs();
+    if n.is_zero() {
+        return Some(0);
+    }
+    let p = i64::from(fmt.significand_bits);
+    let mut lg = n.bits() as i64 - den.bits() as i64;
+    if lg > fmt.max_exp + p {
+        return None;
+    }
+    if lg < fmt.min_exp - 1 {
+        return Some(0);
+    }
+    let below = if lg >= 0 { n < (den << lg as usize) } else { (&n << (-lg) as usize) < *den };
+    if below {
+        lg -= 1;
+    }
+    let mut e = (lg - (p - 1)).max(fmt.min_exp);
+    let (n, d) = if e >= 0 { (n, den << e as usize) } else { (n << (-e) as usize, den.clone()) };
+    let (mut q, r) = n.div_rem(&d);
+    let twice = r << 1usize;
+    if twice > d || (twice == d && q.is_odd()) {
+        q += 1;
+    }
+    if q.bits() as i64 > p {
+        q >>= 1usize;
+        e += 1;
+    }
+    if e > fmt.max_exp {
+        return None;
+    }
+    let q = q.to_u64()?;
+    Some((((e - fmt.min_exp) as u64) << (p - 1)) + q)
+}
+
+/// `num / den` (`den > 0`) as the nearest `f64`, ties to even; overflows to an infinity
+/// and underflows to a subnormal or a signed zero, like `str::parse::<f64>`.
+pub(crate) fn ratio_to_f64(num: &BigInt, den: &BigInt) -> f64 {
+    let v = ratio_to_bits(num, den, &BINARY64).map_or(f64::INFINITY, f64::from_bits);
+    if num.is_negative() { -v } else { v }
+}
+
+/// `ratio_to_f64` for `f32`.
+pub(crate) fn ratio_to_f32(num: &BigInt, den: &BigInt) -> f32 {
+    let v = ratio_to_bits(num, den, &BINARY32).map_or(f32::INFINITY, |b| f32::from_bits(b as u32));
+    if num.is_negative() { -v } else { v }
+}
+
+/// `bd` as the fraction `mantissa / 10^scale`, unreduced. Past the range of `f64` (either
+/// way) it is replaced by `±2^2000` or `±2^-2000`, which round the same in both formats and
+/// keep the powers of ten small.
+fn bigdecimal_binary_ratio(bd: &BigDecimal) -> (BigInt, BigInt) {
+    let (m, scale) = bd.as_bigint_and_exponent();
+    let one = BigInt::from(if m.is_negative() { -1 } else { 1 });
+    let adjusted = bd.digits() as i64 - scale - 1;
+    if !m.is_zero() && adjusted > 310 {
+        return (one << 2000usize, BigInt::from(1u8));
+    }
+    if !m.is_zero() && adjusted < -400 {
+        return (one, BigInt::from(1u8) << 2000usize);
+    }
+    let ten = BigInt::from(10u8);
+    if scale >= 0 {
+        (m, ten.pow(scale as u32))
+    } else {
+        (m * ten.pow((-scale) as u32), BigInt::from(1u8))
+    }
+}
+
+/// `bd` as the nearest `f64`; see `ratio_to_f64`.
+pub(crate) fn bigdecimal_to_f64(bd: &BigDecimal) -> f64 {
+    let (num, den) = bigdecimal_binary_ratio(bd);
+    ratio_to_f64(&num, &den)
+}
+
+/// `bd` as the nearest `f32`; see `ratio_to_f64`.
+pub(crate) fn bigdecimal_to_f32(bd: &BigDecimal) -> f32 {
+    let (num, den) = bigdecimal_binary_ratio(bd);
+    ratio_to_f32(&num, &den)
+}
+
 pub fn bigdecimal_pow_integer(mut base: BigDecimal, exp: BigInt) -> BigDecimal {
     if exp.is_zero() {
         return BigDecimal::from(1);
@@ -467,11 +580,12 @@ pub fn mod_float(
     if b.is_zero() {
         return Err(ERR_DIV_BY_ZERO);
     }
-    let div_floor = BigDecimal::from(
-        a.with_scale(0).to_bigint().unwrap()
-            / b.with_scale(0).to_bigint().unwrap_or(BigInt::from(1u32)),
-    );
-    let res = a - b * div_floor;
+    // truncated quotient, computed on integers at a common scale so fractional divisors work
+    let scale = a.fractional_digit_count().max(b.fractional_digit_count());
+    let (a_int, _) = a.with_scale(scale).into_bigint_and_exponent();
+    let (b_int, _) = b.with_scale(scale).into_bigint_and_exponent();
+    let quotient = BigDecimal::from(a_int / b_int);
+    let res = a - b * quotient;
     Ok(from_bigdecimal(&res))
 }
 
@@ -503,63 +617,147 @@ fn float_from_f64_to_parts(mut v: f64) -> (String, i32, bool) {
     }
 }
 
-pub fn sin_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
-    let bd = to_bigdecimal(&mant, exp, neg);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.sin();
-    if res.is_nan() {
-        return Err(ERR_INVALID_FORMAT);
+/// Guard digits carried through trig, `ln` and `exp` on top of `precision()`.
+const GUARD_DIGITS: u64 = 10;
+
+/// The most digits a trig argument may have before the decimal point. Reducing it needs π
+/// to that many digits, so past `10^10000` trig fails with `NUMBER_TOO_LARGE` rather than
+/// running for minutes.
+const MAX_TRIG_INT_DIGITS: i64 = 10_000;
+
+/// Reduces `x` to `r` in `[-π/4, π/4]` with `x = r + k·π/2`, returning `r` and `k mod 4`.
+///
+/// π is carried to as many digits as `x` has before the decimal point plus `digits` and
+/// a margin, so `r` keeps `digits` significant digits even for `1e40`, where an `f64` has
+/// no digits left below 2π. When `x` lies so close to a multiple of π/2 that `r` cancels
+/// down to fewer digits, the margin doubles and the reduction is redone.
+fn reduce_half_pi(x: &BigDecimal, digits: u64) -> (BigDecimal, u8) {
+    let int_digits = (x.digits() as i64 - x.fractional_digit_count()).max(1);
+    let needed = (digits + GUARD_DIGITS) as i64;
+    let mut margin = needed;
+    loop {
+        let prec = int_digits + margin;
+        let half_pi = crate::consts::pi_scaled(prec as u64) / 2u32;
+        let (x_scaled, _) = x.with_scale(prec).into_bigint_and_exponent();
+        let (mut k, mut r) = x_scaled.div_mod_floor(&half_pi);
+        if &r * 2u32 > half_pi {
+            k += 1u32;
+            r -= &half_pi;
+        }
+        // r is good to about 10^-margin
+        let r = BigDecimal::new(r, prec);
+        if !r.is_zero() && r.order_of_magnitude() + margin >= needed {
+            let quadrant = k.mod_floor(&BigInt::from(4u32)).to_u8().unwrap_or(0);
+            return (r, quadrant);
+        }
+        margin *= 2;
     }
-    if res.is_infinite() {
-        return Err(ERR_INFINITE_RESULT);
+}
+
+/// `(sin r, cos r)` from their Taylor series to `digits` significant digits, for
+/// `|r| <= π/4` where neither series cancels.
+fn sin_cos_taylor(r: &BigDecimal, digits: u64) -> (BigDecimal, BigDecimal) {
+    let working = core::num::NonZeroU64::new(digits).unwrap();
+    let r_sq = r.square().with_precision_round(working, bigdecimal::RoundingMode::HalfEven);
+    let series = |first: BigDecimal, offset: u64| {
+        let eps = first.abs() * ten_to_minus(digits);
+        let mut term = first.clone();
+        let mut sum = first;
+        let mut k = 1u64;
+        loop {
+            let den = BigDecimal::from((2 * k - 1 + offset) * (2 * k + offset));
+            term = -div_bigdecimal(&(term * &r_sq), &den, working);
+            if term.abs() <= eps {
+                break;
+            }
+            sum += &term;
+            k += 1;
+        }
+        sum
+    };
+    (series(r.clone(), 1), series(BigDecimal::from(1), 0))
+}
+
+/// `(sin x, cos x)` for an exact decimal `x` to `precision()` significant digits plus
+/// guard digits, reducing large arguments with high-precision π first. Fails with
+/// `NUMBER_TOO_LARGE` when `x` has more than `MAX_TRIG_INT_DIGITS` integer digits.
+fn sin_cos_bigdecimal(x: &BigDecimal) -> Result<(BigDecimal, BigDecimal), i8> {
+    let digits = precision() + GUARD_DIGITS;
+    if x.is_zero() {
+        return Ok((BigDecimal::zero(), BigDecimal::from(1)));
     }
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    if x.digits() as i64 - x.fractional_digit_count() > MAX_TRIG_INT_DIGITS {
+        return Err(ERR_NUMBER_TOO_LARGE);
+    }
+    let (r, quadrant) = if x.abs() < BigDecimal::new(BigInt::from(78), 2) {
+        (x.clone(), 0)
+    } else {
+        reduce_half_pi(x, digits)
+    };
+    let (s, c) = sin_cos_taylor(&r, digits);
+    Ok(match quadrant {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    })
+}
+
+/// `tan x` as `sin x / cos x` from `sin_cos_bigdecimal`.
+fn tan_bigdecimal(x: &BigDecimal) -> Result<BigDecimal, i8> {
+    let (s, c) = sin_cos_bigdecimal(x)?;
+    Ok(div_bigdecimal(&s, &c, core::num::NonZeroU64::new(precision() + GUARD_DIGITS).unwrap()))
 }
 
+/// A transcendental result rounded to `precision()` significant digits, as irrational parts.
+fn rounded_parts(v: BigDecimal) -> (String, i32, bool, bool) {
+    let digits = core::num::NonZeroU64::new(precision()).unwrap();
+    let (m, e, n) = from_bigdecimal(&v.with_precision_round(digits, bigdecimal::RoundingMode::HalfEven).normalized());
+    (m, e, n, true)
+}
+
+pub fn sin_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
+    let bd = to_bigdecimal(&mant, exp, neg);
+    Ok(rounded_parts(sin_cos_bigdecimal(&bd)?.0))
+}
+
+/// `√x` to `precision()` significant digits; exact, and not irrational, when the rounded
+/// root squares back to `x`.
 pub fn sqrt_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
     let bd = to_bigdecimal(&mant, exp, neg);
     if bd.is_negative() {
         return Err(ERR_NEGATIVE_SQRT);
     }
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.sqrt();
-    if res.is_nan() {
-        return Err(ERR_INVALID_FORMAT);
-    }
-    if res.is_infinite() {
-        return Err(ERR_INFINITE_RESULT);
+    let working = core::num::NonZeroU64::new(precision() + GUARD_DIGITS).unwrap();
+    let root = bd
+        .sqrt_with_context(&bigdecimal::Context::default().with_precision(working))
+        .ok_or(ERR_INVALID_FORMAT)?;
+    let (m, e, n, _) = rounded_parts(root.clone());
+    if to_bigdecimal(&m, e, n).square() == bd {
+        return Ok((m, e, n, false));
     }
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m2, e2, neg2) = from_bigdecimal(&trunc);
-    let is_irrational = e2 < 0;
-    Ok((m2, e2, neg2, is_irrational))
+    Ok(rounded_parts(root))
 }
 
+/// Sine and cosine of the same argument, sharing the conversion and range reduction.
+#[allow(clippy::type_complexity)]
+pub fn sin_cos_float(
+    mant: String,
+    exp: i32,
+    neg: bool,
+) -> Result<((String, i32, bool, bool), (String, i32, bool, bool)), i8> {
+    let bd = to_bigdecimal(&mant, exp, neg);
+    let (s, c) = sin_cos_bigdecimal(&bd)?;
+    Ok((rounded_parts(s), rounded_parts(c)))
+}
 pub fn cos_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
     let bd = to_bigdecimal(&mant, exp, neg);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.cos();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    Ok(rounded_parts(sin_cos_bigdecimal(&bd)?.1))
 }
 
 pub fn tan_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
     let bd = to_bigdecimal(&mant, exp, neg);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.tan();
-    if res.is_nan() {
-        return Err(ERR_INVALID_FORMAT);
-    }
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    Ok(rounded_parts(tan_bigdecimal(&bd)?))
 }
 
 pub fn ln_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
@@ -567,25 +765,12 @@ pub fn ln_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool,
     if bd.is_negative() || bd.is_zero() {
         return Err(ERR_INVALID_FORMAT);
     }
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.ln();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    Ok(rounded_parts(ln_bigdecimal(&bd, precision() + GUARD_DIGITS)))
 }
 
 pub fn exp_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
     let bd = to_bigdecimal(&mant, exp, neg);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.exp();
-    if res.is_infinite() {
-        return Err(ERR_INFINITE_RESULT);
-    }
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    Ok(rounded_parts(exp_bigdecimal(&bd, precision() + GUARD_DIGITS)?))
 }
 
 pub fn log10_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool, bool), i8> {
@@ -593,12 +778,185 @@ pub fn log10_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bo
     if bd.is_negative() || bd.is_zero() {
         return Err(ERR_INVALID_FORMAT);
     }
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.log10();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    // exact for powers of ten
+    let (m, e) = bd.normalized().into_bigint_and_exponent();
+    if m == BigInt::from(1) {
+        let (m, e, neg2) = from_bigdecimal(&BigDecimal::from(-e));
+        return Ok((m, e, neg2, false));
+    }
+    let digits = precision() + GUARD_DIGITS;
+    let ln10 = crate::consts::ln10(digits + 1);
+    let res = div_bigdecimal(&ln_bigdecimal(&bd, digits), &ln10, core::num::NonZeroU64::new(digits).unwrap());
+    Ok(rounded_parts(res))
+}
+
+/// `ln x` for `x > 0` to `digits` significant digits. Near 1 it is `ln_1p` directly;
+/// otherwise `x = y·2^k·10^e` with `1 <= y < 2` and `ln x = ln y + k ln 2 + e ln 10`,
+/// so arguments far outside the `f64` range lose nothing.
+pub(crate) fn ln_bigdecimal(x: &BigDecimal, digits: u64) -> BigDecimal {
+    let one = BigDecimal::from(1);
+    let two = BigDecimal::from(2);
+    if x >= &BigDecimal::new(BigInt::from(5), 1) && x < &two {
+        return ln_1p_bigdecimal(&(x - &one), digits);
+    }
+    let e = x.order_of_magnitude();
+    let mut y = x * BigDecimal::new(BigInt::from(1), e);
+    let mut k = 0u32;
+    while y >= two {
+        y = y.half();
+        k += 1;
+    }
+    // |ln x| >= ln 2 here, so `digits` decimals on top of e's size are enough
+    let decimals = digits + e.unsigned_abs().to_string().len() as u64 + 1;
+    let sum = ln_1p_bigdecimal(&(y - one), digits + 1)
+        + crate::consts::ln2(decimals) * BigDecimal::from(k)
+        + crate::consts::ln10(decimals) * BigDecimal::from(e);
+    sum.with_precision_round(core::num::NonZeroU64::new(digits).unwrap(), bigdecimal::RoundingMode::HalfEven)
+}
+
+/// `e^x` to `digits` significant digits as `10^k · e^r` with `x = k ln 10 + r` and
+/// `|r| <= ln 10 / 2`. Fails with `INFINITE_RESULT` for `x >= 10^15`; below `-10^15`
+/// the result underflows to zero.
+pub(crate) fn exp_bigdecimal(x: &BigDecimal, digits: u64) -> Result<BigDecimal, i8> {
+    let limit = BigDecimal::from(1_000_000_000_000_000u64);
+    if x.abs() >= limit {
+        return if x.is_positive() { Err(ERR_INFINITE_RESULT) } else { Ok(BigDecimal::zero()) };
+    }
+    if x.is_zero() {
+        return Ok(BigDecimal::from(1));
+    }
+    // k has at most 15 digits, so ln 10 needs that many more decimals for r
+    let decimals = digits + 17;
+    let ln10 = crate::consts::ln10(decimals);
+    let k = div_bigdecimal(x, &ln10, core::num::NonZeroU64::new(decimals).unwrap())
+        .with_scale_round(0, bigdecimal::RoundingMode::HalfEven);
+    let r = x - &ln10 * &k;
+    let (k, _) = k.into_bigint_and_exponent();
+    let k = k.to_i64().ok_or(ERR_INFINITE_RESULT)?;
+    let (value, scale) = (exp_m1_bigdecimal(&r, digits + 2) + BigDecimal::from(1)).into_bigint_and_exponent();
+    Ok(BigDecimal::new(value, scale - k)
+        .with_precision_round(core::num::NonZeroU64::new(digits).unwrap(), bigdecimal::RoundingMode::HalfEven))
+}
+
+/// `10^-n` as a `BigDecimal`.
+fn ten_to_minus(n: u64) -> BigDecimal {
+    BigDecimal::new(BigInt::from(1), n as i64)
+}
+
+/// `a / b` to `digits` significant digits; `BigDecimal`'s `/` stops at 100.
+pub(crate) fn div_bigdecimal(a: &BigDecimal, b: &BigDecimal, digits: core::num::NonZeroU64) -> BigDecimal {
+    crate::backend::backend().decimal_div(a, b, digits)
+}
+
+/// `e^x - 1` to `digits` significant digits from the Taylor series `Σ x^k / k!`, which
+/// starts at `x` and so never subtracts two nearly equal numbers. Meant for `|x| < 1`.
+pub fn exp_m1_bigdecimal(x: &BigDecimal, digits: u64) -> BigDecimal {
+    if x.is_zero() {
+        return BigDecimal::zero();
+    }
+    let working = core::num::NonZeroU64::new(digits + 10).unwrap();
+    let eps = x.abs() * ten_to_minus(digits + 5);
+    let mut term = x.clone();
+    let mut sum = x.clone();
+    let mut k = 2u32;
+    loop {
+        term = div_bigdecimal(&(term * x), &BigDecimal::from(k), working);
+        if term.abs() < eps {
+            break;
+        }
+        sum += &term;
+        k += 1;
+    }
+    sum.with_precision_round(core::num::NonZeroU64::new(digits).unwrap(), bigdecimal::RoundingMode::HalfEven)
+}
+
+/// `ln(1 + x)` to `digits` significant digits as `2 atanh(x / (2 + x))`, whose series
+/// `Σ y^(2k+1) / (2k+1)` starts at `y ≈ x / 2` instead of forming `1 + x` first.
+/// Meant for `-0.5 <= x < 1`, where `|y| <= 1/3`.
+pub fn ln_1p_bigdecimal(x: &BigDecimal, digits: u64) -> BigDecimal {
+    if x.is_zero() {
+        return BigDecimal::zero();
+    }
+    let working = core::num::NonZeroU64::new(digits + 10).unwrap();
+    let round = |v: BigDecimal| v.with_precision_round(working, bigdecimal::RoundingMode::HalfEven);
+    let y = div_bigdecimal(x, &(BigDecimal::from(2) + x), working);
+    let y_sq = round(&y * &y);
+    let eps = y.abs() * ten_to_minus(digits + 5);
+    let mut power = y.clone();
+    let mut sum = y;
+    let mut k = 1u32;
+    loop {
+        power = round(power * &y_sq);
+        let term = div_bigdecimal(&power, &BigDecimal::from(2 * k + 1), working);
+        if term.abs() < eps {
+            break;
+        }
+        sum += term;
+        k += 1;
+    }
+    (sum * BigDecimal::from(2)).with_precision_round(core::num::NonZeroU64::new(digits).unwrap(), bigdecimal::RoundingMode::HalfEven)
+}
+
+/// `atan x` to `digits` significant digits. Beyond `±1` it is `±π/2 - atan(1/x)`; inside,
+/// `atan x = 2 atan(x / (1 + √(1 + x²)))` halves the argument until `|x| <= 1/100`, where
+/// the series `Σ (-1)^k x^(2k+1) / (2k+1)` gains two digits a term.
+pub fn atan_bigdecimal(x: &BigDecimal, digits: u64) -> BigDecimal {
+    if x.is_zero() {
+        return BigDecimal::zero();
+    }
+    let target = core::num::NonZeroU64::new(digits).unwrap();
+    let working = core::num::NonZeroU64::new(digits + 10).unwrap();
+    let round = |v: BigDecimal| v.with_precision_round(working, bigdecimal::RoundingMode::HalfEven);
+    let one = BigDecimal::from(1);
+    if x.abs() > one {
+        let half_pi = crate::consts::pi(digits + 10).half();
+        let inner = atan_bigdecimal(&div_bigdecimal(&one, x, working), digits + 10);
+        let value = if x.is_negative() { -half_pi - inner } else { half_pi - inner };
+        return value.with_precision_round(target, bigdecimal::RoundingMode::HalfEven);
+    }
+    let ctx = bigdecimal::Context::default().with_precision(working);
+    let small = BigDecimal::new(BigInt::from(1), 2);
+    let mut y = x.clone();
+    let mut halvings = 0u32;
+    while y.abs() > small {
+        let root = (&one + round(&y * &y)).sqrt_with_context(&ctx).unwrap_or_else(|| one.clone());
+        y = div_bigdecimal(&y, &(&one + root), working);
+        halvings += 1;
+    }
+    let y_sq = round(&y * &y);
+    let eps = y.abs() * ten_to_minus(digits + 5);
+    let mut power = y.clone();
+    let mut sum = y;
+    let mut k = 1u32;
+    loop {
+        power = -round(power * &y_sq);
+        let term = div_bigdecimal(&power, &BigDecimal::from(2 * k + 1), working);
+        if term.abs() < eps {
+            break;
+        }
+        sum += term;
+        k += 1;
+    }
+    (sum * BigDecimal::from(1u64 << halvings)).with_precision_round(target, bigdecimal::RoundingMode::HalfEven)
+}
+
+/// The angle of the point `(x, y)` in `(-π, π]` to `digits` significant digits:
+/// `atan(y / x)`, moved by `±π` into the left half-plane. `x` and `y` are not both zero.
+pub fn atan2_bigdecimal(y: &BigDecimal, x: &BigDecimal, digits: u64) -> BigDecimal {
+    let target = core::num::NonZeroU64::new(digits).unwrap();
+    let pi = crate::consts::pi(digits + 10);
+    let value = if x.is_zero() {
+        if y.is_negative() { -pi.half() } else { pi.half() }
+    } else {
+        let ratio = div_bigdecimal(y, x, core::num::NonZeroU64::new(digits + 10).unwrap());
+        let angle = atan_bigdecimal(&ratio, digits + 10);
+        match (x.is_negative(), y.is_negative()) {
+            (false, _) => angle,
+            (true, true) => angle - pi,
+            (true, false) => angle + pi,
+        }
+    };
+    value.with_precision_round(target, bigdecimal::RoundingMode::HalfEven)
 }
 
 pub fn floor_float(mant: String, exp: i32, neg: bool) -> Result<(String, i32, bool), i8> {
@@ -628,12 +986,7 @@ pub fn abs_float(mant: String, exp: i32, _neg: bool) -> Result<(String, i32, boo
 
 pub fn sin_int(digits: String, negative: bool) -> Result<(String, i32, bool, bool), i8> {
     let bd = to_bigdecimal(&digits, 0, negative);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.sin();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    Ok(rounded_parts(sin_cos_bigdecimal(&bd)?.0))
 }
 
 pub fn sqrt_int(digits: String, negative: bool) -> Result<(String, i32, bool, bool), i8> {
@@ -658,45 +1011,20 @@ pub fn sqrt_int(digits: String, negative: bool) -> Result<(String, i32, bool, bo
 
 pub fn cos_int(digits: String, negative: bool) -> Result<(String, i32, bool, bool), i8> {
     let bd = to_bigdecimal(&digits, 0, negative);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.cos();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    Ok(rounded_parts(sin_cos_bigdecimal(&bd)?.1))
 }
 
 pub fn tan_int(digits: String, negative: bool) -> Result<(String, i32, bool, bool), i8> {
     let bd = to_bigdecimal(&digits, 0, negative);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.tan();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    Ok(rounded_parts(tan_bigdecimal(&bd)?))
 }
 
 pub fn ln_int(digits: String, negative: bool) -> Result<(String, i32, bool, bool), i8> {
-    let bd = to_bigdecimal(&digits, 0, negative);
-    if bd.is_negative() || bd.is_zero() {
-        return Err(ERR_INVALID_FORMAT);
-    }
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.ln();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    ln_float(digits, 0, negative)
 }
 
 pub fn exp_int(digits: String, negative: bool) -> Result<(String, i32, bool, bool), i8> {
-    let bd = to_bigdecimal(&digits, 0, negative);
-    let f = bd.to_f64().ok_or(ERR_INVALID_FORMAT)?;
-    let res = f.exp();
-    let bdres = BigDecimal::from_f64(res).unwrap_or_else(|| BigDecimal::zero());
-    let trunc = truncate_bd_to_decimals(&bdres, 137);
-    let (m, e, neg2) = from_bigdecimal(&trunc);
-    Ok((m, e, neg2, true))
+    exp_float(digits, 0, negative)
 }
 
 pub fn floor_int(digits: String, negative: bool) -> Result<(String, bool), i8> {
@@ -0,0 +1,251 @@
+//! Multiplication of huge integers by number-theoretic transform.
+//!
+//! `num-bigint` multiplies with Karatsuba and Toom-3, which is the better choice up to
+//! tens of thousands of digits. Past that the `O(n log n)` transform wins, so `mul` picks
+//! it once both operands are at least `THRESHOLD_BITS` long. Everything here is pure Rust.
+//!
+//! The operands are split into limbs and convolved modulo the prime `P = 2^64 - 2^32 + 1`,
+//! whose special form makes reduction a few additions. The limbs are as wide as possible
+//! while every coefficient of the product, below `min(len) * 2^(2 * width)`, stays below
+//! `P`, so the convolution is exact.
+
+use crate::alloc_prelude::*;
+use num_bigint::{BigInt, BigUint, Sign};
+
+const P: u64 = 0xFFFF_FFFF_0000_0001;
+/// `2^64 mod P`.
+const EPSILON: u64 = 0xFFFF_FFFF;
+/// Generator of the multiplicative group; `P - 1 = 2^32 * (2^32 - 1)`.
+const GENERATOR: u64 = 7;
+/// Longest transform the group has roots of unity for.
+const MAX_LEN: usize = 1 << 32;
+/// Narrower limbs would only be needed for products past `MAX_LEN` anyway.
+const MIN_LIMB_BITS: u32 = 12;
+const MAX_LIMB_BITS: u32 = 24;
+
+/// Operand size, in bits, from which `mul` uses the transform; about 180 000 decimal
+/// digits, where it starts to beat Toom-3 (see `benches/mul.rs`).
+pub const THRESHOLD_BITS: u64 = 600_000;
+
+/// `x mod P`, using `2^64 = 2^32 - 1` and `2^96 = -1 (mod P)`.
+fn reduce(x: u128) -> u64 {
+    let lo = x as u64;
+    let hi = (x >> 64) as u64;
+    let (hi_hi, hi_lo) = (hi >> 32, hi & EPSILON);
+    let (mut t, borrow) = lo.overflowing_sub(hi_hi);
+    if borrow {
+        t = t.wrapping_sub(EPSILON);
+    }
+    let (mut r, carry) = t.overflowing_add(hi_lo * EPSILON);
+    if carry {
+        r = r.wrapping_add(EPSILON);
+    }
+    if r >= P { r - P } else { r }
+}
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    reduce(u128::from(a) * u128::from(b))
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    let (s, carry) = a.overflowing_add(b);
+    if carry || s >= P { s.wrapping_sub(P) } else { s }
+}
+
+fn sub_mod(a: u64, b: u64) -> u64 {
+    if a >= b { a - b } else { a.wrapping_sub(b).wrapping_add(P) }
+}
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base);
+        }
+        base = mul_mod(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Twiddle factors for every stage of a length-`n` transform: the powers of `w`, a
+/// `2h`-th root of unity (or its inverse), occupy `roots[h..2h]`.
+fn roots(n: usize, inverse: bool) -> Vec<u64> {
+    let mut roots = vec![0; n.max(2)];
+    let mut half = 1;
+    while half < n {
+        let mut w = pow_mod(GENERATOR, (P - 1) / (2 * half as u64));
+        if inverse {
+            w = pow_mod(w, P - 2);
+        }
+        let mut x = 1;
+        for r in &mut roots[half..2 * half] {
+            *r = x;
+            x = mul_mod(x, w);
+        }
+        half *= 2;
+    }
+    roots
+}
+
+/// Decimation-in-frequency transform: natural order in, bit-reversed order out.
+fn forward(a: &mut [u64], roots: &[u64]) {
+    let mut half = a.len() / 2;
+    while half > 0 {
+        let twiddles = &roots[half..2 * half];
+        for chunk in a.chunks_exact_mut(2 * half) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for ((u, v), w) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles) {
+                let (x, y) = (*u, *v);
+                *u = add_mod(x, y);
+                *v = mul_mod(sub_mod(x, y), *w);
+            }
+        }
+        half /= 2;
+    }
+}
+
+/// Decimation-in-time transform with inverse roots: bit-reversed order in, natural order
+/// out, still to be divided by the length.
+fn inverse(a: &mut [u64], roots: &[u64]) {
+    let mut half = 1;
+    while half < a.len() {
+        let twiddles = &roots[half..2 * half];
+        for chunk in a.chunks_exact_mut(2 * half) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for ((u, v), w) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles) {
+                let t = mul_mod(*v, *w);
+                *v = sub_mod(*u, t);
+                *u = add_mod(*u, t);
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// The product of the polynomials `a` and `b` over `n` points, which must cover every
+/// coefficient.
+fn convolve(a: &[u64], b: &[u64], n: usize) -> Vec<u64> {
+    let roots_fwd = roots(n, false);
+    let mut fa = a.to_vec();
+    fa.resize(n, 0);
+    forward(&mut fa, &roots_fwd);
+    if a == b {
+        for x in fa.iter_mut() {
+            *x = mul_mod(*x, *x);
+        }
+    } else {
+        let mut fb = b.to_vec();
+        fb.resize(n, 0);
+        forward(&mut fb, &roots_fwd);
+        for (x, y) in fa.iter_mut().zip(&fb) {
+            *x = mul_mod(*x, *y);
+        }
+    }
+    drop(roots_fwd);
+    inverse(&mut fa, &roots(n, true));
+    let n_inv = pow_mod(n as u64, P - 2);
+    for x in fa.iter_mut() {
+        *x = mul_mod(*x, n_inv);
+    }
+    fa
+}
+
+/// `x` as little-endian limbs of `width` bits, without trailing zeros.
+fn limbs(x: &BigUint, width: u32) -> Vec<u64> {
+    let mask = (1u64 << width) - 1;
+    let mut out = Vec::with_capacity((x.bits() / u64::from(width)) as usize + 1);
+    let (mut acc, mut acc_bits) = (0u128, 0u32);
+    for d in x.iter_u64_digits() {
+        acc |= u128::from(d) << acc_bits;
+        acc_bits += 64;
+        while acc_bits >= width {
+            out.push(acc as u64 & mask);
+            acc >>= width;
+            acc_bits -= width;
+        }
+    }
+    out.push(acc as u64);
+    while out.last() == Some(&0) {
+        out.pop();
+    }
+    out
+}
+
+/// The widest limb for which `min(len) * 2^(2 * width) < P`, given operands of `bits`
+/// and `other_bits` bits.
+fn limb_width(bits: u64, other_bits: u64) -> Option<u32> {
+    let shorter = bits.min(other_bits);
+    (MIN_LIMB_BITS..=MAX_LIMB_BITS).rev().find(|&w| {
+        let len = shorter.div_ceil(u64::from(w));
+        2 * w + (64 - len.leading_zeros()) < 64
+    })
+}
+
+/// `a * b` by transform, or `None` when the product is too long for it.
+fn transform_mul(a: &BigUint, b: &BigUint) -> Option<BigUint> {
+    let width = limb_width(a.bits(), b.bits())?;
+    let (la, lb) = (limbs(a, width), limbs(b, width));
+    if la.is_empty() || lb.is_empty() {
+        return Some(BigUint::default());
+    }
+    let n = (la.len() + lb.len() - 1).next_power_of_two();
+    if n > MAX_LEN {
+        return None;
+    }
+    let product = convolve(&la, &lb, n);
+
+    let mask = (1u128 << width) - 1;
+    let mut digits = Vec::with_capacity(n * width as usize / 32 + 4);
+    let (mut carry, mut acc, mut acc_bits) = (0u128, 0u128, 0u32);
+    for c in product {
+        carry += u128::from(c);
+        acc |= (carry & mask) << acc_bits;
+        carry >>= width;
+        acc_bits += width;
+        while acc_bits >= 32 {
+            digits.push(acc as u32);
+            acc >>= 32;
+            acc_bits -= 32;
+        }
+    }
+    acc |= carry << acc_bits;
+    while acc > 0 {
+        digits.push(acc as u32);
+        acc >>= 32;
+    }
+    Some(BigUint::new(digits))
+}
+
+fn with_sign(a: &BigInt, b: &BigInt, magnitude: BigUint) -> BigInt {
+    let sign = if a.sign() == b.sign() { Sign::Plus } else { Sign::Minus };
+    BigInt::from_biguint(sign, magnitude)
+}
+
+/// `a * b`, by transform when both operands have at least `THRESHOLD_BITS` bits and by
+/// `num-bigint` otherwise. The default `Backend` multiplies through here.
+pub fn mul(a: &BigInt, b: &BigInt) -> BigInt {
+    if a.bits().min(b.bits()) < THRESHOLD_BITS {
+        return a * b;
+    }
+    mul_transform(a, b)
+}
+
+/// `a * b` by transform regardless of size; products too long for it (over about
+/// 40 million decimal digits) fall back to `num-bigint`. Mostly for benchmarking against
+/// `mul`.
+pub fn mul_transform(a: &BigInt, b: &BigInt) -> BigInt {
+    match transform_mul(a.magnitude(), b.magnitude()) {
+        Some(m) => with_sign(a, b, m),
+        None => a * b,
+    }
+}
+
+/// `*a *= b`, through `mul` for huge operands and in place otherwise.
+pub(crate) fn mul_assign(a: &mut BigInt, b: &BigInt) {
+    if a.bits().min(b.bits()) < THRESHOLD_BITS {
+        *a *= b;
+    } else {
+        *a = mul_transform(a, b);
+    }
+}
@@ -1,16 +1,19 @@
+use crate::alloc_prelude::*;
 use crate::compat::{
     float_is_negative, float_is_neg_one, float_is_one, float_is_zero, float_kind,
-    float_to_parts, int_is_infinite, int_is_nan, int_to_parts, int_to_string,
-    make_float_from_parts,
+    float_to_bigdecimal, int_to_parts, int_to_string, normalize_decimal,
 };
-use crate::foundation::{Float, FloatKind, Int};
-use bigdecimal::BigDecimal;
-use num_traits::ToPrimitive;
-use std::cmp::{Ordering, PartialOrd};
-use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::ops::{
+use crate::foundation::{display_digits, Float, FloatKind, Int, SmallFloat};
+use bigdecimal::{BigDecimal, RoundingMode};
+use num_traits::{ToPrimitive, Zero};
+use core::borrow::Borrow;
+use core::cmp::{Ordering, PartialOrd};
+use core::num::NonZeroU64;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::{
     Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
 };
+use core::iter::{Product, Sum};
 
 impl Add for Int {
     type Output = Result<Self, i8>;
@@ -36,6 +39,10 @@ impl Mul for Int {
     }
 }
 
+/// Integer division truncating toward zero, like Rust's primitive integers
+/// (`compat::Behavior::int_division` can select another mode).
+/// Use `Int::div_floor`, `Int::div_ceil`, `Int::div_euclid` or `Int::div_mode`
+/// for other rounding.
 impl Div for Int {
     type Output = Result<Self, i8>;
 
@@ -208,47 +215,47 @@ impl RemAssign for Int {
 
 impl PartialOrd for Int {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by value; integers have no NaN, so the order is total.
+impl Ord for Int {
+    fn cmp(&self, other: &Self) -> Ordering {
         use crate::compat::int_to_bigint;
 
         match (self, other) {
-            (crate::foundation::Int::Big(a), crate::foundation::Int::Big(b)) => {
-                return Some(a.cmp(b));
-            }
+            (crate::foundation::Int::Big(a), crate::foundation::Int::Big(b)) => a.cmp(b),
 
-            (crate::foundation::Int::Small(a), crate::foundation::Int::Small(b)) => {
-                return Some(a.cmp(b));
+            (crate::foundation::Int::Small(a), crate::foundation::Int::Small(b))
+                if core::mem::discriminant(a) == core::mem::discriminant(b) =>
+            {
+                a.cmp(b)
             }
 
-            (crate::foundation::Int::Small(_), crate::foundation::Int::Big(_))
-            | (crate::foundation::Int::Big(_), crate::foundation::Int::Small(_)) => {
-                let a_big = int_to_bigint(self);
-                let b_big = int_to_bigint(other);
-                return Some(a_big.cmp(&b_big));
-            }
+            _ => int_to_bigint(self).cmp(&int_to_bigint(other)),
         }
     }
 }
 
+/// Compares by value, so `Small` and `Big` forms of the same number are equal.
+impl PartialEq for Int {
+    fn eq(&self, other: &Self) -> bool {
+        use crate::compat::int_to_bigint;
+
+        match (self, other) {
+            (crate::foundation::Int::Big(a), crate::foundation::Int::Big(b)) => a == b,
+            _ => int_to_bigint(self) == int_to_bigint(other),
+        }
+    }
+}
+
+impl Eq for Int {}
+
 impl Display for Int {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        if int_is_nan(self) {
-            write!(f, "NaN")?;
-            return Ok(());
-        }
-        if int_is_infinite(self) {
-            let (_d, neg, _k) = int_to_parts(self);
-            if neg {
-                write!(f, "-Infinity")?;
-            } else {
-                write!(f, "Infinity")?;
-            }
-            return Ok(());
-        }
         let (digits, neg, _k) = int_to_parts(self);
-        if neg {
-            write!(f, "-")?;
-        }
-        write!(f, "{}", digits)
+        f.pad_integral(!neg, "", &digits)
     }
 }
 
@@ -401,8 +408,17 @@ impl Neg for Float {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        let (m, e, neg, k) = float_to_parts(&self);
-        make_float_from_parts(m, e, !neg, k)
+        match self {
+            Float::Surd(c, r) => Float::Surd(-c, r),
+            Float::Big(bd) => Float::Big(normalize_decimal(-bd)),
+            Float::Irrational(bd) => Float::Irrational(normalize_decimal(-bd)),
+            Float::Recurring(num, den) => Float::Recurring(-num, den),
+            Float::Small(_) => Float::Big(normalize_decimal(-self.decimal_value().unwrap_or_default())),
+            Float::Complex(re, im) => Float::Complex(Box::new(-*re), Box::new(-*im)),
+            Float::NaN => Float::NaN,
+            Float::Infinity => Float::NegInfinity,
+            Float::NegInfinity => Float::Infinity,
+        }
     }
 }
 
@@ -437,14 +453,12 @@ impl RemAssign for Float {
 }
 
 impl PartialOrd for Float {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        use std::cmp::Ordering;
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        use core::cmp::Ordering;
         use crate::compat::float_to_bigdecimal;
 
         match (self, other) {
-            (Float::Big(a_bd), Float::Big(b_bd)) => {
-                return Some(a_bd.normalized().cmp(&b_bd.normalized()));
-            }
+            (Float::Big(a_bd), Float::Big(b_bd)) => Some(a_bd.cmp(b_bd)),
 
             (Float::Small(a_sf), Float::Small(b_sf)) => {
                 let a_v = match a_sf {
@@ -460,7 +474,7 @@ impl PartialOrd for Float {
 
             (Float::Small(_), Float::Big(_)) | (Float::Big(_), Float::Small(_)) => {
                 if let (Some(a_bd), Some(b_bd)) = (float_to_bigdecimal(self), float_to_bigdecimal(other)) {
-                    return Some(a_bd.normalized().cmp(&b_bd.normalized()));
+                    return Some(a_bd.cmp(&b_bd));
                 }
                 return None;
             }
@@ -475,8 +489,11 @@ impl PartialOrd for Float {
             (_, Float::NegInfinity) => return Some(Ordering::Greater),
 
             _ => {
+                if let Some(ord) = crate::recurring::cmp_exact(self, other) {
+                    return Some(ord);
+                }
                 if let (Some(a_bd), Some(b_bd)) = (float_to_bigdecimal(self), float_to_bigdecimal(other)) {
-                    return Some(a_bd.normalized().cmp(&b_bd.normalized()));
+                    return Some(a_bd.cmp(&b_bd));
                 }
                 return None;
             }
@@ -484,183 +501,170 @@ impl PartialOrd for Float {
     }
 }
 
+/// Precision rounds to that many decimals (half to even), per part for complex values;
+/// width, fill, alignment, `+` and `0` apply to the whole number.
 impl Display for Float {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let k = float_kind(self);
-        if k == FloatKind::NaN {
-            write!(f, "NaN")?;
-            return Ok(());
-        } else if k == FloatKind::Infinity {
-            write!(f, "Infinity")?;
-            return Ok(());
-        } else if k == FloatKind::NegInfinity {
-            write!(f, "-Infinity")?;
-
-            return Ok(());
+        if f.width().is_none() && f.precision().is_none() && !f.sign_plus() {
+            return write_float(self, f, None, false);
         }
+        let mut s = String::new();
+        write_float(self, &mut s, f.precision(), false)?;
+        let zero_pad = !matches!(self, Float::Complex(..))
+            && !matches!(float_kind(self), FloatKind::NaN | FloatKind::Infinity | FloatKind::NegInfinity);
+        crate::format::pad_number(f, &s, zero_pad)
+    }
+}
 
-        if let Float::Complex(ref real, ref imag) = *self {
-            if float_is_zero(imag) {
-                return real.fmt(f);
-            }
-            
-            if float_is_zero(real) {
-                if float_is_one(imag) {
-                    return write!(f, "i");
-                } else if float_is_neg_one(imag) {
-                    return write!(f, "-i");
-                } else {
-                    write!(f, "{}i", imag)?;
-                    return Ok(());
-                }
+impl Float {
+    /// `to_string` with every stored digit of an irrational value, however many
+    /// `display_digits()` allows.
+    pub fn to_string_full(&self) -> String {
+        let mut s = String::new();
+        let _ = write_float(self, &mut s, None, true);
+        s
+    }
+}
+
+/// Writes `x` as `Display` does: `precision` fixes the decimals, and unless `full` is set
+/// an irrational value is cut to `display_digits()` significant digits and marked `…`.
+fn write_float<W: core::fmt::Write>(x: &Float, f: &mut W, precision: Option<usize>, full: bool) -> FmtResult {
+    let k = float_kind(x);
+    if k == FloatKind::NaN {
+        write!(f, "NaN")?;
+        return Ok(());
+    } else if k == FloatKind::Infinity {
+        write!(f, "Infinity")?;
+        return Ok(());
+    } else if k == FloatKind::NegInfinity {
+        write!(f, "-Infinity")?;
+
+        return Ok(());
+    }
+
+    if let Some(places) = precision {
+        match x {
+            Float::Small(SmallFloat::F64(v)) => return write!(f, "{:.*}", places, v),
+            Float::Small(SmallFloat::F32(v)) => return write!(f, "{:.*}", places, v),
+            Float::Complex(..) => {}
+            // the stored digits of a recurring value stop short; round the exact fraction
+            Float::Recurring(num, den) if !den.is_zero() => {
+                return f.write_str(&crate::format::ratio_places(num, den, places));
             }
-            
-            write!(f, "{}", real)?;
-            
-            let imag_neg = float_is_negative(imag);
-            if imag_neg {
-                write!(f, " - ")?;
-                let abs_imag = Float::Big(BigDecimal::from(0))._sub(imag).unwrap_or_else(|_| *imag.clone());
-                if float_is_one(&abs_imag) {
-                    write!(f, "i")?;
-                } else {
-                    write!(f, "{}i", abs_imag)?;
-                }
-            } else {
-                write!(f, " + ")?;
-                if float_is_one(imag) {
-                    write!(f, "i")?;
-                } else {
-                    write!(f, "{}i", imag)?;
+            _ => {
+                if let Some(bd) = float_to_bigdecimal(x) {
+                    return f.write_str(&crate::format::fixed_places(&bd, places));
                 }
             }
-            return Ok(());
         }
+    }
 
-        if k == FloatKind::Recurring {
-            if let Float::Recurring(ref bd) = *self {
-                let s = bd.normalized().to_string();
-                let parts: Vec<&str> = s.split('E').collect();
-                let base = parts[0];
-                let exp_from_e: i32 = if parts.len() == 2 {
-                    parts[1].parse().unwrap_or(0)
-                } else {
-                    0
-                };
-
-                let (digits, exp_decimal) = if let Some(dot) = base.find('.') {
-                    let mantissa = base[..dot].to_string() + &base[dot + 1..];
-                    (
-                        mantissa.trim_start_matches('0').to_string(),
-                        -((base.len() - dot - 1) as i32),
-                    )
-                } else {
-                    (base.trim_start_matches('0').to_string(), 0)
-                };
-                let final_exp = exp_decimal + exp_from_e;
-
-                let (int_part, frac_part) = if digits.is_empty() {
-                    ("0".to_string(), String::new())
-                } else {
-                    let point_pos = (digits.len() as i32) + final_exp;
-                    if point_pos > 0 {
-                        let pp = point_pos as usize;
-                        if pp >= digits.len() {
-                            (digits.clone(), String::new())
-                        } else {
-                            (digits[..pp].to_string(), digits[pp..].to_string())
-                        }
-                    } else {
-                        (
-                            "0".to_string(),
-                            format!("{}{}", "0".repeat((-point_pos) as usize), digits),
-                        )
-                    }
-                };
-
-                let neg = base.starts_with('-');
-
-                let max_check = frac_part.len().min(500);
-                let frac = &frac_part[..max_check];
-
-                let mut found: Option<(usize, usize)> = None;
-                'outer: for rep_len in 1..=frac.len() {
-                    for nonrep_len in 0..=frac.len().saturating_sub(rep_len) {
-                        let start = nonrep_len;
-                        if start + rep_len > frac.len() {
-                            continue;
-                        }
-                        let rep = &frac[start..start + rep_len];
-                        let mut ok = true;
-                        let remaining = frac.len() - start;
-                        if remaining < rep_len * 2 {
-                            ok = false;
-                        }
-                        let mut i = start;
-                        while i < frac.len() {
-                            let take = (rep_len).min(frac.len() - i);
-                            if &frac[i..i + take] != &rep[..take] {
-                                ok = false;
-                                break;
-                            }
-                            i += take;
-                        }
-                        if ok {
-                            found = Some((nonrep_len, rep_len));
-                            break 'outer;
-                        }
-                    }
-                }
+    if let Float::Surd(ref c, ref r) = *x {
+        return match c.to_string().as_str() {
+            "1" => write!(f, "√{}", r),
+            "-1" => write!(f, "-√{}", r),
+            c => write!(f, "{}√{}", c, r),
+        };
+    }
 
-                if let Some((nonrep_len, rep_len)) = found {
-                    let nonrep = &frac[..nonrep_len];
-                    let rep = &frac[nonrep_len..nonrep_len + rep_len];
-                    if neg {
-                        write!(f, "-{}.", int_part)?;
-                    } else {
-                        write!(f, "{}.", int_part)?;
-                    }
-                    write!(f, "{}({})", nonrep, rep)?;
-                    return Ok(());
-                }
-            }
+    if let Float::Complex(ref real, ref imag) = *x {
+        if float_is_zero(imag) {
+            return write_float(real, f, precision, full);
         }
-
-        let (mant, exp, neg, k) = float_to_parts(self);
-        if neg {
-            write!(f, "-")?;
+        
+        if float_is_zero(real) {
+            if float_is_one(imag) {
+                return write!(f, "i");
+            } else if float_is_neg_one(imag) {
+                return write!(f, "-i");
+            } else {
+                write_float(imag, f, precision, full)?;
+                return write!(f, "i");
+            }
         }
         
-        if k == FloatKind::Irrational || (exp >= -50 && exp <= 50) {
-            let mantissa = mant.trim_start_matches('0');
-            let mantissa = if mantissa.is_empty() { "0" } else { mantissa };
-            if exp == 0 {
-                write!(f, "{}.0", mantissa)?;
-            } else if exp > 0 {
-                write!(f, "{}{}", mantissa, "0".repeat(exp as usize))?;
-                write!(f, ".0")?;
+        write_float(real, f, precision, full)?;
+        
+        let imag_neg = float_is_negative(imag);
+        if imag_neg {
+            write!(f, " - ")?;
+            let abs_imag = Float::Big(BigDecimal::from(0))._sub(imag).unwrap_or_else(|_| *imag.clone());
+            if float_is_one(&abs_imag) {
+                write!(f, "i")?;
             } else {
-                let mantissa_len = mantissa.len() as i64;
-                let point_pos = mantissa_len + (exp as i64);
-                if point_pos > 0 {
-                    let (int_part, frac_part) = mantissa.split_at(point_pos as usize);
-                    if frac_part.is_empty() {
-                        write!(f, "{}.0", int_part)?;
-                    } else {
-                        write!(f, "{}.{}", int_part, frac_part)?;
-                    }
-                } else {
-                    write!(f, "0.{}{}", "0".repeat((-point_pos) as usize), mantissa)?;
-                }
+                write_float(&abs_imag, f, precision, full)?;
+                write!(f, "i")?;
             }
         } else {
-            write!(f, "{}e{}", mant, exp)?;
+            write!(f, " + ")?;
+            if float_is_one(imag) {
+                write!(f, "i")?;
+            } else {
+                write_float(imag, f, precision, full)?;
+                write!(f, "i")?;
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some((int_part, nonrep, rep)) = x.recurring_parts() {
+        return write!(f, "{}.{}({})", int_part, nonrep, rep);
+    }
+    if let Float::Recurring(num, den) = x
+        && !den.is_zero()
+    {
+        // a repetend too long to write out is cut off like an irrational expansion
+        return match crate::recurring::ratio_to_float(num, den) {
+            Float::Recurring(num, den) => write_float(&Float::Irrational(crate::recurring::expand(&num, &den)), f, None, full),
+            exact => write_float(&exact, f, None, full),
+        };
+    }
+
+    let shortened;
+    let (x, cut) = match x {
+        Float::Irrational(bd) if !full && display_digits() > 0 && bd.digits() > display_digits() => {
+            let digits = NonZeroU64::new(display_digits()).unwrap_or(NonZeroU64::MIN);
+            shortened = Float::Irrational(bd.with_precision_round(digits, RoundingMode::HalfEven).normalized());
+            (&shortened, true)
         }
-        if k == FloatKind::Irrational {
-            write!(f, "...")?;
+        _ => (x, false),
+    };
+    // the exponent as decompose gives it: float_to_parts narrows it to an i32
+    let (mantissa, exp, neg, k) = x.decompose();
+    let mant = crate::backend::backend().to_decimal(&mantissa.as_bigint());
+    if neg {
+        write!(f, "-")?;
+    }
+    
+    if k == FloatKind::Irrational || (-50..=50).contains(&exp) {
+        let mantissa = mant.trim_start_matches('0');
+        let mantissa = if mantissa.is_empty() { "0" } else { mantissa };
+        if exp == 0 {
+            write!(f, "{}.0", mantissa)?;
+        } else if exp > 0 {
+            write!(f, "{}{}", mantissa, "0".repeat(exp as usize))?;
+            write!(f, ".0")?;
+        } else {
+            let mantissa_len = mantissa.len() as i64;
+            let point_pos = mantissa_len + exp;
+            if point_pos > 0 {
+                let (int_part, frac_part) = mantissa.split_at(point_pos as usize);
+                if frac_part.is_empty() {
+                    write!(f, "{}.0", int_part)?;
+                } else {
+                    write!(f, "{}.{}", int_part, frac_part)?;
+                }
+            } else {
+                write!(f, "0.{}{}", "0".repeat((-point_pos) as usize), mantissa)?;
+            }
         }
-        Ok(())
+    } else {
+        write!(f, "{}e{}", mant, exp)?;
     }
+    if k == FloatKind::Irrational {
+        write!(f, "{}", if cut { "…" } else { "..." })?;
+    }
+    Ok(())
 }
 
 impl Int {
@@ -733,6 +737,35 @@ impl Int {
     pub fn xnor(&self, other: &Int) -> Result<Int, i8> {
         self._xnor(other)
     }
+
+    /// Number of bits needed for the magnitude, `0` for zero (Python's `bit_length`).
+    pub fn bits(&self) -> u64 {
+        self.as_bigint().bits()
+    }
+
+    /// Number of set bits in the magnitude (Python's `bit_count`).
+    pub fn count_ones(&self) -> u64 {
+        self.as_bigint().magnitude().count_ones()
+    }
+
+    /// Whether bit `i` is set, reading negative values in two's complement with infinite
+    /// sign extension as the bitwise operators do (`-1` has every bit set).
+    pub fn bit(&self, i: u64) -> bool {
+        self.as_bigint().bit(i)
+    }
+
+    /// Sets bit `i` to `value` in the same two's-complement view as `bit`.
+    pub fn set_bit(&mut self, i: u64, value: bool) {
+        use crate::compat::int_to_bigint;
+        let mut a = int_to_bigint(self);
+        a.set_bit(i, value);
+        *self = Int::Big(a);
+    }
+
+    /// Number of trailing zero bits, the same for `x` and `-x`; `None` for zero.
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        self.as_bigint().trailing_zeros()
+    }
 }
 
 impl BitAnd for Int {
@@ -890,8 +923,39 @@ impl<'a> Shr<&'a Int> for Int {
 }
 
 impl Float {
+    /// The value as an integer for the bitwise operators when `Behavior::float_bit_patterns`
+    /// is off: `None` while the deprecated bit-pattern semantics are in effect,
+    /// `INVALID_FORMAT` for values that are not finite integers.
+    fn bitwise_integer(&self) -> Option<Result<num_bigint::BigInt, i8>> {
+        if crate::compat::behavior().float_bit_patterns {
+            return None;
+        }
+        let value = match self.decimal_value() {
+            Some(bd) if bd.is_integer() => Ok(bd.with_scale(0).as_bigint_and_exponent().0),
+            _ => Err(crate::math::ERR_INVALID_FORMAT),
+        };
+        Some(value)
+    }
+
+    /// `op` on the integer values of both operands; see `bitwise_integer`.
+    fn integer_bitwise(&self, other: &Float, op: impl FnOnce(num_bigint::BigInt, num_bigint::BigInt) -> num_bigint::BigInt) -> Option<Result<Float, i8>> {
+        let a = self.bitwise_integer()?;
+        let b = other.bitwise_integer()?;
+        Some(match (a, b) {
+            (Ok(a), Ok(b)) => Ok(Float::Big(BigDecimal::from(op(a, b)))),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        })
+    }
+
+    /// Bitwise AND. On integer values when `Behavior::float_bit_patterns` is off; otherwise
+    /// (deprecated) on the IEEE bit patterns of two `Small` floats or the unscaled digits of
+    /// two `Big` ones.
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
     pub fn _bitand(&self, other: &Float) -> Result<Float, i8> {
         use crate::math::ERR_UNIMPLEMENTED;
+        if let Some(res) = self.integer_bitwise(other, |a, b| a & b) {
+            return res;
+        }
         match (self, other) {
             (Float::Small(crate::foundation::SmallFloat::F32(a)), Float::Small(crate::foundation::SmallFloat::F32(b))) => {
                 let a_bits = a.to_bits();
@@ -915,8 +979,13 @@ impl Float {
         }
     }
 
+    /// Bitwise OR, with the same semantics as `_bitand`.
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
     pub fn _bitor(&self, other: &Float) -> Result<Float, i8> {
         use crate::math::ERR_UNIMPLEMENTED;
+        if let Some(res) = self.integer_bitwise(other, |a, b| a | b) {
+            return res;
+        }
         match (self, other) {
             (Float::Small(crate::foundation::SmallFloat::F32(a)), Float::Small(crate::foundation::SmallFloat::F32(b))) => {
                 let a_bits = a.to_bits();
@@ -962,8 +1031,13 @@ impl Float {
         }
     }
 
+    /// Bitwise XOR, with the same semantics as `_bitand`.
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
     pub fn _bitxor(&self, other: &Float) -> Result<Float, i8> {
         use crate::math::ERR_UNIMPLEMENTED;
+        if let Some(res) = self.integer_bitwise(other, |a, b| a ^ b) {
+            return res;
+        }
         match (self, other) {
             (Float::Small(crate::foundation::SmallFloat::F32(a)), Float::Small(crate::foundation::SmallFloat::F32(b))) => {
                 let a_bits = a.to_bits();
@@ -1009,11 +1083,16 @@ impl Float {
         }
     }
 
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
+    #[allow(deprecated)]
     pub fn _xnor(&self, other: &Float) -> Result<Float, i8> {
         let xor = self._bitxor(other)?;
         Ok(xor._not())
     }
 
+    /// Left shift: `self * 2^shift` for integer values when `Behavior::float_bit_patterns` is off;
+    /// otherwise (deprecated) on the bit pattern or unscaled digits, as `_bitand`.
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
     pub fn _shl(&self, shift: &Int) -> Result<Float, i8> {
         use crate::compat::int_to_bigint;
         use num_bigint::BigInt;
@@ -1027,6 +1106,9 @@ impl Float {
             return Err(ERR_NUMBER_TOO_LARGE);
         }
         let shift_usize = shift_big.to_usize().unwrap();
+        if let Some(value) = self.bitwise_integer() {
+            return Ok(Float::Big(BigDecimal::from(value? << shift_usize)));
+        }
         match self {
             Float::Small(crate::foundation::SmallFloat::F32(a)) => {
                 let bits = a.to_bits();
@@ -1047,6 +1129,9 @@ impl Float {
         }
     }
 
+    /// Right shift: `floor(self / 2^shift)` for integer values when `Behavior::float_bit_patterns` is off;
+    /// otherwise (deprecated) on the bit pattern or unscaled digits, as `_bitand`.
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
     pub fn _shr(&self, shift: &Int) -> Result<Float, i8> {
         use crate::compat::int_to_bigint;
         use num_bigint::BigInt;
@@ -1060,6 +1145,9 @@ impl Float {
             return Err(ERR_NUMBER_TOO_LARGE);
         }
         let shift_usize = shift_big.to_usize().unwrap();
+        if let Some(value) = self.bitwise_integer() {
+            return Ok(Float::Big(BigDecimal::from(value? >> shift_usize)));
+        }
         match self {
             Float::Small(crate::foundation::SmallFloat::F32(a)) => {
                 let bits = a.to_bits();
@@ -1080,7 +1168,14 @@ impl Float {
         }
     }
 
+    /// Bitwise NOT: `-self - 1` for integer values when `Behavior::float_bit_patterns` is
+    /// off (NaN for anything else); otherwise (deprecated) on the bit pattern or unscaled
+    /// digits, as `_bitand`.
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
     pub fn _not(&self) -> Float {
+        if let Some(value) = self.bitwise_integer() {
+            return value.map_or(Float::NaN, |v| Float::Big(BigDecimal::from(!v)));
+        }
         match self {
             Float::Small(crate::foundation::SmallFloat::F32(a)) => {
                 let bits = a.to_bits();
@@ -1124,11 +1219,14 @@ impl Float {
         }
     }
 
+    #[deprecated(note = "acts on IEEE bit patterns unless `Behavior::float_bit_patterns` is off; use `Float::to_bits` for bit access, or convert to `Int` for integer bitwise operations")]
+    #[allow(deprecated)]
     pub fn xnor(&self, other: &Float) -> Result<Float, i8> {
         self._xnor(other)
     }
 }
 
+#[allow(deprecated)]
 impl BitAnd for Float {
     type Output = Result<Self, i8>;
     fn bitand(self, other: Self) -> Self::Output {
@@ -1136,6 +1234,7 @@ impl BitAnd for Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitAnd<&'a Float> for &'a Float {
     type Output = Result<Float, i8>;
     fn bitand(self, other: &'a Float) -> Self::Output {
@@ -1143,6 +1242,7 @@ impl<'a> BitAnd<&'a Float> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitAnd<Float> for &'a Float {
     type Output = Result<Float, i8>;
     fn bitand(self, other: Float) -> Self::Output {
@@ -1150,6 +1250,7 @@ impl<'a> BitAnd<Float> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitAnd<&'a Float> for Float {
     type Output = Result<Float, i8>;
     fn bitand(self, other: &'a Float) -> Self::Output {
@@ -1157,6 +1258,7 @@ impl<'a> BitAnd<&'a Float> for Float {
     }
 }
 
+#[allow(deprecated)]
 impl BitOr for Float {
     type Output = Result<Self, i8>;
     fn bitor(self, other: Self) -> Self::Output {
@@ -1164,6 +1266,7 @@ impl BitOr for Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitOr<&'a Float> for &'a Float {
     type Output = Result<Float, i8>;
     fn bitor(self, other: &'a Float) -> Self::Output {
@@ -1171,6 +1274,7 @@ impl<'a> BitOr<&'a Float> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitOr<Float> for &'a Float {
     type Output = Result<Float, i8>;
     fn bitor(self, other: Float) -> Self::Output {
@@ -1178,6 +1282,7 @@ impl<'a> BitOr<Float> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitOr<&'a Float> for Float {
     type Output = Result<Float, i8>;
     fn bitor(self, other: &'a Float) -> Self::Output {
@@ -1185,6 +1290,7 @@ impl<'a> BitOr<&'a Float> for Float {
     }
 }
 
+#[allow(deprecated)]
 impl BitXor for Float {
     type Output = Result<Self, i8>;
     fn bitxor(self, other: Self) -> Self::Output {
@@ -1192,6 +1298,7 @@ impl BitXor for Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitXor<&'a Float> for &'a Float {
     type Output = Result<Float, i8>;
     fn bitxor(self, other: &'a Float) -> Self::Output {
@@ -1199,6 +1306,7 @@ impl<'a> BitXor<&'a Float> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitXor<Float> for &'a Float {
     type Output = Result<Float, i8>;
     fn bitxor(self, other: Float) -> Self::Output {
@@ -1206,6 +1314,7 @@ impl<'a> BitXor<Float> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> BitXor<&'a Float> for Float {
     type Output = Result<Float, i8>;
     fn bitxor(self, other: &'a Float) -> Self::Output {
@@ -1213,6 +1322,7 @@ impl<'a> BitXor<&'a Float> for Float {
     }
 }
 
+#[allow(deprecated)]
 impl Not for Float {
     type Output = Self;
     fn not(self) -> Self::Output {
@@ -1220,6 +1330,7 @@ impl Not for Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> Not for &'a Float {
     type Output = Float;
     fn not(self) -> Self::Output {
@@ -1227,6 +1338,7 @@ impl<'a> Not for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl Shl<Int> for Float {
     type Output = Result<Self, i8>;
     fn shl(self, other: Int) -> Self::Output {
@@ -1234,6 +1346,7 @@ impl Shl<Int> for Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> Shl<&'a Int> for &'a Float {
     type Output = Result<Float, i8>;
     fn shl(self, other: &'a Int) -> Self::Output {
@@ -1241,6 +1354,7 @@ impl<'a> Shl<&'a Int> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> Shl<Int> for &'a Float {
     type Output = Result<Float, i8>;
     fn shl(self, other: Int) -> Self::Output {
@@ -1248,6 +1362,7 @@ impl<'a> Shl<Int> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> Shl<&'a Int> for Float {
     type Output = Result<Float, i8>;
     fn shl(self, other: &'a Int) -> Self::Output {
@@ -1255,6 +1370,7 @@ impl<'a> Shl<&'a Int> for Float {
     }
 }
 
+#[allow(deprecated)]
 impl Shr<Int> for Float {
     type Output = Result<Self, i8>;
     fn shr(self, other: Int) -> Self::Output {
@@ -1262,6 +1378,7 @@ impl Shr<Int> for Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> Shr<&'a Int> for &'a Float {
     type Output = Result<Float, i8>;
     fn shr(self, other: &'a Int) -> Self::Output {
@@ -1269,6 +1386,7 @@ impl<'a> Shr<&'a Int> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> Shr<Int> for &'a Float {
     type Output = Result<Float, i8>;
     fn shr(self, other: Int) -> Self::Output {
@@ -1276,9 +1394,354 @@ impl<'a> Shr<Int> for &'a Float {
     }
 }
 
+#[allow(deprecated)]
 impl<'a> Shr<&'a Int> for Float {
     type Output = Result<Float, i8>;
     fn shr(self, other: &'a Int) -> Self::Output {
         self._shr(other)
     }
 }
+
+/// `Int op Float` and `Float op Int` promote the `Int` to `Float`.
+macro_rules! impl_int_float_ops {
+    ($($trait:ident, $method:ident, $inner:ident);+) => {
+        $(
+            impl $trait<Float> for Int {
+                type Output = Result<Float, i8>;
+                fn $method(self, other: Float) -> Self::Output {
+                    self.to_float()?.$inner(&other)
+                }
+            }
+
+            impl<'a> $trait<&'a Float> for &'a Int {
+                type Output = Result<Float, i8>;
+                fn $method(self, other: &'a Float) -> Self::Output {
+                    self.to_float()?.$inner(other)
+                }
+            }
+
+            impl $trait<Int> for Float {
+                type Output = Result<Float, i8>;
+                fn $method(self, other: Int) -> Self::Output {
+                    self.$inner(&other.to_float()?)
+                }
+            }
+
+            impl<'a> $trait<&'a Int> for &'a Float {
+                type Output = Result<Float, i8>;
+                fn $method(self, other: &'a Int) -> Self::Output {
+                    self.$inner(&other.to_float()?)
+                }
+            }
+        )+
+    };
+}
+
+impl_int_float_ops!(Add, add, _add; Sub, sub, _sub; Mul, mul, _mul; Div, div, _div; Rem, rem, _modulo);
+
+/// Operators between `Int`/`Float` and `i64`/`f64`, on either side. The primitive is
+/// converted with `Int::from` / `Float::from`; `Int op f64` promotes to `Float`.
+///
+/// Only one integer and one float type get impls so that literals (`x + 2`, `x * 0.5`)
+/// still infer; convert other primitive types with `Int::from` / `Float::from` first.
+macro_rules! impl_primitive_ops {
+    (@one $prim:ty, $lhs:ty, $out:ty, $conv:expr, $lift:expr, $trait:ident, $method:ident, $inner:ident) => {
+        impl $trait<$prim> for $lhs {
+            type Output = Result<$out, i8>;
+            fn $method(self, other: $prim) -> Self::Output {
+                $lift(&self)?.$inner(&$conv(other)?)
+            }
+        }
+
+        impl<'a> $trait<$prim> for &'a $lhs {
+            type Output = Result<$out, i8>;
+            fn $method(self, other: $prim) -> Self::Output {
+                $lift(self)?.$inner(&$conv(other)?)
+            }
+        }
+
+        impl $trait<$lhs> for $prim {
+            type Output = Result<$out, i8>;
+            fn $method(self, other: $lhs) -> Self::Output {
+                $conv(self)?.$inner(&$lift(&other)?)
+            }
+        }
+
+        impl<'a> $trait<&'a $lhs> for $prim {
+            type Output = Result<$out, i8>;
+            fn $method(self, other: &'a $lhs) -> Self::Output {
+                $conv(self)?.$inner(&$lift(other)?)
+            }
+        }
+    };
+    ($lhs:ty, $out:ty, $conv:expr, $lift:expr; $($prim:ty),+) => {
+        $(
+            impl_primitive_ops!(@one $prim, $lhs, $out, $conv, $lift, Add, add, _add);
+            impl_primitive_ops!(@one $prim, $lhs, $out, $conv, $lift, Sub, sub, _sub);
+            impl_primitive_ops!(@one $prim, $lhs, $out, $conv, $lift, Mul, mul, _mul);
+            impl_primitive_ops!(@one $prim, $lhs, $out, $conv, $lift, Div, div, _div);
+            impl_primitive_ops!(@one $prim, $lhs, $out, $conv, $lift, Rem, rem, _modulo);
+        )+
+    };
+}
+
+fn int_as_is(i: &Int) -> Result<Int, i8> {
+    Ok(i.clone())
+}
+
+fn float_as_is(f: &Float) -> Result<Float, i8> {
+    Ok(f.clone())
+}
+
+impl_primitive_ops!(Int, Int, |v| Ok::<Int, i8>(Int::from(v)), int_as_is; i64);
+impl_primitive_ops!(Int, Float, |v| Ok::<Float, i8>(Float::from(v)), Int::to_float; f64);
+impl_primitive_ops!(Float, Float, |v| Int::from(v).to_float(), float_as_is; i64);
+impl_primitive_ops!(Float, Float, |v| Ok::<Float, i8>(Float::from(v)), float_as_is; f64);
+
+/// `v` as a `Float`, with the infinities as `Float::Infinity`/`Float::NegInfinity`;
+/// `None` for NaN.
+fn float_from_f64(v: f64) -> Option<Float> {
+    match v {
+        v if v.is_nan() => None,
+        f64::INFINITY => Some(Float::Infinity),
+        f64::NEG_INFINITY => Some(Float::NegInfinity),
+        v => Some(Float::from(v)),
+    }
+}
+
+/// Comparisons by value between `Int`/`Float` and primitive numbers, on either side:
+/// `n > 5`, `x == 3`, `0.5 <= x`. A NaN on either side is unequal and unordered.
+macro_rules! impl_primitive_cmp {
+    ($lhs:ty, $conv:expr; $($prim:ty),+) => {
+        $(
+            impl PartialEq<$prim> for $lhs {
+                fn eq(&self, other: &$prim) -> bool {
+                    self.partial_cmp(other) == Some(Ordering::Equal)
+                }
+            }
+
+            impl PartialOrd<$prim> for $lhs {
+                fn partial_cmp(&self, other: &$prim) -> Option<Ordering> {
+                    self.partial_cmp(&$conv(*other)?)
+                }
+            }
+
+            impl PartialEq<$lhs> for $prim {
+                fn eq(&self, other: &$lhs) -> bool {
+                    other == self
+                }
+            }
+
+            impl PartialOrd<$lhs> for $prim {
+                fn partial_cmp(&self, other: &$lhs) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive_cmp!(Int, |v| Some(Int::from(v)); i32, i64, u64);
+impl_primitive_cmp!(Int, float_from_f64; f64);
+impl_primitive_cmp!(Float, |v| Some(Int::from(v)); i32, i64, u64);
+impl_primitive_cmp!(Float, float_from_f64; f64);
+
+/// Assign-ops taking the right-hand side by reference or as an `i64`/`f64`. Like the
+/// owned versions, an error leaves `0` in an `Int` and `NaN` in a `Float`.
+macro_rules! impl_assign_ops {
+    (@one $lhs:ty, $rhs:ty, $conv:expr, $fallback:expr, $trait:ident, $method:ident, $inner:ident) => {
+        impl $trait<$rhs> for $lhs {
+            fn $method(&mut self, other: $rhs) {
+                *self = $conv(other).and_then(|rhs| self.$inner(Borrow::borrow(&rhs))).unwrap_or_else(|_| $fallback);
+            }
+        }
+    };
+    ($lhs:ty, $rhs:ty, $conv:expr, $fallback:expr) => {
+        impl_assign_ops!(@one $lhs, $rhs, $conv, $fallback, AddAssign, add_assign, _add);
+        impl_assign_ops!(@one $lhs, $rhs, $conv, $fallback, SubAssign, sub_assign, _sub);
+        impl_assign_ops!(@one $lhs, $rhs, $conv, $fallback, MulAssign, mul_assign, _mul);
+        impl_assign_ops!(@one $lhs, $rhs, $conv, $fallback, DivAssign, div_assign, _div);
+        impl_assign_ops!(@one $lhs, $rhs, $conv, $fallback, RemAssign, rem_assign, _modulo);
+    };
+}
+
+impl_assign_ops!(@one Int, &Int, Ok::<&Int, i8>, Int::new(), DivAssign, div_assign, _div);
+impl_assign_ops!(@one Int, &Int, Ok::<&Int, i8>, Int::new(), RemAssign, rem_assign, _modulo);
+
+impl AddAssign<&Int> for Int {
+    fn add_assign(&mut self, other: &Int) {
+        self.add_assign_ref(other);
+    }
+}
+
+impl SubAssign<&Int> for Int {
+    fn sub_assign(&mut self, other: &Int) {
+        self.sub_assign_ref(other);
+    }
+}
+
+impl MulAssign<&Int> for Int {
+    fn mul_assign(&mut self, other: &Int) {
+        self.mul_assign_ref(other);
+    }
+}
+impl_assign_ops!(Int, i64, |v| Ok::<Int, i8>(Int::from(v)), Int::new());
+impl_assign_ops!(@one Float, &Float, Ok::<&Float, i8>, Float::NaN, DivAssign, div_assign, _div);
+impl_assign_ops!(@one Float, &Float, Ok::<&Float, i8>, Float::NaN, RemAssign, rem_assign, _modulo);
+
+impl AddAssign<&Float> for Float {
+    fn add_assign(&mut self, other: &Float) {
+        if self.add_assign_ref(other).is_err() {
+            *self = Float::NaN;
+        }
+    }
+}
+
+impl SubAssign<&Float> for Float {
+    fn sub_assign(&mut self, other: &Float) {
+        if self.sub_assign_ref(other).is_err() {
+            *self = Float::NaN;
+        }
+    }
+}
+
+impl MulAssign<&Float> for Float {
+    fn mul_assign(&mut self, other: &Float) {
+        if self.mul_assign_ref(other).is_err() {
+            *self = Float::NaN;
+        }
+    }
+}
+
+impl Int {
+    /// Applies `op` to the value as a `BigInt` in place: a `Big` int keeps its allocation
+    /// and `other` is borrowed rather than cloned.
+    fn assign_in_place(&mut self, other: &Int, op: impl FnOnce(&mut num_bigint::BigInt, &num_bigint::BigInt)) {
+        if let Int::Small(_) = self {
+            *self = Int::Big(self.as_bigint().into_owned());
+        }
+        if let Int::Big(a) = self {
+            op(a, &other.as_bigint());
+        }
+        if crate::foundation::auto_shrink() {
+            *self = self.shrink();
+        }
+    }
+
+    /// `self = self + other` without cloning either operand; the same result as `_add`,
+    /// for hot loops that accumulate into one value. `+=` with a reference uses it.
+    pub fn add_assign_ref(&mut self, other: &Int) {
+        self.assign_in_place(other, |a, b| *a += b);
+    }
+
+    /// `self = self - other` in place; see `add_assign_ref`.
+    pub fn sub_assign_ref(&mut self, other: &Int) {
+        self.assign_in_place(other, |a, b| *a -= b);
+    }
+
+    /// `self = self * other` in place; see `add_assign_ref`.
+    pub fn mul_assign_ref(&mut self, other: &Int) {
+        self.assign_in_place(other, |a, b| crate::backend::backend().mul_assign(a, b));
+    }
+}
+
+impl Float {
+    /// `self = self + other` without cloning either operand when both are `Big`; the same
+    /// result as `_add`, which every other kind goes through. On error `self` is left
+    /// unchanged. `+=` with a reference uses it (storing NaN on error).
+    pub fn add_assign_ref(&mut self, other: &Float) -> Result<(), i8> {
+        if let (Float::Big(a), Float::Big(b)) = (&mut *self, other) {
+            *a += b;
+            return Ok(());
+        }
+        *self = self._add(other)?;
+        Ok(())
+    }
+
+    /// `self = self - other` in place; see `add_assign_ref`.
+    pub fn sub_assign_ref(&mut self, other: &Float) -> Result<(), i8> {
+        if let (Float::Big(a), Float::Big(b)) = (&mut *self, other) {
+            *a -= b;
+            return Ok(());
+        }
+        *self = self._sub(other)?;
+        Ok(())
+    }
+
+    /// `self = self * other` in place; see `add_assign_ref`.
+    pub fn mul_assign_ref(&mut self, other: &Float) -> Result<(), i8> {
+        if let (Float::Big(a), Float::Big(b)) = (&mut *self, other) {
+            *a *= b;
+            return Ok(());
+        }
+        *self = self._mul(other)?;
+        Ok(())
+    }
+}
+impl_assign_ops!(Float, i64, |v| Int::from(v).to_float(), Float::NaN);
+impl_assign_ops!(Float, f64, |v| Ok::<Float, i8>(Float::from(v)), Float::NaN);
+
+/// `Sum` and `Product` over values or references. Collecting into `Result<_, i8>` stops at
+/// the first error and returns it; collecting into the type itself folds like `+=` / `*=`,
+/// so an error leaves `0` in an `Int` and NaN in a `Float`. The first item starts the fold,
+/// so a one-item sum is that item unchanged; an empty sum is `0` and an empty product `1`.
+macro_rules! impl_sum_product {
+    ($t:ty, $zero:expr, $one:expr, $fallback:expr, $add:expr, $mul:expr) => {
+        impl_sum_product!(@one $t, $t, Sum, sum, $zero, $fallback, $add, |x: $t| x);
+        impl_sum_product!(@one $t, &'a $t, Sum, sum, $zero, $fallback, $add, |x: &$t| x.clone());
+        impl_sum_product!(@one $t, $t, Product, product, $one, $fallback, $mul, |x: $t| x);
+        impl_sum_product!(@one $t, &'a $t, Product, product, $one, $fallback, $mul, |x: &$t| x.clone());
+    };
+    (@one $t:ty, $item:ty, $trait:ident, $method:ident, $empty:expr, $fallback:expr, $step:expr, $first:expr) => {
+        impl<'a> $trait<$item> for Result<$t, i8> {
+            fn $method<I: Iterator<Item = $item>>(mut iter: I) -> Self {
+                let mut acc = match iter.next() {
+                    Some(x) => $first(x),
+                    None => return Ok($empty),
+                };
+                for x in iter {
+                    $step(&mut acc, Borrow::<$t>::borrow(&x))?;
+                }
+                Ok(acc)
+            }
+        }
+
+        impl<'a> $trait<$item> for $t {
+            fn $method<I: Iterator<Item = $item>>(mut iter: I) -> Self {
+                let mut acc = match iter.next() {
+                    Some(x) => $first(x),
+                    None => return $empty,
+                };
+                for x in iter {
+                    if $step(&mut acc, Borrow::<$t>::borrow(&x)).is_err() {
+                        acc = $fallback;
+                    }
+                }
+                acc
+            }
+        }
+    };
+}
+
+impl_sum_product!(
+    Int,
+    Int::new(),
+    Int::from(1),
+    Int::new(),
+    |a: &mut Int, b: &Int| -> Result<(), i8> {
+        a.add_assign_ref(b);
+        Ok(())
+    },
+    |a: &mut Int, b: &Int| -> Result<(), i8> {
+        a.mul_assign_ref(b);
+        Ok(())
+    }
+);
+impl_sum_product!(
+    Float,
+    Float::new(),
+    Float::Big(crate::cache::ONE.clone()),
+    Float::NaN,
+    Float::add_assign_ref,
+    Float::mul_assign_ref
+);
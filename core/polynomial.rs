@@ -0,0 +1,584 @@
+use crate::alloc_prelude::*;
+use crate::compat::int_to_bigint;
+use crate::foundation::{precision, Float, Int};
+use crate::impls::round_parts;
+use crate::math::{ERR_DIV_BY_ZERO, ERR_INVALID_FORMAT};
+use bigdecimal::{BigDecimal, FromPrimitive};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, Zero};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::num::NonZeroU64;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Newton iterations allowed per root before giving up.
+const NEWTON_MAX_STEPS: usize = 200;
+/// Relative step size at which Newton's method in `f64` counts as converged.
+const NEWTON_TOLERANCE: f64 = 1e-15;
+/// Newton iterations allowed when refining a root in `Float` arithmetic. Each one about
+/// doubles the correct digits of a simple root, so this reaches any `precision()` from an
+/// `f64` start; a multiple root, which converges only linearly, gives up instead.
+const REFINE_MAX_STEPS: usize = 30;
+/// Roots whose imaginary part is this small relative to their size are reported as real.
+const REAL_ROOT_TOLERANCE: f64 = 1e-10;
+
+/// Polynomial in one variable with coefficients of a single type, stored lowest degree
+/// first with no trailing zero coefficients.
+///
+/// `Polynomial<Int>` (alias `PolynomialInt`) is exact throughout; its `div_rem` requires the
+/// quotient to have integer coefficients and its `gcd` works over the integers.
+/// `Polynomial<Float>` (alias `PolynomialFloat`) also finds roots numerically.
+///
+/// ```
+/// use imagnum::{create_int, PolynomialInt};
+///
+/// // x² - 3x + 2
+/// let p = PolynomialInt::new(vec![create_int("2"), create_int("-3"), create_int("1")]);
+/// assert_eq!(p.eval(&create_int("5")).unwrap(), create_int("12"));
+/// assert_eq!(p.to_string(), "x^2 - 3x + 2");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<T> {
+    coeffs: Vec<T>,
+}
+
+pub type PolynomialInt = Polynomial<Int>;
+pub type PolynomialFloat = Polynomial<Float>;
+
+impl<T> Polynomial<T> {
+    /// Coefficients, lowest degree first. Empty for the zero polynomial.
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    pub fn into_coeffs(self) -> Vec<T> {
+        self.coeffs
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// The coefficient of the highest power, `None` for the zero polynomial.
+    pub fn leading(&self) -> Option<&T> {
+        self.coeffs.last()
+    }
+}
+
+fn int(v: impl Into<BigInt>) -> Int {
+    Int::Big(v.into())
+}
+
+fn float(v: impl Into<BigDecimal>) -> Float {
+    Float::Big(v.into())
+}
+
+/// Operations shared by every coefficient type, built on the coefficients' `_add` / `_sub`
+/// / `_mul`.
+macro_rules! polynomial_ops {
+    ($t:ty, $zero:expr, $one:expr, $from_usize:expr) => {
+        impl Polynomial<$t> {
+            /// Drops trailing zero coefficients.
+            pub fn new(mut coeffs: Vec<$t>) -> Self {
+                while coeffs.last().is_some_and(|c| c.is_zero()) {
+                    coeffs.pop();
+                }
+                Polynomial { coeffs }
+            }
+
+            pub fn zero() -> Self {
+                Polynomial { coeffs: Vec::new() }
+            }
+
+            pub fn constant(c: $t) -> Self {
+                Self::new(vec![c])
+            }
+
+            /// `c · xⁿ`
+            pub fn monomial(c: $t, n: usize) -> Self {
+                let mut coeffs = vec![$zero; n];
+                coeffs.push(c);
+                Self::new(coeffs)
+            }
+
+            /// The coefficient of `xⁿ`, zero past the degree.
+            pub fn coeff(&self, n: usize) -> $t {
+                self.coeffs.get(n).cloned().unwrap_or($zero)
+            }
+
+            fn zip(&self, other: &Self, f: fn(&$t, &$t) -> Result<$t, i8>) -> Result<Self, i8> {
+                let len = self.coeffs.len().max(other.coeffs.len());
+                Ok(Self::new((0..len).map(|i| f(&self.coeff(i), &other.coeff(i))).collect::<Result<_, _>>()?))
+            }
+
+            pub fn _add(&self, other: &Self) -> Result<Self, i8> {
+                self.zip(other, <$t>::_add)
+            }
+
+            pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+                self.zip(other, <$t>::_sub)
+            }
+
+            pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
+                if self.is_zero() || other.is_zero() {
+                    return Ok(Self::zero());
+                }
+                let mut coeffs = vec![$zero; self.coeffs.len() + other.coeffs.len() - 1];
+                for (i, a) in self.coeffs.iter().enumerate() {
+                    for (j, b) in other.coeffs.iter().enumerate() {
+                        coeffs[i + j] = coeffs[i + j]._add(&a._mul(b)?)?;
+                    }
+                }
+                Ok(Self::new(coeffs))
+            }
+
+            /// Multiplies every coefficient by `k`.
+            pub fn scale(&self, k: &$t) -> Result<Self, i8> {
+                Ok(Self::new(self.coeffs.iter().map(|c| c._mul(k)).collect::<Result<_, _>>()?))
+            }
+
+            /// Value at `x` by Horner's rule.
+            pub fn eval(&self, x: &$t) -> Result<$t, i8> {
+                self.coeffs.iter().rev().try_fold($zero, |acc, c| acc._mul(x)?._add(c))
+            }
+
+            pub fn derivative(&self) -> Result<Self, i8> {
+                let coeffs = self.coeffs.iter().enumerate().skip(1).map(|(n, c)| c._mul(&$from_usize(n))).collect::<Result<_, _>>()?;
+                Ok(Self::new(coeffs))
+            }
+        }
+
+        impl Neg for Polynomial<$t> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Polynomial { coeffs: self.coeffs.into_iter().map(|c| -c).collect() }
+            }
+        }
+
+        impl Display for Polynomial<$t> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                if self.is_zero() {
+                    return write!(f, "{}", $zero);
+                }
+                let one: $t = $one;
+                let mut first = true;
+                for (n, c) in self.coeffs.iter().enumerate().rev() {
+                    if c.is_zero() {
+                        continue;
+                    }
+                    let s = c.to_string();
+                    let (negative, abs) = match s.strip_prefix('-') {
+                        Some(abs) => (true, abs.to_string()),
+                        None => (false, s),
+                    };
+                    match (first, negative) {
+                        (true, true) => write!(f, "-")?,
+                        (false, true) => write!(f, " - ")?,
+                        (false, false) => write!(f, " + ")?,
+                        (true, false) => {}
+                    }
+                    first = false;
+                    let unit = *c == one || *c == -one.clone();
+                    if n == 0 || !unit {
+                        write!(f, "{}", abs)?;
+                    }
+                    match n {
+                        0 => {}
+                        1 => write!(f, "x")?,
+                        _ => write!(f, "x^{}", n)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        polynomial_ops!(@op $t, Add, add, _add);
+        polynomial_ops!(@op $t, Sub, sub, _sub);
+        polynomial_ops!(@op $t, Mul, mul, _mul);
+    };
+    (@op $t:ty, $trait:ident, $method:ident, $inner:ident) => {
+        impl $trait for Polynomial<$t> {
+            type Output = Result<Self, i8>;
+            fn $method(self, other: Self) -> Result<Self, i8> {
+                self.$inner(&other)
+            }
+        }
+
+        impl<'a> $trait<&'a Polynomial<$t>> for Polynomial<$t> {
+            type Output = Result<Polynomial<$t>, i8>;
+            fn $method(self, other: &'a Polynomial<$t>) -> Result<Polynomial<$t>, i8> {
+                self.$inner(other)
+            }
+        }
+    };
+}
+
+polynomial_ops!(Int, int(0), int(1), |n: usize| int(n));
+polynomial_ops!(Float, float(0), float(1), |n: usize| float(BigInt::from(n)));
+
+fn from_bigints(coeffs: Vec<BigInt>) -> Polynomial<Int> {
+    Polynomial::<Int>::new(coeffs.into_iter().map(Int::Big).collect())
+}
+
+/// Remainder of `a · lc(b)^k` divided by `b`, which stays integral.
+fn pseudo_rem(a: &[BigInt], b: &[BigInt]) -> Vec<BigInt> {
+    let mut r = a.to_vec();
+    let lead = b.last().expect("divisor is not zero");
+    while r.len() >= b.len() {
+        let top = r.pop().expect("remainder is not empty");
+        let shift = r.len() + 1 - b.len();
+        for c in r.iter_mut() {
+            *c *= lead;
+        }
+        for (i, bc) in b[..b.len() - 1].iter().enumerate() {
+            r[shift + i] -= &top * bc;
+        }
+        while r.last().is_some_and(|c| c.is_zero()) {
+            r.pop();
+        }
+    }
+    r
+}
+
+fn content(coeffs: &[BigInt]) -> BigInt {
+    coeffs.iter().fold(BigInt::zero(), |g, c| g.gcd(c))
+}
+
+/// Divides out the content, leaving a positive leading coefficient.
+fn primitive_part(coeffs: &[BigInt]) -> Vec<BigInt> {
+    let mut g = content(coeffs);
+    if coeffs.last().is_some_and(|c| c.is_negative()) {
+        g = -g;
+    }
+    coeffs.iter().map(|c| c / &g).collect()
+}
+
+impl Polynomial<Int> {
+    fn as_bigints(&self) -> Vec<BigInt> {
+        self.coeffs.iter().map(int_to_bigint).collect()
+    }
+
+    /// Long division, `self = q · divisor + r` with `deg r < deg divisor`. Fails with
+    /// `DIV_BY_ZERO` for a zero divisor and `INVALID_FORMAT` when a quotient coefficient
+    /// would not be an integer (which cannot happen for a monic divisor).
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), i8> {
+        let b = divisor.as_bigints();
+        let lead = b.last().ok_or(ERR_DIV_BY_ZERO)?;
+        let mut r = self.as_bigints();
+        let mut q = vec![BigInt::zero(); r.len().saturating_sub(b.len() - 1)];
+        while r.len() >= b.len() {
+            let (c, rem) = r.last().expect("remainder is not empty").div_rem(lead);
+            if !rem.is_zero() {
+                return Err(ERR_INVALID_FORMAT);
+            }
+            let shift = r.len() - b.len();
+            for (i, bc) in b.iter().enumerate() {
+                r[shift + i] -= &c * bc;
+            }
+            q[shift] = c;
+            while r.last().is_some_and(|c| c.is_zero()) {
+                r.pop();
+            }
+        }
+        Ok((from_bigints(q), from_bigints(r)))
+    }
+
+    pub fn _div(&self, divisor: &Self) -> Result<Self, i8> {
+        Ok(self.div_rem(divisor)?.0)
+    }
+
+    pub fn _modulo(&self, divisor: &Self) -> Result<Self, i8> {
+        Ok(self.div_rem(divisor)?.1)
+    }
+
+    /// Greatest common divisor over the integers: the content is the integer gcd of the
+    /// contents and the leading coefficient is positive. `gcd(0, 0)` is `0`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.as_bigints(), other.as_bigints());
+        if a.len() < b.len() {
+            core::mem::swap(&mut a, &mut b);
+        }
+        if b.is_empty() {
+            return from_bigints(if a.is_empty() { a } else { primitive_part(&a).into_iter().map(|c| c * content(&a)).collect() });
+        }
+        let g = content(&a).gcd(&content(&b));
+        let (mut a, mut b) = (primitive_part(&a), primitive_part(&b));
+        while !b.is_empty() {
+            let r = pseudo_rem(&a, &b);
+            a = b;
+            b = if r.is_empty() { r } else { primitive_part(&r) };
+        }
+        from_bigints(a.into_iter().map(|c| c * &g).collect())
+    }
+
+    /// The same polynomial with `Float` coefficients.
+    pub fn to_float(&self) -> Result<Polynomial<Float>, i8> {
+        Ok(Polynomial::<Float>::new(self.coeffs.iter().map(Int::to_float).collect::<Result<_, _>>()?))
+    }
+}
+
+/// Complex number as an `f64` pair, for the root finder.
+#[derive(Clone, Copy)]
+struct C64(f64, f64);
+
+impl C64 {
+    fn add(self, o: C64) -> C64 {
+        C64(self.0 + o.0, self.1 + o.1)
+    }
+
+    fn sub(self, o: C64) -> C64 {
+        C64(self.0 - o.0, self.1 - o.1)
+    }
+
+    fn mul(self, o: C64) -> C64 {
+        C64(self.0 * o.0 - self.1 * o.1, self.0 * o.1 + self.1 * o.0)
+    }
+
+    fn div(self, o: C64) -> Option<C64> {
+        let n = o.0 * o.0 + o.1 * o.1;
+        if n == 0.0 {
+            return None;
+        }
+        Some(C64((self.0 * o.0 + self.1 * o.1) / n, (self.1 * o.0 - self.0 * o.1) / n))
+    }
+
+    fn abs(self) -> f64 {
+        self.0.hypot(self.1)
+    }
+
+    fn from_float(value: &Float) -> Result<C64, i8> {
+        let (re, im) = match value {
+            Float::Complex(re, im) => (re.to_f64()?, im.to_f64()?),
+            _ => (value.to_f64()?, 0.0),
+        };
+        if !re.is_finite() || !im.is_finite() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        Ok(C64(re, im))
+    }
+
+    /// A real `Float` when the imaginary part is negligible, otherwise `Float::Complex`.
+    fn to_float(self) -> Result<Float, i8> {
+        let part = |v: f64| BigDecimal::from_f64(v).map(Float::Irrational).ok_or(ERR_INVALID_FORMAT);
+        if self.1.abs() <= REAL_ROOT_TOLERANCE * self.0.abs().max(1.0) {
+            return part(self.0);
+        }
+        Ok(Float::Complex(Box::new(part(self.0)?), Box::new(part(self.1)?)))
+    }
+}
+
+/// `(p(x), p'(x))` by Horner's rule, coefficients lowest degree first.
+fn eval_with_derivative(p: &[C64], x: C64) -> (C64, C64) {
+    let mut value = C64(0.0, 0.0);
+    let mut slope = C64(0.0, 0.0);
+    for &c in p.iter().rev() {
+        slope = slope.mul(x).add(value);
+        value = value.mul(x).add(c);
+    }
+    (value, slope)
+}
+
+/// Newton's method from `x`. Fails with `DIV_BY_ZERO` when the derivative vanishes and
+/// `INVALID_FORMAT` when it does not settle.
+fn newton_c64(p: &[C64], mut x: C64) -> Result<C64, i8> {
+    for _ in 0..NEWTON_MAX_STEPS {
+        let (value, slope) = eval_with_derivative(p, x);
+        if value.abs() == 0.0 {
+            return Ok(x);
+        }
+        let step = value.div(slope).ok_or(ERR_DIV_BY_ZERO)?;
+        x = x.sub(step);
+        if step.abs() <= NEWTON_TOLERANCE * x.abs().max(1.0) {
+            return Ok(x);
+        }
+    }
+    Err(ERR_INVALID_FORMAT)
+}
+
+/// `(p(x), p'(x))` by Horner's rule in `Float` arithmetic.
+fn eval_float_with_derivative(p: &[Float], x: &Float) -> Result<(Float, Float), i8> {
+    let mut value = float(0);
+    let mut slope = float(0);
+    for c in p.iter().rev() {
+        slope = slope._mul(x)?._add(&value)?;
+        value = value._mul(x)?._add(c)?;
+    }
+    Ok((value, slope))
+}
+
+/// A refined root at `precision()` digits, or the exact decimal those digits spell when
+/// it solves `p` exactly. A root Newton's method never had to round is returned as is.
+fn settle(p: &[Float], x: Float) -> Result<Float, i8> {
+    if ![x.re(), x.im()].iter().any(Float::is_irrational) {
+        return Ok(x);
+    }
+    let rounded = round_parts(x, NonZeroU64::new(precision()).unwrap());
+    let exact = |part: Float| part.decimal_value().map(|d| Float::Big(d.normalized())).unwrap_or(part);
+    let candidate = match &rounded {
+        Float::Complex(re, im) => Float::complex(exact(*re.clone()), exact(*im.clone())),
+        _ => exact(rounded.clone()),
+    };
+    let is_root = p.iter().rev().try_fold(float(0), |acc, c| acc._mul(&candidate)?._add(c))?.is_zero();
+    Ok(if is_root { candidate } else { rounded })
+}
+
+/// Newton's method from `x` in `Float` arithmetic, which rounds to `precision()` digits;
+/// it stops once a step no longer moves `x`. Fails with `DIV_BY_ZERO` when the derivative
+/// vanishes and `INVALID_FORMAT` when it does not settle, as near a multiple root, where
+/// rounding swamps the step.
+fn newton_float(p: &[Float], mut x: Float) -> Result<Float, i8> {
+    let digits = NonZeroU64::new(precision()).unwrap();
+    let tolerance = Float::Big(BigDecimal::new(BigInt::from(1u32), 2 * precision() as i64));
+    for _ in 0..REFINE_MAX_STEPS {
+        let (value, slope) = eval_float_with_derivative(p, &x)?;
+        if value.is_zero() {
+            return settle(p, x);
+        }
+        if slope.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        let step = value._div(&slope)?;
+        let next = round_parts(x._sub(&step)?, digits);
+        // |step|² <= (10^-precision)² · max(|x|², 1)
+        let size = next.norm_sqr()?;
+        let scale = if size > 1 { size } else { float(1) };
+        if next == x || step.norm_sqr()? <= tolerance._mul(&scale)? {
+            return settle(p, next);
+        }
+        x = next;
+    }
+    Err(ERR_INVALID_FORMAT)
+}
+
+/// Both roots of `a x² + b x + c` with `a != 0`, exact when the discriminant's square root
+/// is. Uses `q = -(b ± √d) / 2` with the sign that avoids cancellation, then `q / a`
+/// and `c / q`.
+fn quadratic_roots(c: &Float, b: &Float, a: &Float) -> Result<Vec<Float>, i8> {
+    let two = float(2);
+    let d = b._mul(b)?._sub(&float(4)._mul(a)?._mul(c)?)?;
+    let s = if d.is_zero() { float(0) } else { newton_float(&[-d.clone(), float(0), float(1)], d.sqrt()?)? };
+    let (plus, minus) = (b._add(&s)?, b._sub(&s)?);
+    let q = -(if plus.norm_sqr()? >= minus.norm_sqr()? { plus } else { minus })._div(&two)?;
+    if q.is_zero() {
+        // b = d = 0, so c = 0 too
+        return Ok(vec![float(0), float(0)]);
+    }
+    Ok(vec![q._div(a)?, c._div(&q)?])
+}
+
+impl Polynomial<Float> {
+    /// Long division, `self = q · divisor + r` with `deg r < deg divisor`. Fails with
+    /// `DIV_BY_ZERO` for a zero divisor.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), i8> {
+        let lead = divisor.leading().ok_or(ERR_DIV_BY_ZERO)?;
+        let m = divisor.coeffs.len();
+        let mut r = self.coeffs.clone();
+        let mut q = vec![float(0); r.len().saturating_sub(m - 1)];
+        while r.len() >= m {
+            let c = r.last().expect("remainder is not empty")._div(lead)?;
+            let shift = r.len() - m;
+            for (i, d) in divisor.coeffs.iter().enumerate() {
+                r[shift + i] = r[shift + i]._sub(&c._mul(d)?)?;
+            }
+            // the leading term cancels by construction, even if rounding says otherwise
+            r.pop();
+            q[shift] = c;
+            while r.last().is_some_and(|c| c.is_zero()) {
+                r.pop();
+            }
+        }
+        Ok((Self::new(q), Self::new(r)))
+    }
+
+    pub fn _div(&self, divisor: &Self) -> Result<Self, i8> {
+        Ok(self.div_rem(divisor)?.0)
+    }
+
+    pub fn _modulo(&self, divisor: &Self) -> Result<Self, i8> {
+        Ok(self.div_rem(divisor)?.1)
+    }
+
+    /// Divides by the leading coefficient. The zero polynomial stays zero.
+    pub fn monic(&self) -> Result<Self, i8> {
+        match self.leading() {
+            Some(lead) => Ok(Self::new(self.coeffs.iter().map(|c| c._div(lead)).collect::<Result<_, _>>()?)),
+            None => Ok(Self::zero()),
+        }
+    }
+
+    /// Monic greatest common divisor by Euclid's algorithm. Exact only while the divisions
+    /// along the way are; `gcd(0, 0)` is `0`.
+    pub fn gcd(&self, other: &Self) -> Result<Self, i8> {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a._modulo(&b)?;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    fn as_c64(&self) -> Result<Vec<C64>, i8> {
+        self.coeffs.iter().map(C64::from_float).collect()
+    }
+
+    /// Refines `guess` (real or `Float::Complex`) to a nearby root with Newton's method,
+    /// first in `f64` complex arithmetic and then in `Float` arithmetic to `precision()`
+    /// digits. A root the digits solve exactly comes back exact, others as `Irrational`
+    /// parts; a multiple root, where rounding stalls the method, only to about `f64`
+    /// precision. Fails with `DIV_BY_ZERO` when it hits a critical point and
+    /// `INVALID_FORMAT` when it does not converge.
+    pub fn newton(&self, guess: &Float) -> Result<Float, i8> {
+        let p = self.as_c64()?;
+        let start = newton_c64(&p, C64::from_float(guess)?)?.to_float()?;
+        Ok(newton_float(&self.coeffs, start.clone()).unwrap_or(start))
+    }
+
+    /// All complex roots, repeated by multiplicity. Linear and quadratic polynomials are
+    /// solved with `Float` arithmetic, so `x - 0.1` has the root `0.1` and `x² - 2` the
+    /// roots `±√2` to `precision()` digits. Higher degrees find each root by Newton's
+    /// method in `f64` from a complex starting point on the deflated polynomial, then refine
+    /// it against the original as `newton` does. Real roots come back as real `Float`s,
+    /// the rest as `Float::Complex`. A constant has no roots; the zero polynomial fails with
+    /// `INVALID_FORMAT`.
+    pub fn roots(&self) -> Result<Vec<Float>, i8> {
+        match self.coeffs.as_slice() {
+            [] => return Err(ERR_INVALID_FORMAT),
+            [_] => return Ok(Vec::new()),
+            [c0, c1] => return Ok(vec![-c0._div(c1)?]),
+            [c, b, a] => return quadratic_roots(c, b, a),
+            _ => {}
+        }
+        let original = self.as_c64()?;
+        let mut p = original.clone();
+        let mut roots = Vec::with_capacity(p.len() - 1);
+        while p.len() > 1 {
+            let root = if p.len() == 2 {
+                C64(0.0, 0.0).sub(p[0]).div(p[1]).ok_or(ERR_INVALID_FORMAT)?
+            } else {
+                // an off-axis start lets complex roots of real polynomials be reached
+                [C64(0.4, 0.9), C64(-0.7, 0.3), C64(1.3, -1.1)]
+                    .into_iter()
+                    .find_map(|start| newton_c64(&p, start).ok())
+                    .ok_or(ERR_INVALID_FORMAT)?
+            };
+            let root = newton_c64(&original, root).unwrap_or(root);
+            let start = root.to_float()?;
+            roots.push(newton_float(&self.coeffs, start.clone()).unwrap_or(start));
+            // synthetic division by (x - root)
+            let mut carry = C64(0.0, 0.0);
+            let mut quotient = vec![C64(0.0, 0.0); p.len() - 1];
+            for i in (1..p.len()).rev() {
+                carry = carry.mul(root).add(p[i]);
+                quotient[i - 1] = carry;
+            }
+            p = quotient;
+        }
+        Ok(roots)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::alloc_prelude::*;
+use crate::foundation::Float;
+use crate::math::{ERR_DIV_BY_ZERO, ERR_INVALID_FORMAT};
+use bigdecimal::BigDecimal;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Quaternion `w + xi + yj + zk` with arbitrary-precision `Float` components.
+///
+/// Arithmetic returns `Result<Quaternion, i8>` like the scalar types. `*` is the Hamilton
+/// product, which is not commutative.
+///
+/// ```
+/// use imagnum::{create_float, Quaternion};
+///
+/// let i = Quaternion::new(create_float("0"), create_float("1"), create_float("0"), create_float("0"));
+/// let j = Quaternion::new(create_float("0"), create_float("0"), create_float("1"), create_float("0"));
+/// let k = (i.clone() * &j).unwrap();
+/// assert_eq!(k.z, create_float("1"));
+/// assert_eq!((j * &i).unwrap(), -k);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quaternion {
+    pub w: Float,
+    pub x: Float,
+    pub y: Float,
+    pub z: Float,
+}
+
+fn zero() -> Float {
+    Float::Big(BigDecimal::from(0))
+}
+
+fn one() -> Float {
+    Float::Big(BigDecimal::from(1))
+}
+
+impl Quaternion {
+    pub fn new(w: Float, x: Float, y: Float, z: Float) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// `1 + 0i + 0j + 0k`
+    pub fn identity() -> Self {
+        Quaternion::new(one(), zero(), zero(), zero())
+    }
+
+    /// Pure quaternion `0 + xi + yj + zk` for a 3-D vector.
+    pub fn from_vector(x: Float, y: Float, z: Float) -> Self {
+        Quaternion::new(zero(), x, y, z)
+    }
+
+    fn parts(&self) -> [&Float; 4] {
+        [&self.w, &self.x, &self.y, &self.z]
+    }
+
+    fn map(&self, f: impl Fn(&Float) -> Result<Float, i8>) -> Result<Self, i8> {
+        Ok(Quaternion::new(f(&self.w)?, f(&self.x)?, f(&self.y)?, f(&self.z)?))
+    }
+
+    fn zip(&self, other: &Self, f: impl Fn(&Float, &Float) -> Result<Float, i8>) -> Result<Self, i8> {
+        Ok(Quaternion::new(
+            f(&self.w, &other.w)?,
+            f(&self.x, &other.x)?,
+            f(&self.y, &other.y)?,
+            f(&self.z, &other.z)?,
+        ))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.parts().iter().all(|p| p.is_zero())
+    }
+
+    pub fn conj(&self) -> Self {
+        Quaternion::new(self.w.clone(), -self.x.clone(), -self.y.clone(), -self.z.clone())
+    }
+
+    pub fn _add(&self, other: &Self) -> Result<Self, i8> {
+        self.zip(other, Float::_add)
+    }
+
+    pub fn _sub(&self, other: &Self) -> Result<Self, i8> {
+        self.zip(other, Float::_sub)
+    }
+
+    /// Hamilton product `self · other`.
+    pub fn _mul(&self, other: &Self) -> Result<Self, i8> {
+        let (a, b) = (self, other);
+        // sum of signed products, e.g. w = a.w·b.w − a.x·b.x − a.y·b.y − a.z·b.z
+        let sum = |terms: [(bool, &Float, &Float); 4]| -> Result<Float, i8> {
+            let mut acc = zero();
+            for (negate, l, r) in terms {
+                let p = l._mul(r)?;
+                acc = if negate { acc._sub(&p)? } else { acc._add(&p)? };
+            }
+            Ok(acc)
+        };
+        Ok(Quaternion::new(
+            sum([(false, &a.w, &b.w), (true, &a.x, &b.x), (true, &a.y, &b.y), (true, &a.z, &b.z)])?,
+            sum([(false, &a.w, &b.x), (false, &a.x, &b.w), (false, &a.y, &b.z), (true, &a.z, &b.y)])?,
+            sum([(false, &a.w, &b.y), (true, &a.x, &b.z), (false, &a.y, &b.w), (false, &a.z, &b.x)])?,
+            sum([(false, &a.w, &b.z), (false, &a.x, &b.y), (true, &a.y, &b.x), (false, &a.z, &b.w)])?,
+        ))
+    }
+
+    /// Multiplies every component by `k`.
+    pub fn scale(&self, k: &Float) -> Result<Self, i8> {
+        self.map(|p| p._mul(k))
+    }
+
+    /// Four-dimensional dot product.
+    pub fn dot(&self, other: &Self) -> Result<Float, i8> {
+        let mut acc = zero();
+        for (l, r) in self.parts().into_iter().zip(other.parts()) {
+            acc = acc._add(&l._mul(r)?)?;
+        }
+        Ok(acc)
+    }
+
+    /// `w² + x² + y² + z²`, exact for exact components.
+    pub fn norm_sqr(&self) -> Result<Float, i8> {
+        self.dot(self)
+    }
+
+    pub fn norm(&self) -> Result<Float, i8> {
+        self.norm_sqr()?.sqrt()
+    }
+
+    /// `conj(q) / |q|²`. Fails with `DIV_BY_ZERO` for the zero quaternion.
+    pub fn inverse(&self) -> Result<Self, i8> {
+        let n = self.norm_sqr()?;
+        if n.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        self.conj().map(|p| p._div(&n))
+    }
+
+    /// Unit quaternion in the same direction. Fails with `DIV_BY_ZERO` for the zero quaternion.
+    pub fn normalize(&self) -> Result<Self, i8> {
+        let n = self.norm()?;
+        if n.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        self.map(|p| p._div(&n))
+    }
+
+    /// Spherical linear interpolation between the unit quaternions `self` and `other` for
+    /// `t` in `[0, 1]`, taking the shorter arc. The angle between them is `Float::acos` of
+    /// their dot product, at `precision()`; nearly parallel inputs fall back to a normalized
+    /// linear interpolation.
+    pub fn slerp(&self, other: &Self, t: &Float) -> Result<Self, i8> {
+        let mut cos = self.dot(other)?;
+        let mut other = other.clone();
+        if cos.is_nan() {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        if cos.is_negative() {
+            cos = -cos;
+            other = -other;
+        }
+        let one_minus_t = one()._sub(t)?;
+        if cos > Float::Big(BigDecimal::new(9995.into(), 4)) {
+            return self.scale(&one_minus_t)?._add(&other.scale(t)?)?.normalize();
+        }
+        let theta = cos.acos()?;
+        let sin_theta = theta.sin()?;
+        let wa = one_minus_t._mul(&theta)?.sin()?._div(&sin_theta)?;
+        let wb = t._mul(&theta)?.sin()?._div(&sin_theta)?;
+        self.scale(&wa)?._add(&other.scale(&wb)?)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+macro_rules! quaternion_op {
+    ($trait:ident, $method:ident, $inner:ident) => {
+        impl $trait for Quaternion {
+            type Output = Result<Self, i8>;
+            fn $method(self, other: Self) -> Result<Self, i8> {
+                self.$inner(&other)
+            }
+        }
+
+        impl<'a> $trait<&'a Quaternion> for Quaternion {
+            type Output = Result<Quaternion, i8>;
+            fn $method(self, other: &'a Quaternion) -> Result<Quaternion, i8> {
+                self.$inner(other)
+            }
+        }
+    };
+}
+
+quaternion_op!(Add, add, _add);
+quaternion_op!(Sub, sub, _sub);
+quaternion_op!(Mul, mul, _mul);
+
+impl Display for Quaternion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.w)?;
+        for (part, unit) in [(&self.x, 'i'), (&self.y, 'j'), (&self.z, 'k')] {
+            let s = part.to_string();
+            match s.strip_prefix('-') {
+                Some(abs) => write!(f, " - {}{}", abs, unit)?,
+                None => write!(f, " + {}{}", s, unit)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+use crate::alloc_prelude::*;
+use crate::foundation::{precision, Float, Int};
+use crate::compat::int_to_bigint;
+use crate::math::{bigdecimal_to_fraction, ERR_DIV_BY_ZERO, ERR_INVALID_FORMAT};
+use bigdecimal::{BigDecimal, RoundingMode};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use core::cmp::Ordering;
+use core::num::NonZeroU64;
+
+/// Copies of the repetend that the digits read by `from_digits` must end in.
+const REPETEND_COPIES: usize = 4;
+/// Longest run of digits `Float::recurring_parts` and `Display` write out, and the largest
+/// numerator or denominator, in digits, `recurring_pow` computes exactly.
+const MAX_DIGITS: usize = 10_000;
+
+/// How many times `p` divides `n` (`n != 0`).
+fn factor_count(n: &BigInt, p: u32) -> usize {
+    let mut n = n.clone();
+    let mut count = 0;
+    while (&n % p).is_zero() {
+        n /= p;
+        count += 1;
+    }
+    count
+}
+
+/// `num / den` (`den != 0`) in lowest terms with a positive denominator.
+fn reduced(num: &BigInt, den: &BigInt) -> (BigInt, BigInt) {
+    let g = num.gcd(den);
+    let (num, den) = (num / &g, den / &g);
+    if den.is_negative() { (-num, -den) } else { (num, den) }
+}
+
+/// `num / den` (`den != 0`) as an exact float: `Big` when the decimal terminates, otherwise
+/// `Recurring` holding the fraction in lowest terms.
+pub(crate) fn ratio_to_float(num: &BigInt, den: &BigInt) -> Float {
+    let (num, den) = reduced(num, den);
+    let (twos, fives) = (factor_count(&den, 2), factor_count(&den, 5));
+    if den != num_traits::pow(BigInt::from(2u32), twos) * num_traits::pow(BigInt::from(5u32), fives) {
+        return Float::Recurring(num, den);
+    }
+    let places = twos.max(fives);
+    let scaled = num * num_traits::pow(BigInt::from(10u32), places) / den;
+    Float::Big(BigDecimal::new(scaled, places as i64).normalized())
+}
+
+/// The first `precision()` significant digits of `num / den` (`num != 0`, `den > 0`), cut off
+/// rather than rounded so that `from_digits` finds the repetend in them again.
+pub(crate) fn expand(num: &BigInt, den: &BigInt) -> BigDecimal {
+    let digits = precision();
+    // 0.302 decimal digits per bit, with a couple to spare
+    let shift = (digits as i64 + (den.bits() as i64 - num.bits() as i64) * 302 / 1000 + 2).max(0);
+    let scaled = num * num_traits::pow(BigInt::from(10u32), shift as usize) / den;
+    let cut = BigDecimal::new(scaled, shift).with_precision_round(NonZeroU64::new(digits).unwrap(), RoundingMode::Down);
+    cut.normalized()
+}
+
+/// `|num| / den` (`den > 0`, lowest terms) as integer part, non-repeating digits and
+/// repetend, the repetend empty when the decimal terminates. `None` when either run of
+/// digits is longer than `MAX_DIGITS`.
+fn decimal_parts(num: &BigInt, den: &BigInt) -> Option<(BigInt, String, String)> {
+    let (int_part, mut rem) = num.abs().div_rem(den);
+    let next_digit = |rem: &mut BigInt, out: &mut String| {
+        let (q, r) = (&*rem * 10u32).div_rem(den);
+        out.push_str(&q.to_string());
+        *rem = r;
+    };
+    // the digits before the repetend come from the 2s and 5s of the denominator
+    let prefix = factor_count(den, 2).max(factor_count(den, 5));
+    if prefix > MAX_DIGITS {
+        return None;
+    }
+    let mut non_repeating = String::new();
+    for _ in 0..prefix {
+        next_digit(&mut rem, &mut non_repeating);
+    }
+    let mut repetend = String::new();
+    let start = rem.clone();
+    while !rem.is_zero() {
+        if repetend.len() >= MAX_DIGITS {
+            return None;
+        }
+        next_digit(&mut rem, &mut repetend);
+        if rem == start {
+            break;
+        }
+    }
+    Some((int_part, non_repeating, repetend))
+}
+
+/// The digits `ratio_to_float` kept for a recurring value before it held the fraction: the
+/// non-repeating digits and `REPETEND_COPIES` copies of the repetend. `None` when
+/// `decimal_parts` gives up.
+pub(crate) fn stored_digits(num: &BigInt, den: &BigInt) -> Option<BigDecimal> {
+    let (int_part, non_repeating, repetend) = decimal_parts(num, den)?;
+    let frac = format!("{}{}", non_repeating, repetend.repeat(REPETEND_COPIES));
+    let mantissa = int_part * num_traits::pow(BigInt::from(10u32), frac.len()) + frac.parse::<BigInt>().unwrap_or_default();
+    Some(BigDecimal::new(if num.is_negative() { -mantissa } else { mantissa }, frac.len() as i64))
+}
+
+/// Stored digits split into sign, integer part, non-repeating digits and repetend. The
+/// repetend is the period that repeats from the earliest digit on and fills the tail at
+/// least `REPETEND_COPIES` times; it is empty when the digits have no such tail, and all
+/// fractional digits are then non-repeating.
+fn split_digits(bd: &BigDecimal) -> (bool, BigInt, String, String) {
+    let (mantissa, scale) = bd.as_bigint_and_exponent();
+    let neg = mantissa.is_negative();
+    if scale <= 0 {
+        let int_part = mantissa.abs() * num_traits::pow(BigInt::from(10u32), (-scale) as usize);
+        return (neg, int_part, String::new(), String::new());
+    }
+    let scale = scale as usize;
+    let (int_part, frac) = mantissa.abs().div_rem(&num_traits::pow(BigInt::from(10u32), scale));
+    let frac = format!("{:0>width$}", frac, width = scale);
+    let d = frac.as_bytes();
+
+    // the true repetend's tail reaches back furthest: a shorter period that also fits the
+    // last few digits (`0.2111121111…` ends in `1111`) breaks off sooner
+    let found = (1..=d.len() / REPETEND_COPIES)
+        .filter_map(|rep_len| {
+            let mut start = d.len() - rep_len;
+            while start > 0 && d[start - 1] == d[start - 1 + rep_len] {
+                start -= 1;
+            }
+            (d.len() - start >= REPETEND_COPIES * rep_len).then_some((start, rep_len))
+        })
+        .min();
+
+    match found {
+        Some((nonrep_len, rep_len)) => {
+            let rep = frac[nonrep_len..nonrep_len + rep_len].to_string();
+            (neg, int_part, frac[..nonrep_len].to_string(), rep)
+        }
+        None => (neg, int_part, frac, String::new()),
+    }
+}
+
+/// `±int_part.non_repeating(repetend)` as a reduced fraction with a positive denominator;
+/// the digit strings must be ASCII digits, and an empty repetend means none.
+fn parts_ratio(neg: bool, int_part: BigInt, non_repeating: &str, repetend: &str) -> (BigInt, BigInt) {
+    let pow10 = |n: usize| num_traits::pow(BigInt::from(10u32), n);
+    let digits = |s: &str| s.parse::<BigInt>().unwrap_or_default();
+    let (num, den) = if repetend.is_empty() {
+        let den = pow10(non_repeating.len());
+        (int_part * &den + digits(non_repeating), den)
+    } else {
+        let nines = pow10(repetend.len()) - 1u32;
+        let den = pow10(non_repeating.len()) * &nines;
+        (int_part * &den + digits(non_repeating) * nines + digits(repetend), den)
+    };
+    let g = num.gcd(&den);
+    let num = num / &g;
+    (if neg { -num } else { num }, den / g)
+}
+
+/// Decimal digits read back as a value, for where only digits are at hand: `Recurring` when
+/// they end in `REPETEND_COPIES` copies of a repetend (`0.3333` is `0.(3)`), `Big` otherwise.
+pub(crate) fn from_digits(bd: &BigDecimal) -> Float {
+    let (neg, int_part, non_repeating, repetend) = split_digits(bd);
+    let (num, den) = parts_ratio(neg, int_part, &non_repeating, &repetend);
+    ratio_to_float(&num, &den)
+}
+
+/// The exact value of a `Big`, `Small` or `Recurring` float as `(numerator, denominator)`
+/// in lowest terms with a positive denominator.
+pub(crate) fn ratio(f: &Float) -> Option<(BigInt, BigInt)> {
+    match f {
+        Float::Recurring(num, den) if !den.is_zero() => Some(reduced(num, den)),
+        Float::Big(_) | Float::Small(_) => Some(bigdecimal_to_fraction(&f.decimal_value()?)),
+        _ => None,
+    }
+}
+
+/// `a` against `b` on their exact fractions when either is recurring and both are exact;
+/// `None` otherwise.
+pub(crate) fn cmp_exact(a: &Float, b: &Float) -> Option<Ordering> {
+    if !matches!(a, Float::Recurring(..)) && !matches!(b, Float::Recurring(..)) {
+        return None;
+    }
+    let ((p, q), (r, s)) = (ratio(a)?, ratio(b)?);
+    Some((p * s).cmp(&(r * q)))
+}
+
+/// The fraction closest to `num / den` (in lowest terms, `den > 0`) whose denominator is
+/// at most `max_den`, from the continued-fraction convergents of `|num| / den` and the best
+/// semiconvergent below the bound.
+fn closest_fraction(num: &BigInt, den: &BigInt, max_den: &BigInt) -> (BigInt, BigInt) {
+    if den <= max_den {
+        return (num.clone(), den.clone());
+    }
+    let (mut p0, mut q0, mut p1, mut q1) = (BigInt::zero(), BigInt::one(), BigInt::one(), BigInt::zero());
+    let (mut n, mut d) = (num.abs(), den.clone());
+    loop {
+        let a = &n / &d;
+        let q2 = &q0 + &a * &q1;
+        if &q2 > max_den {
+            break;
+        }
+        let p2 = &p0 + &a * &p1;
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        let r = &n - &a * &d;
+        (n, d) = (d, r);
+    }
+    let k = (max_den - &q0) / &q1;
+    let (lp, lq) = (&p0 + &k * &p1, &q0 + &k * &q1);
+    // |p/q - x| compared through |p·den - |num|·q| / q
+    let dist = |p: &BigInt, q: &BigInt| (p * den - num.abs() * q).abs();
+    let (p, q) = if dist(&p1, &q1) * &lq <= dist(&lp, &lq) * &q1 { (p1, q1) } else { (lp, lq) };
+    (if num.is_negative() { -p } else { p }, q)
+}
+
+/// The arithmetic operation `Float::recurring_op` applies to two fractions.
+#[derive(Clone, Copy)]
+pub(crate) enum RatioOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Float {
+    /// The exact value as a fraction `(numerator, denominator)` in lowest terms with a
+    /// positive denominator, for `Big`, `Small` and `Recurring` values; `None` for every
+    /// other kind. A recurring value gives its true fraction, so `0.(3)` is `(1, 3)`.
+    ///
+    /// ```
+    /// use imagnum::{create_float, create_int};
+    ///
+    /// let (n, d) = create_float("0.1(6)").to_ratio().unwrap();
+    /// assert_eq!((n, d), (create_int("1"), create_int("6")));
+    /// ```
+    pub fn to_ratio(&self) -> Option<(Int, Int)> {
+        let (num, den) = ratio(self)?;
+        Some((Int::Big(num), Int::Big(den)))
+    }
+
+    /// The digits of a recurring value as `(integer_part, non_repeating, repetend)`, so
+    /// `-1.1(6)` gives `("-1", "1", "6")`; the integer part carries the sign (`"-0"` for
+    /// `-0.(3)`). `None` for every kind other than `Recurring`, and when the repetend or the
+    /// digits before it run past `MAX_DIGITS` (10 000) digits.
+    ///
+    /// ```
+    /// use imagnum::create_float;
+    ///
+    /// let parts = create_float("12.34(56)").recurring_parts().unwrap();
+    /// assert_eq!(parts, ("12".to_string(), "34".to_string(), "56".to_string()));
+    /// ```
+    pub fn recurring_parts(&self) -> Option<(String, String, String)> {
+        let Float::Recurring(_, _) = self else {
+            return None;
+        };
+        let (num, den) = ratio(self)?;
+        let (int_part, non_repeating, repetend) = decimal_parts(&num, &den)?;
+        if repetend.is_empty() {
+            return None;
+        }
+        let sign = if num.is_negative() { "-" } else { "" };
+        Some((format!("{}{}", sign, int_part), non_repeating, repetend))
+    }
+
+    /// The value `integer.non_repeating(repetend)`, the inverse of `recurring_parts`: a
+    /// `Recurring` float, or `Big` when the value terminates (an empty repetend, or `(0)`
+    /// and `(9)`). `integer` may start with `+` or `-`, and any part may be empty.
+    ///
+    /// Fails with `INVALID_FORMAT` when a part has anything but ASCII digits.
+    ///
+    /// ```
+    /// use imagnum::{create_float, Float};
+    ///
+    /// assert_eq!(Float::from_recurring_parts("0", "", "3").unwrap(), create_float("0.(3)"));
+    /// assert_eq!(Float::from_recurring_parts("-0", "4", "9").unwrap(), create_float("-0.5"));
+    /// ```
+    pub fn from_recurring_parts(integer: &str, non_repeating: &str, repetend: &str) -> Result<Float, i8> {
+        let neg = integer.starts_with('-');
+        let integer = integer.strip_prefix(['+', '-']).unwrap_or(integer);
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(integer) || !is_digits(non_repeating) || !is_digits(repetend) {
+            return Err(ERR_INVALID_FORMAT);
+        }
+        let int_part = integer.parse::<BigInt>().unwrap_or_default();
+        let (num, den) = parts_ratio(neg, int_part, non_repeating, repetend);
+        Ok(ratio_to_float(&num, &den))
+    }
+
+    /// The value as a fraction `(numerator, denominator)` in lowest terms with a positive
+    /// denominator. With no `max_denominator` the fraction is exact for `Big`, `Small` and
+    /// `Recurring` values and is that of the stored digits for `Irrational` values and surds.
+    /// With one, the result is the closest fraction whose denominator does not exceed it,
+    /// found from the continued fraction of the value (so `π` with `1000` gives `355/113`).
+    ///
+    /// Fails with `INVALID_FORMAT` for NaN, infinities and complex values, and for a
+    /// `max_denominator` below 1.
+    ///
+    /// ```
+    /// use imagnum::{create_float, create_int};
+    ///
+    /// let (n, d) = create_float("0.75").to_fraction(None).unwrap();
+    /// assert_eq!((n, d), (create_int("3"), create_int("4")));
+    /// let (n, d) = create_float("2").sqrt().unwrap().to_fraction(Some(&create_int("100"))).unwrap();
+    /// assert_eq!((n, d), (create_int("140"), create_int("99")));
+    /// ```
+    pub fn to_fraction(&self, max_denominator: Option<&Int>) -> Result<(Int, Int), i8> {
+        let (num, den) = match self {
+            Float::Irrational(bd) => bigdecimal_to_fraction(bd),
+            Float::Surd(c, r) => bigdecimal_to_fraction(&crate::surd::expand(c, r)),
+            _ => ratio(self).ok_or(ERR_INVALID_FORMAT)?,
+        };
+        let (num, den) = match max_denominator.map(int_to_bigint) {
+            Some(max) if max < BigInt::one() => return Err(ERR_INVALID_FORMAT),
+            Some(max) => closest_fraction(&num, &den, &max),
+            None => (num, den),
+        };
+        Ok((Int::Big(num), Int::Big(den)))
+    }
+
+    /// `numerator / denominator` as an exact float: `Big` when the decimal terminates and
+    /// `Recurring` otherwise. Fails with `DIV_BY_ZERO` for a zero denominator.
+    ///
+    /// ```
+    /// use imagnum::{create_float, create_int, Float};
+    ///
+    /// let third = Float::from_fraction(&create_int("1"), &create_int("3")).unwrap();
+    /// assert_eq!(third, create_float("0.(3)"));
+    /// ```
+    pub fn from_fraction(numerator: &Int, denominator: &Int) -> Result<Float, i8> {
+        let den = int_to_bigint(denominator);
+        if den.is_zero() {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        Ok(ratio_to_float(&int_to_bigint(numerator), &den))
+    }
+
+    /// `self op other` on the exact fractions when either side is recurring and both are
+    /// exact, so `0.(3) + 0.(6)` is `1` rather than `0.99…`. `None` otherwise, and for a
+    /// zero divisor, leaving the caller's decimal path to handle it.
+    pub(crate) fn recurring_op(&self, other: &Self, op: RatioOp) -> Option<Float> {
+        if !matches!(self, Float::Recurring(..)) && !matches!(other, Float::Recurring(..)) {
+            return None;
+        }
+        let ((a, b), (c, d)) = (ratio(self)?, ratio(other)?);
+        let (num, den) = match op {
+            RatioOp::Add => (a * &d + c * &b, b * d),
+            RatioOp::Sub => (a * &d - c * &b, b * d),
+            RatioOp::Mul => (a * c, b * d),
+            RatioOp::Div if c.is_zero() => return None,
+            RatioOp::Div => (a * d, b * c),
+        };
+        Some(ratio_to_float(&num, &den))
+    }
+
+    /// `(a / b)^n` for a recurring value, `n >= 0`, while the power's numerator and
+    /// denominator stay within `MAX_DIGITS` digits.
+    pub(crate) fn recurring_pow(&self, n: u64) -> Option<Float> {
+        let Float::Recurring(_, _) = self else {
+            return None;
+        };
+        let (a, b) = ratio(self)?;
+        // 3.32 bits per decimal digit
+        if a.bits().max(b.bits()).saturating_mul(n) > MAX_DIGITS as u64 * 10 / 3 {
+            return None;
+        }
+        let n = usize::try_from(n).ok()?;
+        Some(ratio_to_float(&num_traits::pow(a, n), &num_traits::pow(b, n)))
+    }
+}
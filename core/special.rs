@@ -0,0 +1,619 @@
+use crate::alloc_prelude::*;
+use crate::consts;
+use crate::foundation::{precision, Float};
+use crate::math::{div_bigdecimal, ln_bigdecimal, ERR_DIV_BY_ZERO, ERR_INVALID_FORMAT, ERR_NUMBER_TOO_LARGE};
+use bigdecimal::{BigDecimal, Context};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use core::num::NonZeroU64;
+
+/// Guard digits carried on top of `precision()` by every special function.
+const GUARD: u64 = 10;
+
+/// Largest integer argument for which `gamma` and `beta` return exact factorials; past it
+/// they are rounded like any other argument.
+pub const EXACT_GAMMA_LIMIT: u64 = 10_000;
+
+/// Arguments of `exp` at or beyond this magnitude overflow (or underflow) the decimal
+/// exponent range.
+const EXP_LIMIT: f64 = 1e15;
+
+fn context(digits: u64) -> Context {
+    Context::default().with_precision(NonZeroU64::new(digits.max(1)).unwrap())
+}
+
+fn digits(ctx: &Context) -> u64 {
+    ctx.precision().get()
+}
+
+/// `10^-n` as a `BigDecimal`.
+fn ten_to_minus(n: u64) -> BigDecimal {
+    BigDecimal::new(BigInt::one(), n as i64)
+}
+
+fn div(a: &BigDecimal, b: &BigDecimal, ctx: &Context) -> BigDecimal {
+    div_bigdecimal(a, b, ctx.precision())
+}
+
+/// Natural logarithm of a positive decimal at the context's precision.
+fn ln(x: &BigDecimal, ctx: &Context) -> BigDecimal {
+    ln_bigdecimal(x, digits(ctx))
+}
+
+fn exp(x: &BigDecimal, ctx: &Context) -> Result<BigDecimal, i8> {
+    if x.abs().to_f64().is_none_or(|v| v >= EXP_LIMIT) {
+        return Err(ERR_NUMBER_TOO_LARGE);
+    }
+    Ok(x.exp_with_context(ctx))
+}
+
+/// `sin(x)` from its Taylor series; meant for `|x| <= π/2`.
+fn sin(x: &BigDecimal, ctx: &Context) -> BigDecimal {
+    let x_sq = ctx.round_decimal(x * x);
+    let eps = x.abs() * ten_to_minus(digits(ctx) + 1);
+    let mut term = x.clone();
+    let mut sum = x.clone();
+    let mut k = 1u64;
+    loop {
+        term = div(&-(term * &x_sq), &BigDecimal::from((2 * k) * (2 * k + 1)), ctx);
+        if term.abs() < eps {
+            break;
+        }
+        sum += &term;
+        k += 1;
+    }
+    ctx.round_decimal(sum)
+}
+
+/// `B_2, B_4, ..., B_2n` as reduced fractions, from the integer tangent numbers `T_k`
+/// (Brent and Harvey) via `B_2k = (-1)^(k-1) 2k T_k / (4^k (4^k - 1))`.
+fn bernoulli_even(n: usize) -> Vec<(BigInt, BigInt)> {
+    let mut t = vec![BigInt::zero(); n + 1];
+    if n == 0 {
+        return Vec::new();
+    }
+    t[1] = BigInt::one();
+    for k in 2..=n {
+        t[k] = &t[k - 1] * (k - 1);
+    }
+    for k in 2..=n {
+        for j in k..=n {
+            t[j] = &t[j - 1] * (j - k) + &t[j] * (j - k + 2);
+        }
+    }
+    (1..=n)
+        .map(|k| {
+            let four_k = BigInt::one() << (2 * k);
+            let mut num = &t[k] * (2 * k);
+            if k % 2 == 0 {
+                num = -num;
+            }
+            let den = &four_k * (&four_k - 1u32);
+            let g = num.gcd(&den);
+            (num / &g, den / g)
+        })
+        .collect()
+}
+
+/// Terms of the Stirling series needed at `y` for `digits` digits: the `k`-th term is
+/// about `2 (2k)! / (2πy)^2k`.
+fn stirling_terms(y: f64, digits: u64) -> usize {
+    let target = -(digits as f64 + 1.0) * core::f64::consts::LN_10;
+    let log_2pi_y = (2.0 * core::f64::consts::PI * y).ln();
+    let mut log_term = 2f64.ln();
+    let mut k = 0usize;
+    while log_term > target {
+        k += 1;
+        log_term += ((2 * k - 1) as f64).ln() + ((2 * k) as f64).ln() - 2.0 * log_2pi_y;
+    }
+    k.max(1)
+}
+
+/// `ln Γ(y)` for `y >= digits` from Stirling's series
+/// `(y - 1/2) ln y - y + ln(2π)/2 + Σ B_2k / (2k (2k-1) y^(2k-1))`.
+fn ln_gamma_stirling(y: &BigDecimal, ctx: &Context) -> BigDecimal {
+    let d = digits(ctx);
+    let half = BigDecimal::new(BigInt::from(5), 1);
+    let two_pi = consts::pi(d + 2).double();
+    let mut sum = (y - &half) * ln(y, ctx) - y + ln(&two_pi, ctx).half();
+
+    let inv_y = y.inverse_with_context(ctx);
+    let inv_y_sq = ctx.round_decimal(&inv_y * &inv_y);
+    let mut power = inv_y;
+    let terms = stirling_terms(y.to_f64().unwrap_or(f64::MAX), d);
+    for (k, (num, den)) in bernoulli_even(terms).into_iter().enumerate() {
+        let k = k as u64 + 1;
+        let den = BigDecimal::from(den * (2 * k) * (2 * k - 1));
+        sum += div(&(BigDecimal::from(num) * &power), &den, ctx);
+        power = ctx.round_decimal(power * &inv_y_sq);
+    }
+    ctx.round_decimal(sum)
+}
+
+/// `ln |Γ(x)|` and whether `Γ(x)` is negative, for a real `x` that is not a pole.
+///
+/// Negative arguments use the reflection `Γ(x) Γ(1-x) = π / sin(πx)`; small positive ones
+/// are shifted up to `digits` with `Γ(x) = Γ(x+n) / (x (x+1) ... (x+n-1))`.
+fn ln_gamma_parts(x: &BigDecimal, ctx: &Context) -> (BigDecimal, bool) {
+    let d = digits(ctx);
+    if x.is_negative() {
+        // sin(πx) = (-1)^n sin(π(x - n)) with n the nearest integer, so |π(x - n)| <= π/2
+        let n = x.round(0);
+        let odd = n.with_scale(0).as_bigint_and_exponent().0.is_odd();
+        let s = sin(&ctx.round_decimal(consts::pi(d + 2) * (x - &n)), ctx);
+        let negative = s.is_negative() != odd;
+        let (reflected, _) = ln_gamma_parts(&(BigDecimal::one() - x), ctx);
+        let value = ln(&consts::pi(d + 2), ctx) - ln(&s.abs(), ctx) - reflected;
+        return (ctx.round_decimal(value), negative);
+    }
+    let shift = BigDecimal::from(d);
+    if x >= &shift {
+        return (ln_gamma_stirling(x, ctx), false);
+    }
+    let mut y = x.clone();
+    let mut product = BigDecimal::one();
+    while y < shift {
+        product = ctx.round_decimal(product * &y);
+        y += BigDecimal::one();
+    }
+    (ctx.round_decimal(ln_gamma_stirling(&y, ctx) - ln(&product, ctx)), false)
+}
+
+/// Digits lost to the size of `ln Γ(x)` (about `|x| ln |x|`) when it is exponentiated or
+/// subtracted from.
+fn magnitude_digits(x: &BigDecimal) -> u64 {
+    let v = x.abs().to_f64().unwrap_or(f64::MAX);
+    if v <= 10.0 { 0 } else { (v * v.ln()).log10().ceil() as u64 }
+}
+
+/// The real value of `x`, with `INVALID_FORMAT` for NaN, infinities and complex values.
+fn real_value(x: &Float) -> Result<BigDecimal, i8> {
+    if x.is_complex() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    x.decimal_value().ok_or(ERR_INVALID_FORMAT)
+}
+
+/// `x` as a `u64` when it is a positive integer no larger than `EXACT_GAMMA_LIMIT`.
+fn small_positive_integer(x: &BigDecimal) -> Option<u64> {
+    if !x.is_integer() || !x.is_positive() {
+        return None;
+    }
+    x.to_u64().filter(|&n| n <= EXACT_GAMMA_LIMIT)
+}
+
+fn is_pole(x: &BigDecimal) -> bool {
+    x.is_integer() && !x.is_positive()
+}
+
+/// `(n - 1)!` as a `BigInt`.
+fn factorial_below(n: u64) -> BigInt {
+    (2..n).fold(BigInt::one(), |acc, k| acc * k)
+}
+
+/// `num / den` exactly when the quotient terminates, otherwise rounded to `precision()`
+/// digits and marked irrational.
+fn ratio(num: BigInt, den: BigInt) -> Float {
+    let g = num.gcd(&den);
+    let (num, den) = (num / &g, den / g);
+    let (mut rest, mut twos, mut fives) = (den.clone(), 0i64, 0i64);
+    while rest.is_even() {
+        rest /= 2u32;
+        twos += 1;
+    }
+    while (&rest % 5u32).is_zero() {
+        rest /= 5u32;
+        fives += 1;
+    }
+    if rest.is_one() {
+        let scale = twos.max(fives);
+        let factor = num_traits::pow(BigInt::from(10), scale as usize) / den;
+        return Float::Big(BigDecimal::new(num * factor, scale).normalized());
+    }
+    let ctx = context(precision());
+    Float::Irrational(div(&BigDecimal::from(num), &BigDecimal::from(den), &ctx).normalized())
+}
+
+/// Rounds a working-precision result to `precision()` digits.
+fn finish(value: BigDecimal) -> Float {
+    Float::Irrational(context(precision()).round_decimal(value).normalized())
+}
+
+impl Float {
+    /// The gamma function `Γ(self)`, with `Γ(n) = (n - 1)!`.
+    ///
+    /// Exact for positive integers up to `EXACT_GAMMA_LIMIT`; otherwise computed from
+    /// Stirling's series at `precision()` significant digits (plus guard digits) and
+    /// returned as `Irrational`. `Γ(inf)` is infinite. Fails with `DIV_BY_ZERO` at the poles
+    /// `0, -1, -2, ...`, `NUMBER_TOO_LARGE` when the result leaves the decimal exponent
+    /// range, and `INVALID_FORMAT` for NaN, `-inf` and complex values.
+    pub fn gamma(&self) -> Result<Float, i8> {
+        if matches!(self, Float::Infinity) {
+            return Ok(Float::Infinity);
+        }
+        let x = real_value(self)?;
+        if is_pole(&x) {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        if let Some(n) = small_positive_integer(&x) {
+            return Ok(Float::Big(BigDecimal::from(factorial_below(n))));
+        }
+        let ctx = context(precision() + GUARD + magnitude_digits(&x));
+        let (ln_abs, negative) = ln_gamma_parts(&x, &ctx);
+        let value = exp(&ln_abs, &ctx)?;
+        Ok(finish(if negative { -value } else { value }))
+    }
+
+    /// `ln |Γ(self)|`, which stays representable long after `gamma` overflows. For negative
+    /// arguments the sign of `Γ` is dropped, as in C's `lgamma`.
+    ///
+    /// Exactly 0 at 1 and 2; otherwise `Irrational` at `precision()` significant digits.
+    /// `inf` gives `inf`. Fails with `DIV_BY_ZERO` at the poles `0, -1, -2, ...` and with
+    /// `INVALID_FORMAT` for NaN, `-inf` and complex values.
+    pub fn ln_gamma(&self) -> Result<Float, i8> {
+        if matches!(self, Float::Infinity) {
+            return Ok(Float::Infinity);
+        }
+        let x = real_value(self)?;
+        if is_pole(&x) {
+            return Err(ERR_DIV_BY_ZERO);
+        }
+        if x.is_integer() && matches!(x.to_u8(), Some(1 | 2)) {
+            return Ok(Float::Big(BigDecimal::zero()));
+        }
+        let ctx = context(precision() + GUARD + magnitude_digits(&x));
+        Ok(finish(ln_gamma_parts(&x, &ctx).0))
+    }
+
+    /// The error function `erf(self) = 2/√π ∫₀ˣ e^(-t²) dt`.
+    ///
+    /// Exact at 0 and `±inf`; otherwise `Irrational` at `precision()` significant digits,
+    /// summed from `e^(-x²) Σ 2ⁿ x^(2n+1) / (2n+1)!!`, whose terms are all positive, or
+    /// taken as `1 - erfc(x)` for large `|x|`. Fails with `INVALID_FORMAT` for NaN and
+    /// complex values.
+    pub fn erf(&self) -> Result<Float, i8> {
+        match self {
+            Float::Infinity => return Ok(Float::Big(BigDecimal::one())),
+            Float::NegInfinity => return Ok(Float::Big(-BigDecimal::one())),
+            _ => {}
+        }
+        let x = real_value(self)?;
+        if x.is_zero() {
+            return Ok(Float::Big(x));
+        }
+        let ctx = context(precision() + GUARD);
+        let value = erf_positive(&x.abs(), &ctx)?;
+        Ok(finish(if x.is_negative() { -value } else { value }))
+    }
+
+    /// The complementary error function `erfc(self) = 1 - erf(self)`, without the
+    /// cancellation of subtracting for large arguments: there it comes from the continued
+    /// fraction `e^(-x²)/√π · 1/(x + (1/2)/(x + 1/(x + (3/2)/(x + ...))))`.
+    ///
+    /// Exact at 0 and `±inf`; otherwise `Irrational` at `precision()` significant digits,
+    /// underflowing to 0 once `e^(-x²)` leaves the decimal exponent range. Fails with
+    /// `INVALID_FORMAT` for NaN and complex values.
+    pub fn erfc(&self) -> Result<Float, i8> {
+        match self {
+            Float::Infinity => return Ok(Float::Big(BigDecimal::zero())),
+            Float::NegInfinity => return Ok(Float::Big(BigDecimal::from(2))),
+            _ => {}
+        }
+        let x = real_value(self)?;
+        if x.is_zero() {
+            return Ok(Float::Big(BigDecimal::one()));
+        }
+        let ctx = context(precision() + GUARD);
+        if x.is_negative() {
+            return Ok(finish(BigDecimal::one() + erf_positive(&x.abs(), &ctx)?));
+        }
+        match erfc_positive(&x, &ctx) {
+            Err(ERR_NUMBER_TOO_LARGE) => Ok(Float::Irrational(BigDecimal::zero())),
+            other => Ok(finish(other?)),
+        }
+    }
+}
+
+/// Whether `x² >= digits`, past which the continued fraction converges faster than the
+/// series.
+fn use_continued_fraction(x: &BigDecimal, ctx: &Context) -> bool {
+    let limit = BigDecimal::from(digits(ctx));
+    x * x >= limit
+}
+
+/// `erf(x)` for `x > 0`.
+fn erf_positive(x: &BigDecimal, ctx: &Context) -> Result<BigDecimal, i8> {
+    if use_continued_fraction(x, ctx) {
+        let ln_10 = core::f64::consts::LN_10;
+        // erfc(x) < e^(-x²) is below the last digit
+        if x.to_f64().is_none_or(|v| v * v > (digits(ctx) + 1) as f64 * ln_10) {
+            return Ok(BigDecimal::one());
+        }
+        return Ok(BigDecimal::one() - erfc_positive(x, ctx)?);
+    }
+    erf_series(x, ctx)
+}
+
+/// `erfc(x)` for `x > 0`. Below the continued-fraction range it is `1 - erf(x)` with the
+/// `x² / ln 10` digits that the subtraction cancels added to the working precision.
+fn erfc_positive(x: &BigDecimal, ctx: &Context) -> Result<BigDecimal, i8> {
+    if use_continued_fraction(x, ctx) {
+        return erfc_continued_fraction(x, ctx);
+    }
+    let lost = (x.to_f64().unwrap_or(0.0).powi(2) / core::f64::consts::LN_10).ceil() as u64;
+    let wide = context(digits(ctx) + lost + 1);
+    Ok(ctx.round_decimal(BigDecimal::one() - erf_series(x, &wide)?))
+}
+
+fn erf_series(x: &BigDecimal, ctx: &Context) -> Result<BigDecimal, i8> {
+    let d = digits(ctx);
+    let two_x_sq = ctx.round_decimal((x * x).double());
+    let mut term = x.clone();
+    let mut sum = x.clone();
+    let mut n = 1u64;
+    loop {
+        term = div(&(term * &two_x_sq), &BigDecimal::from(2 * n + 1), ctx);
+        // terms grow until n ≈ x², then fall away
+        if term < &sum * ten_to_minus(d + 1) {
+            break;
+        }
+        sum += &term;
+        n += 1;
+    }
+    let sqrt_pi = consts::pi(d + 2).sqrt_with_context(ctx).ok_or(ERR_INVALID_FORMAT)?;
+    let factor = div(&exp(&-(x * x), ctx)?.double(), &sqrt_pi, ctx);
+    Ok(ctx.round_decimal(sum * factor))
+}
+
+/// The continued fraction for `erfc`, evaluated forwards with the modified Lentz method.
+fn erfc_continued_fraction(x: &BigDecimal, ctx: &Context) -> Result<BigDecimal, i8> {
+    let d = digits(ctx);
+    let eps = ten_to_minus(d + 1);
+    let mut f = x.clone();
+    let mut c = x.clone();
+    let mut dd = BigDecimal::zero();
+    let mut n = 1u64;
+    loop {
+        let a = BigDecimal::from(n).half();
+        dd = (x + &a * dd).inverse_with_context(ctx);
+        c = x + div(&a, &c, ctx);
+        let delta = ctx.round_decimal(&c * &dd);
+        f = ctx.round_decimal(&f * &delta);
+        if (delta - BigDecimal::one()).abs() < eps {
+            break;
+        }
+        n += 1;
+    }
+    let sqrt_pi = consts::pi(d + 2).sqrt_with_context(ctx).ok_or(ERR_INVALID_FORMAT)?;
+    Ok(div(&exp(&-(x * x), ctx)?, &(sqrt_pi * f), ctx))
+}
+
+/// The beta function `B(a, b) = Γ(a) Γ(b) / Γ(a + b)`.
+///
+/// For positive integers up to `EXACT_GAMMA_LIMIT` it is the exact fraction
+/// `(a-1)! (b-1)! / (a+b-1)!`, returned exactly when its decimal expansion terminates.
+/// Otherwise it is `exp(ln Γ(a) + ln Γ(b) - ln Γ(a+b))` with the signs of the gammas, at
+/// `precision()` significant digits; 0 when only `a + b` is a pole. Fails with
+/// `DIV_BY_ZERO` when `a` or `b` is a pole and `INVALID_FORMAT` for NaN, infinite and
+/// complex arguments.
+///
+/// ```
+/// use imagnum::{create_float, special::beta};
+///
+/// let half = create_float("0.5");
+/// // B(1/2, 1/2) = π
+/// assert!((beta(&half, &half).unwrap().to_f64().unwrap() - std::f64::consts::PI).abs() < 1e-15);
+/// assert_eq!(beta(&create_float("1"), &create_float("4")).unwrap(), create_float("0.25"));
+/// ```
+pub fn beta(a: &Float, b: &Float) -> Result<Float, i8> {
+    let a = real_value(a)?;
+    let b = real_value(b)?;
+    if is_pole(&a) || is_pole(&b) {
+        return Err(ERR_DIV_BY_ZERO);
+    }
+    let sum = &a + &b;
+    if is_pole(&sum) {
+        return Ok(Float::Big(BigDecimal::zero()));
+    }
+    if let (Some(m), Some(n), Some(s)) = (small_positive_integer(&a), small_positive_integer(&b), small_positive_integer(&sum)) {
+        return Ok(ratio(factorial_below(m) * factorial_below(n), factorial_below(s)));
+    }
+    let extra = [&a, &b, &sum].iter().map(|x| magnitude_digits(x)).max().unwrap_or(0);
+    let ctx = context(precision() + GUARD + extra);
+    let (la, na) = ln_gamma_parts(&a, &ctx);
+    let (lb, nb) = ln_gamma_parts(&b, &ctx);
+    let (ls, ns) = ln_gamma_parts(&sum, &ctx);
+    let value = exp(&ctx.round_decimal(la + lb - ls), &ctx)?;
+    Ok(finish(if na ^ nb ^ ns { -value } else { value }))
+}
+
+/// Largest `|x|` and `|n|` the Bessel functions accept; the series' terms and working digits
+/// both grow with `|x|`, and its leading factorial with `n`, so past it they would run for
+/// seconds to minutes.
+pub const BESSEL_LIMIT: u64 = 1_000;
+
+/// The order as a `u64`, or `NUMBER_TOO_LARGE` when `|n|` or `|x|` is past `BESSEL_LIMIT`.
+fn bessel_order(n: i64, x: &BigDecimal) -> Result<u64, i8> {
+    let order = n.unsigned_abs();
+    if order > BESSEL_LIMIT || x.abs() > BESSEL_LIMIT {
+        return Err(ERR_NUMBER_TOO_LARGE);
+    }
+    Ok(order)
+}
+
+/// Digits of `e^|x|` that the alternating Bessel series cancels.
+fn cancelled_digits(x: &BigDecimal) -> u64 {
+    (x.abs().to_f64().unwrap_or(f64::MAX) * core::f64::consts::LOG10_E).ceil() as u64
+}
+
+/// `(x/2)^n / n!`, the leading term of the Bessel series.
+fn bessel_leading(half_x: &BigDecimal, n: u64, ctx: &Context) -> BigDecimal {
+    let factorial = BigDecimal::from(factorial_below(n + 1));
+    div(&half_x.powi_with_context(n as i64, ctx), &factorial, ctx)
+}
+
+/// `Σ (-x²/4)^k (x/2)^n / (k! (n+k)!)`, each term also multiplied by `weight(k)`.
+fn bessel_series(x: &BigDecimal, n: u64, ctx: &Context, weight: impl Fn(u64) -> BigDecimal) -> BigDecimal {
+    let half_x = x.half();
+    let q = -ctx.round_decimal(&half_x * &half_x);
+    let mut term = bessel_leading(&half_x, n, ctx);
+    let mut sum = ctx.round_decimal(&term * weight(0));
+    let mut largest = term.abs();
+    let mut k = 1u64;
+    loop {
+        term = div(&(term * &q), &BigDecimal::from(k * (n + k)), ctx);
+        let abs = term.abs();
+        if abs > largest {
+            largest = abs;
+        } else if abs < &largest * ten_to_minus(digits(ctx)) {
+            break;
+        }
+        sum += ctx.round_decimal(&term * weight(k));
+        k += 1;
+    }
+    ctx.round_decimal(sum)
+}
+
+/// `value` of order `|n|` turned into order `n`: `f_-n = (-1)^n f_n` for both kinds.
+fn negative_order(value: BigDecimal, n: i64) -> BigDecimal {
+    if n < 0 && n % 2 != 0 { -value } else { value }
+}
+
+/// The Bessel function of the first kind `J_n(x)` of integer order.
+///
+/// Summed from `Σ (-1)^k (x/2)^(2k+n) / (k! (n+k)!)` with enough extra working digits to
+/// absorb the series' cancellation (about `|x| / ln 10`, so the cost grows with `|x|`),
+/// and returned as `Irrational` at `precision()` significant digits; `J_n(0)` is exact.
+/// Negative orders use `J_-n = (-1)^n J_n`. Fails with `INVALID_FORMAT` for NaN,
+/// infinite and complex `x`, and with `NUMBER_TOO_LARGE` when `|n|` or `|x|` is past
+/// `BESSEL_LIMIT`.
+pub fn bessel_j(n: i64, x: &Float) -> Result<Float, i8> {
+    let x = real_value(x)?;
+    if x.is_zero() {
+        return Ok(Float::Big(BigDecimal::from(u8::from(n == 0))));
+    }
+    let order = bessel_order(n, &x)?;
+    let ctx = context(precision() + GUARD + cancelled_digits(&x));
+    let value = bessel_series(&x, order, &ctx, |_| BigDecimal::one());
+    Ok(finish(negative_order(value, n)))
+}
+
+/// The Bessel function of the second kind `Y_n(x)` of integer order, for `x > 0`:
+///
+/// `Y_n(x) = (2/π)(ln(x/2) + γ) J_n(x) - (1/π) Σ_(k<n) (n-k-1)!/k! (x/2)^(2k-n)
+///           - (1/π) Σ (-1)^k (H_k + H_(n+k)) (x/2)^(2k+n) / (k! (n+k)!)`
+///
+/// with `H_k` the harmonic numbers and `γ` from `consts::euler_gamma`. Returned as
+/// `Irrational` at `precision()` significant digits; negative orders use
+/// `Y_-n = (-1)^n Y_n`. Fails with `DIV_BY_ZERO` at the singularity `x = 0` and with
+/// `INVALID_FORMAT` for negative, NaN, infinite and complex `x`, and with
+/// `NUMBER_TOO_LARGE` when `|n|` or `x` is past `BESSEL_LIMIT`.
+pub fn bessel_y(n: i64, x: &Float) -> Result<Float, i8> {
+    let x = real_value(x)?;
+    if x.is_zero() {
+        return Err(ERR_DIV_BY_ZERO);
+    }
+    if x.is_negative() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    let order = bessel_order(n, &x)?;
+    let ctx = context(precision() + GUARD + cancelled_digits(&x));
+    let d = digits(&ctx);
+    let pi = consts::pi(d + 2);
+    let half_x = x.half();
+
+    let j = bessel_series(&x, order, &ctx, |_| BigDecimal::one());
+    let log_term = (ln(&half_x, &ctx) + consts::euler_gamma(d + 2)) * j.double();
+
+    // Σ_(k<n) (n-k-1)!/k! (x/2)^(2k-n), walking k upwards from (n-1)! (x/2)^-n
+    let mut finite = BigDecimal::zero();
+    if order > 0 {
+        let half_x_sq = ctx.round_decimal(&half_x * &half_x);
+        let mut term = div(&BigDecimal::from(factorial_below(order)), &half_x.powi_with_context(order as i64, &ctx), &ctx);
+        for k in 0..order {
+            finite += &term;
+            if k + 1 < order {
+                term = div(&(term * &half_x_sq), &BigDecimal::from((k + 1) * (order - k - 1)), &ctx);
+            }
+        }
+    }
+
+    // harmonic numbers H_0 .. H_k, extended as the series needs them
+    let harmonic = core::cell::RefCell::new(vec![BigDecimal::zero()]);
+    let h = |m: u64| {
+        let mut h = harmonic.borrow_mut();
+        while h.len() as u64 <= m {
+            let next = &h[h.len() - 1] + BigDecimal::from(h.len() as u64).inverse_with_context(&ctx);
+            h.push(next);
+        }
+        h[m as usize].clone()
+    };
+    let series = bessel_series(&x, order, &ctx, |k| h(k) + h(order + k));
+
+    let value = div(&(log_term - finite - series), &pi, &ctx);
+    Ok(finish(negative_order(value, n)))
+}
+
+/// The arithmetic-geometric mean steps from `(1, √(1 - m))`: the final mean, and
+/// `Σ 2^(n-1) c_n²` with `c_0² = m` and `c_n = (a_(n-1) - b_(n-1)) / 2`.
+fn agm(m: &BigDecimal, ctx: &Context) -> Result<(BigDecimal, BigDecimal), i8> {
+    let mut a = BigDecimal::one();
+    let mut b = (BigDecimal::one() - m).sqrt_with_context(ctx).ok_or(ERR_INVALID_FORMAT)?;
+    let mut sum = m.half();
+    let mut weight = BigDecimal::one();
+    // the last digits can oscillate under rounding; the guard digits absorb the slack
+    let eps = ten_to_minus(digits(ctx) - GUARD / 2);
+    while (&a - &b).abs() > &a * &eps {
+        let c = (&a - &b).half();
+        sum += ctx.round_decimal(&c * &c * &weight);
+        weight = weight.double();
+        let next_b = (&a * &b).sqrt_with_context(ctx).ok_or(ERR_INVALID_FORMAT)?;
+        a = ctx.round_decimal((a + b).half());
+        b = next_b;
+    }
+    Ok((a, ctx.round_decimal(sum)))
+}
+
+/// The elliptic parameter `m` of `elliptic_k` / `elliptic_e`, which must be real and at
+/// most 1.
+fn elliptic_parameter(m: &Float) -> Result<BigDecimal, i8> {
+    let m = real_value(m)?;
+    if m > BigDecimal::one() {
+        return Err(ERR_INVALID_FORMAT);
+    }
+    Ok(m)
+}
+
+/// The complete elliptic integral of the first kind
+/// `K(m) = ∫₀^(π/2) dθ / √(1 - m sin²θ)`, as `π / (2 AGM(1, √(1 - m)))`.
+///
+/// `m` is the parameter (`m = k²`). Returned as `Irrational` at `precision()` significant
+/// digits; `K(1)` is infinite. Fails with `INVALID_FORMAT` for `m > 1`, NaN, infinite and
+/// complex values.
+pub fn elliptic_k(m: &Float) -> Result<Float, i8> {
+    let m = elliptic_parameter(m)?;
+    if m.is_one() {
+        return Ok(Float::Infinity);
+    }
+    let ctx = context(precision() + GUARD);
+    let (mean, _) = agm(&m, &ctx)?;
+    Ok(finish(div(&consts::pi(digits(&ctx) + 2), &mean.double(), &ctx)))
+}
+
+/// The complete elliptic integral of the second kind
+/// `E(m) = ∫₀^(π/2) √(1 - m sin²θ) dθ`, as `K(m) (1 - Σ 2^(n-1) c_n²)` from the same AGM.
+///
+/// `m` is the parameter (`m = k²`). Returned as `Irrational` at `precision()` significant
+/// digits; `E(1) = 1` exactly. Fails with `INVALID_FORMAT` for `m > 1`, NaN, infinite and
+/// complex values.
+pub fn elliptic_e(m: &Float) -> Result<Float, i8> {
+    let m = elliptic_parameter(m)?;
+    if m.is_one() {
+        return Ok(Float::Big(BigDecimal::one()));
+    }
+    let ctx = context(precision() + GUARD);
+    let (mean, sum) = agm(&m, &ctx)?;
+    let k = div(&consts::pi(digits(&ctx) + 2), &mean.double(), &ctx);
+    Ok(finish(k * (BigDecimal::one() - sum)))
+}
@@ -0,0 +1,230 @@
+use crate::compat::int_to_bigint;
+use crate::foundation::{precision, Float, Int};
+use crate::impls::EXACT_POW_DIGITS;
+use crate::math::{ERR_INVALID_FORMAT, ERR_NEGATIVE_SQRT, ERR_NUMBER_TOO_LARGE};
+use bigdecimal::{BigDecimal, Context, RoundingMode};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use core::num::NonZeroU64;
+
+/// `n = k² * r` with `r` square-free, as `(k, r)`.
+///
+/// Trial division up to `∛n` is enough: what is left then has at most two prime factors,
+/// so it is either square-free or the square of a prime.
+fn split_square(mut n: u64) -> (u64, u64) {
+    let (mut k, mut r) = (1u64, 1u64);
+    let mut p = 2u64;
+    while p.saturating_mul(p).saturating_mul(p) <= n {
+        let mut count = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            count += 1;
+        }
+        k *= p.pow(count / 2);
+        if count % 2 == 1 {
+            r *= p;
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    let root = n.isqrt();
+    if n > 1 && root * root == n {
+        k *= root;
+    } else {
+        r *= n;
+    }
+    (k, r)
+}
+
+/// The value of a `Big` or `Small` float; `None` for every kind that is not held exactly.
+pub(crate) fn exact_value(f: &Float) -> Option<BigDecimal> {
+    match f {
+        Float::Big(_) | Float::Small(_) => f.decimal_value(),
+        _ => None,
+    }
+}
+
+/// `a / b` when the quotient is a terminating decimal, i.e. the reduced denominator has
+/// no prime factors besides 2 and 5.
+fn exact_div(a: &BigDecimal, b: &BigDecimal) -> Option<BigDecimal> {
+    if b.is_zero() {
+        return None;
+    }
+    let (an, ascale) = a.as_bigint_and_exponent();
+    let (bn, bscale) = b.as_bigint_and_exponent();
+    let g = an.gcd(&bn);
+    let (mut p, mut q) = (an / &g, bn / &g);
+    if q.is_negative() {
+        p = -p;
+        q = -q;
+    }
+    let (mut rest, mut twos, mut fives) = (q.clone(), 0u32, 0u32);
+    while rest.is_even() {
+        rest /= 2u32;
+        twos += 1;
+    }
+    while (&rest % 5u32).is_zero() {
+        rest /= 5u32;
+        fives += 1;
+    }
+    if !rest.is_one() {
+        return None;
+    }
+    let n = twos.max(fives);
+    let scaled = p * (num_traits::pow(BigInt::from(10u32), n as usize) / q);
+    Some(BigDecimal::new(scaled, n as i64 + ascale - bscale).normalized())
+}
+
+/// `coefficient * sqrt(radicand)` for an already square-free radicand, collapsing to an
+/// exact decimal when the radicand is 1 or the coefficient is 0.
+fn build(coefficient: BigDecimal, radicand: BigInt) -> Float {
+    if coefficient.is_zero() {
+        Float::Big(BigDecimal::zero())
+    } else if radicand.is_one() {
+        Float::Big(coefficient.normalized())
+    } else {
+        Float::Surd(coefficient.normalized(), radicand)
+    }
+}
+
+/// `sqrt(b) * sqrt(d)` for square-free `b` and `d` as `(g, r)` with `g * sqrt(r)`: the
+/// shared factor `g = gcd(b, d)` comes out whole and `r = (b / g)(d / g)` stays square-free.
+fn radical_product(b: &BigInt, d: &BigInt) -> (BigInt, BigInt) {
+    let g = b.gcd(d);
+    let r = (b / &g) * (d / &g);
+    (g, r)
+}
+
+/// Decimal expansion of `coefficient * sqrt(radicand)` to `precision()` significant digits.
+pub(crate) fn expand(coefficient: &BigDecimal, radicand: &BigInt) -> BigDecimal {
+    let digits = NonZeroU64::new(precision()).unwrap();
+    let ctx = Context::default().with_precision(NonZeroU64::new(precision() + 10).unwrap());
+    let root = BigDecimal::from(radicand.clone()).sqrt_with_context(&ctx).unwrap_or_default();
+    (root * coefficient).with_precision_round(digits, RoundingMode::HalfEven).normalized()
+}
+
+/// `sqrt(value)` as a surd when `Behavior::symbolic_radicals` is on and `value` is an
+/// exact, positive non-square whose digits fit in a `u64`; `None` otherwise, leaving the
+/// caller's decimal path to handle it.
+pub(crate) fn symbolic_sqrt(value: &BigDecimal) -> Option<Float> {
+    if !crate::compat::behavior().symbolic_radicals || !value.is_positive() {
+        return None;
+    }
+    let (mut m, mut scale) = value.normalized().as_bigint_and_exponent();
+    // sqrt(m * 10^-scale) needs an even scale to take 10^(-scale/2) out whole
+    if scale % 2 != 0 {
+        m *= 10u32;
+        scale += 1;
+    }
+    let (k, r) = split_square(m.to_u64()?);
+    if r == 1 {
+        return None;
+    }
+    Some(Float::Surd(BigDecimal::new(BigInt::from(k), scale / 2).normalized(), BigInt::from(r)))
+}
+
+impl Float {
+    /// `coefficient * sqrt(radicand)` kept symbolically as `Float::Surd`, with square
+    /// factors moved into the coefficient (`surd(1, 8)` is `2√2`). Perfect squares and a
+    /// zero coefficient give an exact `Float::Big`.
+    ///
+    /// Surds stay exact through multiplication and division by exact values and by other
+    /// surds, through addition of like surds and integer powers; other operations see the
+    /// decimal expansion to `precision()` digits and give `Irrational` results.
+    ///
+    /// Fails with `INVALID_FORMAT` unless `coefficient` is an exact finite real
+    /// (`Big` or `Small`), with `NEGATIVE_SQRT` for a negative radicand and with
+    /// `NUMBER_TOO_LARGE` when the radicand does not fit in a `u64`.
+    ///
+    /// ```
+    /// use imagnum::{create_float, create_int, Float};
+    ///
+    /// let r = Float::surd(&create_float("1"), &create_int("8")).unwrap();
+    /// assert_eq!(r.to_string(), "2√2");
+    /// assert_eq!(r._mul(&r).unwrap(), create_float("8"));
+    /// ```
+    pub fn surd(coefficient: &Float, radicand: &Int) -> Result<Float, i8> {
+        let coefficient = exact_value(coefficient).ok_or(ERR_INVALID_FORMAT)?;
+        let radicand = int_to_bigint(radicand);
+        if radicand.is_negative() {
+            return Err(ERR_NEGATIVE_SQRT);
+        }
+        let (k, r) = split_square(radicand.to_u64().ok_or(ERR_NUMBER_TOO_LARGE)?);
+        if r == 0 {
+            return Ok(Float::Big(BigDecimal::zero()));
+        }
+        Ok(build(coefficient * BigDecimal::from(k), BigInt::from(r)))
+    }
+
+    /// Whether the value is a symbolic `coefficient * sqrt(radicand)`.
+    pub fn is_surd(&self) -> bool {
+        matches!(self, Float::Surd(_, _))
+    }
+
+    /// The decimal expansion of a surd to `precision()` significant digits as an
+    /// `Irrational`; every other value is returned unchanged.
+    pub fn expand(&self) -> Float {
+        match self {
+            Float::Surd(c, r) => Float::Irrational(expand(c, r)),
+            _ => self.clone(),
+        }
+    }
+
+    /// `self * other` when it stays symbolic: a surd times a surd or an exact value.
+    pub(crate) fn surd_mul(&self, other: &Self) -> Option<Float> {
+        match (self, other) {
+            (Float::Surd(a, b), Float::Surd(c, d)) => {
+                let (g, r) = radical_product(b, d);
+                Some(build(a * c * BigDecimal::from(g), r))
+            }
+            (Float::Surd(a, b), x) | (x, Float::Surd(a, b)) => Some(build(a * exact_value(x)?, b.clone())),
+            _ => None,
+        }
+    }
+
+    /// `self / other` when it stays symbolic and the new coefficient terminates.
+    pub(crate) fn surd_div(&self, other: &Self) -> Option<Float> {
+        match (self, other) {
+            // a√b / (c√d) = a·g·√r / (c·d)
+            (Float::Surd(a, b), Float::Surd(c, d)) => {
+                let (g, r) = radical_product(b, d);
+                let den = c * BigDecimal::from(d.clone());
+                Some(build(exact_div(&(a * BigDecimal::from(g)), &den)?, r))
+            }
+            (Float::Surd(a, b), x) => Some(build(exact_div(a, &exact_value(x)?)?, b.clone())),
+            // x / (c√d) = x√d / (c·d)
+            (x, Float::Surd(c, d)) => {
+                let den = c * BigDecimal::from(d.clone());
+                Some(build(exact_div(&exact_value(x)?, &den)?, d.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// `self + other` (or `self - other`) for like surds, which share a radicand.
+    pub(crate) fn surd_add(&self, other: &Self, subtract: bool) -> Option<Float> {
+        match (self, other) {
+            (Float::Surd(a, b), Float::Surd(c, d)) if b == d => {
+                Some(build(if subtract { a - c } else { a + c }, b.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// `(a√b)^n = a^n · b^(n/2)`, times `√b` for odd `n`; `n >= 0`. `None` when the
+    /// coefficient would pass `EXACT_POW_DIGITS` digits, leaving `pow_int` to round.
+    pub(crate) fn surd_pow(&self, n: u64) -> Option<Float> {
+        let Float::Surd(a, b) = self else {
+            return None;
+        };
+        // 0.302 decimal digits per bit
+        let digits = a.digits().saturating_mul(n).saturating_add(b.bits().saturating_mul(n / 2) / 10 * 3);
+        if digits > EXACT_POW_DIGITS {
+            return None;
+        }
+        let half = usize::try_from(n / 2).ok()?;
+        let coefficient = num_traits::pow(a.clone(), usize::try_from(n).ok()?)
+            * BigDecimal::from(num_traits::pow(b.clone(), half));
+        Some(build(coefficient, if n % 2 == 1 { b.clone() } else { BigInt::one() }))
+    }
+}
@@ -0,0 +1,164 @@
+use crate::compat::float_kind;
+use crate::foundation::{Float, FloatKind, Int};
+use crate::math::{ERR_DIV_BY_ZERO, ERR_INFINITE_RESULT, ERR_NUMBER_TOO_LARGE};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+/// Wrapper whose `+ - * / %` return the wrapped type directly instead of a `Result`,
+/// so expressions chain without `?` after every step.
+///
+/// Float errors become values: division by zero and overflow give `Infinity` or
+/// `NegInfinity` with the sign the result would have had, indeterminate forms such as
+/// `0 / 0` and `∞ - ∞` give `NaN`, and so does any other error. `Int` has no such values, so an `Int` error poisons the
+/// wrapper instead: every later operation keeps the first error, and `value()` reports it.
+///
+/// ```
+/// use imagnum::{create_int, Unchecked};
+///
+/// let (a, b, c) = (create_int("7"), create_int("5"), create_int("3"));
+/// let r = (a.unchecked() + b) * c;
+/// assert_eq!(r.clone().value(), Ok(create_int("36")));
+/// assert!((r / create_int("0")).value().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unchecked<T>(Result<T, i8>);
+
+impl<T> Unchecked<T> {
+    pub fn new(value: T) -> Self {
+        Unchecked(Ok(value))
+    }
+
+    /// The result of the whole expression, or the first error for `Int`.
+    pub fn value(self) -> Result<T, i8> {
+        self.0
+    }
+
+    pub fn get(&self) -> Result<&T, i8> {
+        self.0.as_ref().map_err(|e| *e)
+    }
+
+    /// The first error an `Int` expression hit; always `None` for `Float`.
+    pub fn error(&self) -> Option<i8> {
+        self.0.as_ref().err().copied()
+    }
+}
+
+impl<T> From<T> for Unchecked<T> {
+    fn from(value: T) -> Self {
+        Unchecked::new(value)
+    }
+}
+
+impl<T: Display> Display for Unchecked<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.0 {
+            Ok(v) => v.fmt(f),
+            Err(_) => write!(f, "NaN"),
+        }
+    }
+}
+
+type FloatOp = fn(&Float, &Float) -> Result<Float, i8>;
+
+/// The value `lhs op rhs` stands for when `op` fails with `err`.
+fn float_error(lhs: &Float, rhs: &Float, op: FloatOp, err: i8) -> Float {
+    let real = |f: &Float| matches!(float_kind(f), FloatKind::Finite | FloatKind::Irrational | FloatKind::Recurring);
+    let negative = match err {
+        ERR_DIV_BY_ZERO if lhs.is_zero() || lhs.is_nan() => return Float::NaN,
+        ERR_DIV_BY_ZERO => lhs.is_negative(),
+        // an infinite or complex operand makes the failure an indeterminate form
+        ERR_INFINITE_RESULT | ERR_NUMBER_TOO_LARGE if real(lhs) && real(rhs) => {
+            // both scaled into [-1, 1] keep the sign of the result but cannot overflow
+            let scale = if lhs.abs() > rhs.abs() { lhs.abs() } else { rhs.abs() };
+            let sign = lhs._div(&scale).and_then(|l| rhs._div(&scale).and_then(|r| op(&l, &r)));
+            match sign {
+                Ok(v) if !v.is_zero() => v.is_negative(),
+                _ => return Float::NaN,
+            }
+        }
+        _ => return Float::NaN,
+    };
+    if negative { Float::NegInfinity } else { Float::Infinity }
+}
+
+fn float_op(lhs: Result<Float, i8>, rhs: Result<&Float, i8>, op: FloatOp) -> Result<Float, i8> {
+    let lhs = lhs.unwrap_or(Float::NaN);
+    Ok(match rhs {
+        Ok(rhs) => op(&lhs, rhs).unwrap_or_else(|e| float_error(&lhs, rhs, op, e)),
+        Err(_) => Float::NaN,
+    })
+}
+
+fn int_op(lhs: Result<Int, i8>, rhs: Result<&Int, i8>, op: fn(&Int, &Int) -> Result<Int, i8>) -> Result<Int, i8> {
+    op(&lhs?, rhs?)
+}
+
+macro_rules! unchecked_ops {
+    ($t:ty, $apply:ident) => {
+        unchecked_ops!(@op $t, $apply, Add, add, AddAssign, add_assign, _add);
+        unchecked_ops!(@op $t, $apply, Sub, sub, SubAssign, sub_assign, _sub);
+        unchecked_ops!(@op $t, $apply, Mul, mul, MulAssign, mul_assign, _mul);
+        unchecked_ops!(@op $t, $apply, Div, div, DivAssign, div_assign, _div);
+        unchecked_ops!(@op $t, $apply, Rem, rem, RemAssign, rem_assign, _modulo);
+
+        impl Neg for Unchecked<$t> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Unchecked(self.0.map(|v| -v))
+            }
+        }
+
+        impl $t {
+            /// Wraps the value so its operators return values instead of `Result`s.
+            pub fn unchecked(self) -> Unchecked<$t> {
+                Unchecked::new(self)
+            }
+        }
+    };
+    (@op $t:ty, $apply:ident, $trait:ident, $method:ident, $assign:ident, $assign_method:ident, $inner:ident) => {
+        impl $trait for Unchecked<$t> {
+            type Output = Self;
+            fn $method(self, other: Self) -> Self {
+                Unchecked($apply(self.0, other.get(), <$t>::$inner))
+            }
+        }
+
+        impl<'a> $trait<&'a Unchecked<$t>> for Unchecked<$t> {
+            type Output = Self;
+            fn $method(self, other: &'a Unchecked<$t>) -> Self {
+                Unchecked($apply(self.0, other.get(), <$t>::$inner))
+            }
+        }
+
+        impl $trait<$t> for Unchecked<$t> {
+            type Output = Self;
+            fn $method(self, other: $t) -> Self {
+                Unchecked($apply(self.0, Ok(&other), <$t>::$inner))
+            }
+        }
+
+        impl<'a> $trait<&'a $t> for Unchecked<$t> {
+            type Output = Self;
+            fn $method(self, other: &'a $t) -> Self {
+                Unchecked($apply(self.0, Ok(other), <$t>::$inner))
+            }
+        }
+
+        impl $assign for Unchecked<$t> {
+            fn $assign_method(&mut self, other: Self) {
+                let lhs = core::mem::replace(&mut self.0, Err(0));
+                self.0 = $apply(lhs, other.get(), <$t>::$inner);
+            }
+        }
+
+        impl $assign<$t> for Unchecked<$t> {
+            fn $assign_method(&mut self, other: $t) {
+                let lhs = core::mem::replace(&mut self.0, Err(0));
+                self.0 = $apply(lhs, Ok(&other), <$t>::$inner);
+            }
+        }
+    };
+}
+
+unchecked_ops!(Float, float_op);
+unchecked_ops!(Int, int_op);
@@ -0,0 +1,23 @@
+//! Arithmetic on values of every kind never panics, and the exact operations obey the
+//! laws `tests/properties.rs` checks.
+
+#![no_main]
+
+use imagnum::{Float, Int};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Int, Int, Float, Float)| {
+    let (a, b, x, y) = input;
+    assert_eq!(a._add(&b), b._add(&a));
+    assert_eq!(a._mul(&b), b._mul(&a));
+    assert!(a._sub(&a).is_ok_and(|d| d.is_zero()));
+    let _ = a._div(&b);
+    let _ = a._modulo(&b);
+
+    let _ = x._add(&y);
+    let _ = x._sub(&y);
+    let _ = x._mul(&y);
+    let _ = x._div(&y);
+    let _ = x.to_string();
+    let _ = y.to_f64();
+});
@@ -0,0 +1,16 @@
+//! Parsing never panics, and what parses prints back to the same value.
+
+#![no_main]
+
+use imagnum::{Float, Int};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| {
+    if let Ok(i) = Int::from_str(s) {
+        assert_eq!(Int::from_str(&i.to_string()), Ok(i));
+    }
+    if let Ok(f) = Float::from_str(s) {
+        let _ = f.to_string();
+        let _ = f.to_f64();
+    }
+});
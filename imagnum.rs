@@ -10,8 +10,25 @@ LICENSE: MIT License
 2025 Lucia Programming Language
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("CRATE_DOCS.md")]
 
+extern crate alloc;
+
+/// The `alloc` items the standard prelude provides, for `no_std` builds; in `std` builds
+/// they are the same items.
+#[allow(unused_imports)]
+mod alloc_prelude {
+    pub use alloc::borrow::ToOwned;
+    pub use alloc::boxed::Box;
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+    // `f64::sqrt`, `ln` and the like are `std` methods; without it they come from `libm`.
+    pub use num_traits::Float as _;
+}
+
 /// Core foundation types and traits
 #[path = "core/foundation.rs"]
 pub mod foundation;
@@ -32,20 +49,112 @@ pub mod ops;
 #[path = "core/functions.rs"]
 pub mod functions;
 
+/// Compile-time checks and parsers behind the `int!`, `float!` and `complex!` macros
+#[doc(hidden)]
+#[path = "core/literal.rs"]
+pub mod literal;
+
 /// Compatibility layer for older versions (will be removed in future)
 #[path = "core/compat.rs"]
 pub mod compat;
 
+/// Mathematical constants and exact-value tables
+#[path = "core/consts.rs"]
+pub mod consts;
+
+/// Lazy digit iterators for numeric types
+#[path = "core/digits.rs"]
+pub mod digits;
+
+/// Configurable number formatting (fixed, scientific, engineering)
+#[path = "core/format.rs"]
+pub mod format;
+
+/// Compact binary encoding (`Int::to_bytes_le`, `Float::to_bytes`)
+#[path = "core/bytes.rs"]
+pub mod bytes;
+
+/// Complex numbers with unboxed parts, including Gaussian integers
+#[path = "core/complex.rs"]
+pub mod complex;
+
+/// Quaternions over `Float`
+#[path = "core/quaternion.rs"]
+pub mod quaternion;
+
+/// Vectors and matrices over `Float`
+#[path = "core/linalg.rs"]
+pub mod linalg;
+
+/// Polynomials over `Int` and `Float`
+#[path = "core/polynomial.rs"]
+pub mod polynomial;
+
+/// Special functions: gamma, log-gamma, error functions and beta
+#[path = "core/special.rs"]
+pub mod special;
+
+/// Symbolic square roots (`Float::Surd`)
+#[path = "core/surd.rs"]
+pub mod surd;
+
+/// Fractions: exact arithmetic on recurring decimals (`Float::Recurring`) and conversion to and from ratios
+#[path = "core/recurring.rs"]
+pub mod recurring;
+
+/// `IntOrSpecial`: integers that can also be NaN or infinite
+#[path = "core/int_or_special.rs"]
+pub mod int_or_special;
+
+/// Transform-based multiplication for huge integers
+#[path = "core/ntt.rs"]
+pub mod ntt;
+
+/// Subquadratic parsing of huge decimal integers, also from a stream
+#[path = "core/chunked.rs"]
+pub mod chunked;
+
+/// Pluggable arbitrary-precision backend behind `Int` and `Float`
+#[path = "core/backend.rs"]
+pub mod backend;
+
+/// Lazily built constants and small integers shared by the arithmetic
+#[path = "core/cache.rs"]
+mod cache;
+
+/// Inverse trigonometric and hyperbolic functions on `Float`, complex values included
+#[path = "core/inverse.rs"]
+mod inverse;
+
+/// Expression evaluator behind the calculator binary
+#[path = "core/eval.rs"]
+pub mod eval;
+
+/// Operator wrapper that returns values instead of `Result`s
+#[path = "core/unchecked.rs"]
+pub mod unchecked;
+
 /// Features module containing optional features
 #[path = "core/features.rs"]
 pub mod features;
 
-pub use foundation::{Float, Int};
-pub use functions::{create_complex, create_float, create_imaginary, create_int, create_irrational};
+pub use foundation::{BitWidth, DivMode, Float, Int, NumberInfo, RoundingMode};
+pub use complex::{Complex, ComplexFloat, ComplexInt};
+pub use quaternion::Quaternion;
+pub use linalg::{Matrix, Vector};
+pub use polynomial::{Polynomial, PolynomialFloat, PolynomialInt};
+pub use unchecked::Unchecked;
+pub use int_or_special::IntOrSpecial;
+/// The sign in `Int::to_u32_digits` and `Int::to_radix_le`, re-exported from `num-bigint`.
+pub use num_bigint::Sign;
+pub use functions::{
+    create_complex, create_float, create_imaginary, create_int, create_irrational, floats_from_f64,
+    floats_to_f64, ints_from_i64, ints_to_i64, parse_float_bytes, parse_int_bytes, try_create_float, try_create_int, ParseError, ParseOptions,
+};
 
 /// Macros for creating numbers
 pub mod macros {
-    pub use super::{float, int};
+    pub use super::{complex, float, int};
 }
 use math::{
     ERR_DIV_BY_ZERO, ERR_INFINITE_RESULT, ERR_INVALID_FORMAT, ERR_NEGATIVE_RESULT,
@@ -53,6 +162,15 @@ use math::{
 };
 pub use crate::impls::{ApproxEq, IntoSmallFloat, IntoSmallInt};
 
+/// Process-wide runtime settings
+pub mod config {
+    pub use super::backend::{backend, set_backend};
+    pub use super::foundation::{
+        auto_shrink, display_digits, precision, set_auto_shrink, set_display_digits, set_precision,
+        DEFAULT_DISPLAY_DIGITS, DEFAULT_PRECISION, MAX_PRECISION,
+    };
+}
+
 /// Error codes and error handling functions
 pub mod errors {
     use super::*;
@@ -67,6 +185,7 @@ pub mod errors {
 
     pub use super::functions::get_error_code;
     pub use super::functions::get_error_message;
+    pub use super::functions::{Expected, ParseError};
 }
 
 #[cfg(feature = "random")]
@@ -76,6 +195,12 @@ pub mod random {
     pub use super::features::feature_rand::*;
 }
 
+#[cfg(feature = "wasm-bindgen")]
+#[doc = "JavaScript bindings (enabled with `features = [\"wasm-bindgen\"]`)"]
+pub mod wasm {
+    pub use super::features::feature_wasm::*;
+}
+
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const COPYRIGHT: &str = "2025 Lucia Programming Language";
 pub const LICENSE: &str = "MIT License";
@@ -86,14 +211,22 @@ pub const DOCUMENTATION: &str = "https://docs.rs/imagnum";
 pub const AUTHORS: &str = "SirPigari <leonardmarkovic015@gmail.com>";
 
 /// List of all features available in the crate
-pub const FEATURES: &[&str] = &["serde", "random", "cli"];
+pub const FEATURES: &[&str] = &["std", "serde", "random", "arbitrary", "wasm-bindgen", "gmp", "cli"];
 
 /// List of enabled features in the current build
 pub const ENABLED_FEATURES: &[&str] = &[
+    #[cfg(feature = "std")]
+    "std",
     #[cfg(feature = "serde")]
     "serde",
     #[cfg(feature = "random")]
     "random",
+    #[cfg(feature = "arbitrary")]
+    "arbitrary",
+    #[cfg(feature = "wasm-bindgen")]
+    "wasm-bindgen",
+    #[cfg(feature = "gmp")]
+    "gmp",
     #[cfg(feature = "cli")]
     "cli",
 ];
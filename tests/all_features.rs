@@ -1,4 +1,4 @@
-use imagnum::{Float, Int, create_float, create_int};
+use imagnum::{errors, try_create_int, Float, Int, create_float, create_int};
 
 #[test]
 fn test_features() {
@@ -36,7 +36,7 @@ fn test_int_arithmetic_and_pow() {
     // div
     let res_div = b._div(&a).unwrap();
     match res_div {
-        Int::Big(bi) => assert_eq!(bi.to_string(), "4"),
+        Int::Big(bi) => assert_eq!(bi.to_string(), "3"),
         _ => panic!("expected BigInt for div result"),
     }
 
@@ -66,6 +66,23 @@ fn test_int_conv_and_props() {
     assert!(inf_like.is_zero());
 }
 
+#[test]
+fn test_try_create_int() {
+    assert_eq!(try_create_int("12345678901234567890").unwrap(), create_int("12345678901234567890"));
+    assert_eq!(try_create_int(" +7\n").unwrap(), create_int("7"));
+    assert_eq!(try_create_int("-0").unwrap(), create_int("0"));
+    for bad in ["", " ", "abc", "1.5", "1e3", "0x10", "--1", "nan", "NaN"] {
+        assert_eq!(try_create_int(bad), Err(errors::INVALID_FORMAT), "{:?}", bad);
+        // the lenient constructor still maps them to zero
+        assert!(create_int(bad).is_zero());
+    }
+    for inf in ["inf", "Infinity", "-inf", "+INFINITY"] {
+        assert_eq!(try_create_int(inf), Err(errors::INFINITE_RESULT), "{:?}", inf);
+    }
+    assert_eq!(Int::from_str("abc"), Err(errors::INVALID_FORMAT));
+    assert_eq!(Int::from_str("-17").unwrap(), create_int("-17"));
+}
+
 #[test]
 fn test_float_arithmetic_and_properties() {
     let a = create_float("123.456");
@@ -95,3 +95,56 @@ fn test_float_approx_eq_precision() {
     let d = create_float("0.2");
     assert!(!c.approx_eq(&d, 0.001));
 }
+
+#[test]
+fn test_int_approx_eq_huge_magnitudes() {
+    let a = create_int(&format!("1{}", "0".repeat(30)));
+    let b = create_int(&format!("1{}5", "0".repeat(29)));
+    assert!(a.approx_eq(&b, 5.0));
+    assert!(!a.approx_eq(&b, 4.9));
+    let far = create_int(&format!("2{}", "0".repeat(30)));
+    assert!(a.approx_eq(&far, 1e30));
+    assert!(!a.approx_eq(&far, 9.9e29));
+    assert!(a.approx_eq(&far, f64::INFINITY));
+    assert!(!a.approx_eq(&far, f64::NAN));
+}
+
+#[test]
+fn test_approx_eq_rel() {
+    let zero = create_float("0");
+    let a = create_float("1e400");
+    let b = create_float("1.0000001e400");
+    assert!(a.approx_eq_rel(&b, &create_float("1e-6"), &zero));
+    assert!(!a.approx_eq_rel(&b, &create_float("1e-8"), &zero));
+
+    let tiny = create_float("1e-300");
+    let tiny2 = create_float("3e-300");
+    assert!(!tiny.approx_eq_rel(&tiny2, &create_float("0.5"), &zero));
+    assert!(tiny.approx_eq_rel(&tiny2, &zero, &create_float("2e-300")));
+
+    assert!(create_int("1000").approx_eq_rel(&create_int("1001"), &create_float("0.001"), &zero));
+    assert!(!create_int("1000").approx_eq_rel(&create_int("1002"), &create_float("0.001"), &zero));
+    let nan = Float::NaN;
+    assert!(!nan.approx_eq_rel(&nan, &create_float("1"), &create_float("1")));
+    assert!(create_complex("1", "2").approx_eq_rel(&create_complex("1.01", "2.02"), &create_float("0.01"), &zero));
+}
+
+#[test]
+fn test_approx_eq_ulps() {
+    let one = Float::from(1.0f64);
+    let next = Float::from(f64::from_bits(1.0f64.to_bits() + 1));
+    let two_up = Float::from(f64::from_bits(1.0f64.to_bits() + 2));
+    assert!(one.approx_eq_ulps(&next, 1));
+    assert!(!one.approx_eq_ulps(&two_up, 1));
+    assert!(one.approx_eq_ulps(&two_up, 2));
+
+    let a = create_float("3.14159");
+    assert!(a.approx_eq_ulps(&create_float("3.14161"), 2));
+    assert!(!a.approx_eq_ulps(&create_float("3.14162"), 2));
+    assert!(a.approx_eq_ulps(&create_float("3.142"), 1));
+
+    assert!(create_int("10").approx_eq_ulps(&create_int("13"), 3));
+    assert!(!create_int("10").approx_eq_ulps(&create_int("14"), 3));
+    let inf = Float::Infinity;
+    assert!(inf.approx_eq_ulps(&inf, 0));
+}
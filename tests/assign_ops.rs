@@ -0,0 +1,86 @@
+use imagnum::{create_float, create_int, Float};
+
+#[test]
+fn int_assign_by_reference() {
+    let step = create_int("3");
+    let mut acc = create_int("0");
+    for _ in 0..4 {
+        acc += &step;
+    }
+    assert_eq!(acc, create_int("12"));
+    acc -= &step;
+    acc *= &step;
+    acc /= &create_int("2");
+    acc %= &create_int("5");
+    assert_eq!(acc, create_int("3"));
+    // errors leave 0, as with the owned assign-ops
+    acc /= &create_int("0");
+    assert_eq!(acc, create_int("0"));
+}
+
+#[test]
+fn int_assign_primitive() {
+    let mut x = create_int("10");
+    x += 5;
+    x -= 1;
+    x *= -2;
+    x /= 4;
+    x %= 4;
+    assert_eq!(x, create_int("-3"));
+}
+
+#[test]
+fn float_assign_by_reference_and_primitive() {
+    let half = create_float("0.5");
+    let mut x = create_float("1");
+    x += &half;
+    x *= 2;
+    x -= 0.25;
+    x /= &half;
+    x %= 4;
+    assert_eq!(x, create_float("1.5"));
+    x /= 0;
+    assert!(matches!(x, Float::NaN));
+}
+
+#[test]
+fn int_assign_ref_in_place() {
+    let big = create_int(&"9".repeat(40));
+    let mut x = big.clone();
+    x.add_assign_ref(&create_int("1"));
+    assert_eq!(x.to_string(), format!("1{}", "0".repeat(40)));
+    x.mul_assign_ref(&create_int("-2"));
+    assert_eq!(x.to_string(), format!("-2{}", "0".repeat(40)));
+    x.sub_assign_ref(&x.clone());
+    assert_eq!(x, create_int("0"));
+
+    // small operands overflowing their width promote, matching `_mul`
+    let mut y = create_int("4294967296");
+    let expected = y._mul(&y).unwrap();
+    y.mul_assign_ref(&y.clone());
+    assert_eq!(y, expected);
+    assert_eq!(y.to_string(), "18446744073709551616");
+}
+
+#[test]
+fn float_assign_ref_in_place() {
+    let step = create_float(&format!("0.{}1", "0".repeat(60)));
+    let mut acc = create_float("1");
+    for _ in 0..10 {
+        acc.add_assign_ref(&step).unwrap();
+    }
+    assert_eq!(acc, create_float(&format!("1.{}1", "0".repeat(59))));
+    acc.sub_assign_ref(&create_float("1")).unwrap();
+    acc.mul_assign_ref(&create_float("2.5")).unwrap();
+    assert_eq!(acc, create_float(&format!("0.{}25", "0".repeat(59))));
+
+    // other kinds go through the regular ops
+    let mut third = create_float("1")._div(&create_float("3")).unwrap();
+    third.mul_assign_ref(&create_float("3")).unwrap();
+    assert_eq!(third, create_float("1"));
+
+    // errors leave the value unchanged
+    let mut z = create_float("2");
+    assert!(z.add_assign_ref(&Float::NaN).is_err());
+    assert_eq!(z, create_float("2"));
+}
@@ -0,0 +1,141 @@
+use bigdecimal::BigDecimal;
+use imagnum::backend::{backend, set_backend, Backend, NumBigint};
+use imagnum::{create_float, create_int, Float};
+use num_bigint::BigInt;
+use std::num::NonZeroU64;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+static CALLS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Delegates to the default backend, recording which operations were routed to it.
+struct Recording;
+
+fn record(op: &'static str) {
+    CALLS.lock().unwrap().push(op);
+}
+
+impl Backend for Recording {
+    fn name(&self) -> &'static str {
+        "recording"
+    }
+    fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        record("mul");
+        NumBigint.mul(a, b)
+    }
+    fn mul_assign(&self, a: &mut BigInt, b: &BigInt) {
+        record("mul_assign");
+        NumBigint.mul_assign(a, b)
+    }
+    fn div_rem(&self, a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
+        record("div_rem");
+        NumBigint.div_rem(a, b)
+    }
+    fn root(&self, a: &BigInt, n: u32) -> BigInt {
+        record("root");
+        NumBigint.root(a, n)
+    }
+    fn to_decimal(&self, a: &BigInt) -> String {
+        record("to_decimal");
+        NumBigint.to_decimal(a)
+    }
+    fn parse_decimal(&self, s: &str) -> Option<BigInt> {
+        record("parse_decimal");
+        NumBigint.parse_decimal(s)
+    }
+    fn decimal_mul(&self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        record("decimal_mul");
+        NumBigint.decimal_mul(a, b)
+    }
+    fn decimal_div(&self, a: &BigDecimal, b: &BigDecimal, digits: NonZeroU64) -> BigDecimal {
+        record("decimal_div");
+        NumBigint.decimal_div(a, b, digits)
+    }
+}
+
+fn routed(f: impl FnOnce()) -> Vec<&'static str> {
+    CALLS.lock().unwrap().clear();
+    f();
+    std::mem::take(&mut *CALLS.lock().unwrap())
+}
+
+// one test, since the backend is process-wide
+#[test]
+fn backend_routes_arithmetic() {
+    assert_eq!(backend().name(), "num-bigint");
+    let (a, b) = (create_int("123456789012345678901234567890"), create_int("-987654321"));
+    let expected = (a._mul(&b).unwrap(), a._div(&b).unwrap(), a._modulo(&b).unwrap(), a.isqrt().unwrap());
+    let pi = Float::Irrational(BigDecimal::from_str("3.14159265358979323846").unwrap());
+
+    set_backend(&Recording);
+    assert_eq!(backend().name(), "recording");
+    assert!(routed(|| assert_eq!(create_int("42"), create_int("42"))).contains(&"parse_decimal"));
+    assert_eq!(routed(|| assert_eq!(a._mul(&b).unwrap(), expected.0)), ["mul"]);
+    assert_eq!(routed(|| assert_eq!(a._div(&b).unwrap(), expected.1)), ["div_rem"]);
+    assert_eq!(routed(|| assert_eq!(a._modulo(&b).unwrap(), expected.2)), ["div_rem"]);
+    assert_eq!(routed(|| assert_eq!(a.isqrt().unwrap(), expected.3)), ["root"]);
+    assert_eq!(routed(|| {
+        let mut x = a.clone();
+        x *= &b;
+        assert_eq!(x, expected.0);
+    }), ["mul_assign"]);
+    assert_eq!(routed(|| assert_eq!(a.to_string(), "123456789012345678901234567890")), ["to_decimal"]);
+    assert!(routed(|| {
+        create_float("1.5")._mul(&create_float("2.25")).unwrap();
+    })
+    .contains(&"decimal_mul"));
+    assert!(routed(|| {
+        pi._div(&create_float("3")).unwrap();
+    })
+    .contains(&"decimal_div"));
+
+    set_backend(&NumBigint);
+    assert_eq!(backend().name(), "num-bigint");
+    assert!(routed(|| assert_eq!(a._mul(&b).unwrap(), expected.0)).is_empty());
+}
+
+#[cfg(feature = "gmp")]
+mod test_gmp {
+    use imagnum::backend::{Backend, Gmp, NumBigint, GMP_THRESHOLD_BITS};
+    use num_bigint::BigInt;
+    use std::num::NonZeroU64;
+
+    fn big(seed: u32, digits: usize) -> BigInt {
+        let text: String = (0..digits).map(|i| char::from(b'1' + ((i as u32 * 7 + seed) % 9) as u8)).collect();
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn gmp_matches_num_bigint() {
+        assert_eq!(Gmp.name(), "gmp");
+        let small = BigInt::from(-123456789);
+        for digits in [20, GMP_THRESHOLD_BITS as usize / 3, 6_000] {
+            let (a, b) = (big(1, digits), -big(5, digits / 2 + 1));
+            assert_eq!(Gmp.mul(&a, &b), NumBigint.mul(&a, &b));
+            assert_eq!(Gmp.mul(&a, &small), NumBigint.mul(&a, &small));
+            let mut x = a.clone();
+            Gmp.mul_assign(&mut x, &b);
+            assert_eq!(x, NumBigint.mul(&a, &b));
+            for (n, d) in [(&a, &b), (&-&a, &b), (&b, &small), (&-&a, &-&small)] {
+                assert_eq!(Gmp.div_rem(n, d), NumBigint.div_rem(n, d));
+            }
+            assert_eq!(Gmp.root(&a, 2), NumBigint.root(&a, 2));
+            assert_eq!(Gmp.root(&a, 7), NumBigint.root(&a, 7));
+            assert_eq!(Gmp.to_decimal(&b), NumBigint.to_decimal(&b));
+            let text = b.to_string();
+            assert_eq!(Gmp.parse_decimal(&text), Some(b.clone()));
+            let plus = format!("+{}", a);
+            assert_eq!(Gmp.parse_decimal(&plus), Some(a.clone()));
+            let (x, y) = ("1.5".parse().unwrap(), format!("{}e-3", a).parse().unwrap());
+            assert_eq!(Gmp.decimal_mul(&x, &y), NumBigint.decimal_mul(&x, &y));
+            assert_eq!(
+                Gmp.decimal_div(&y, &x, NonZeroU64::new(50).unwrap()),
+                NumBigint.decimal_div(&y, &x, NonZeroU64::new(50).unwrap())
+            );
+        }
+        let long = "7".repeat(10_000);
+        for text in [format!("{}_1", long), format!("{} 1", long), format!("-{}a", long), "-".repeat(3_000), format!("-{}", long)] {
+            assert_eq!(Gmp.parse_decimal(&text), NumBigint.parse_decimal(&text));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use imagnum::compat::{behavior, set_behavior, ArithmeticPolicy, Behavior, BehaviorVersion};
+use imagnum::foundation::SmallFloat;
+use imagnum::{create_float, create_int, errors, DivMode, Float};
+
+// The behavior is process-wide, so everything that changes it lives in one test.
+#[test]
+#[allow(deprecated)]
+fn behavior_versions() {
+    assert_eq!(behavior(), Behavior::default());
+    assert_eq!(Behavior::default(), Behavior::version(BehaviorVersion::V2));
+
+    let half = || Float::Small(SmallFloat::F64(0.5));
+    let seven = create_int("7");
+    let two = create_int("2");
+
+    set_behavior(Behavior::version(BehaviorVersion::V1));
+    assert_eq!(behavior().int_division, DivMode::Round);
+    assert_eq!((&seven / &two).unwrap(), create_int("4"));
+    assert_eq!(create_int("-7")._div(&two).unwrap(), create_int("-4"));
+    assert!(matches!(half()._add(&half()).unwrap(), Float::Big(_)));
+    // explicit modes are unaffected
+    assert_eq!(seven.div_trunc(&two).unwrap(), create_int("3"));
+
+    set_behavior(Behavior::version(BehaviorVersion::V1).int_division(DivMode::Trunc));
+    assert_eq!((&seven / &two).unwrap(), create_int("3"));
+    assert!(matches!(half()._add(&half()).unwrap(), Float::Big(_)));
+
+    set_behavior(Behavior::default().int_division(DivMode::Floor));
+    assert_eq!(create_int("-7")._div(&two).unwrap(), create_int("-4"));
+
+    set_behavior(Behavior::default().symbolic_radicals(true));
+    assert_eq!(create_int("8").sqrt().unwrap().to_string(), "2√2");
+    assert_eq!(create_float("0.5").sqrt().unwrap().to_string(), "0.5√2");
+    assert_eq!(create_int("2").sqrt().unwrap().powi(2).unwrap(), create_int("2"));
+    assert!(matches!(create_int("16").sqrt().unwrap(), Float::Big(_)));
+    assert!(create_int("2").sqrt().unwrap().sqrt().unwrap().is_irrational());
+
+    // integer semantics for the Float bitwise operators
+    let one_f32 = || Float::from(1.0f32);
+    let two_f32 = || Float::from(2.0f32);
+    assert_eq!(one_f32()._bitor(&two_f32()).unwrap(), Float::Infinity);
+    set_behavior(Behavior::default().float_bit_patterns(false));
+    assert_eq!(one_f32()._bitor(&two_f32()).unwrap(), create_float("3"));
+    assert_eq!(create_float("12")._bitand(&create_float("10")).unwrap(), create_float("8"));
+    assert_eq!(create_float("12")._bitxor(&create_float("-1")).unwrap(), create_float("-13"));
+    assert_eq!(create_float("0")._not(), create_float("-1"));
+    assert_eq!(create_float("-5")._shr(&create_int("1")).unwrap(), create_float("-3"));
+    assert_eq!(create_float("3")._shl(&create_int("70")).unwrap(), create_float("3541774862152233910272"));
+    assert_eq!(create_float("1.5")._bitand(&one_f32()), Err(errors::INVALID_FORMAT));
+    assert_eq!(Float::NaN._bitor(&one_f32()), Err(errors::INVALID_FORMAT));
+    assert!(create_float("0.5")._not().is_nan());
+
+    // IEEE special values instead of errors
+    let (inf, neg_inf, zero, one) = (create_float("inf"), create_float("-inf"), create_float("0"), create_float("1"));
+    assert_eq!(behavior().arithmetic_policy, ArithmeticPolicy::Strict);
+    assert_eq!(inf._add(&neg_inf), Err(errors::INFINITE_RESULT));
+    assert_eq!(inf._sub(&inf), Err(errors::INFINITE_RESULT));
+    assert_eq!(neg_inf._sub(&neg_inf), Err(errors::INFINITE_RESULT));
+    assert_eq!(one._div(&zero), Err(errors::DIV_BY_ZERO));
+    assert_eq!(Float::NaN._add(&one), Err(errors::INVALID_FORMAT));
+    set_behavior(Behavior::default().arithmetic_policy(ArithmeticPolicy::Ieee));
+    assert!(inf._add(&neg_inf).unwrap().is_nan());
+    assert!(inf._sub(&inf).unwrap().is_nan());
+    assert!(inf._mul(&zero).unwrap().is_nan());
+    assert!(inf._div(&inf).unwrap().is_nan());
+    assert_eq!(one._div(&zero).unwrap(), Float::Infinity);
+    assert_eq!(create_float("-2.5")._div(&zero).unwrap(), Float::NegInfinity);
+    assert!(zero._div(&zero).unwrap().is_nan());
+    assert!(one._modulo(&zero).unwrap().is_nan());
+    assert_eq!(zero._pow(&create_float("-1")).unwrap(), Float::Infinity);
+    assert!(Float::NaN._mul(&one).unwrap().is_nan());
+    assert_eq!((one.clone() / zero.clone()).unwrap(), Float::Infinity);
+    assert_eq!(create_float("10").pow_int(&create_int(&u64::MAX.to_string())), Ok(Float::Infinity));
+    assert_eq!(create_float("-10").powi(i64::MAX), Ok(Float::NegInfinity));
+    assert_eq!(create_float("0.1").pow_int(&create_int(&u64::MAX.to_string())), Ok(create_float("0")));
+    assert_eq!(create_float("10").powi(-i64::MAX), Ok(create_float("0")));
+    // results that were never errors are unchanged
+    assert_eq!(create_float("1.5")._add(&one).unwrap(), create_float("2.5"));
+    assert_eq!(inf._add(&one).unwrap(), Float::Infinity);
+
+    // real-only powers
+    let minus_four = create_float("-4");
+    assert_eq!(minus_four._pow(&create_float("0.5")).unwrap(), Float::complex(create_float("0"), create_float("2")));
+    set_behavior(Behavior::default().complex_powers(false));
+    assert_eq!(minus_four._pow(&create_float("0.5")), Err(errors::INVALID_FORMAT));
+    assert_eq!(create_float("-32")._pow(&create_float("0.2")).unwrap(), create_float("-2"));
+    set_behavior(Behavior::version(BehaviorVersion::V1));
+    assert!(!behavior().complex_powers);
+
+    set_behavior(Behavior::default());
+    assert!(matches!(create_int("8").sqrt().unwrap(), Float::Irrational(_)));
+    assert_eq!((&seven / &two).unwrap(), create_int("3"));
+    assert!(matches!(half()._add(&half()).unwrap(), Float::Small(SmallFloat::F64(v)) if v == 1.0));
+}
@@ -0,0 +1,71 @@
+use bigdecimal::BigDecimal;
+use imagnum::{create_float, create_int, errors, Float, Int, Sign};
+use num_bigint::BigInt;
+use std::borrow::Cow;
+use std::str::FromStr;
+
+#[test]
+fn int_bigint_round_trip() {
+    let big = BigInt::from_str("123456789012345678901234567890").unwrap();
+    let i = Int::from(big.clone());
+    assert_eq!(i, create_int("123456789012345678901234567890"));
+    assert!(matches!(i.as_bigint(), Cow::Borrowed(_)));
+    assert_eq!(BigInt::from(&i), big);
+    assert_eq!(BigInt::from(i), big);
+
+    let small = create_int("-42").shrink();
+    assert_eq!(*small.as_bigint(), BigInt::from(-42));
+    let back: BigInt = small.into();
+    assert_eq!(back, BigInt::from(-42));
+}
+
+#[test]
+fn float_bigdecimal_round_trip() {
+    let bd = BigDecimal::from_str("-1.2500").unwrap();
+    let f = Float::from(bd.clone());
+    assert_eq!(f, create_float("-1.25"));
+    assert_eq!(f.as_bigdecimal(), Some(&bd));
+    assert_eq!(BigDecimal::try_from(f), Ok(bd));
+
+    assert_eq!(BigDecimal::try_from(Float::from(0.5f64)), Ok(BigDecimal::from_str("0.5").unwrap()));
+    assert!(create_float("2").sqrt().unwrap().as_bigdecimal().is_some());
+    assert_eq!(Float::NaN.as_bigdecimal(), None);
+    assert_eq!(BigDecimal::try_from(Float::Infinity), Err(errors::INVALID_FORMAT));
+    assert_eq!(BigDecimal::try_from(imagnum::create_complex("1", "2")), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn int_limbs_round_trip() {
+    let x = create_int("-123456789012345678901234567890");
+    let (sign, limbs) = x.to_u32_digits();
+    assert_eq!(sign, Sign::Minus);
+    assert_eq!((sign, limbs.clone()), BigInt::from(&x).to_u32_digits());
+    assert_eq!(Int::from_u32_digits(sign, &limbs).unwrap(), x);
+
+    assert_eq!(create_int("0").to_u32_digits(), (Sign::NoSign, vec![]));
+    assert_eq!(create_int("4294967296").shrink().to_u32_digits(), (Sign::Plus, vec![0, 1]));
+    assert_eq!(Int::from_u32_digits(Sign::Plus, &[5, 0, 0]).unwrap(), create_int("5"));
+    assert_eq!(Int::from_u32_digits(Sign::Minus, &[]).unwrap(), create_int("0"));
+    assert_eq!(Int::from_u32_digits(Sign::NoSign, &[0]).unwrap(), create_int("0"));
+    assert_eq!(Int::from_u32_digits(Sign::NoSign, &[1]), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn int_radix_digits_round_trip() {
+    let x = create_int("-255");
+    assert_eq!(x.to_radix_le(16).unwrap(), (Sign::Minus, vec![15, 15]));
+    assert_eq!(x.to_radix_le(256).unwrap(), (Sign::Minus, vec![255]));
+    assert_eq!(create_int("6").to_radix_le(2).unwrap(), (Sign::Plus, vec![0, 1, 1]));
+    assert_eq!(create_int("0").to_radix_le(10).unwrap(), (Sign::NoSign, vec![0]));
+    assert_eq!(x.to_radix_le(1), Err(errors::INVALID_FORMAT));
+    assert_eq!(x.to_radix_le(257), Err(errors::INVALID_FORMAT));
+
+    let big = create_int("98765432109876543210987654321");
+    for radix in [2, 7, 10, 36, 100, 256] {
+        let (sign, digits) = big.to_radix_le(radix).unwrap();
+        assert_eq!(Int::from_radix_le(sign, &digits, radix).unwrap(), big);
+    }
+    assert_eq!(Int::from_radix_le(Sign::Plus, &[3, 10], 10), Err(errors::INVALID_FORMAT));
+    assert_eq!(Int::from_radix_le(Sign::Plus, &[1], 300), Err(errors::INVALID_FORMAT));
+    assert_eq!(Int::from_radix_le(Sign::NoSign, &[1], 10), Err(errors::INVALID_FORMAT));
+}
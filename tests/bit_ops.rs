@@ -135,6 +135,7 @@ fn test_float_bitxor() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_float_xnor() {
     let a = Float::from(1.0f32);
     let b = Float::from(2.0f32);
@@ -207,3 +208,46 @@ fn test_int_big_large_xor() {
     let b = Int::Big(BigInt::from(0));
     assert_eq!(a ^ b, Ok(Int::Big(BigInt::from(1) << 100)));
 }
+
+#[test]
+fn test_int_bit_introspection() {
+    let a = Int::Big((BigInt::from(1) << 200) + 0b1011_0000);
+    assert_eq!(a.bits(), 201);
+    assert_eq!(a.count_ones(), 4);
+    assert_eq!(a.trailing_zeros(), Some(4));
+    assert!(a.bit(200) && a.bit(4) && !a.bit(3) && !a.bit(201));
+
+    assert_eq!(Int::from(0).bits(), 0);
+    assert_eq!(Int::from(0).trailing_zeros(), None);
+    assert_eq!(Int::from(-12).bits(), 4);
+    assert_eq!(Int::from(-12).count_ones(), 2);
+    assert_eq!(Int::from(-12).trailing_zeros(), Some(2));
+    // negative values read in two's complement, like the bitwise operators
+    assert!(Int::from(-1).bit(1000));
+    assert!(!Int::from(-2).bit(0));
+
+    let mut b = Int::from(0b1000);
+    b.set_bit(0, true);
+    b.set_bit(3, false);
+    b.set_bit(130, true);
+    assert_eq!(b, Int::Big((BigInt::from(1) << 130) + 1));
+    let mut c = Int::from(-1);
+    c.set_bit(0, false);
+    assert_eq!(c, Int::from(-2));
+}
+
+#[test]
+fn test_float_to_and_from_bits() {
+    assert_eq!(Float::from(1.5f64).to_bits(), Ok(1.5f64.to_bits()));
+    assert_eq!(Float::from(0.1f32).to_bits(), Ok((0.1f32 as f64).to_bits()));
+    assert_eq!(imagnum::create_float("0.1").to_bits(), Ok(0.1f64.to_bits()));
+    assert_eq!(Float::NegInfinity.to_bits(), Ok(f64::NEG_INFINITY.to_bits()));
+    assert_eq!(imagnum::create_float("-1e400").to_bits(), Ok(f64::NEG_INFINITY.to_bits()));
+    assert!(Float::Complex(Box::new(Float::from(1.0f64)), Box::new(Float::from(1.0f64))).to_bits().is_err());
+
+    assert_eq!(Float::from_bits(0x3FF8_0000_0000_0000), Float::from(1.5f64));
+    assert_eq!(Float::from_bits(f64::INFINITY.to_bits()), Float::Infinity);
+    assert!(Float::from_bits(f64::NAN.to_bits()).is_nan());
+    let bits = (-2.75f64).to_bits();
+    assert_eq!(Float::from_bits(bits).to_bits(), Ok(bits));
+}
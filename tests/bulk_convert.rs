@@ -0,0 +1,26 @@
+use imagnum::{Float, create_float, create_int, floats_from_f64, floats_to_f64, ints_from_i64, ints_to_i64};
+
+#[test]
+fn ints_round_trip() {
+    let values = [0, -1, 42, i64::MAX, i64::MIN];
+    let ints = ints_from_i64(&values);
+    assert_eq!(ints.len(), values.len());
+    assert_eq!(ints[2], create_int("42"));
+    assert_eq!(ints_to_i64(&ints).unwrap(), values);
+
+    let too_big = [create_int("1"), create_int("9223372036854775808")];
+    assert!(ints_to_i64(&too_big).is_err());
+}
+
+#[test]
+fn floats_round_trip() {
+    let values = [0.0, -1.5, 0.1, 1e300, f64::INFINITY];
+    let floats = floats_from_f64(&values);
+    assert_eq!(floats[1], create_float("-1.5"));
+    assert_eq!(floats_to_f64(&floats).unwrap(), values);
+
+    assert_eq!(floats_to_f64(&[create_float("0.25")]).unwrap(), [0.25]);
+    assert!(floats_to_f64(&[Float::NaN]).is_err());
+    assert!(floats_from_f64(&[f64::NAN])[0].is_nan());
+    assert!(ints_from_i64(&[]).is_empty());
+}
@@ -0,0 +1,75 @@
+use imagnum::errors::INVALID_FORMAT;
+use imagnum::foundation::{SmallFloat, SmallInt};
+use imagnum::{create_complex, create_float, create_int, Float, Int};
+
+#[test]
+fn int_bytes_round_trip() {
+    for s in ["0", "1", "-1", "127", "128", "-128", "-129", "123456789012345678901234567890", "-340282366920938463463374607431768211456"] {
+        let i = create_int(s);
+        assert_eq!(Int::from_bytes_le(&i.to_bytes_le()), i, "{}", s);
+        assert_eq!(Int::from_bytes_be(&i.to_bytes_be()), i, "{}", s);
+    }
+    assert_eq!(create_int("256").to_bytes_le(), vec![0, 1]);
+    assert_eq!(create_int("255").to_bytes_le(), vec![255, 0]);
+    assert_eq!(create_int("-2").to_bytes_be(), vec![0xfe]);
+    assert_eq!(Int::Small(SmallInt::U8(200)).to_bytes_le(), vec![200, 0]);
+    assert_eq!(Int::from_bytes_le(&[]), create_int("0"));
+}
+
+fn assert_round_trip(f: &Float) {
+    let bytes = f.to_bytes();
+    let back = Float::from_bytes(&bytes).unwrap();
+    assert_eq!(back.fingerprint(), f.fingerprint(), "{:?}", f);
+}
+
+#[test]
+fn float_bytes_round_trip() {
+    let values = [
+        create_float("0"),
+        create_float("1.50"),
+        create_float("-0.000000123"),
+        create_float("1e-400"),
+        create_float("12345678901234567890.123456789"),
+        (create_float("1") / create_float("7")).unwrap(),
+        create_float("2").sqrt().unwrap(),
+        create_complex("3", "-4.5"),
+        Float::NaN,
+        Float::Infinity,
+        Float::NegInfinity,
+        Float::Small(SmallFloat::F64(-0.1)),
+        Float::Small(SmallFloat::F32(2.5)),
+    ];
+    for f in &values {
+        assert_round_trip(f);
+    }
+    assert!(Float::from_bytes(&Float::NaN.to_bytes()).unwrap().is_nan());
+    // tag, zigzag scale 2 -> 4, one mantissa byte
+    assert_eq!(create_float("1.50").to_bytes(), vec![0, 4, 2, 150, 0]);
+}
+
+#[test]
+fn frames_can_be_concatenated() {
+    let values = [create_float("1.25"), create_complex("1", "2"), Float::Infinity];
+    let mut buf = Vec::new();
+    for v in &values {
+        buf.extend(v.to_bytes());
+    }
+    let mut pos = 0;
+    for v in &values {
+        let (f, used) = Float::read_bytes(&buf[pos..]).unwrap();
+        assert_eq!(&f, v);
+        pos += used;
+    }
+    assert_eq!(pos, buf.len());
+}
+
+#[test]
+fn malformed_frames() {
+    let bytes = create_float("123.456").to_bytes();
+    assert_eq!(Float::from_bytes(&bytes[..bytes.len() - 1]), Err(INVALID_FORMAT));
+    assert_eq!(Float::from_bytes(&[bytes.clone(), vec![0]].concat()), Err(INVALID_FORMAT));
+    assert_eq!(Float::from_bytes(&[]), Err(INVALID_FORMAT));
+    assert_eq!(Float::from_bytes(&[99]), Err(INVALID_FORMAT));
+    assert_eq!(Float::from_bytes(&[3, 3, 4, 4, 4]), Err(INVALID_FORMAT));
+    assert_eq!(Float::from_bytes(&[0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]), Err(INVALID_FORMAT));
+}
@@ -0,0 +1,74 @@
+use imagnum::foundation::FloatKind;
+use imagnum::{create_complex, create_float, create_int, ApproxEq, Float};
+
+fn assert_round_trip(f: &Float) {
+    let s = f.to_canonical_string();
+    let back = Float::from_str(&s).unwrap_or_else(|e| panic!("{} failed to parse: {}", s, e));
+    assert_eq!(back.fingerprint(), f.fingerprint(), "{} did not round-trip", s);
+}
+
+#[test]
+fn recurring_round_trips() {
+    let third = (create_float("1") / create_float("3")).unwrap();
+    assert_eq!(third.to_canonical_string(), "0.(3)");
+    assert_round_trip(&third);
+
+    let seventh = (create_float("-1") / create_float("7")).unwrap();
+    assert_eq!(seventh.to_canonical_string(), "-0.(142857)");
+    // to_str() cuts the digits short
+    assert_ne!(Float::from_str(&seventh.to_str()).unwrap(), seventh);
+    assert_round_trip(&seventh);
+    assert_round_trip(&create_float("12.34(56)"));
+}
+
+#[test]
+fn irrational_keeps_every_digit() {
+    let root = create_float("2").sqrt().unwrap();
+    let s = root.to_canonical_string();
+    assert!(s.starts_with("1.41421356237309") && s.ends_with("...{136}"), "{}", s);
+    assert_round_trip(&root);
+    assert_round_trip(&create_int("3").ln().unwrap());
+
+    let padded = create_float("1.41...{5}");
+    assert_eq!(padded.to_canonical_string(), "1.41...{5}");
+    assert_eq!(padded.describe().kind, FloatKind::Irrational);
+    // Display output ("1.41...") parses too
+    assert_eq!(create_float("1.41...").describe().kind, FloatKind::Irrational);
+    assert!(create_float("1.4142...{2}").is_nan());
+    assert!(create_float("1.41...{x}").is_nan());
+}
+
+#[test]
+fn decimals_keep_scale() {
+    let f = create_float("1.50");
+    assert_eq!(f.to_canonical_string(), "1.50");
+    assert_round_trip(&f);
+    assert_round_trip(&create_float("-0.000123"));
+    assert_round_trip(&create_float("1e300"));
+}
+
+#[test]
+fn complex_round_trips() {
+    assert_round_trip(&create_complex("3", "-4.25"));
+    let z = Float::Complex(Box::new(create_float("2").sqrt().unwrap()), Box::new(create_float("0.(6)")));
+    assert_round_trip(&z);
+}
+
+#[test]
+fn special_values_parse_with_create_float() {
+    for (f, s) in [(Float::NaN, "NaN"), (Float::Infinity, "Infinity"), (Float::NegInfinity, "-Infinity")] {
+        assert_eq!(f.to_canonical_string(), s);
+        assert_eq!(create_float(s).fingerprint(), f.fingerprint());
+        assert!(Float::from_str(s).is_err());
+    }
+}
+
+#[test]
+fn long_repetends_are_written_out() {
+    let f = Float::from_fraction(&create_int("1"), &create_int("10007")).unwrap();
+    let s = f.to_canonical_string();
+    assert!(s.starts_with("0.0000999300489657") && s.ends_with('}'), "{}", s);
+    let back = create_float(&s);
+    assert!(back.is_irrational());
+    assert!(back.approx_eq_ulps(&f, 1));
+}
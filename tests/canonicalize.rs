@@ -0,0 +1,68 @@
+use imagnum::config::set_auto_shrink;
+use imagnum::foundation::{Float, SmallFloat};
+use imagnum::{create_complex, create_float};
+use num_bigint::BigInt;
+
+/// A `Recurring` value built directly, so not reduced or checked to recur.
+fn recurring(num: i64, den: i64) -> Float {
+    Float::Recurring(BigInt::from(num), BigInt::from(den))
+}
+
+#[test]
+fn trailing_nines_collapse_after_any_op() {
+    let nines = recurring(9, 9);
+    let zero = create_float("0");
+    for result in [
+        nines._add(&zero).unwrap(),
+        nines._sub(&zero).unwrap(),
+        nines._mul(&create_float("1")).unwrap(),
+        nines._div(&create_float("1")).unwrap(),
+    ] {
+        assert!(!result.is_recurring(), "{:?}", result);
+        assert_eq!(result, create_float("1"));
+    }
+    let r = recurring(117, 90)._add(&zero).unwrap();
+    assert_eq!(r.to_string(), "1.3");
+}
+
+#[test]
+fn thirds_agree_whichever_way_they_are_summed() {
+    let third = create_float("0.(3)");
+    let product = third._mul(&create_float("3")).unwrap();
+    let sum = third._add(&third).unwrap()._add(&third).unwrap();
+    assert_eq!(product.to_string(), sum.to_string());
+    assert_eq!(product, create_float("1"));
+    assert!(!product.is_recurring() && !sum.is_recurring());
+}
+
+#[test]
+fn canonicalize_clears_negative_zero() {
+    let z = Float::Small(SmallFloat::F64(-0.0)).canonicalize();
+    assert!(matches!(z, Float::Small(SmallFloat::F64(v)) if v == 0.0 && v.is_sign_positive()));
+    let z = Float::Small(SmallFloat::F32(-0.0)).canonicalize();
+    assert!(matches!(z, Float::Small(SmallFloat::F32(v)) if v.is_sign_positive()));
+    let c = create_complex("1", "2")._mul(&Float::Small(SmallFloat::F64(-0.0))).unwrap().canonicalize();
+    assert_eq!(c.to_string(), "0.0");
+}
+
+#[test]
+fn canonicalize_demotes_exact_big_values() {
+    assert!(matches!(create_float("1.50").canonicalize(), Float::Small(SmallFloat::F64(v)) if v == 1.5));
+    assert!(matches!(create_float("-42").canonicalize(), Float::Small(SmallFloat::F64(v)) if v == -42.0));
+    // 0.1 has no exact binary form, and irrational values keep their kind
+    assert!(matches!(create_float("0.1").canonicalize(), Float::Big(_)));
+    assert!(create_float("2").sqrt().unwrap().canonicalize().is_irrational());
+    assert!(matches!(recurring(9, 9).canonicalize(), Float::Small(SmallFloat::F64(v)) if v == 1.0));
+    assert!(create_float("0.(3)").canonicalize().is_recurring());
+}
+
+#[test]
+fn auto_shrink_demotes_float_results() {
+    set_auto_shrink(true);
+    let sum = create_float("1.25")._add(&create_float("0.25")).unwrap();
+    let inexact = create_float("0.1")._add(&create_float("0.2")).unwrap();
+    set_auto_shrink(false);
+    assert!(matches!(sum, Float::Small(SmallFloat::F64(v)) if v == 1.5));
+    assert!(matches!(inexact, Float::Big(_)));
+    assert!(matches!(create_float("1.25")._add(&create_float("0.25")).unwrap(), Float::Big(_)));
+}
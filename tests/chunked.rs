@@ -0,0 +1,78 @@
+use imagnum::{chunked, create_int, errors, Int};
+use num_bigint::{BigInt, BigUint};
+use std::io::{BufReader, Read};
+use std::str::FromStr;
+
+/// `len` deterministic decimal digits.
+fn digits(len: usize, seed: u64) -> String {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            char::from(b'0' + ((state >> 33) % 10) as u8)
+        })
+        .collect()
+}
+
+/// A reader that hands out at most `step` bytes at a time.
+struct Trickle<'a>(&'a [u8], usize);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.0.len().min(self.1).min(buf.len());
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn split_parse_matches_num_bigint() {
+    for len in [1, 18, 19, 20, 1_999, 2_000, 2_001, 7_777, 40_000] {
+        let s = digits(len, len as u64);
+        let expected = BigUint::from_str(&s).unwrap();
+        assert_eq!(chunked::parse_digits(s.as_bytes()), Some(BigInt::from(expected.clone())), "{}", len);
+        assert_eq!(create_int(&format!("-{}", s)), Int::from(-BigInt::from(expected)));
+    }
+    let zeros = format!("{}{}", "0".repeat(5_000), digits(3_000, 1));
+    assert_eq!(create_int(&zeros), Int::from(BigInt::from_str(&zeros).unwrap()));
+    assert_eq!(chunked::parse_digits(b""), None);
+    assert_eq!(chunked::parse_digits(b"12a"), None);
+}
+
+#[test]
+fn long_strings_keep_the_string_rules() {
+    let s = digits(5_000, 7);
+    let grouped: String = s.chars().enumerate().flat_map(|(i, c)| if i % 3 == 2 { vec![c, '_'] } else { vec![c] }).collect();
+    assert_eq!(create_int(&format!("+{}", grouped)), create_int(&s));
+    for bad in [format!("_{}", s), format!("--{}", s), format!("{}x", s), format!("{}.5", s)] {
+        assert_eq!(imagnum::try_create_int(&bad), Err(errors::INVALID_FORMAT));
+    }
+}
+
+#[test]
+fn from_reader() {
+    let s = digits(200_000, 3);
+    let expected = create_int(&s);
+    let text = format!(" \n-{}_1 \r\n", s);
+    let reader = BufReader::with_capacity(4_096, Trickle(text.as_bytes(), 1_000));
+    assert_eq!(Int::from_reader(reader), Ok(create_int(&format!("-{}1", s))));
+    assert_eq!(Int::from_reader(s.as_bytes()), Ok(expected));
+    assert_eq!(Int::from_reader("007".as_bytes()), Ok(create_int("7")));
+
+    assert_eq!(Int::from_reader("1_000_000".as_bytes()), Ok(create_int("1000000")));
+
+    for bad in ["", "  ", "-", "+_1", "12 34", "1.0", "0x10", "- 5"] {
+        assert_eq!(Int::from_reader(bad.as_bytes()), Err(errors::INVALID_FORMAT), "{:?}", bad);
+    }
+}
+
+#[test]
+fn from_reader_underscores_sit_between_digits() {
+    for bad in ["_1", "-_1", "1_", "1_ ", "1__2", "12___3"] {
+        assert_eq!(Int::from_reader(bad.as_bytes()), Err(errors::INVALID_FORMAT), "{:?}", bad);
+    }
+    let text = format!("{}__{}", digits(5_000, 7), digits(5_000, 7));
+    let reader = BufReader::with_capacity(4_096, Trickle(text.as_bytes(), 1));
+    assert_eq!(Int::from_reader(reader), Err(errors::INVALID_FORMAT));
+}
@@ -0,0 +1,37 @@
+use imagnum::create_int;
+use imagnum::foundation::Int;
+
+#[test]
+fn factorial() {
+    assert_eq!(create_int("0").factorial().unwrap(), create_int("1"));
+    assert_eq!(create_int("5").factorial().unwrap(), create_int("120"));
+    assert_eq!(
+        create_int("25").factorial().unwrap(),
+        create_int("15511210043330985984000000")
+    );
+    assert!(create_int("-3").factorial().is_err());
+}
+
+#[test]
+fn binomial() {
+    assert_eq!(create_int("10").binomial(&create_int("3")).unwrap(), create_int("120"));
+    assert_eq!(create_int("10").binomial(&create_int("11")).unwrap(), create_int("0"));
+    assert_eq!(create_int("10").binomial(&create_int("-1")).unwrap(), create_int("0"));
+    assert_eq!(
+        create_int("100").binomial(&create_int("50")).unwrap(),
+        create_int("100891344545564193334812497256")
+    );
+    // C(-4, 3) = (-4)(-5)(-6)/3! = -20
+    assert_eq!(create_int("-4").binomial(&create_int("3")).unwrap(), create_int("-20"));
+}
+
+#[test]
+fn multinomial_and_pochhammer() {
+    let ks = [create_int("2"), create_int("3"), create_int("1")];
+    // 6! / (2! 3! 1!) = 60
+    assert_eq!(Int::multinomial(&ks).unwrap(), create_int("60"));
+
+    assert_eq!(create_int("3").rising_factorial(&create_int("4")).unwrap(), create_int("360"));
+    assert_eq!(create_int("7").falling_factorial(&create_int("3")).unwrap(), create_int("210"));
+    assert_eq!(create_int("7").falling_factorial(&create_int("0")).unwrap(), create_int("1"));
+}
@@ -381,3 +381,53 @@ fn test_complex_equality() {
     let z6 = create_complex("0", "0");
     assert_eq!(z5, z6);
 }
+
+#[test]
+fn test_parse_complex_literal() {
+    assert_eq!(create_float("3+4i"), create_complex("3", "4"));
+    assert_eq!(create_float("-2.5-0.5i"), create_complex("-2.5", "-0.5"));
+    assert_eq!(create_float("1e3+2e-2i"), create_complex("1000", "0.02"));
+    assert_eq!(create_float("1-i"), create_complex("1", "-1"));
+    assert_eq!(create_float("3.0 + 4.0i").to_string(), "3.0 + 4.0i");
+    // exponent signs don't split the literal
+    assert_eq!(create_float("2e+3i"), create_complex("0", "2000"));
+    assert!(create_float("3+xi").is_nan());
+    assert!(create_float("3+4i+1i").is_nan());
+}
+
+#[test]
+fn test_from_str_complex() {
+    assert_eq!(Float::from_str("3-4i").unwrap(), create_complex("3", "-4"));
+    assert_eq!(Float::from_str("3+4j"), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_complex_parts_and_polar() {
+    let z = create_complex("3", "4");
+    assert_eq!(z.re(), create_float("3"));
+    assert_eq!(z.im(), create_float("4"));
+    assert_eq!(create_float("2.5").re(), create_float("2.5"));
+    assert!(create_float("2.5").im().is_zero());
+    assert_eq!(z.norm_sqr().unwrap(), create_float("25"));
+
+    let (r, theta) = z.to_polar().unwrap();
+    assert_eq!(r, create_float("5"));
+    assert!((theta.to_f64().unwrap() - 4f64.atan2(3.0)).abs() < 1e-12);
+
+    assert!(create_float("7").arg().unwrap().is_zero());
+    let pi = create_float("-1").arg().unwrap().to_f64().unwrap();
+    assert!((pi - std::f64::consts::PI).abs() < 1e-12);
+    assert!(Float::NaN.arg().is_err());
+    // the angle carries precision() digits, not an f64's
+    let digits = |x: &Float| x.to_str().chars().take(120).collect::<String>();
+    assert_eq!(digits(&create_float("-1").arg().unwrap()), digits(&Float::Big(consts::pi(130))));
+    let quarter = create_complex("-1", "-1").arg().unwrap();
+    assert_eq!(digits(&quarter), digits(&-Float::Big(consts::pi(130) * 3 / 4)));
+    let edge = Float::complex(Float::NegInfinity, create_float("1")).arg().unwrap();
+    assert!((edge.to_f64().unwrap() - std::f64::consts::PI).abs() < 1e-15);
+
+    let back = Float::from_polar(&r, &theta).unwrap();
+    assert!((back.re().to_f64().unwrap() - 3.0).abs() < 1e-10);
+    assert!((back.im().to_f64().unwrap() - 4.0).abs() < 1e-10);
+    assert!(Float::from_polar(&z, &theta).is_err());
+}
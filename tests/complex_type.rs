@@ -0,0 +1,59 @@
+use imagnum::{create_complex, create_float, create_int, errors, Complex, ComplexFloat, ComplexInt, Float, Int};
+
+fn gi(re: &str, im: &str) -> ComplexInt {
+    ComplexInt::new(create_int(re), create_int(im))
+}
+
+#[test]
+fn test_gaussian_arithmetic() {
+    let a = gi("3", "4");
+    let b = gi("1", "-2");
+    assert_eq!(a.clone() + b.clone(), Ok(gi("4", "2")));
+    assert_eq!(a.clone() - &b, Ok(gi("2", "6")));
+    assert_eq!(a.clone() * b.clone(), Ok(gi("11", "-2")));
+    assert_eq!(a.norm(), Ok(create_int("25")));
+    assert_eq!(a.conj(), gi("3", "-4"));
+    assert_eq!(-a.clone(), gi("-3", "-4"));
+    assert_eq!(a.to_string(), "3 + 4i");
+    assert_eq!(b.to_string(), "1 - 2i");
+}
+
+#[test]
+fn test_gaussian_division() {
+    // exact: (11 - 2i) / (1 - 2i) = 3 + 4i
+    assert_eq!(gi("11", "-2") / gi("1", "-2"), Ok(gi("3", "4")));
+    assert_eq!(gi("11", "-2") % gi("1", "-2"), Ok(gi("0", "0")));
+
+    for (a, b) in [(gi("7", "2"), gi("2", "-1")), (gi("-13", "5"), gi("3", "4")), (gi("5", "0"), gi("0", "2"))] {
+        let (q, r) = a.div_rem(&b).unwrap();
+        assert_eq!((q * &b).unwrap() + r.clone(), Ok(a));
+        assert!(r.norm().unwrap() < b.norm().unwrap());
+    }
+    assert_eq!(gi("1", "1").div_rem(&gi("0", "0")), Err(errors::DIV_BY_ZERO));
+}
+
+#[test]
+fn test_complex_float() {
+    let a = ComplexFloat::new(create_float("1"), create_float("2"));
+    let b = ComplexFloat::new(create_float("3"), create_float("-1"));
+    let q = (a.clone() / &b).unwrap();
+    assert_eq!((q * b).unwrap(), a);
+    assert_eq!(
+        a.clone() / ComplexFloat::new(create_float("0"), create_float("0")),
+        Err(errors::DIV_BY_ZERO)
+    );
+}
+
+#[test]
+fn test_complex_conversions() {
+    let z = create_complex("3", "-4");
+    let c = ComplexFloat::try_from(z.clone()).unwrap();
+    assert_eq!(c.re, create_float("3"));
+    assert_eq!(Float::from(c), z);
+
+    assert_eq!(ComplexInt::try_from(z.clone()), Ok(gi("3", "-4")));
+    assert_eq!(gi("3", "-4").to_float(), Ok(z));
+    assert_eq!(Complex::<Int>::from_float(&create_float("7")), Ok(gi("7", "0")));
+    assert!(ComplexInt::from_float(&create_complex("1.5", "2")).is_err());
+    assert!(ComplexFloat::from_float(&Float::NaN).is_err());
+}
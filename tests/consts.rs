@@ -0,0 +1,40 @@
+use imagnum::consts::{e, euler_gamma, ln10, ln2, phi, pi, sqrt2};
+use imagnum::math::LN_10;
+
+const PI_60: &str = "3.141592653589793238462643383279502884197169399375105820974944";
+const E_60: &str = "2.718281828459045235360287471352662497757247093699959574966967";
+const PHI_60: &str = "1.618033988749894848204586834365638117720309179805762862135448";
+const LN2_60: &str = "0.693147180559945309417232121458176568075500134360255254120680";
+const LN10_60: &str = "2.302585092994045684017991454684364207601101488628772976033327";
+const SQRT2_60: &str = "1.414213562373095048801688724209698078569671875376948073176679";
+const EULER_GAMMA_60: &str = "0.577215664901532860606512090082402431042159335939923598805767";
+
+#[test]
+fn constants_match_reference_digits() {
+    assert_eq!(pi(60).to_string(), PI_60);
+    assert_eq!(e(60).to_string(), E_60);
+    assert_eq!(phi(60).to_string(), PHI_60);
+    assert_eq!(ln2(60).to_string(), LN2_60);
+    assert_eq!(ln10(60).to_string(), LN10_60);
+    assert_eq!(sqrt2(60).to_string(), SQRT2_60);
+    assert_eq!(euler_gamma(60).to_string(), EULER_GAMMA_60);
+}
+
+#[test]
+fn constants_truncate_from_cache() {
+    // a longer expansion is cached first, shorter ones are cut from it
+    let long = e(200).to_string();
+    assert_eq!(e(5).to_string(), "2.71828");
+    assert_eq!(e(60).to_string(), E_60);
+    assert!(long.starts_with(E_60));
+    assert_eq!(sqrt2(0).to_string(), "1");
+    assert_eq!(ln2(3).to_string(), "0.693");
+}
+
+#[test]
+fn ln10_agrees_with_fixed_string() {
+    // LN_10 rounds its last digit, the generator truncates
+    let digits = ln10(136).to_string();
+    assert_eq!(digits.len(), LN_10.len());
+    assert_eq!(digits[..digits.len() - 1], LN_10[..LN_10.len() - 1]);
+}
@@ -0,0 +1,33 @@
+use imagnum::create_float;
+use imagnum::foundation::Float;
+
+#[test]
+fn literal_keeps_trailing_zero_significance() {
+    let a = create_float("1.500");
+    let b = create_float("1.5");
+    assert_eq!(a, b);
+    assert_eq!(a.declared_precision(), Some(4));
+    assert_eq!(b.declared_precision(), Some(2));
+}
+
+#[test]
+fn with_precision_pads_and_rounds() {
+    let padded = Float::with_precision("1.5", 4).unwrap();
+    assert_eq!(padded.declared_precision(), Some(4));
+    assert_eq!(padded, create_float("1.5"));
+
+    let rounded = Float::with_precision("3.14159", 3).unwrap();
+    assert_eq!(rounded, create_float("3.14"));
+    assert_eq!(rounded.declared_precision(), Some(3));
+
+    assert!(Float::with_precision("nan", 3).is_err());
+    assert!(Float::with_precision("1.0", 0).is_err());
+}
+
+#[test]
+fn round_sig() {
+    let x = create_float("123.456");
+    assert_eq!(x.round_sig(2), create_float("120"));
+    assert_eq!(x.round_sig(5), create_float("123.46"));
+    assert_eq!(create_float("0.00012345").round_sig(3), create_float("0.000123"));
+}
@@ -0,0 +1,47 @@
+use imagnum::foundation::FloatKind;
+use imagnum::{create_float, create_int, Float, Int};
+
+fn parts(s: &str) -> (Int, i64, bool, FloatKind) {
+    create_float(s).decompose()
+}
+
+#[test]
+fn decompose_gives_typed_parts() {
+    assert_eq!(parts("-12.340"), (create_int("1234"), -2, true, FloatKind::Finite));
+    assert_eq!(parts("1200"), (create_int("12"), 2, false, FloatKind::Finite));
+    assert_eq!(parts("0.000"), (create_int("0"), 0, false, FloatKind::Finite));
+    assert_eq!(Float::from(0.1f64).decompose(), (create_int("1"), -1, false, FloatKind::Finite));
+    assert_eq!(parts("0.(3)").3, FloatKind::Recurring);
+    assert_eq!(create_float("2").sqrt().unwrap().decompose().3, FloatKind::Irrational);
+
+    assert_eq!(Float::NaN.decompose(), (create_int("0"), 0, false, FloatKind::NaN));
+    assert_eq!(Float::NegInfinity.decompose(), (create_int("0"), 0, true, FloatKind::NegInfinity));
+    assert_eq!(imagnum::create_complex("1", "2").decompose().3, FloatKind::Complex);
+}
+
+#[test]
+fn decompose_matches_float_to_parts() {
+    for s in ["-12.340", "1e-30", "987654321987654321.5", "0", "0.(142857)"] {
+        let f = create_float(s);
+        let (m, e, neg, kind) = f.decompose();
+        assert_eq!(imagnum::compat::float_to_parts(&f), (m.to_string(), e as i32, neg, kind));
+    }
+}
+
+#[test]
+fn recompose_inverts_decompose() {
+    for s in ["-12.340", "1e-30", "0", "123456789012345678901234567890.5"] {
+        let f = create_float(s);
+        let (m, e, neg, kind) = f.decompose();
+        assert_eq!(Float::recompose(&m, e, neg, kind), f);
+    }
+    let third = create_float("1")._div(&create_float("3")).unwrap();
+    let (m, e, neg, kind) = third.decompose();
+    let back = Float::recompose(&m, e, neg, kind);
+    assert!(back.is_recurring());
+    assert_eq!(back, third);
+
+    assert_eq!(Float::recompose(&create_int("-5"), 1, false, FloatKind::Finite), create_float("-50"));
+    assert_eq!(Float::recompose(&create_int("0"), 0, true, FloatKind::Infinity), Float::NegInfinity);
+    assert!(Float::recompose(&create_int("1"), 0, false, FloatKind::Complex).is_nan());
+}
@@ -0,0 +1,45 @@
+use imagnum::foundation::FloatKind;
+use imagnum::{create_complex, create_float, create_int, create_irrational};
+
+#[test]
+fn describe_int() {
+    let info = create_int("-12345").describe();
+    assert!(info.is_int);
+    assert!(info.negative);
+    assert_eq!(info.digits, 5);
+    assert_eq!(info.scale, 0);
+    assert!(info.exact);
+    assert!(info.memory > 0);
+}
+
+#[test]
+fn describe_float_kinds() {
+    let info = create_float("12.250").describe();
+    assert!(!info.is_int);
+    assert_eq!(info.kind, FloatKind::Finite);
+    assert_eq!(info.digits, 4);
+    assert_eq!(info.scale, 2);
+    assert!(info.exact);
+
+    let irr = create_irrational("1.41421356").describe();
+    assert_eq!(irr.kind, FloatKind::Irrational);
+    assert!(!irr.exact);
+
+    let rec = create_float("0.(3)").describe();
+    assert_eq!(rec.kind, FloatKind::Recurring);
+}
+
+#[test]
+fn describe_special_and_complex() {
+    let nan = create_float("nan").describe();
+    assert_eq!(nan.kind, FloatKind::NaN);
+    assert!(!nan.exact);
+
+    let ninf = create_float("-inf").describe();
+    assert_eq!(ninf.kind, FloatKind::NegInfinity);
+    assert!(ninf.negative);
+
+    let c = create_complex("3", "4").describe();
+    assert_eq!(c.kind, FloatKind::Complex);
+    assert!(c.exact);
+}
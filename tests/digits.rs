@@ -0,0 +1,66 @@
+use imagnum::digits::DecimalDigit;
+use imagnum::{create_float, create_int};
+
+fn collect_int(s: &str, radix: u32) -> String {
+    create_int(s)
+        .digits(radix)
+        .unwrap()
+        .map(|d| std::char::from_digit(d as u32, radix).unwrap())
+        .collect()
+}
+
+fn collect_float(f: &imagnum::Float) -> String {
+    f.decimal_digits()
+        .unwrap()
+        .map(|t| match t {
+            DecimalDigit::Digit(d) => (b'0' + d) as char,
+            DecimalDigit::Point => '.',
+            DecimalDigit::RepetendStart => '(',
+        })
+        .collect()
+}
+
+#[test]
+fn int_digits_match_to_str_radix() {
+    assert_eq!(collect_int("0", 10), "0");
+    assert_eq!(collect_int("-12345", 10), "12345");
+    assert_eq!(collect_int("255", 16), "ff");
+    assert_eq!(collect_int("5", 2), "101");
+    assert_eq!(collect_int("35", 36), "z");
+
+    // large enough to go through several splits, with zero runs inside chunks
+    let big = create_int("7").pow(&create_int("5000")).unwrap()._add(&create_int("1")).unwrap();
+    let big = big._mul(&create_int("10").pow(&create_int("700")).unwrap()).unwrap();
+    for radix in [3, 8, 10] {
+        let expected = big.to_str_radix(radix).unwrap();
+        let got: String = big
+            .digits(radix)
+            .unwrap()
+            .map(|d| std::char::from_digit(d as u32, radix).unwrap())
+            .collect();
+        assert_eq!(got, expected, "radix {}", radix);
+    }
+
+    assert!(create_int("1").digits(1).is_err());
+    assert!(create_int("1").digits(37).is_err());
+}
+
+#[test]
+fn float_decimal_digits() {
+    assert_eq!(collect_float(&create_float("123.45")), "123.45");
+    assert_eq!(collect_float(&create_float("-0.00123")), "0.00123");
+    assert_eq!(collect_float(&create_float("1.500")), "1.5");
+    assert_eq!(collect_float(&create_float("5e3")), "5000");
+    assert_eq!(collect_float(&create_float("0")), "0");
+    assert_eq!(collect_float(&imagnum::Float::from(0.25f64)), "0.25");
+    assert!(imagnum::Float::NaN.decimal_digits().is_err());
+}
+
+#[test]
+fn recurring_marks_repetend() {
+    let third = create_float("1")._div(&create_float("3")).unwrap();
+    assert!(third.is_recurring());
+    assert_eq!(collect_float(&third), "0.(3");
+    let x = create_float("1")._div(&create_float("6")).unwrap();
+    assert_eq!(collect_float(&x), "0.1(6");
+}
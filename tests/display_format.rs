@@ -33,3 +33,86 @@ fn test_recurring_preservation_after_add() {
     let s = format!("{}", sum);
     assert_eq!(s, "1.(3)");
 }
+
+#[test]
+fn test_display_precision_rounds_half_even() {
+    use imagnum::create_float;
+    assert_eq!(format!("{:.2}", create_float("1.005")), "1.00");
+    assert_eq!(format!("{:.2}", create_float("1.015")), "1.02");
+    assert_eq!(format!("{:.3}", create_float("2")), "2.000");
+    assert_eq!(format!("{:.0}", create_float("2.5")), "2");
+    assert_eq!(format!("{:.5}", create_float("-0.000001")), "0.00000");
+}
+
+#[test]
+fn test_display_precision_recurring_and_small() {
+    let third = create_int("1").to_float().unwrap()._div(&create_int("3").to_float().unwrap()).unwrap();
+    assert_eq!(format!("{:.5}", third), "0.33333");
+    assert_eq!(format!("{:.3}", imagnum::Float::from(0.1f64)), format!("{:.3}", 0.1f64));
+}
+
+#[test]
+fn test_display_precision_irrational_drops_marker() {
+    let pi = imagnum::create_float("3.14159265358979").sqrt().unwrap();
+    let s = format!("{:.4}", pi);
+    assert!(!s.ends_with("..."), "{}", s);
+    assert_eq!(s.split('.').nth(1).unwrap().len(), 4);
+}
+
+#[test]
+fn test_display_width_fill_alignment_sign() {
+    use imagnum::create_float;
+    let x = create_float("1.5");
+    assert_eq!(format!("{:>8}", x), "     1.5");
+    assert_eq!(format!("{:*<8}", x), "1.5*****");
+    assert_eq!(format!("{:^9.2}", x), "  1.50   ");
+    assert_eq!(format!("{:+}", x), "+1.5");
+    assert_eq!(format!("{:+.1}", create_float("-1.5")), "-1.5");
+    assert_eq!(format!("{:08.2}", create_float("-1.5")), "-0001.50");
+    assert_eq!(format!("{:+08.1}", x), "+00001.5");
+    assert_eq!(format!("{:>6}", imagnum::Float::NaN), "   NaN");
+    assert_eq!(format!("{:+}", imagnum::Float::NaN), "NaN");
+}
+
+#[test]
+fn test_display_int_flags() {
+    let n = create_int("42");
+    assert_eq!(format!("{:>6}", n), "    42");
+    assert_eq!(format!("{:_<6}", n), "42____");
+    assert_eq!(format!("{:+}", n), "+42");
+    assert_eq!(format!("{:06}", create_int("-42")), "-00042");
+    assert_eq!(format!("{:^7}", create_int("-42")), "  -42  ");
+    assert_eq!(format!("{}", create_int("123456789012345678901234567890")), "123456789012345678901234567890");
+}
+
+#[test]
+fn test_display_complex_flags() {
+    use imagnum::create_complex;
+    let z = create_complex("1.25", "-2");
+    assert_eq!(format!("{:.1}", z), "1.2 - 2.0i");
+    assert_eq!(format!("{:>14.1}", z), "    1.2 - 2.0i");
+    assert_eq!(format!("{:+}", z), "+1.25 - 2.0i");
+    assert_eq!(format!("{:012.1}", z), "  1.2 - 2.0i");
+}
+
+#[test]
+fn test_irrational_display_is_shortened() {
+    use imagnum::config::{set_display_digits, DEFAULT_DISPLAY_DIGITS};
+    use imagnum::{create_complex, create_float, create_irrational};
+    let pi = create_irrational("3.141592653589793238462643383279502884197");
+    assert_eq!(pi.to_string(), "3.1415926535897932385…");
+    assert_eq!(pi.to_string_full(), "3.141592653589793238462643383279502884197...");
+    let z = create_complex("0", "1")._mul(&pi).unwrap();
+    assert_eq!(z.to_string(), "3.1415926535897932385…i");
+
+    set_display_digits(5);
+    assert_eq!(pi.to_string(), "3.1416…");
+    set_display_digits(0);
+    assert_eq!(pi.to_string(), pi.to_string_full());
+    set_display_digits(DEFAULT_DISPLAY_DIGITS);
+
+    // short irrationals print in full, and the shortened form parses back
+    assert_eq!(create_irrational("1.5").to_string(), "1.5...");
+    let back = create_float(&pi.to_string());
+    assert_eq!(back.to_string(), "3.1415926535897932385...");
+}
@@ -0,0 +1,168 @@
+use imagnum::eval::{arity, eval, Evaluator, Registry, Value};
+use imagnum::{create_float, create_int, errors, Float};
+
+fn int(s: &str) -> Value {
+    Value::Int(create_int(s))
+}
+
+fn float(s: &str) -> Value {
+    Value::Float(create_float(s))
+}
+
+#[test]
+fn test_precedence() {
+    assert_eq!(eval("1 + 2 * 3").unwrap(), int("7"));
+    assert_eq!(eval("(1 + 2) * 3").unwrap(), int("9"));
+    assert_eq!(eval("2 ^ 3 ^ 2").unwrap(), int("512"));
+    assert_eq!(eval("-2 ^ 2").unwrap(), int("-4"));
+    assert_eq!(eval("2 * -3").unwrap(), int("-6"));
+    assert_eq!(eval("10 - 4 - 3").unwrap(), int("3"));
+    assert_eq!(eval("1 + 1 == 2").unwrap(), Value::Bool(true));
+    assert_eq!(eval("3 < 2").unwrap(), Value::Bool(false));
+}
+
+#[test]
+fn test_literals_and_types() {
+    assert_eq!(eval("0x1F + 0b11 + 0o7").unwrap(), int("41"));
+    assert_eq!(eval("7 / 2").unwrap(), float("3.5"));
+    assert_eq!(eval("2 ^ -1").unwrap(), float("0.5"));
+    assert!(matches!(eval("3 ^ -2").unwrap(), Value::Float(Float::Recurring(..))));
+    assert_eq!(eval("1.5 + 1").unwrap(), float("2.5"));
+    assert!(matches!(eval("0.(3)").unwrap(), Value::Float(Float::Recurring(..))));
+    assert_eq!(eval("3 + 4i").unwrap().to_string(), "3.0 + 4.0i");
+    assert_eq!(eval("i * i").unwrap().to_float().unwrap().re(), create_float("-1"));
+}
+
+#[test]
+fn test_functions_and_constants() {
+    assert_eq!(eval("sqrt(16) + abs(-3)").unwrap(), float("7"));
+    assert_eq!(eval("round(pi, 2)").unwrap(), float("3.14"));
+    assert_eq!(eval("log(100, 10)").unwrap().to_float().unwrap().round(10), create_float("2"));
+    assert_eq!(eval("floor(sqrt(2) * 10)").unwrap(), float("14"));
+    assert_eq!(eval("abs(2 - 5) * 2").unwrap(), int("6"));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(eval("1 +"), Err(errors::WRONG_SYNTAX));
+    assert_eq!(eval("(1 + 2"), Err(errors::WRONG_SYNTAX));
+    assert_eq!(eval("1 2"), Err(errors::WRONG_SYNTAX));
+    assert_eq!(eval("1 $ 2"), Err(errors::WRONG_SYNTAX));
+    assert_eq!(eval("sqrt(1, 2)"), Err(errors::WRONG_SYNTAX));
+    assert_eq!(eval("nope(1)"), Err(errors::UNIMPLEMENTED));
+    assert_eq!(eval("nope + 1"), Err(errors::INVALID_FORMAT));
+    assert_eq!(eval("0xZZ"), Err(errors::INVALID_FORMAT));
+    assert_eq!(eval("1 / 0"), Err(errors::DIV_BY_ZERO));
+}
+
+#[test]
+fn test_evaluator_state() {
+    let mut ev = Evaluator::new();
+    assert_eq!(ev.execute("x = 6 * 7").unwrap(), (Some("x".to_string()), int("42")));
+    assert_eq!(ev.execute("x + 1").unwrap(), (None, int("43")));
+    assert_eq!(ev.execute("x == 42").unwrap(), (None, Value::Bool(true)));
+
+    // variables shadow constants
+    ev.set_var("e", int("5"));
+    assert_eq!(ev.eval("e").unwrap(), int("5"));
+    assert_eq!(ev.vars().map(|(k, _)| k).collect::<Vec<_>>(), vec!["e", "x"]);
+    ev.clear_vars();
+    assert!(matches!(ev.eval("e").unwrap(), Value::Float(Float::Irrational(_))));
+
+    ev.register("sum", |args| {
+        args.iter().try_fold(Value::Int(create_int("0")), |acc, v| match (acc, v) {
+            (Value::Int(a), Value::Int(b)) => Ok(Value::Int(a._add(b)?)),
+            (a, b) => Ok(Value::Float(a.to_float()?._add(&b.to_float()?)?)),
+        })
+    });
+    assert_eq!(ev.eval("sum(1, 2, 3 * 4)").unwrap(), int("15"));
+    assert_eq!(ev.eval("sum()").unwrap(), int("0"));
+
+    let empty = Evaluator::empty();
+    assert_eq!(empty.eval("pi"), Err(errors::INVALID_FORMAT));
+    assert_eq!(empty.eval("sqrt(4)"), Err(errors::UNIMPLEMENTED));
+}
+
+#[test]
+fn test_registry() {
+    let mut registry = Registry::new();
+    registry.set_const("g", float("9.81"));
+    registry.register_unary("half", |x| x._div(&create_float("2")));
+    registry.register("clamp01", |args| {
+        arity(args, 1)?;
+        let x = args[0].to_float()?;
+        Ok(Value::Float(x.clamp(&create_float("0"), &create_float("1"))?))
+    });
+    assert!(registry.has_function("sqrt"));
+    assert!(registry.constant_names().contains(&"g"));
+
+    // evaluators share the registry's functions
+    let a = Evaluator::with_registry(registry.clone());
+    let mut b = Evaluator::with_registry(registry);
+    assert_eq!(a.eval("half(g)").unwrap(), float("4.905"));
+    assert_eq!(b.eval("clamp01(g) + clamp01(-g)").unwrap(), float("1"));
+    assert_eq!(b.eval("half(1, 2)"), Err(errors::WRONG_SYNTAX));
+
+    // overriding and removing
+    b.registry_mut().register_unary("sqrt", |_| Ok(create_float("0")));
+    assert_eq!(b.eval("sqrt(4)").unwrap(), float("0"));
+    assert_eq!(a.eval("sqrt(4)").unwrap(), float("2"));
+    assert!(b.registry_mut().unregister("half"));
+    assert_eq!(b.eval("half(2)"), Err(errors::UNIMPLEMENTED));
+    assert_eq!(b.registry_mut().remove_const("g"), Some(float("9.81")));
+    assert_eq!(b.eval("g"), Err(errors::INVALID_FORMAT));
+
+    let empty = Registry::empty();
+    assert!(empty.function_names().is_empty() && empty.constant_names().is_empty());
+}
+
+#[test]
+fn test_evaluator_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Evaluator>();
+    assert_send_sync::<Registry>();
+}
+
+#[test]
+fn test_comparisons() {
+    let t = Value::Bool(true);
+    let f = Value::Bool(false);
+    assert_eq!(eval("2 == 2.0").unwrap(), t);
+    assert_eq!(eval("0.1 + 0.2 == 0.3").unwrap(), t);
+    assert_eq!(eval("10 >= 0x0A").unwrap(), t);
+    assert_eq!(eval("-1 > -2 == true").unwrap(), t);
+    assert_eq!(eval("1 <= 0.5").unwrap(), f);
+    assert_eq!(eval("true != false").unwrap(), t);
+
+    // NaN is unordered and unequal to itself
+    assert_eq!(eval("nan == nan").unwrap(), f);
+    assert_eq!(eval("nan != nan").unwrap(), t);
+    assert_eq!(eval("nan < 1").unwrap(), f);
+    assert_eq!(eval("nan >= 1").unwrap(), f);
+    assert_eq!(eval("inf > 10 ^ 100").unwrap(), t);
+
+    // complex values compare by parts
+    assert_eq!(eval("i * i == -1").unwrap(), t);
+    assert_eq!(eval("3 + 4i == 3 + 4i").unwrap(), t);
+    assert_eq!(eval("3 + 4i != 3 - 4i").unwrap(), t);
+    assert_eq!(eval("i < 1"), Err(errors::INVALID_FORMAT));
+
+    assert_eq!(eval("true < false"), Err(errors::INVALID_FORMAT));
+    assert_eq!(eval("true == 1"), Err(errors::INVALID_FORMAT));
+    assert_eq!(eval("(1 < 2) + 1"), Err(errors::INVALID_FORMAT));
+    assert_eq!(eval("-(1 < 2)"), Err(errors::INVALID_FORMAT));
+    assert_eq!(eval("1 < 2").unwrap().to_string(), "true");
+}
+
+#[test]
+fn test_constant_decimals() {
+    let mut registry = Registry::new();
+    let pi = |r: &Registry| match r.constant("pi") {
+        Some(Value::Float(Float::Irrational(bd))) => bd.fractional_digit_count(),
+        other => panic!("unexpected pi {:?}", other),
+    };
+    assert_eq!(pi(&registry), imagnum::eval::CONSTANT_DECIMALS as i64);
+    registry.set_constant_decimals(200);
+    assert_eq!(pi(&registry), 200);
+    assert_eq!(registry.constant("PI"), registry.constant("pi"));
+}
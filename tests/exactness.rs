@@ -0,0 +1,36 @@
+use imagnum::{create_complex, create_float, create_int, Float};
+
+fn sqrt2() -> Float {
+    create_int("2").sqrt().unwrap()
+}
+
+#[test]
+fn irrational_operands_give_irrational_results() {
+    let r = sqrt2();
+    assert!(!r.is_exact());
+    for res in [r._mul(&r), r._add(&create_float("1")), create_float("1")._sub(&r), r._div(&r), r._modulo(&create_float("1"))] {
+        let res = res.unwrap();
+        assert!(res.is_irrational() && !res.is_exact(), "{:?}", res);
+    }
+    assert!(!(-r.clone()).is_exact());
+    assert!(!r.powi(3).unwrap().is_exact());
+
+    // an exact zero factor and rounding functions give exact results back
+    assert!(r._mul(&create_float("0")).unwrap().is_exact());
+    assert!(r.floor().unwrap().is_exact());
+}
+
+#[test]
+fn exact_arithmetic_stays_exact() {
+    let sum = create_float("0.1")._add(&create_float("0.2")).unwrap();
+    assert!(sum.is_exact());
+    assert_eq!(sum, create_float("0.3"));
+    assert!(create_float("1")._div(&create_float("3")).unwrap().is_exact());
+    assert!(create_float("2.5").powi(4).unwrap().is_exact());
+    assert!(Float::Infinity.is_exact());
+    assert!(!Float::NaN.is_exact());
+
+    let z = create_complex("1", "2");
+    assert!(z.is_exact());
+    assert!(!z._mul(&sqrt2()).unwrap().is_exact());
+}
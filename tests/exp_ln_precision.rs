@@ -0,0 +1,65 @@
+//! Precision contract for `Float::exp` and `Float::ln`.
+//!
+//! Every vector in `data/exp_ln_vectors.txt` must match the reference to a relative
+//! error below `10^-CORRECT_DIGITS`. Raise `CORRECT_DIGITS` when the implementation
+//! gets more precise; lowering it is a regression.
+
+use bigdecimal::BigDecimal;
+use imagnum::compat::float_to_bigdecimal;
+use imagnum::create_float;
+use num_traits::Zero;
+use std::str::FromStr;
+
+const CORRECT_DIGITS: i64 = 38;
+const VECTORS: &str = include_str!("data/exp_ln_vectors.txt");
+
+fn check(function: &str, input: &str, expected: &str) -> Result<(), String> {
+    let x = create_float(input);
+    let got = match function {
+        "exp" => x.exp(),
+        "ln" => x.ln(),
+        other => return Err(format!("unknown function {}", other)),
+    }
+    .map_err(|e| format!("{}({}) failed with {}", function, input, e))?;
+    let got = float_to_bigdecimal(&got).ok_or_else(|| format!("{}({}) is not finite", function, input))?;
+    let want = BigDecimal::from_str(expected).unwrap();
+
+    let err = (&got - &want).abs();
+    let tolerance = BigDecimal::new(1.into(), CORRECT_DIGITS);
+    let ok = if want.is_zero() { err <= tolerance } else { err <= tolerance * want.abs() };
+    if ok {
+        Ok(())
+    } else {
+        Err(format!("{}({}) = {}, expected {}", function, input, got, want))
+    }
+}
+
+#[test]
+fn exp_and_ln_match_reference_vectors() {
+    let failures: Vec<String> = VECTORS
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let parts: Vec<&str> = l.split_whitespace().collect();
+            check(parts[0], parts[1], parts[2]).err()
+        })
+        .collect();
+    assert!(failures.is_empty(), "{} vectors off:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn corpus_covers_both_functions() {
+    let count = |f: &str| VECTORS.lines().filter(|l| l.starts_with(&format!("{} ", f))).count();
+    assert!(count("exp") >= 15);
+    assert!(count("ln") >= 15);
+    // ln's ln_1p path, where 1 + x and 1 are nearly equal
+    let near_one = VECTORS
+        .lines()
+        .filter_map(|l| l.strip_prefix("ln "))
+        .filter(|l| {
+            let x = BigDecimal::from_str(l.split_whitespace().next().unwrap()).unwrap();
+            (x - BigDecimal::from(1)).abs() < BigDecimal::from_str("1e-4").unwrap()
+        })
+        .count();
+    assert!(near_one >= 10);
+}
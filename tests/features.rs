@@ -31,6 +31,83 @@ mod test_serde {
         assert_eq!(original, deserialized);
     }
 
+    #[test]
+    fn test_float_serde_keeps_kind() {
+        use imagnum::{create_float, Float};
+
+        let values = [
+            (create_float("1") / create_float("3")).unwrap(),
+            create_float("2").sqrt().unwrap(),
+            create_float("1.50"),
+        ];
+        for original in values {
+            let serialized = serde_json::to_string(&original).unwrap();
+            let deserialized: Float = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(original.fingerprint(), deserialized.fingerprint(), "{}", serialized);
+        }
+    }
+
+    #[test]
+    fn test_float_serde_tagged_kinds() {
+        use imagnum::{create_complex, create_float, Float};
+
+        let third = (create_float("1") / create_float("3")).unwrap();
+        assert_eq!(serde_json::to_string(&third).unwrap(), r#"{"kind":"recurring","value":"0.(3)"}"#);
+        assert_eq!(serde_json::to_string(&create_float("1.50")).unwrap(), r#""1.50""#);
+        assert_eq!(serde_json::to_string(&Float::NaN).unwrap(), r#"{"kind":"nan"}"#);
+
+        let z = create_complex("3", "-4");
+        let json = serde_json::to_string(&z).unwrap();
+        assert_eq!(json, r#"{"kind":"complex","re":"3","im":"-4"}"#);
+        assert_eq!(serde_json::from_str::<Float>(&json).unwrap(), z);
+
+        let z = Float::Complex(Box::new(create_float("2").sqrt().unwrap()), Box::new(third));
+        let back: Float = serde_json::from_str(&serde_json::to_string(&z).unwrap()).unwrap();
+        assert_eq!(back.fingerprint(), z.fingerprint());
+
+        assert!(serde_json::from_str::<Float>(r#"{"kind":"nan"}"#).unwrap().is_nan());
+        assert_eq!(serde_json::from_str::<Float>(r#"{"kind":"neg_infinity"}"#).unwrap(), Float::NegInfinity);
+        assert!(serde_json::from_str::<Float>(r#"{"kind":"complex","re":"1"}"#).is_err());
+        assert!(serde_json::from_str::<Float>(r#"{"kind":"recurring","value":"0.5"}"#).is_err());
+        assert!(serde_json::from_str::<Float>(r#"{"kind":"quaternion"}"#).is_err());
+
+        let long = Float::from_fraction(&imagnum::create_int("1"), &imagnum::create_int("10007")).unwrap();
+        let back: Float = serde_json::from_str(&serde_json::to_string(&long).unwrap()).unwrap();
+        assert!(back.is_irrational());
+    }
+
+    #[test]
+    fn test_float_serde_binary_round_trip() {
+        use imagnum::{create_complex, create_float, Float};
+
+        let values = [
+            create_float("1.50"),
+            Float::from(0.1f64),
+            (create_float("1") / create_float("7")).unwrap(),
+            create_float("2").sqrt().unwrap(),
+            create_float("8").sqrt().unwrap(),
+            create_complex("3", "-4"),
+            Float::NaN,
+            Float::Infinity,
+            Float::NegInfinity,
+        ];
+        for original in values {
+            let mut buf = Vec::new();
+            ciborium::into_writer(&original, &mut buf).unwrap();
+            let back: Float = ciborium::from_reader(buf.as_slice()).unwrap();
+            assert_eq!(back.fingerprint(), original.fingerprint(), "{:?}", original);
+        }
+    }
+
+    #[test]
+    fn test_float_serde_accepts_strings() {
+        use imagnum::{create_complex, create_float, Float};
+
+        assert_eq!(serde_json::from_str::<Float>(r#""0.(3)""#).unwrap(), create_float("0.(3)"));
+        assert_eq!(serde_json::from_str::<Float>(r#""3+4i""#).unwrap(), create_complex("3", "4"));
+        assert_eq!(serde_json::from_str::<Float>(r#"{"kind":"finite","value":"2.5"}"#).unwrap(), create_float("2.5"));
+    }
+
     #[test]
     fn test_struct_serde() {
         use imagnum::{Int, Float};
@@ -53,6 +130,10 @@ mod test_random {
     use imagnum::*;
     use imagnum::random::*;
     use num_traits::cast::ToPrimitive;
+    use num_bigint::BigInt;
+    use rand::distr::{Distribution, Uniform};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     #[test]
     fn test_randint() {
@@ -94,6 +175,29 @@ mod test_random {
         }
     }
 
+    #[test]
+    fn test_randdecimal_uses_every_digit() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let (min, max) = (create_float("0"), create_float("1"));
+        for _ in 0..20 {
+            let Float::Big(bd) = randdecimal_with_rng(&mut rng, &min, &max, 60) else {
+                panic!("expected a Big float");
+            };
+            let (digits, scale) = bd.as_bigint_and_exponent();
+            assert_eq!(scale, 60);
+            // an f64 fraction has nothing past its 17th significant digit
+            assert!(digits % BigInt::from(10u32).pow(40) != BigInt::from(0u32));
+        }
+
+        // both bounds can come up
+        let hundredth = create_float("0.01");
+        let draws: Vec<Float> = (0..200).map(|_| randdecimal_with_rng(&mut rng, &min, &hundredth, 2)).collect();
+        assert!(draws.iter().all(|d| *d == min || *d == hundredth));
+        assert!(draws.contains(&min) && draws.contains(&hundredth));
+        // no decimal of that scale lies between the bounds
+        assert_eq!(randdecimal_with_rng(&mut rng, &create_float("0.11"), &create_float("0.19"), 1), create_float("0.1"));
+    }
+
     #[test]
     fn test_randcomplex() {
         let min = Float::from_str("0.0").unwrap();
@@ -121,13 +225,8 @@ mod test_random {
 
         for _ in 0..100 {
             let r = randreal(&min, &max);
-            match r {
-                Float::Big(bd) | Float::Recurring(bd) | Float::Irrational(bd) => {
-                    let val = bd.to_f64().unwrap_or(f64::NAN);
-                    assert!(val >= 0.0 && val <= 100.0);
-                }
-                _ => {}
-            }
+            let val = r.to_f64().unwrap_or(f64::NAN);
+            assert!(val >= 0.0 && val <= 100.0);
         }
     }
 
@@ -139,4 +238,102 @@ mod test_random {
             assert!(val >= 0.0 && val <= 1.0, "rand() produced {}", val);
         }
     }
+
+    #[test]
+    fn test_seeded_generators_repeat() {
+        let mut a = StdRng::seed_from_u64(42);
+        let mut b = StdRng::seed_from_u64(42);
+        let (min, max) = (create_int("-1000000000000000000000"), create_int("1000000000000000000000"));
+        assert_eq!(randint_with_rng(&mut a, &min, &max), randint_with_rng(&mut b, &min, &max));
+        assert_eq!(rand_with_rng(&mut a), rand_with_rng(&mut b));
+        let (lo, hi) = (create_float("0"), create_float("5"));
+        assert_eq!(randdecimal_with_rng(&mut a, &lo, &hi, 30), randdecimal_with_rng(&mut b, &lo, &hi, 30));
+        assert_eq!(randfloat_with_rng(&mut a, &lo, &hi), randfloat_with_rng(&mut b, &lo, &hi));
+        assert_eq!(randcomplex_with_rng(&mut a, &lo, &hi), randcomplex_with_rng(&mut b, &lo, &hi));
+        let (x, y) = (randreal_with_rng(&mut a, &lo, &hi), randreal_with_rng(&mut b, &lo, &hi));
+        assert_eq!((x.to_string(), x.is_irrational()), (y.to_string(), y.is_irrational()));
+        assert_eq!(rand_prime_with_rng(&mut a, 64), rand_prime_with_rng(&mut b, 64));
+
+        // a different seed gives a different stream
+        let mut c = StdRng::seed_from_u64(43);
+        assert_ne!(randint_with_rng(&mut a, &min, &max), randint_with_rng(&mut c, &min, &max));
+    }
+
+    #[test]
+    fn test_rand_bits() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(rand_bits_with_rng(&mut rng, 0), create_int("0"));
+        let bound = create_int("2").pow(&create_int("130")).unwrap();
+        let mut widest = 0;
+        for _ in 0..200 {
+            let r = rand_bits_with_rng(&mut rng, 130);
+            assert!(r >= create_int("0") && r < bound);
+            widest = widest.max(r.to_bigint().unwrap().bits());
+        }
+        assert_eq!(widest, 130);
+        for _ in 0..50 {
+            assert!(rand_bits(5) < create_int("32"));
+        }
+    }
+
+    fn is_prime(n: u64) -> bool {
+        n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+    }
+
+    #[test]
+    fn test_rand_prime() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for bits in 2..=40u64 {
+            let p = rand_prime_with_rng(&mut rng, bits).unwrap();
+            let p = p.to_bigint().unwrap().to_u64().unwrap();
+            assert!(is_prime(p), "{} is not prime", p);
+            assert_eq!(64 - u64::from(p.leading_zeros()), bits);
+        }
+        let p = rand_prime(256).unwrap();
+        assert_eq!(p.to_bigint().unwrap().bits(), 256);
+        assert_eq!(rand_prime(1), Err(errors::INVALID_FORMAT));
+    }
+
+    #[test]
+    fn test_uniform_int() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let die = UniformInt::new_inclusive(&create_int("1"), &create_int("6")).unwrap();
+        let mut seen = [false; 6];
+        for roll in (&die).sample_iter(&mut rng).take(200) {
+            seen[roll.to_bigint().unwrap().to_usize().unwrap() - 1] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let huge = create_int(&"9".repeat(40));
+        for _ in 0..50 {
+            let r = rng.random_range(create_int("0")..huge.clone());
+            assert!(r >= create_int("0") && r < huge);
+        }
+        let uniform = Uniform::new(create_int("-3"), create_int("3")).unwrap();
+        assert!((0..50).map(|_| rng.sample(&uniform)).all(|r| r >= create_int("-3") && r < create_int("3")));
+
+        assert_eq!(UniformInt::new(&create_int("2"), &create_int("2")), Err(errors::INVALID_FORMAT));
+        assert!(UniformInt::new_inclusive(&create_int("2"), &create_int("2")).is_ok());
+        assert!(Uniform::new(create_int("5"), create_int("1")).is_err());
+    }
+
+    #[test]
+    fn test_uniform_float() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let (lo, hi) = (create_float("-0.5"), create_float("0.25"));
+        let tenths = UniformFloat::new_inclusive(&lo, &hi, 2).unwrap();
+        for _ in 0..100 {
+            let r = tenths.sample(&mut rng);
+            assert!(r >= lo && r <= hi);
+            assert_eq!(r._mul(&create_float("100")).unwrap().to_int().unwrap().to_float().unwrap(), r._mul(&create_float("100")).unwrap());
+        }
+        for _ in 0..100 {
+            let r = rng.random_range(lo.clone()..hi.clone());
+            assert!(r >= lo && r < hi);
+        }
+        assert!(Uniform::new(Float::NaN, create_float("1")).is_err());
+        assert_eq!(UniformFloat::new(&hi, &lo, 2), Err(errors::INVALID_FORMAT));
+        // nothing on the grid between 0.11 and 0.19 with one decimal
+        assert!(UniformFloat::new(&create_float("0.11"), &create_float("0.19"), 1).is_err());
+    }
 }
@@ -0,0 +1,36 @@
+use imagnum::math::fnv1a_128;
+use imagnum::{Float, Int, create_float, create_int};
+
+#[test]
+fn fnv_reference_vectors() {
+    assert_eq!(fnv1a_128(b""), 0x6c62272e07bb014262b821756295c58d);
+    assert_eq!(fnv1a_128(b"a"), 0xd228cb696f1a8caf78912b704e4a8964);
+}
+
+#[test]
+fn int_fingerprint() {
+    let big = create_int("42");
+    assert_eq!(big.fingerprint(), Int::new_small(42i8).fingerprint());
+    assert_eq!(big.fingerprint(), Int::new_small(42u64).fingerprint());
+    assert_ne!(big.fingerprint(), create_int("-42").fingerprint());
+    assert_ne!(big.fingerprint(), create_float("42").fingerprint());
+}
+
+#[test]
+fn float_fingerprint() {
+    assert_eq!(create_float("1.5").fingerprint(), create_float("1.5").fingerprint());
+    assert_ne!(create_float("1.5").fingerprint(), create_float("1.50").fingerprint());
+    assert_ne!(create_float("0.1").fingerprint(), Float::new_small(0.1f64).fingerprint());
+    assert_ne!(Float::Infinity.fingerprint(), Float::NegInfinity.fingerprint());
+    assert_eq!(Float::NaN.fingerprint(), Float::NaN.fingerprint());
+    let z = Float::complex(create_float("1"), create_float("2"));
+    let w = Float::complex(create_float("2"), create_float("1"));
+    assert_ne!(z.fingerprint(), w.fingerprint());
+}
+
+#[test]
+fn fingerprints_are_pinned() {
+    // These values are part of the stability guarantee; never update them.
+    assert_eq!(create_int("12345678901234567890").fingerprint(), 0x3732a129a1419942e9878b40238abff4);
+    assert_eq!(create_float("-3.25").fingerprint(), 0xcb3cade906b276f494f74d7a11ff2cc8);
+}
@@ -0,0 +1,81 @@
+use imagnum::{BitWidth, create_int};
+
+fn int(s: &str) -> imagnum::Int {
+    create_int(s)
+}
+
+#[test]
+fn checked() {
+    let max = int(&i64::MAX.to_string());
+    assert_eq!(max.checked_add_width(&int("1"), BitWidth::I64), None);
+    assert_eq!(max.checked_add_width(&int("1"), BitWidth::I128), Some(int("9223372036854775808")));
+    assert_eq!(int("0").checked_sub_width(&int("1"), BitWidth::U8), None);
+    assert_eq!(int("16").checked_mul_width(&int("8"), BitWidth::I8), None);
+    assert_eq!(int("-16").checked_mul_width(&int("8"), BitWidth::I8), Some(int("-128")));
+    assert_eq!(int("-128").checked_div_width(&int("-1"), BitWidth::I8), None);
+    assert_eq!(int("7").checked_div_width(&int("0"), BitWidth::I8), None);
+    assert_eq!(int("-7").checked_div_width(&int("2"), BitWidth::I8), Some(int("-3")));
+}
+
+#[test]
+fn wrapping() {
+    let max = int(&i64::MAX.to_string());
+    assert_eq!(max.wrapping_add_width(&int("1"), BitWidth::I64), int(&i64::MIN.to_string()));
+    assert_eq!(int("0").wrapping_sub_width(&int("1"), BitWidth::U8), int("255"));
+    assert_eq!(int("200").wrapping_mul_width(&int("2"), BitWidth::I8), int("-112"));
+    assert_eq!(int("-128").wrapping_div_width(&int("-1"), BitWidth::I8).unwrap(), int("-128"));
+    assert!(int("1").wrapping_div_width(&int("0"), BitWidth::I8).is_err());
+    let x = int(&u128::MAX.to_string());
+    assert_eq!(x.wrap_to_width(BitWidth::I128), int("-1"));
+    assert_eq!(int("-1").wrap_to_width(BitWidth::U32), int(&u32::MAX.to_string()));
+}
+
+#[test]
+fn saturating() {
+    assert_eq!(int("100").saturating_add_width(&int("100"), BitWidth::I8), int("127"));
+    assert_eq!(int("-100").saturating_sub_width(&int("100"), BitWidth::I8), int("-128"));
+    assert_eq!(int("3").saturating_sub_width(&int("5"), BitWidth::U16), int("0"));
+    assert_eq!(int("-128").saturating_div_width(&int("-1"), BitWidth::I8).unwrap(), int("127"));
+    let big = int("100000000000000000000");
+    assert_eq!(big.saturating_mul_width(&big, BitWidth::U64), int(&u64::MAX.to_string()));
+}
+
+#[test]
+fn fits() {
+    assert!(int("255").fits_width(BitWidth::U8));
+    assert!(!int("256").fits_width(BitWidth::U8));
+    assert!(!int("-1").fits_width(BitWidth::U128));
+    assert!(int("-32768").fits_width(BitWidth::I16));
+    assert_eq!(BitWidth::I128.bits(), 128);
+    assert!(!BitWidth::U32.is_signed());
+}
+
+#[test]
+fn bitwise() {
+    assert_eq!(int("0").not_width(BitWidth::U32), int(&u32::MAX.to_string()));
+    assert_eq!(int("0").not_width(BitWidth::I32), int("-1"));
+    assert_eq!(int("-1").and_width(&int("300"), BitWidth::U8), int("44"));
+    assert_eq!(int("1").shl_width(200, BitWidth::U64), int("0"));
+    assert_eq!(int(&u128::MAX.to_string()).rotate_left_width(7, BitWidth::U128), int(&u128::MAX.to_string()));
+
+    // every 8-bit value against the primitive types
+    for a in i8::MIN..=i8::MAX {
+        let (x, ux) = (int(&a.to_string()), int(&(a as u8).to_string()));
+        assert_eq!(x.not_width(BitWidth::I8), int(&(!a).to_string()));
+        assert_eq!(ux.not_width(BitWidth::U8), int(&(!(a as u8)).to_string()));
+        for b in [-128i8, -37, -1, 0, 1, 85, 127] {
+            let y = int(&b.to_string());
+            assert_eq!(x.and_width(&y, BitWidth::I8), int(&(a & b).to_string()));
+            assert_eq!(x.or_width(&y, BitWidth::U8), int(&((a | b) as u8).to_string()));
+            assert_eq!(x.xor_width(&y, BitWidth::I8), int(&(a ^ b).to_string()));
+        }
+        for n in 0..8 {
+            assert_eq!(x.shl_width(n, BitWidth::I8), int(&(a << n).to_string()));
+            assert_eq!(x.shr_width(n, BitWidth::I8), int(&(a >> n).to_string()));
+            assert_eq!(ux.shr_width(n, BitWidth::U8), int(&((a as u8) >> n).to_string()));
+            assert_eq!(x.rotate_left_width(n, BitWidth::I8), int(&a.rotate_left(n).to_string()));
+            assert_eq!(ux.rotate_right_width(n + 8, BitWidth::U8), int(&(a as u8).rotate_right(n).to_string()));
+        }
+        assert_eq!(x.shr_width(100, BitWidth::I8), int(if a < 0 { "-1" } else { "0" }));
+    }
+}
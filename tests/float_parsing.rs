@@ -0,0 +1,91 @@
+use imagnum::errors::{self, Expected};
+use imagnum::{create_float, try_create_float, Float};
+
+fn text(s: &str) -> String {
+    try_create_float(s).unwrap_or_else(|e| panic!("{:?} failed with {}", s, e)).to_string()
+}
+
+#[test]
+fn plain_decimals() {
+    assert_eq!(text("0"), "0.0");
+    assert_eq!(text("-0.25"), "-0.25");
+    assert_eq!(text("+7"), "7.0");
+    assert_eq!(text("007.50"), "7.5");
+    assert_eq!(text("  3.5\n"), "3.5");
+    // either side of the point may be empty, not both
+    assert_eq!(text(".5"), "0.5");
+    assert_eq!(text("-.5"), "-0.5");
+    assert_eq!(text("5."), "5.0");
+    assert_eq!(text("+5."), "5.0");
+    for bad in [".", "-.", "+", "", "  "] {
+        assert_eq!(try_create_float(bad), Err(errors::INVALID_FORMAT), "{:?}", bad);
+    }
+}
+
+#[test]
+fn exponents() {
+    assert_eq!(text("1e5"), "100000.0");
+    assert_eq!(text("1E+05"), "100000.0");
+    assert_eq!(text("2.5e-3"), "0.0025");
+    assert_eq!(text(".5e2"), "50.0");
+    assert_eq!(text("5.e1"), "50.0");
+    assert_eq!(text("1e400"), "1e400");
+    assert_eq!(text("-1e-400"), "-1e-400");
+    assert_eq!(create_float("1e400"), create_float(&format!("1{}", "0".repeat(400))));
+    for bad in ["1e", "1e+", "e5", "1e5.5", "1e 5", "1ee5", "1e5e5"] {
+        assert_eq!(try_create_float(bad), Err(errors::INVALID_FORMAT), "{:?}", bad);
+    }
+}
+
+#[test]
+fn huge_exponents() {
+    assert_eq!(text("1e1000000000000"), "1e1000000000000");
+    assert_eq!(text("1e-9223372036854775807"), "1e-9223372036854775807");
+    assert_eq!(text("1e9223372036854775806"), "1e9223372036854775806");
+    for big in ["1e9223372036854775807", "0.5e-9223372036854775807", "1e99999999999999999999", "-2e-99999999999999999999"] {
+        assert_eq!(try_create_float(big), Err(errors::NUMBER_TOO_LARGE), "{:?}", big);
+        assert!(create_float(big).is_nan());
+    }
+    let err = "1.5e-99999999999999999999".parse::<Float>().unwrap_err();
+    assert_eq!((err.code, err.offset, err.expected), (errors::NUMBER_TOO_LARGE, 4, Expected::Number));
+}
+
+#[test]
+fn underscores() {
+    assert_eq!(text("1_000.5e-3"), "1.0005");
+    assert_eq!(text("1_000_000"), "1000000.0");
+    assert_eq!(text("0.000_001"), "0.000001");
+    assert_eq!(text("1e1_0"), "10000000000.0");
+    assert_eq!(text("1__0"), "10.0");
+    assert_eq!(text("0.1_2(3_4)"), "0.12(34)");
+    assert_eq!(text("1_0+2_0i"), "10.0 + 20.0i");
+    assert_eq!(text("2_5i"), "25.0i");
+    // only between digits
+    for bad in ["_1", "1_", "1_.5", "1._5", "1.5_", "1_e3", "1e_3", "1e3_", "0.(_3)", "0.1(3_)"] {
+        assert_eq!(try_create_float(bad), Err(errors::INVALID_FORMAT), "{:?}", bad);
+    }
+}
+
+#[test]
+fn other_forms() {
+    assert_eq!(text("0.(3)"), "0.(3)");
+    assert_eq!(text("-1.2(34)"), "-1.2(34)");
+    assert_eq!(text("0x1.8p3"), "12.0");
+    assert_eq!(text("3√2"), "3√2");
+    assert_eq!(text("1.5e3i"), "1500.0i");
+    assert_eq!(try_create_float("nan").map(|f| f.is_nan()), Ok(true));
+    assert_eq!(try_create_float("-inf"), Ok(Float::NegInfinity));
+    // an unreadable imaginary coefficient is an error, not 0i
+    assert_eq!(try_create_float("xi"), Err(errors::INVALID_FORMAT));
+    assert_eq!(try_create_float("1.2.3i"), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn from_str_agrees() {
+    for s in ["1_000.5e-3", ".5", "5.", "1e+5", "1e400"] {
+        assert_eq!(Float::from_str(s), try_create_float(s), "{}", s);
+    }
+    assert_eq!(Float::from_str("1e99999999999999999999"), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(Float::from_str("inf"), Err(errors::INVALID_FORMAT));
+    assert_eq!(Float::from_str(" "), Err(errors::INVALID_FORMAT));
+}
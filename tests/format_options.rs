@@ -0,0 +1,86 @@
+use imagnum::create_float;
+use imagnum::foundation::Float;
+use imagnum::format::{ComplexDisplayMode, FormatOptions, Notation};
+
+fn fmt(v: &str, opts: FormatOptions) -> String {
+    create_float(v).format(&opts)
+}
+
+#[test]
+fn fixed() {
+    let o = || FormatOptions::new().notation(Notation::Fixed);
+    assert_eq!(fmt("12345.678", o()), "12345.678");
+    assert_eq!(fmt("-0.00012", o()), "-0.00012");
+    assert_eq!(fmt("1e60", o()), format!("1{}", "0".repeat(60)));
+    assert_eq!(fmt("1234567.5", o().thousands_separator(',')), "1,234,567.5");
+    assert_eq!(fmt("123", o().thousands_separator('_')), "123");
+    assert_eq!(fmt("1.5", o().significant_digits(4)), "1.500");
+    assert_eq!(fmt("2.71828", o().significant_digits(3)), "2.72");
+}
+
+#[test]
+fn scientific_and_engineering() {
+    let sci = || FormatOptions::new().notation(Notation::Scientific);
+    assert_eq!(fmt("12345.678", sci()), "1.2345678e4");
+    assert_eq!(fmt("-0.00012", sci()), "-1.2e-4");
+    assert_eq!(fmt("1", sci()), "1e0");
+    assert_eq!(fmt("0", sci()), "0e0");
+    assert_eq!(fmt("12345", sci().significant_digits(3).uppercase_exponent(true)), "1.23E4");
+
+    let eng = || FormatOptions::new().notation(Notation::Engineering);
+    assert_eq!(fmt("12345.678", eng()), "12.345678e3");
+    assert_eq!(fmt("0.00012", eng()), "120e-6");
+    assert_eq!(fmt("1234567", eng()), "1.234567e6");
+    assert_eq!(fmt("999", eng()), "999e0");
+}
+
+#[test]
+fn auto_threshold() {
+    assert_eq!(fmt("123.5", FormatOptions::new()), "123.5");
+    assert_eq!(fmt("1e51", FormatOptions::new()), "1e51");
+    assert_eq!(fmt("12345", FormatOptions::new().exponent_threshold(3)), "1.2345e4");
+    assert_eq!(fmt("1234", FormatOptions::new().exponent_threshold(3)), "1234");
+}
+
+#[test]
+fn special_values_and_small_floats() {
+    assert_eq!(Float::NaN.format(&FormatOptions::new()), "NaN");
+    assert_eq!(Float::from(0.1f64).format(&FormatOptions::new()), "0.1");
+    let z = Float::complex(create_float("1.5"), create_float("-2"));
+    assert_eq!(z.format(&FormatOptions::new()), "1.5 - 2i");
+}
+
+#[test]
+fn exp_traits() {
+    let x = create_float("1234.5");
+    assert_eq!(format!("{:e}", x), "1.2345e3");
+    assert_eq!(format!("{:E}", x), "1.2345E3");
+    assert_eq!(format!("{:.2e}", x), "1.23e3");
+    assert_eq!(format!("{:.3e}", create_float("1.5")), format!("{:.3e}", 1.5f64));
+    assert_eq!(format!("{:>10e}", create_float("100")), "       1e2");
+}
+
+#[test]
+fn polar_and_exponential_modes() {
+    let z = Float::complex(create_float("3"), create_float("4"));
+    let polar = FormatOptions::new().complex_mode(ComplexDisplayMode::Polar).significant_digits(4);
+    assert_eq!(z.format(&polar), "5.000∠0.9273");
+    let exp = polar.clone().complex_mode(ComplexDisplayMode::Exponential);
+    assert_eq!(z.format(&exp), "5.000·e^{i0.9273}");
+    assert_eq!(z.conj().format(&exp), "5.000·e^{-i0.9273}");
+    // reals are points on the axis
+    assert_eq!(create_float("-2").format(&polar), "2.000∠3.142");
+    assert_eq!(create_float("7").format(&polar), "7.000∠0.000");
+    assert_eq!(Float::NaN.format(&polar), "NaN");
+    assert_eq!(Float::Infinity.format(&polar), Float::Infinity.to_string());
+    assert_eq!(z.format(&FormatOptions::new()), "3 + 4i");
+}
+
+#[test]
+fn to_polar_string() {
+    let z = Float::complex(create_float("1"), create_float("-1"));
+    assert_eq!(z.to_polar_string(4), "1.4142∠-0.7854");
+    assert_eq!(z.to_polar_string(0), "1∠-1");
+    assert_eq!(create_float("0").to_polar_string(2), "0.00∠0.00");
+    assert_eq!(Float::NaN.to_polar_string(2), "NaN");
+}
@@ -0,0 +1,39 @@
+use imagnum::{create_float, create_int, errors, Float};
+
+fn fraction(f: &Float, max: Option<&str>) -> (String, String) {
+    let max = max.map(create_int);
+    let (n, d) = f.to_fraction(max.as_ref()).unwrap();
+    (n.to_string(), d.to_string())
+}
+
+fn pair(n: &str, d: &str) -> (String, String) {
+    (n.to_string(), d.to_string())
+}
+
+#[test]
+fn test_to_and_from_fraction() {
+    assert_eq!(fraction(&create_float("0.75"), None), pair("3", "4"));
+    assert_eq!(fraction(&create_float("-1.1(6)"), None), pair("-7", "6"));
+    assert_eq!(fraction(&create_float("250"), None), pair("250", "1"));
+    assert_eq!(fraction(&create_float("0.1(6)"), Some("5")), pair("1", "5"));
+
+    // continued-fraction approximations, checked against Python's Fraction.limit_denominator
+    let pi = Float::Irrational(imagnum::consts::pi(50));
+    assert_eq!(fraction(&pi, Some("1")), pair("3", "1"));
+    assert_eq!(fraction(&pi, Some("10")), pair("22", "7"));
+    assert_eq!(fraction(&pi, Some("100")), pair("311", "99"));
+    assert_eq!(fraction(&pi, Some("1000")), pair("355", "113"));
+    assert_eq!(fraction(&pi, Some("1000000")), pair("3126535", "995207"));
+    let e = Float::Irrational(-imagnum::consts::e(50));
+    assert_eq!(fraction(&e, Some("1000")), pair("-1457", "536"));
+    let root = Float::surd(&create_float("1"), &create_int("2")).unwrap();
+    assert_eq!(fraction(&root, Some("100")), pair("140", "99"));
+
+    assert_eq!(Float::NaN.to_fraction(None), Err(errors::INVALID_FORMAT));
+    assert_eq!(pi.to_fraction(Some(&create_int("0"))), Err(errors::INVALID_FORMAT));
+
+    let third = Float::from_fraction(&create_int("-4"), &create_int("-12")).unwrap();
+    assert_eq!(third, create_float("0.(3)"));
+    assert_eq!(Float::from_fraction(&create_int("3"), &create_int("-4")).unwrap(), create_float("-0.75"));
+    assert_eq!(Float::from_fraction(&create_int("1"), &create_int("0")), Err(errors::DIV_BY_ZERO));
+}
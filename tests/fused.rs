@@ -0,0 +1,42 @@
+use imagnum::{create_complex, create_float, errors, Float};
+
+fn f(s: &str) -> Float {
+    create_float(s)
+}
+
+#[test]
+fn mul_add_is_exact() {
+    assert_eq!(f("1.5").mul_add(&f("2"), &f("0.25")), Ok(f("3.25")));
+    // x² - 1 keeps the 1e-18 term a product rounded to 17 digits would lose
+    let x = f("1.000000001");
+    assert_eq!(x.mul_add(&x, &f("-1")), Ok(f("0.000000002000000001")));
+    assert!(Float::NaN.mul_add(&f("1"), &f("1")).is_err());
+    assert_eq!(
+        create_complex("0", "1").mul_add(&create_complex("0", "1"), &f("1")),
+        Ok(create_complex("0", "0"))
+    );
+}
+
+#[test]
+fn sum_is_order_independent() {
+    let terms = [f("1e30"), f("0.1"), f("-1e30"), f("0.2")];
+    let reordered = [f("0.1"), f("0.2"), f("1e30"), f("-1e30")];
+    assert_eq!(Float::sum(&terms), Ok(f("0.3")));
+    assert_eq!(Float::sum(&reordered), Ok(f("0.3")));
+    // small floats count with their decimal digits
+    let small = [Float::from(0.1f64), Float::from(0.2f64)];
+    assert_eq!(Float::sum(&small), Ok(f("0.3")));
+    assert!(Float::sum(&[]).unwrap().is_zero());
+    assert_eq!(Float::sum(&[f("1"), Float::Infinity]), Ok(Float::Infinity));
+}
+
+#[test]
+fn dot_rounds_once() {
+    assert_eq!(Float::dot(&[f("1"), f("2"), f("3")], &[f("4"), f("-5"), f("6")]), Ok(f("12")));
+    assert_eq!(Float::dot(&[f("1")], &[]), Err(errors::INVALID_FORMAT));
+
+    let root2 = f("2").sqrt().unwrap();
+    let r = Float::dot(&[root2.clone(), f("1")], &[root2, f("-2")]).unwrap();
+    assert!(r.is_irrational());
+    assert!(r.to_f64().unwrap().abs() < 1e-14);
+}
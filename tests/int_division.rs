@@ -0,0 +1,44 @@
+use imagnum::{DivMode, create_int};
+
+fn int(s: &str) -> imagnum::Int {
+    create_int(s)
+}
+
+#[test]
+fn div_operator_truncates() {
+    assert_eq!((int("7") / int("2")).unwrap(), int("3"));
+    assert_eq!((int("-7") / int("2")).unwrap(), int("-3"));
+    assert_eq!((int("7") / int("-2")).unwrap(), int("-3"));
+    assert_eq!((int("5") / int("3")).unwrap(), int("1"));
+    assert!((int("1") / int("0")).is_err());
+}
+
+#[test]
+fn floor_and_ceil() {
+    assert_eq!(int("7").div_floor(&int("2")).unwrap(), int("3"));
+    assert_eq!(int("-7").div_floor(&int("2")).unwrap(), int("-4"));
+    assert_eq!(int("7").div_floor(&int("-2")).unwrap(), int("-4"));
+    assert_eq!(int("-7").div_floor(&int("-2")).unwrap(), int("3"));
+
+    assert_eq!(int("7").div_ceil(&int("2")).unwrap(), int("4"));
+    assert_eq!(int("-7").div_ceil(&int("2")).unwrap(), int("-3"));
+    assert_eq!(int("7").div_ceil(&int("-2")).unwrap(), int("-3"));
+    assert_eq!(int("-7").div_ceil(&int("-2")).unwrap(), int("4"));
+    assert_eq!(int("6").div_ceil(&int("3")).unwrap(), int("2"));
+}
+
+#[test]
+fn euclid() {
+    for (a, b, q, r) in [("7", "2", "3", "1"), ("-7", "2", "-4", "1"), ("7", "-2", "-3", "1"), ("-7", "-2", "4", "1")] {
+        assert_eq!(int(a).div_euclid(&int(b)).unwrap(), int(q));
+        assert_eq!(int(a).rem_euclid(&int(b)).unwrap(), int(r));
+    }
+    assert!(int("1").rem_euclid(&int("0")).is_err());
+}
+
+#[test]
+fn explicit_mode() {
+    assert_eq!(int("5").div_mode(&int("3"), DivMode::Round).unwrap(), int("2"));
+    assert_eq!(int("-5").div_mode(&int("2"), DivMode::Round).unwrap(), int("-3"));
+    assert_eq!(int("-5").div_mode(&int("2"), DivMode::default()).unwrap(), int("-2"));
+}
@@ -0,0 +1,79 @@
+use imagnum::{create_float, create_int, errors, Float, IntOrSpecial};
+
+fn v(s: &str) -> IntOrSpecial {
+    IntOrSpecial::parse(s).unwrap()
+}
+
+#[test]
+fn parse_and_convert() {
+    assert_eq!(v("42"), IntOrSpecial::Int(create_int("42")));
+    assert!(v("NaN").is_nan());
+    assert!(matches!(v("-Infinity"), IntOrSpecial::NegInfinity));
+    assert_eq!(IntOrSpecial::parse("1.5").unwrap_err(), errors::INVALID_FORMAT);
+
+    assert!(IntOrSpecial::from_float(&Float::NaN).unwrap().is_nan());
+    assert_eq!(IntOrSpecial::from_float(&create_float("-7")).unwrap(), v("-7"));
+    assert_eq!(IntOrSpecial::from_float(&create_float("0.5")).unwrap_err(), errors::INVALID_FORMAT);
+    assert!(matches!(v("inf").to_float().unwrap(), Float::Infinity));
+    assert_eq!(v("3").into_int().unwrap(), create_int("3"));
+    assert_eq!(v("nan").into_int().unwrap_err(), errors::INVALID_FORMAT);
+    assert_eq!(v("-inf").into_int().unwrap_err(), errors::INFINITE_RESULT);
+    assert_eq!(IntOrSpecial::from(create_int("5")).as_int(), Some(&create_int("5")));
+    assert_eq!([v("-5"), v("nan"), v("-inf")].map(|x| x.to_string()), ["-5", "NaN", "-Infinity"]);
+}
+
+#[test]
+fn arithmetic_propagates_specials() {
+    let cases = [
+        ("inf", "add", "5", "inf"),
+        ("inf", "add", "-inf", "nan"),
+        ("inf", "sub", "inf", "nan"),
+        ("-inf", "sub", "inf", "-inf"),
+        ("nan", "add", "1", "nan"),
+        ("inf", "mul", "-2", "-inf"),
+        ("inf", "mul", "0", "nan"),
+        ("-inf", "mul", "-inf", "inf"),
+        ("7", "div", "inf", "0"),
+        ("-inf", "div", "2", "-inf"),
+        ("inf", "div", "inf", "nan"),
+        ("7", "mod", "inf", "7"),
+        ("inf", "mod", "3", "nan"),
+        ("-inf", "pow", "3", "-inf"),
+        ("-inf", "pow", "2", "inf"),
+        ("inf", "pow", "0", "1"),
+        ("2", "pow", "inf", "inf"),
+        ("1", "pow", "inf", "1"),
+        ("0", "pow", "inf", "0"),
+        ("-2", "pow", "inf", "nan"),
+        ("7", "div", "2", "3"),
+        ("2", "pow", "10", "1024"),
+    ];
+    for (a, op, b, expected) in cases {
+        let (a, b) = (v(a), v(b));
+        let result = match op {
+            "add" => a._add(&b),
+            "sub" => a._sub(&b),
+            "mul" => a._mul(&b),
+            "div" => a._div(&b),
+            "mod" => a._modulo(&b),
+            _ => a.pow(&b),
+        }
+        .unwrap();
+        let expected = v(expected);
+        assert!(result == expected || (result.is_nan() && expected.is_nan()), "{} {} {} = {}", a, op, b, result);
+    }
+    assert_eq!(v("inf")._div(&v("0")).unwrap_err(), errors::DIV_BY_ZERO);
+    assert_eq!(v("inf")._modulo(&v("0")).unwrap_err(), errors::DIV_BY_ZERO);
+    assert_eq!(v("inf").pow(&v("-1")).unwrap_err(), errors::INVALID_FORMAT);
+    assert_eq!(v("2").pow(&v("-inf")).unwrap_err(), errors::INVALID_FORMAT);
+}
+
+#[test]
+fn ordering() {
+    assert!(v("-inf") < v("-1000000000000000000000"));
+    assert!(v("1000000000000000000000") < v("inf"));
+    assert!(v("2") > v("1"));
+    assert_ne!(v("nan"), v("nan"));
+    assert_eq!(v("nan").partial_cmp(&v("1")), None);
+    assert_eq!(v("inf"), v("inf"));
+}
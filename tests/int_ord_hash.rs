@@ -0,0 +1,57 @@
+use imagnum::foundation::{Int, SmallInt};
+use imagnum::create_int;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn hash_of(i: &Int) -> u64 {
+    let mut h = DefaultHasher::new();
+    i.hash(&mut h);
+    h.finish()
+}
+
+#[test]
+fn small_and_big_hash_alike() {
+    let forms = [
+        Int::Small(SmallInt::I32(5)),
+        Int::Small(SmallInt::U8(5)),
+        Int::Small(SmallInt::I128(5)),
+        create_int("5"),
+    ];
+    for f in &forms {
+        assert_eq!(hash_of(f), hash_of(&forms[0]));
+    }
+    assert_eq!(hash_of(&Int::Small(SmallInt::I64(-7))), hash_of(&create_int("-7")));
+    assert_ne!(hash_of(&create_int("7")), hash_of(&create_int("-7")));
+}
+
+#[test]
+fn usable_as_map_keys() {
+    let mut map = HashMap::new();
+    map.insert(Int::Small(SmallInt::I32(5)), "five");
+    assert_eq!(map.get(&create_int("5")), Some(&"five"));
+
+    let set: HashSet<Int> = [create_int("1"), Int::Small(SmallInt::U16(1)), create_int("2")].into_iter().collect();
+    assert_eq!(set.len(), 2);
+
+    let mut tree = BTreeMap::new();
+    tree.insert(create_int("100000000000000000000"), 'c');
+    tree.insert(Int::Small(SmallInt::I8(-3)), 'a');
+    tree.insert(Int::Small(SmallInt::U64(42)), 'b');
+    assert_eq!(tree.values().collect::<String>(), "abc");
+    assert_eq!(tree.get(&Int::Small(SmallInt::I16(42))), Some(&'b'));
+}
+
+#[test]
+fn total_order() {
+    let mut v = vec![
+        create_int("3"),
+        Int::Small(SmallInt::I64(-10)),
+        create_int("-100000000000000000000"),
+        Int::Small(SmallInt::U8(0)),
+    ];
+    v.sort();
+    assert_eq!(v, vec![create_int("-100000000000000000000"), create_int("-10"), create_int("0"), create_int("3")]);
+    assert_eq!(v.iter().max(), Some(&create_int("3")));
+    assert_eq!(create_int("4").clamp(create_int("0"), Int::Small(SmallInt::I32(2))), create_int("2"));
+}
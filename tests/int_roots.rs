@@ -0,0 +1,37 @@
+use imagnum::{create_float, create_int};
+
+#[test]
+fn isqrt_is_exact_beyond_f64() {
+    // (2^64 + 1)^2 = 2^128 + 2^65 + 1
+    let n = create_int("340282366920938463500268095579187314689");
+    assert_eq!(n.isqrt().unwrap(), create_int("18446744073709551617"));
+    let m = create_int("340282366920938463500268095579187314688");
+    assert_eq!(m.isqrt().unwrap(), create_int("18446744073709551616"));
+    assert_eq!(create_int("0").isqrt().unwrap(), create_int("0"));
+    assert!(create_int("-4").isqrt().is_err());
+
+    // Int::sqrt takes the exact path for perfect squares
+    assert_eq!(n.sqrt().unwrap(), create_float("18446744073709551617"));
+}
+
+#[test]
+fn nth_root() {
+    assert_eq!(create_int("1000").nth_root(3).unwrap(), create_int("10"));
+    assert_eq!(create_int("1001").nth_root(3).unwrap(), create_int("10"));
+    assert_eq!(create_int("999").nth_root(3).unwrap(), create_int("9"));
+    assert_eq!(create_int("-27").nth_root(3).unwrap(), create_int("-3"));
+    assert_eq!(create_int("12345").nth_root(1).unwrap(), create_int("12345"));
+    assert!(create_int("-16").nth_root(4).is_err());
+    assert!(create_int("16").nth_root(0).is_err());
+}
+
+#[test]
+fn perfect_powers() {
+    assert_eq!(create_int("64").is_perfect_power(), Some((create_int("2"), 6)));
+    assert_eq!(create_int("243").is_perfect_power(), Some((create_int("3"), 5)));
+    assert_eq!(create_int("-125").is_perfect_power(), Some((create_int("-5"), 3)));
+    assert_eq!(create_int("-16").is_perfect_power(), None);
+    assert_eq!(create_int("12").is_perfect_power(), None);
+    let big = create_int("7").pow(&create_int("40")).unwrap();
+    assert_eq!(big.is_perfect_power(), Some((create_int("7"), 40)));
+}
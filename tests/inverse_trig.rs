@@ -0,0 +1,120 @@
+use imagnum::foundation::Float;
+use imagnum::{consts, create_float, errors};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+fn c(re: f64, im: f64) -> Float {
+    Float::complex(Float::from(re), Float::from(im))
+}
+
+fn assert_close(value: &Float, re: f64, im: f64) {
+    let (got_re, got_im) = (value.re().to_f64().unwrap(), value.im().to_f64().unwrap());
+    let ok = |got: f64, want: f64| (got - want).abs() <= 1e-12 * want.abs().max(1.0);
+    assert!(ok(got_re, re) && ok(got_im, im), "{} is not {} + {}i", value, re, im);
+}
+
+#[test]
+fn real_values_match_f64() {
+    for x in [-1.0f64, -0.75, -0.1, 0.0, 0.3, 0.5, 0.999, 1.0] {
+        let f = Float::from(x);
+        let asin = f.asin().unwrap();
+        assert!(!asin.is_complex(), "asin({}) = {}", x, asin);
+        assert_close(&asin, x.asin(), 0.0);
+        assert_close(&f.acos().unwrap(), x.acos(), 0.0);
+    }
+    for x in [-1e6f64, -3.0, -0.5, 0.0, 0.25, 2.0, 1e6] {
+        let f = Float::from(x);
+        assert_close(&f.atan().unwrap(), x.atan(), 0.0);
+        assert_close(&f.asinh().unwrap(), x.asinh(), 0.0);
+        assert!(!f.atan().unwrap().is_complex() && !f.asinh().unwrap().is_complex());
+    }
+    for x in [1.0f64, 1.5, 10.0, 1e6] {
+        assert_close(&Float::from(x).acosh().unwrap(), x.acosh(), 0.0);
+    }
+    for x in [-0.9f64, -0.2, 0.0, 0.5, 0.99] {
+        assert_close(&Float::from(x).atanh().unwrap(), x.atanh(), 0.0);
+    }
+    assert_eq!(create_float("0").asin(), Ok(create_float("0")));
+    assert_eq!(create_float("1").acos(), Ok(create_float("0")));
+}
+
+#[test]
+fn complex_values() {
+    // reference values from the principal branches, as Python's cmath gives them
+    let z = c(1.0, 2.0);
+    assert_close(&z.asin().unwrap(), 0.4270785863924762, 1.5285709194809982);
+    assert_close(&z.acos().unwrap(), 1.1437177404024204, -1.5285709194809982);
+    assert_close(&z.atan().unwrap(), 1.3389725222944935, 0.40235947810852507);
+    assert_close(&z.asinh().unwrap(), 1.4693517443681852, 1.0634400235777521);
+    assert_close(&z.acosh().unwrap(), 1.5285709194809982, 1.1437177404024204);
+    assert_close(&z.atanh().unwrap(), 0.17328679513998632, 1.1780972450961724);
+    let w = c(-1.0, -2.0);
+    assert_close(&w.acos().unwrap(), 1.9978749131873728, 1.5285709194809982);
+    assert_close(&w.acosh().unwrap(), 1.5285709194809982, -1.9978749131873728);
+}
+
+#[test]
+fn inverses_round_trip() {
+    for z in [c(0.3, 0.4), c(-2.0, 1.5), c(3.0, -4.0), c(-0.5, -0.25), c(0.0, 5.0)] {
+        let (re, im) = (z.re().to_f64().unwrap(), z.im().to_f64().unwrap());
+        assert_close(&z.asin().unwrap().sin().unwrap(), re, im);
+        assert_close(&z.acos().unwrap().cos().unwrap(), re, im);
+        assert_close(&z.atan().unwrap().tan().unwrap(), re, im);
+        // sinh and cosh from exp
+        let e = |w: Float| (w.exp().unwrap(), (-w).exp().unwrap());
+        let half = Float::from(0.5);
+        let (a, b) = e(z.asinh().unwrap());
+        assert_close(&a._sub(&b).unwrap()._mul(&half).unwrap(), re, im);
+        let (a, b) = e(z.acosh().unwrap());
+        assert_close(&a._add(&b).unwrap()._mul(&half).unwrap(), re, im);
+    }
+}
+
+#[test]
+fn branch_cuts_take_the_upper_and_right_sides() {
+    // real-axis cuts: the limit from above
+    assert_close(&Float::from(2.0).asin().unwrap(), FRAC_PI_2, 1.3169578969248166);
+    assert_close(&Float::from(-2.0).asin().unwrap(), -FRAC_PI_2, 1.3169578969248166);
+    assert_close(&Float::from(2.0).acos().unwrap(), 0.0, -1.3169578969248166);
+    assert_close(&Float::from(-2.0).acos().unwrap(), PI, -1.3169578969248166);
+    assert_close(&Float::from(2.0).atanh().unwrap(), 0.5493061443340549, FRAC_PI_2);
+    assert_close(&Float::from(-2.0).atanh().unwrap(), -0.5493061443340549, FRAC_PI_2);
+    assert_close(&Float::from(0.5).acosh().unwrap(), 0.0, FRAC_PI_3);
+    assert_close(&Float::from(-2.0).acosh().unwrap(), 1.3169578969248166, PI);
+    // just above the cut agrees
+    assert_close(&c(2.0, 1e-12).asin().unwrap(), FRAC_PI_2, 1.3169578969248166);
+    // imaginary-axis cuts: the limit from the right
+    assert_close(&c(0.0, 2.0).atan().unwrap(), FRAC_PI_2, 0.5493061443340549);
+    assert_close(&c(0.0, -2.0).atan().unwrap(), FRAC_PI_2, -0.5493061443340549);
+    assert_close(&c(0.0, 2.0).asinh().unwrap(), 1.3169578969248166, FRAC_PI_2);
+    assert_close(&c(0.0, -2.0).asinh().unwrap(), 1.3169578969248166, -FRAC_PI_2);
+    assert_close(&c(1e-12, -2.0).atan().unwrap(), FRAC_PI_2, -0.5493061443340549);
+    // across a cut the value jumps to the conjugate side
+    assert_close(&c(2.0, -1e-12).asin().unwrap(), FRAC_PI_2, -1.3169578969248166);
+    assert_close(&c(-1e-12, 2.0).atan().unwrap(), -FRAC_PI_2, 0.5493061443340549);
+}
+
+#[test]
+fn results_carry_the_working_precision() {
+    let digits = |x: &Float| x.to_str().chars().take(120).collect::<String>();
+    let pi = Float::Big(consts::pi(140));
+    let six = Float::from(6.0);
+    assert_eq!(digits(&create_float("0.5").asin().unwrap()._mul(&six).unwrap()), digits(&pi));
+    assert_eq!(digits(&create_float("-1").acos().unwrap()), digits(&pi));
+    assert_eq!(digits(&create_float("1").atan().unwrap()._mul(&Float::from(4.0)).unwrap()), digits(&pi));
+    let half_pi = pi._div(&Float::from(2.0)).unwrap();
+    assert_eq!(digits(&create_float("2").asin().unwrap().re()), digits(&half_pi));
+    assert_eq!(digits(&c(0.0, 2.0).asinh().unwrap().im()), digits(&half_pi));
+}
+
+#[test]
+fn singularities_and_specials() {
+    assert!(c(0.0, 1.0).atan().is_err());
+    assert!(Float::from(1.0).atanh().is_err());
+    assert!(Float::from(-1.0).atanh().is_err());
+    assert_eq!(Float::NaN.asin(), Err(errors::INVALID_FORMAT));
+    assert_eq!(Float::Infinity.atan(), Err(errors::INVALID_FORMAT));
+    // large arguments keep their digits
+    assert_close(&Float::from(1e10).asinh().unwrap(), 1e10f64.asinh(), 0.0);
+    assert_close(&Float::from(-1e10).asinh().unwrap(), (-1e10f64).asinh(), 0.0);
+    assert_close(&c(0.0, 1e10).asin().unwrap(), 0.0, 1e10f64.asinh());
+}
@@ -0,0 +1,92 @@
+use imagnum::{create_float, errors, Float, Matrix, Vector};
+
+fn m(rows: &[&[&str]]) -> Matrix {
+    Matrix::from_rows(rows.iter().map(|r| r.iter().map(|s| create_float(s)).collect()).collect()).unwrap()
+}
+
+fn v(items: &[&str]) -> Vector {
+    Vector::new(items.iter().map(|s| create_float(s)).collect())
+}
+
+#[test]
+fn test_shapes() {
+    let a = m(&[&["1", "2", "3"], &["4", "5", "6"]]);
+    assert_eq!((a.rows(), a.cols()), (2, 3));
+    assert_eq!(a[(1, 2)], create_float("6"));
+    assert_eq!(a.get(2, 0), None);
+    assert_eq!(a.transpose(), m(&[&["1", "4"], &["2", "5"], &["3", "6"]]));
+    assert_eq!(a.column(1), v(&["2", "5"]));
+
+    assert_eq!(Matrix::new(2, 2, vec![create_float("1")]), Err(errors::INVALID_FORMAT));
+    let ragged = vec![vec![create_float("1")], vec![create_float("1"), create_float("2")]];
+    assert_eq!(Matrix::from_rows(ragged), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = m(&[&["1", "2"], &["3", "4"]]);
+    let b = m(&[&["0.5", "0"], &["0", "-1"]]);
+    assert_eq!((a.clone() + &b).unwrap(), m(&[&["1.5", "2"], &["3", "3"]]));
+    assert_eq!((a.clone() - &b).unwrap(), m(&[&["0.5", "2"], &["3", "5"]]));
+    assert_eq!((a.clone() * &b).unwrap(), m(&[&["0.5", "-2"], &["1.5", "-4"]]));
+    assert_eq!((a.clone() * &Matrix::identity(2)).unwrap(), a);
+    assert_eq!((a.clone() * v(&["1", "1"])).unwrap(), v(&["3", "7"]));
+    assert_eq!(a.clone() * m(&[&["1", "2", "3"]]), Err(errors::INVALID_FORMAT));
+
+    let x = v(&["3", "4"]);
+    assert_eq!(x.dot(&x).unwrap(), create_float("25"));
+    assert_eq!(x.norm().unwrap(), create_float("5"));
+    assert_eq!((x.clone() - v(&["1", "1"])).unwrap(), v(&["2", "3"]));
+    assert_eq!(x + v(&["1"]), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_determinant_exact() {
+    assert_eq!(m(&[&["2", "1"], &["1", "3"]]).determinant().unwrap(), create_float("5"));
+    // needs a row swap
+    let a = m(&[&["0", "1", "2"], &["1", "0", "3"], &["4", "-3", "8"]]);
+    assert_eq!(a.determinant().unwrap(), create_float("-2"));
+    assert!(m(&[&["1", "2"], &["2", "4"]]).determinant().unwrap().is_zero());
+    assert_eq!(m(&[&["0.5", "1.25"], &["2", "3"]]).determinant().unwrap(), create_float("-1"));
+
+    // 30-digit entries stay exact
+    let big = "123456789012345678901234567890";
+    let a = m(&[&[big, "1"], &["1", big]]);
+    let expected = create_float("15241578753238836750495351562536198787501905199875019052099");
+    assert_eq!(a.determinant().unwrap(), expected);
+
+    assert_eq!(m(&[&["1", "2"]]).determinant(), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_solve_exact() {
+    let a = m(&[&["2", "1", "-1"], &["-3", "-1", "2"], &["-2", "1", "2"]]);
+    assert_eq!(a.solve(&v(&["8", "-11", "-3"])).unwrap(), v(&["2", "3", "-1"]));
+
+    let a = m(&[&["0", "1"], &["1", "0"]]);
+    assert_eq!(a.solve(&v(&["7", "9"])).unwrap(), v(&["9", "7"]));
+
+    // the answer is 1/3: only the final division rounds
+    let a = m(&[&["3"]]);
+    let x = a.solve(&v(&["1"])).unwrap();
+    assert_eq!(x[0], create_float("1")._div(&create_float("3")).unwrap());
+
+    assert_eq!(m(&[&["1", "2"], &["2", "4"]]).solve(&v(&["1", "1"])), Err(errors::DIV_BY_ZERO));
+    assert_eq!(m(&[&["1", "2"], &["3", "4"]]).solve(&v(&["1"])), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_irrational_entries() {
+    let s = create_float("2").sqrt().unwrap();
+    assert!(matches!(s, Float::Irrational(_)));
+    let a = Matrix::from_rows(vec![vec![s.clone(), create_float("1")], vec![create_float("1"), s.clone()]]).unwrap();
+    let det = a.determinant().unwrap();
+    assert!((det.to_f64().unwrap() - 1.0).abs() < 1e-12);
+
+    let b = Vector::new(vec![create_float("1"), create_float("1")]);
+    let x = a.solve(&b).unwrap();
+    let back = (a * &x).unwrap();
+    for i in 0..2 {
+        assert!((back[i].to_f64().unwrap() - 1.0).abs() < 1e-12);
+    }
+}
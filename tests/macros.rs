@@ -0,0 +1,61 @@
+use imagnum::macros::{complex, float, int};
+
+#[test]
+fn int_macro() {
+    assert_eq!(int!(255), imagnum::create_int("255"));
+    assert_eq!(int!(-0xFF), imagnum::create_int("-255"));
+    assert_eq!(int!(0o17), imagnum::create_int("15"));
+    assert_eq!(int!(0b1010_1010), imagnum::create_int("170"));
+    assert_eq!(int!(1_000_000u64), imagnum::create_int("1000000"));
+    assert_eq!(int!(340282366920938463463374607431768211456), imagnum::create_int("340282366920938463463374607431768211456"));
+    assert_eq!(int!("-123"), imagnum::create_int("-123"));
+    assert_eq!(int!(" 0x10 "), imagnum::create_int("16"));
+    // other expressions are parsed at run time
+    let s = String::from("42");
+    assert_eq!(int!(&s), imagnum::create_int("42"));
+}
+
+#[test]
+fn float_macro() {
+    assert_eq!(float!(1.5e10), imagnum::create_float("15000000000"));
+    assert_eq!(float!(-2.5), imagnum::create_float("-2.5"));
+    assert_eq!(float!(3), imagnum::create_float("3"));
+    assert_eq!(float!(0xFF), imagnum::create_float("255"));
+    assert_eq!(float!(1_000.25f64), imagnum::create_float("1000.25"));
+    assert_eq!(float!(2.), imagnum::create_float("2"));
+    assert_eq!(float!(1e-3), imagnum::create_float("0.001"));
+    assert_eq!(float!("0.(3)"), imagnum::create_float("0.(3)"));
+    assert_eq!(float!("-0x1.8p3"), imagnum::create_float("-12"));
+    assert_eq!(float!("3.14159..."), imagnum::create_float("3.14159..."));
+    assert_eq!(float!("2√3"), imagnum::create_float("2√3"));
+    assert_eq!(float!("1-2i"), imagnum::create_float("1-2i"));
+    assert!(float!("nan").is_nan());
+    assert!(matches!(float!("-inf"), imagnum::Float::NegInfinity));
+    let s = "0.5";
+    assert_eq!(float!(s), imagnum::create_float("0.5"));
+}
+
+#[test]
+fn complex_macro() {
+    assert_eq!(complex!(3, 4).to_string(), "3.0 + 4.0i");
+    assert_eq!(complex!(-0.5, "1e-3"), imagnum::create_complex("-0.5", "0.001"));
+    assert_eq!(complex!(1, -2), imagnum::create_float("1-2i"));
+    assert_eq!(complex!("1", "2"), imagnum::create_complex("1", "2"));
+}
+
+#[test]
+fn literal_checks() {
+    use imagnum::literal::{is_float_literal, is_int_literal};
+    for ok in ["0", "1_000", "0xFF", "0b1", "0o7", "5u8", "0xFFi64", "\"-12\"", "\"+0x1f\""] {
+        assert!(is_int_literal(ok), "{}", ok);
+    }
+    for bad in ["1.5", "0x", "0b2", "1e3", "\"12x\"", "\"\"", "\"-\"", "5f32", "\"1 2\""] {
+        assert!(!is_int_literal(bad), "{}", bad);
+    }
+    for ok in ["1.5", "1.", "1e10", "2.5E-3f32", "\".5\"", "\"1e+5\"", "\"Infinity\"", "\"0X1P-2\"", "\"1.2(34)\"", "\"i\"", "\"-2.5i\"", "\"1+i\"", "\"1...{7}\"", "\"-√2\""] {
+        assert!(is_float_literal(ok), "{}", ok);
+    }
+    for bad in ["\"1.2.3\"", "\"abc\"", "\"+inf\"", "\"1e\"", "\"0x\"", "\"1.()\"", "\"1...{}\"", "\"2√\"", "\"1+2+3i\"", "\".\""] {
+        assert!(!is_float_literal(bad), "{}", bad);
+    }
+}
@@ -0,0 +1,75 @@
+use imagnum::errors::INVALID_FORMAT;
+use imagnum::foundation::{SmallFloat, SmallInt};
+use imagnum::{create_complex, create_float, create_int, Float, Int};
+use std::cmp::Ordering;
+
+#[test]
+fn float_min_max_ignore_a_single_nan() {
+    let a = create_float("1.5");
+    let b = Float::Small(SmallFloat::F64(-2.0));
+    assert_eq!(a.min(&b), create_float("-2"));
+    assert_eq!(a.max(&b), create_float("1.5"));
+    assert_eq!(a.max(&Float::Infinity), Float::Infinity);
+    assert_eq!(Float::NaN.min(&a), a);
+    assert_eq!(a.max(&Float::NaN), a);
+    assert!(Float::NaN.max(&Float::NaN).is_nan());
+    assert!(a.min(&create_complex("1", "1")).is_nan());
+}
+
+#[test]
+fn float_clamp() {
+    let (lo, hi) = (create_float("0"), create_float("1"));
+    assert_eq!(create_float("1.5").clamp(&lo, &hi), Ok(hi.clone()));
+    assert_eq!(create_float("-0.5").clamp(&lo, &hi), Ok(lo.clone()));
+    assert_eq!(create_float("0.25").clamp(&lo, &hi), Ok(create_float("0.25")));
+    assert_eq!(Float::NegInfinity.clamp(&lo, &hi), Ok(lo.clone()));
+    assert!(Float::NaN.clamp(&lo, &hi).unwrap().is_nan());
+    assert_eq!(create_float("0.5").clamp(&hi, &lo), Err(INVALID_FORMAT));
+    assert_eq!(create_float("0.5").clamp(&Float::NaN, &hi), Err(INVALID_FORMAT));
+}
+
+#[test]
+fn float_copysign_and_signum() {
+    assert_eq!(create_float("2.5").copysign(&create_float("-0.1")), create_float("-2.5"));
+    assert_eq!(create_float("-2.5").copysign(&create_float("0")), create_float("2.5"));
+    assert_eq!(Float::Infinity.copysign(&Float::NegInfinity), Float::NegInfinity);
+    assert!(create_float("1").copysign(&Float::NaN).is_nan());
+
+    assert_eq!(create_float("-0.001").signum(), create_float("-1"));
+    assert_eq!(create_float("0").signum(), create_float("0"));
+    assert_eq!(Float::Infinity.signum(), create_float("1"));
+    assert!(Float::NaN.signum().is_nan());
+    assert_eq!(create_complex("3", "4").signum(), create_complex("0.6", "0.8"));
+}
+
+#[test]
+fn float_total_cmp_sorts_everything() {
+    let mut v = [
+        Float::NaN,
+        create_complex("1", "2"),
+        create_float("2"),
+        Float::Infinity,
+        create_complex("1", "-1"),
+        Float::NegInfinity,
+        create_float("-3.5"),
+    ];
+    v.sort_by(|a, b| a.total_cmp(b));
+    assert_eq!(v[0], Float::NegInfinity);
+    assert_eq!(v[1], create_float("-3.5"));
+    assert_eq!(v[2], create_float("2"));
+    assert_eq!(v[3], Float::Infinity);
+    assert_eq!(v[4], create_complex("1", "-1"));
+    assert_eq!(v[5], create_complex("1", "2"));
+    assert!(v[6].is_nan());
+    assert_eq!(Float::NaN.total_cmp(&Float::NaN), Ordering::Equal);
+}
+
+#[test]
+fn int_helpers() {
+    assert_eq!(create_int("-42").signum(), create_int("-1"));
+    assert_eq!(Int::Small(SmallInt::U8(0)).signum(), create_int("0"));
+    assert_eq!(create_int("7").copysign(&Int::Small(SmallInt::I8(-1))), create_int("-7"));
+    assert_eq!(create_int("-7").copysign(&create_int("0")), create_int("7"));
+    assert_eq!(create_int("3").min(create_int("-3")), create_int("-3"));
+    assert_eq!(create_int("10").clamp(create_int("0"), create_int("5")), create_int("5"));
+}
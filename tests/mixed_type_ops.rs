@@ -0,0 +1,92 @@
+use imagnum::{create_float, create_int, Float, Int};
+
+#[test]
+fn int_and_float_promote() {
+    let i = create_int("3");
+    let f = create_float("0.5");
+    assert_eq!((i.clone() + f.clone()).unwrap(), create_float("3.5"));
+    assert_eq!((&f - &i).unwrap(), create_float("-2.5"));
+    assert_eq!((&i * &f).unwrap(), create_float("1.5"));
+    assert_eq!((i.clone() / f.clone()).unwrap(), create_float("6"));
+    assert_eq!((create_float("7.5") % create_int("2")).unwrap(), create_float("1.5"));
+    assert!((create_int("1") / create_float("0")).is_err());
+}
+
+#[test]
+fn int_and_primitives() {
+    let i = create_int("10");
+    assert_eq!((i.clone() + 5).unwrap(), create_int("15"));
+    assert_eq!((&i - 20i64).unwrap(), create_int("-10"));
+    assert_eq!((3 * &i).unwrap(), create_int("30"));
+    assert_eq!((i.clone() / 4).unwrap(), create_int("2"));
+    assert_eq!((i.clone() % 4).unwrap(), create_int("2"));
+    assert_eq!((100 / i.clone()).unwrap(), create_int("10"));
+    // a float primitive promotes
+    assert_eq!((i.clone() + 0.5).unwrap(), create_float("10.5"));
+    assert_eq!((0.25 * &i).unwrap(), create_float("2.5"));
+    assert!((i / 0).is_err());
+}
+
+#[test]
+fn float_and_primitives() {
+    let f = create_float("1.25");
+    assert_eq!((f.clone() + 2).unwrap(), create_float("3.25"));
+    assert_eq!((3.5 + f.clone()).unwrap(), create_float("4.75"));
+    assert_eq!((&f * 4).unwrap(), create_float("5"));
+    assert_eq!((10 - &f).unwrap(), create_float("8.75"));
+    assert_eq!((f.clone() / 0.5).unwrap(), create_float("2.5"));
+    assert_eq!((5.0 % f).unwrap(), create_float("0"));
+}
+
+#[test]
+fn negative_small_int_promotes_with_sign() {
+    let small = Int::from(-5i32);
+    assert_eq!(small.to_float().unwrap(), create_float("-5"));
+    assert_eq!((small + Float::from(1.0)).unwrap(), create_float("-4"));
+}
+
+#[test]
+fn float_remainder_with_fractional_divisor() {
+    assert_eq!((create_float("5") % create_float("1.25")).unwrap(), create_float("0"));
+    assert_eq!((create_float("5.5") % create_float("1.5")).unwrap(), create_float("1"));
+    assert_eq!((create_float("-5.5") % create_float("1.5")).unwrap(), create_float("-1"));
+}
+
+#[test]
+fn int_and_float_compare() {
+    let i = create_int("3");
+    assert!(create_float("2.5") < i);
+    assert!(i < create_float("3.0001"));
+    assert!(i <= create_float("3.0"));
+    assert_eq!(create_float("3.000"), i);
+    assert!(create_float("inf") > create_int("1000000000000000000000"));
+    assert!(create_float("-inf") < i);
+    assert_eq!(create_float("nan").partial_cmp(&i), None);
+    assert_ne!(create_float("nan"), i);
+    assert_eq!(create_float("3+2i").partial_cmp(&i), None);
+}
+
+#[test]
+fn compare_with_primitives() {
+    let n = create_int("10");
+    assert!(n > 5);
+    assert!(n == 10);
+    assert!(n != 11i64);
+    assert!(n < u64::MAX);
+    assert!(-3 < n);
+    assert!(10.5 > n);
+    assert!(n >= 10.0);
+    assert!(create_int("-1") < 0u64);
+
+    let x = create_float("2.5");
+    assert!(x == 2.5);
+    assert!(x > 2);
+    assert!(x < 3i64);
+    assert!(2.25 < x);
+    assert!(create_float("3") == 3);
+    assert!(x < f64::INFINITY);
+    assert!(create_float("inf") == f64::INFINITY);
+    assert_eq!(x.partial_cmp(&f64::NAN), None);
+    assert!(create_float("nan") != 0);
+    assert!(!create_float("nan").eq(&f64::NAN));
+}
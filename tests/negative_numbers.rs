@@ -1,4 +1,4 @@
-use imagnum::{create_float, create_int};
+use imagnum::{create_float, create_int, Float};
 
 #[test]
 fn negative_int_arithmetic() {
@@ -80,11 +80,29 @@ fn negative_division() {
     let res2 = (&c / &d).unwrap();
     let res3 = (&c / &b).unwrap();
 
-    assert_eq!(res2.to_string(), "4");
-    assert_eq!(res3.to_string(), "-4");
+    assert_eq!(res2.to_string(), "3");
+    assert_eq!(res3.to_string(), "-3");
 
     let a = create_float("-9.0");
     let b = create_float("3.0");
     let res = (a / b).unwrap();
     assert_eq!(res.to_string(), "-3.0");
-}
\ No newline at end of file
+}
+#[test]
+fn negation_and_abs_of_special_values() {
+    assert_eq!(-Float::Infinity, Float::NegInfinity);
+    assert_eq!(-Float::NegInfinity, Float::Infinity);
+    assert_eq!(Float::NegInfinity.abs(), Float::Infinity);
+    assert!((-Float::NaN).is_nan());
+
+    let z = -Float::complex(create_float("1.5"), create_float("-2"));
+    assert_eq!(z, Float::complex(create_float("-1.5"), create_float("2")));
+}
+
+#[test]
+fn negative_modulo_and_division() {
+    let r = create_float("-7.5")._modulo(&create_float("2.25")).unwrap();
+    assert_eq!(r.to_string(), "-0.75");
+    let q = (create_float("-1") / create_float("0.4")).unwrap();
+    assert_eq!(q.to_string(), "-2.5");
+}
@@ -0,0 +1,48 @@
+use imagnum::{ntt, Int};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+fn operand(words: usize, seed: u64) -> BigInt {
+    let mut state = seed;
+    let words = (0..words)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 32) as u32
+        })
+        .collect();
+    BigInt::from(BigUint::new(words))
+}
+
+#[test]
+fn transform_matches_schoolbook() {
+    for (wa, wb) in [(1, 1), (1, 7), (3, 5), (64, 64), (100, 3), (513, 1025), (4000, 4000)] {
+        let a = operand(wa, wa as u64);
+        let b = -operand(wb, wb as u64 + 1);
+        assert_eq!(ntt::mul_transform(&a, &b), &a * &b, "{} x {} words", wa, wb);
+        assert_eq!(ntt::mul_transform(&b, &b), &b * &b, "{} words squared", wb);
+    }
+    assert_eq!(ntt::mul_transform(&operand(10, 1), &BigInt::zero()), BigInt::zero());
+    assert_eq!(ntt::mul_transform(&-BigInt::one(), &BigInt::one()), -BigInt::one());
+}
+
+#[test]
+fn transform_worst_case_coefficients() {
+    // all-ones limbs make every convolution coefficient as large as it can be
+    for bits in [1000u64, 100_000, 1_000_003] {
+        let max = (BigInt::one() << bits) - 1;
+        assert_eq!(ntt::mul_transform(&max, &max), &max * &max, "{} bits", bits);
+    }
+}
+
+#[test]
+fn int_mul_above_threshold() {
+    let a = operand((ntt::THRESHOLD_BITS / 32 + 10) as usize, 3);
+    let b = operand((ntt::THRESHOLD_BITS / 32 + 99) as usize, 4);
+    let expected = Int::from(&a * &b);
+    let (x, y) = (Int::from(a), Int::from(b));
+    assert_eq!(x._mul(&y).unwrap(), expected);
+    assert_eq!(ntt::mul(&x.to_bigint().unwrap(), &y.to_bigint().unwrap()), expected.to_bigint().unwrap());
+    let mut z = x.clone();
+    z *= &y;
+    assert_eq!(z, expected);
+}
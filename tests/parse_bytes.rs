@@ -0,0 +1,50 @@
+use imagnum::{create_float, create_int, parse_float_bytes, parse_int_bytes};
+
+#[test]
+fn parse_int_bytes_reports_consumed() {
+    let (n, used) = parse_int_bytes(b"12345 + 6").unwrap();
+    assert_eq!(n, create_int("12345"));
+    assert_eq!(used, 5);
+
+    let (n, used) = parse_int_bytes(b"-1_000_000)").unwrap();
+    assert_eq!(n, create_int("-1000000"));
+    assert_eq!(used, 10);
+
+    let (n, used) = parse_int_bytes(b"0xFFg").unwrap();
+    assert_eq!(n, create_int("255"));
+    assert_eq!(used, 4);
+
+    // "0x" without hex digits is just the literal 0
+    let (n, used) = parse_int_bytes(b"0xz").unwrap();
+    assert_eq!(n, create_int("0"));
+    assert_eq!(used, 1);
+
+    assert!(parse_int_bytes(b"abc").is_err());
+    assert!(parse_int_bytes(b"-").is_err());
+}
+
+#[test]
+fn parse_float_bytes_reports_consumed() {
+    let (f, used) = parse_float_bytes(b"3.25*x").unwrap();
+    assert_eq!(f, create_float("3.25"));
+    assert_eq!(used, 4);
+
+    let (f, used) = parse_float_bytes(b"1.5e-3,").unwrap();
+    assert_eq!(f, create_float("0.0015"));
+    assert_eq!(used, 6);
+
+    // exponent marker without digits is not part of the literal
+    let (f, used) = parse_float_bytes(b"2e+").unwrap();
+    assert_eq!(f, create_float("2"));
+    assert_eq!(used, 1);
+
+    let (f, used) = parse_float_bytes(b"0.1(6) ").unwrap();
+    assert_eq!(f, create_float("0.1(6)"));
+    assert_eq!(used, 6);
+
+    // a trailing dot is left for the caller (e.g. a method call)
+    let (_, used) = parse_float_bytes(b"7.abs").unwrap();
+    assert_eq!(used, 1);
+
+    assert!(parse_float_bytes(b".").is_err());
+}
@@ -0,0 +1,58 @@
+use imagnum::errors::{self, Expected, ParseError};
+use imagnum::{create_float, create_int, Float, Int};
+
+fn float_err(s: &str) -> (usize, Expected) {
+    let err = s.parse::<Float>().unwrap_err();
+    assert_eq!(err.code, Float::from_str(s).unwrap_err(), "{}", s);
+    (err.offset, err.expected)
+}
+
+fn int_err(s: &str) -> (i8, usize, Expected) {
+    let err = s.parse::<Int>().unwrap_err();
+    (err.code, err.offset, err.expected)
+}
+
+#[test]
+fn parse_matches_from_str() {
+    assert_eq!("  -12_345 ".parse::<Int>(), Ok(create_int("-12345")));
+    assert_eq!("0.1(6)".parse::<Float>().unwrap(), create_float("0.1(6)"));
+    assert_eq!("1.5-2i".parse::<Float>().unwrap(), create_float("1.5-2i"));
+    assert_eq!("0x1.8p3".parse::<Float>().unwrap(), create_float("12"));
+}
+
+#[test]
+fn int_error_positions() {
+    assert_eq!(int_err(""), (errors::INVALID_FORMAT, 0, Expected::Digit));
+    assert_eq!(int_err("  -"), (errors::INVALID_FORMAT, 3, Expected::Digit));
+    assert_eq!(int_err("12a4"), (errors::INVALID_FORMAT, 2, Expected::End));
+    assert_eq!(int_err(" 1.5"), (errors::INVALID_FORMAT, 2, Expected::End));
+    assert_eq!(int_err("-inf"), (errors::INFINITE_RESULT, 0, Expected::FiniteValue));
+}
+
+#[test]
+fn float_error_positions() {
+    assert_eq!(float_err(""), (0, Expected::Digit));
+    assert_eq!(float_err("-."), (2, Expected::Digit));
+    assert_eq!(float_err("1.2.3"), (3, Expected::End));
+    assert_eq!(float_err("2e"), (2, Expected::Digit));
+    assert_eq!(float_err("2e-x"), (3, Expected::Digit));
+    assert_eq!(float_err("0.1(6"), (5, Expected::ClosingParen));
+    assert_eq!(float_err("1+2j"), (3, Expected::ImaginaryUnit));
+    assert_eq!(float_err("1+i2"), (2, Expected::Digit));
+    assert_eq!(float_err("3i4"), (2, Expected::End));
+    assert_eq!(float_err("2√x"), (4, Expected::Digit));
+    assert_eq!(float_err(" 0x1.gp2"), (5, Expected::End));
+    assert_eq!(float_err("NaN"), (0, Expected::FiniteValue));
+}
+
+#[test]
+fn display_and_underline() {
+    let err = "12x".parse::<Int>().unwrap_err();
+    assert_eq!(err.to_string(), "Invalid format at byte 2: expected end of input");
+    assert_eq!(err.underline("12x"), "12x\n  ^");
+    // the caret counts characters, not bytes
+    let err = "√√2".parse::<Float>().unwrap_err();
+    assert_eq!(err, ParseError { code: errors::INVALID_FORMAT, offset: 3, expected: Expected::Digit });
+    assert_eq!(err.underline("√√2"), "√√2\n ^");
+    assert_eq!(i8::from(err), errors::INVALID_FORMAT);
+}
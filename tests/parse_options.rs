@@ -0,0 +1,79 @@
+use imagnum::errors::{INVALID_FORMAT, NUMBER_TOO_LARGE};
+use imagnum::{create_float, create_int, Float, Int, ParseOptions};
+
+#[test]
+fn default_options_match_create_float() {
+    let opts = ParseOptions::new();
+    for s in ["1.5", "-0.25", "+3", ".5", "1e3", "2.5E-2", "0.1(6)"] {
+        assert_eq!(Float::parse_with(s, &opts).unwrap(), create_float(s), "{}", s);
+    }
+}
+
+#[test]
+fn locale_separators() {
+    let de = ParseOptions::new().decimal_separator(',').grouping('.');
+    assert_eq!(Float::parse_with("1.234.567,89", &de).unwrap(), create_float("1234567.89"));
+    assert_eq!(Float::parse_with("-0,5", &de).unwrap(), create_float("-0.5"));
+
+    let fr = ParseOptions::new().decimal_separator(',').grouping(' ');
+    assert_eq!(Float::parse_with("12 345,6", &fr).unwrap(), create_float("12345.6"));
+
+    let en = ParseOptions::new().grouping(',');
+    assert_eq!(Int::parse_with("1,000,000", &en).unwrap(), create_int("1000000"));
+}
+
+#[test]
+fn grouping_must_sit_between_digits() {
+    let en = ParseOptions::new().grouping(',');
+    for s in [",100", "100,", "1,,000", "1.000,5"] {
+        assert_eq!(Float::parse_with(s, &en), Err(INVALID_FORMAT), "{}", s);
+    }
+    assert_eq!(Float::parse_with("1,000", &ParseOptions::new()), Err(INVALID_FORMAT));
+}
+
+#[test]
+fn underscores() {
+    assert_eq!(Float::parse_with("1_000.000_1", &ParseOptions::new()), Err(INVALID_FORMAT));
+    let opts = ParseOptions::new().allow_underscores(true);
+    assert_eq!(Float::parse_with("1_000.000_1", &opts).unwrap(), create_float("1000.0001"));
+    assert_eq!(Int::parse_with("-1_000", &opts).unwrap(), create_int("-1000"));
+    assert_eq!(Int::parse_with("_1", &opts), Err(INVALID_FORMAT));
+}
+
+#[test]
+fn scientific_toggle() {
+    let off = ParseOptions::new().allow_scientific(false);
+    assert_eq!(Float::parse_with("1e3", &off), Err(INVALID_FORMAT));
+    assert_eq!(Float::parse_with("1e", &ParseOptions::new()), Err(INVALID_FORMAT));
+
+    let opts = ParseOptions::new();
+    assert_eq!(Int::parse_with("1.5e3", &opts).unwrap(), create_int("1500"));
+    assert_eq!(Int::parse_with("12e0", &opts).unwrap(), create_int("12"));
+    assert_eq!(Int::parse_with("1.25e1", &opts), Err(INVALID_FORMAT));
+    assert_eq!(Int::parse_with("1e-1", &opts), Err(INVALID_FORMAT));
+    assert_eq!(Int::parse_with("1e99999999999", &opts), Err(NUMBER_TOO_LARGE));
+    assert_eq!(Int::parse_with("1e999999999", &opts), Err(NUMBER_TOO_LARGE));
+    assert_eq!(Int::parse_with("0e99999999999", &opts).unwrap(), create_int("0"));
+    assert_eq!(Int::parse_with("-0.0e99999999999999999999", &opts).unwrap(), create_int("0"));
+    assert_eq!(Int::parse_with("1e1000", &opts).unwrap().to_string().len(), 1001);
+}
+
+#[test]
+fn recurring_toggle() {
+    let off = ParseOptions::new().allow_recurring(false);
+    assert_eq!(Float::parse_with("0.(3)", &off), Err(INVALID_FORMAT));
+    let de = ParseOptions::new().decimal_separator(',');
+    assert_eq!(Float::parse_with("0,1(6)", &de).unwrap(), create_float("0.1(6)"));
+    assert_eq!(Float::parse_with("0.(3)e2", &ParseOptions::new()), Err(INVALID_FORMAT));
+    assert_eq!(Int::parse_with("0.(3)", &ParseOptions::new()), Err(INVALID_FORMAT));
+}
+
+#[test]
+fn rejects_non_finite_and_conflicting_options() {
+    let opts = ParseOptions::new();
+    for s in ["nan", "inf", "2i", "", "-", "1.2.3"] {
+        assert_eq!(Float::parse_with(s, &opts), Err(INVALID_FORMAT), "{}", s);
+    }
+    let clash = ParseOptions::new().decimal_separator(',').grouping(',');
+    assert_eq!(Float::parse_with("1,5", &clash), Err(INVALID_FORMAT));
+}
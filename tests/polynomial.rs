@@ -0,0 +1,134 @@
+use imagnum::{create_float, create_int, errors, Float, PolynomialFloat, PolynomialInt};
+
+fn pi(coeffs: &[&str]) -> PolynomialInt {
+    PolynomialInt::new(coeffs.iter().map(|s| create_int(s)).collect())
+}
+
+fn pf(coeffs: &[&str]) -> PolynomialFloat {
+    PolynomialFloat::new(coeffs.iter().map(|s| create_float(s)).collect())
+}
+
+#[test]
+fn test_construction_and_display() {
+    let p = pi(&["1", "0", "-2", "0", "0"]);
+    assert_eq!(p.degree(), Some(2));
+    assert_eq!(p.coeffs().len(), 3);
+    assert_eq!(p.to_string(), "-2x^2 + 1");
+    assert_eq!(pi(&["-1", "-1", "5"]).to_string(), "5x^2 - x - 1");
+    assert_eq!(pi(&["0"]).degree(), None);
+    assert!(pi(&[]).is_zero());
+    assert_eq!(pi(&[]).to_string(), "0");
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = pi(&["1", "1"]);
+    let b = pi(&["-1", "1"]);
+    assert_eq!((a.clone() * &b).unwrap(), pi(&["-1", "0", "1"]));
+    assert_eq!((a.clone() + &b).unwrap(), pi(&["0", "2"]));
+    assert_eq!((a.clone() - &a).unwrap(), pi(&[]));
+    assert_eq!(-a, pi(&["-1", "-1"]));
+
+    let p = pi(&["5", "0", "3", "2"]);
+    assert_eq!(p.eval(&create_int("-2")).unwrap(), create_int("1"));
+    assert_eq!(p.derivative().unwrap(), pi(&["0", "6", "6"]));
+    assert_eq!(pi(&["7"]).derivative().unwrap(), pi(&[]));
+
+    let p = pf(&["0.5", "0", "1"]);
+    assert_eq!(p.eval(&create_float("1.5")).unwrap(), create_float("2.75"));
+}
+
+#[test]
+fn test_div_rem() {
+    // (x³ - 2x² - 4) = (x - 3)(x² + x + 3) + 5
+    let a = pi(&["-4", "0", "-2", "1"]);
+    let b = pi(&["-3", "1"]);
+    let (q, r) = a.div_rem(&b).unwrap();
+    assert_eq!(q, pi(&["3", "1", "1"]));
+    assert_eq!(r, pi(&["5"]));
+    assert_eq!(((q * &b).unwrap() + r).unwrap(), a);
+
+    assert_eq!(pi(&["1", "1"]).div_rem(&pi(&["1", "2"])), Err(errors::INVALID_FORMAT));
+    assert_eq!(a.div_rem(&pi(&[])), Err(errors::DIV_BY_ZERO));
+
+    let (q, r) = pf(&["1", "0", "1"]).div_rem(&pf(&["0", "2"])).unwrap();
+    assert_eq!(q, pf(&["0", "0.5"]));
+    assert_eq!(r, pf(&["1"]));
+}
+
+#[test]
+fn test_gcd() {
+    // (x - 1)(x + 2) and 6(x - 1)(x - 3)
+    let a = pi(&["-2", "1", "1"]);
+    let b = pi(&["18", "-24", "6"]);
+    assert_eq!(a.gcd(&b), pi(&["-1", "1"]));
+    assert_eq!(pi(&["4", "-6"]).gcd(&pi(&["2", "-4"])), pi(&["2"]));
+    assert_eq!(pi(&["-3", "-3"]).gcd(&pi(&[])), pi(&["3", "3"]));
+
+    let a = pf(&["-2", "1", "1"]);
+    let b = pf(&["3", "-4", "1"]);
+    assert_eq!(a.gcd(&b).unwrap(), pf(&["-1", "1"]));
+}
+
+fn sorted_real(roots: &[Float]) -> Vec<f64> {
+    let mut v: Vec<f64> = roots.iter().map(|r| r.to_f64().unwrap()).collect();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    v
+}
+
+#[test]
+fn test_roots() {
+    let roots = pi(&["6", "-5", "1"]).to_float().unwrap().roots().unwrap();
+    let real = sorted_real(&roots);
+    assert!((real[0] - 2.0).abs() < 1e-12 && (real[1] - 3.0).abs() < 1e-12);
+
+    // x² + 1 has roots ±i
+    let roots = pf(&["1", "0", "1"]).roots().unwrap();
+    assert_eq!(roots.len(), 2);
+    for r in &roots {
+        assert!(r.is_complex());
+        assert!(r.re().to_f64().unwrap().abs() < 1e-12);
+        assert!((r.im().to_f64().unwrap().abs() - 1.0).abs() < 1e-12);
+    }
+
+    // x⁵ - 1: one real root and two conjugate pairs
+    let roots = pf(&["-1", "0", "0", "0", "0", "1"]).roots().unwrap();
+    assert_eq!(roots.len(), 5);
+    assert_eq!(roots.iter().filter(|r| !r.is_complex()).count(), 1);
+
+    // linear and quadratic roots are exact where they can be, and precise where not
+    assert_eq!(pf(&["-0.1", "1"]).roots().unwrap(), vec![create_float("0.1")]);
+    let roots = pf(&["6", "-5", "1"]).roots().unwrap();
+    assert!(roots.iter().all(|r| !r.is_irrational()));
+    assert_eq!(roots, vec![create_float("3"), create_float("2")]);
+    let roots = pf(&["-2", "0", "1"]).roots().unwrap();
+    for (r, s) in roots.iter().zip(["-1.41421356237309504880168872420969807856", "1.41421356237309504880168872420969807856"]) {
+        assert!(r.is_irrational());
+        assert!(r.to_canonical_string().starts_with(s), "{}", r.to_canonical_string());
+    }
+
+    // higher degrees are refined to precision(), and exact roots found exactly
+    let roots = pf(&["-2", "0", "0", "1"]).roots().unwrap();
+    let real = roots.iter().find(|r| !r.is_complex()).unwrap();
+    assert!(real.to_canonical_string().starts_with("1.25992104989487316476721060727822835057"));
+    let roots = pf(&["6", "-11", "6", "-1"]).roots().unwrap();
+    assert_eq!(sorted_real(&roots), vec![1.0, 2.0, 3.0]);
+    assert!(roots.iter().all(|r| !r.is_irrational()));
+
+    assert!(pf(&["3"]).roots().unwrap().is_empty());
+    assert_eq!(pf(&[]).roots(), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_newton() {
+    let p = pf(&["-2", "0", "1"]);
+    let root = p.newton(&create_float("1")).unwrap();
+    assert!((root.to_f64().unwrap() - 2f64.sqrt()).abs() < 1e-15);
+    assert!(root.to_canonical_string().starts_with("1.41421356237309504880168872420969807856"));
+
+    let guess = Float::Complex(Box::new(create_float("0.1")), Box::new(create_float("1")));
+    let root = pf(&["1", "0", "1"]).newton(&guess).unwrap();
+    assert!((root.im().to_f64().unwrap() - 1.0).abs() < 1e-15);
+
+    assert_eq!(p.newton(&create_float("0")), Err(errors::DIV_BY_ZERO));
+}
@@ -32,4 +32,51 @@ fn test_2pow2dot5() {
         expected_start,
         result.to_str()
     );
-}
\ No newline at end of file
+}
+#[test]
+fn test_powi_exact() {
+    use imagnum::{create_complex, create_float, create_int, errors, Float};
+    assert_eq!(create_float("1.5").powi(3), Ok(create_float("3.375")));
+    assert_eq!(create_float("-2").powi(-3), Ok(create_float("-0.125")));
+    assert_eq!(create_float("1.1").pow_int(&create_int("20")).unwrap().to_str(), "6.72749994932560009201");
+    // 1.000001^1000 has 6001 significant digits, all of them kept
+    let big = create_float("1.000001").powi(1000).unwrap();
+    assert!(matches!(big, Float::Big(_)));
+    assert_eq!(big.declared_precision(), Some(6001));
+    assert_eq!(create_float("0").powi(-1), Err(errors::DIV_BY_ZERO));
+    assert_eq!(create_float("-1").pow_int(&create_int("1000000000000000000000001")), Ok(create_float("-1")));
+    assert_eq!(create_float("2").pow_int(&create_int("1000000000000000000000001")), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(Float::NegInfinity.powi(3), Ok(Float::NegInfinity));
+    assert!(Float::NaN.powi(0).is_err());
+    // (1 + i)^2 = 2i, exactly
+    assert_eq!(create_complex("1", "1").powi(2), Ok(create_complex("0", "2")));
+}
+
+#[test]
+fn test_powi_rounded() {
+    use imagnum::{create_float, Float};
+    // past the exact limit: 137 significant digits, no f64 on the way
+    let r = create_float("1.0000001").powi(1_000_000).unwrap();
+    assert!(matches!(r, Float::Irrational(_)));
+    assert!(r.to_str().starts_with("1.10517091254979341663838270934671615934906628"));
+    // an integer-valued exponent goes through pow_int
+    let via_pow = create_float("1.0000001").pow(&create_float("1000000")).unwrap();
+    assert_eq!(via_pow, r);
+    let third = create_float("2").sqrt().unwrap().powi(2).unwrap();
+    assert!((third.to_f64().unwrap() - 2.0).abs() < 1e-15);
+}
+
+#[test]
+fn test_powi_out_of_range() {
+    use imagnum::{create_complex, create_float, create_int, errors};
+    let max = create_int(&u64::MAX.to_string());
+    assert_eq!(create_float("10").pow_int(&max), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(create_float("0.1").pow_int(&max), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(create_float("10").powi(-i64::MAX), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(create_complex("10", "10").pow_int(&max), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(create_complex("0.1", "0.1").pow_int(&max), Err(errors::NUMBER_TOO_LARGE));
+    // large but in range: rounded like a real power instead of multiplied out exactly
+    let r = create_complex("1", "1").powi(1_000_000_000_000).unwrap();
+    assert!(r.to_canonical_string().starts_with("978582864733117186457827213950710587821"));
+    assert_eq!(create_complex("1", "1").powi(8), Ok(create_complex("16", "0")));
+}
@@ -0,0 +1,42 @@
+use imagnum::foundation::{Float, SmallFloat};
+use imagnum::{create_complex, create_float, create_int};
+
+#[test]
+fn int_mul_and_div_pow10() {
+    assert_eq!(create_int("123").mul_pow10(4), create_int("1230000"));
+    assert_eq!(create_int("-7").mul_pow10(30), create_int("-7000000000000000000000000000000"));
+    assert_eq!(create_int("5").mul_pow10(0), create_int("5"));
+    assert_eq!(create_int("123456").div_pow10(3), create_int("123"));
+    assert_eq!(create_int("-123456").div_pow10(3), create_int("-123"));
+    assert_eq!(create_int("999").div_pow10(5), create_int("0"));
+    let big = create_int("98765432109876543210987654321");
+    assert_eq!(big.mul_pow10(12).div_pow10(12), big);
+}
+
+#[test]
+fn shift_decimal_moves_the_point() {
+    assert_eq!(create_float("1.2345").shift_decimal(2).to_string(), "123.45");
+    assert_eq!(create_float("1.2345").shift_decimal(-3).to_string(), "0.0012345");
+    assert_eq!(create_float("-42").shift_decimal(-1), create_float("-4.2"));
+    assert_eq!(create_float("0.5").shift_decimal(0), create_float("0.5"));
+    assert_eq!(create_float("1e-300").shift_decimal(300), create_float("1"));
+}
+
+#[test]
+fn shift_decimal_keeps_the_kind() {
+    let third = create_float("0.(3)");
+    let shifted = third.shift_decimal(1);
+    assert!(shifted.is_recurring());
+    assert_eq!(shifted.to_string(), "3.(3)");
+
+    let root = create_float("2").sqrt().unwrap();
+    assert!(root.shift_decimal(3).is_irrational());
+
+    assert_eq!(create_complex("1.5", "-2").shift_decimal(1), create_complex("15", "-20"));
+    // a small float shifts its exact binary value
+    let small = Float::Small(SmallFloat::F64(0.5)).shift_decimal(1);
+    assert!(matches!(small, Float::Big(_)));
+    assert_eq!(small, create_float("5"));
+    assert!(Float::NaN.shift_decimal(3).is_nan());
+    assert_eq!(Float::NegInfinity.shift_decimal(3), Float::NegInfinity);
+}
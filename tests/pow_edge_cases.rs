@@ -0,0 +1,87 @@
+use imagnum::{create_float, errors, Float};
+
+fn third(n: &str) -> Float {
+    create_float(n)._div(&create_float("3")).unwrap()
+}
+
+#[test]
+fn zero_exponent_gives_one() {
+    let one = create_float("1");
+    assert_eq!(create_float("0")._pow(&create_float("0")).unwrap(), one);
+    assert_eq!(create_float("0.0")._pow(&create_float("0.0")).unwrap(), one);
+    assert_eq!(create_float("-2.5")._pow(&create_float("0")).unwrap(), one);
+    assert_eq!(Float::Infinity._pow(&create_float("0")).unwrap(), one);
+    assert_eq!(Float::NaN._pow(&create_float("0")), Err(errors::INVALID_FORMAT));
+    assert_eq!(create_float("2")._pow(&Float::NaN), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn zero_base() {
+    let zero = create_float("0");
+    assert_eq!(zero._pow(&create_float("3")).unwrap(), zero);
+    assert_eq!(zero._pow(&create_float("0.5")).unwrap(), zero);
+    assert_eq!(zero._pow(&create_float("-1")), Err(errors::DIV_BY_ZERO));
+    assert_eq!(zero._pow(&create_float("-0.5")), Err(errors::DIV_BY_ZERO));
+    assert_eq!(zero._pow(&Float::Infinity).unwrap(), zero);
+    assert_eq!(zero._pow(&Float::NegInfinity).unwrap(), Float::Infinity);
+}
+
+#[test]
+fn infinite_operands() {
+    let zero = create_float("0");
+    assert_eq!(Float::Infinity._pow(&create_float("0.5")).unwrap(), Float::Infinity);
+    assert_eq!(Float::Infinity._pow(&create_float("-0.5")).unwrap(), zero);
+    assert_eq!(Float::NegInfinity._pow(&create_float("3")).unwrap(), Float::NegInfinity);
+    assert_eq!(Float::NegInfinity._pow(&create_float("-3")).unwrap(), zero);
+
+    assert_eq!(create_float("2")._pow(&Float::Infinity).unwrap(), Float::Infinity);
+    assert_eq!(create_float("-2")._pow(&Float::Infinity).unwrap(), Float::Infinity);
+    assert_eq!(create_float("0.5")._pow(&Float::Infinity).unwrap(), zero);
+    assert_eq!(create_float("1")._pow(&Float::Infinity).unwrap(), create_float("1"));
+    assert_eq!(create_float("-1")._pow(&Float::NegInfinity).unwrap(), create_float("1"));
+    assert_eq!(create_float("2")._pow(&Float::NegInfinity).unwrap(), zero);
+    assert_eq!(create_float("0.5")._pow(&Float::NegInfinity).unwrap(), Float::Infinity);
+}
+
+#[test]
+fn negative_base_odd_denominator_is_real() {
+    assert_eq!(create_float("-8")._pow(&third("1")).unwrap(), create_float("-2"));
+    assert_eq!(create_float("-8")._pow(&third("2")).unwrap(), create_float("4"));
+    assert_eq!(create_float("-8")._pow(&third("-1")).unwrap(), create_float("-0.5"));
+    assert_eq!(create_float("-32")._pow(&create_float("0.2")).unwrap(), create_float("-2"));
+    assert_eq!(create_float("-32")._pow(&create_float("0.4")).unwrap(), create_float("4"));
+}
+
+#[test]
+fn negative_base_even_denominator_is_complex() {
+    let zero = create_float("0");
+    let minus_four = create_float("-4");
+    assert_eq!(minus_four._pow(&create_float("0.5")).unwrap(), Float::complex(zero.clone(), create_float("2")));
+    assert_eq!(minus_four._pow(&create_float("1.5")).unwrap(), Float::complex(zero.clone(), create_float("-8")));
+    assert_eq!(minus_four._pow(&create_float("-0.5")).unwrap(), Float::complex(zero.clone(), create_float("-0.5")));
+    assert_eq!(minus_four.sqrt().unwrap(), minus_four._pow(&create_float("0.5")).unwrap());
+
+    // (-16)^(1/4) = √2 + √2·i, the principal root
+    let Float::Complex(re, im) = create_float("-16")._pow(&create_float("0.25")).unwrap() else {
+        panic!("expected a complex result");
+    };
+    let sqrt2 = create_float("2").sqrt().unwrap();
+    assert!(re._sub(&sqrt2).unwrap().abs() < create_float("1e-12"));
+    assert!(im._sub(&sqrt2).unwrap().abs() < create_float("1e-12"));
+
+    assert!(create_float("-2")._pow(&create_float("3.14159")).unwrap().is_complex());
+}
+
+#[test]
+fn negative_base_square_roots_follow_sqrt() {
+    let minus_eight = create_float("-8");
+    let r = minus_eight.pow(&create_float("0.5")).unwrap();
+    assert_eq!(r, minus_eight.sqrt().unwrap());
+    assert_eq!(Float::from(-8.0)._pow(&Float::from(0.5)).unwrap(), r);
+    // (2√2·i)^3 = -16√2·i
+    let Float::Complex(re, im) = minus_eight._pow(&create_float("1.5")).unwrap() else {
+        panic!("expected a complex result");
+    };
+    assert!(re.is_zero());
+    assert!(im._add(&create_float("22.627416997969520780")).unwrap().abs() < create_float("1e-12"));
+}
@@ -0,0 +1,32 @@
+use imagnum::{create_float, create_int, errors, Float};
+
+#[test]
+fn non_negative_exponents_match_pow() {
+    assert_eq!(create_int("2").pow_signed(&create_int("10")).unwrap(), create_float("1024"));
+    assert_eq!(create_int("-3").pow_signed(&create_int("3")).unwrap(), create_float("-27"));
+    assert_eq!(create_int("0").pow_signed(&create_int("0")).unwrap(), create_float("1"));
+}
+
+#[test]
+fn negative_exponents_give_exact_reciprocals() {
+    let r = create_int("2").pow_signed(&create_int("-3")).unwrap();
+    assert!(matches!(r, Float::Big(_)));
+    assert_eq!(r, create_float("0.125"));
+
+    let r = create_int("3").pow_signed(&create_int("-2")).unwrap();
+    assert!(r.is_recurring());
+    assert_eq!(r.to_string(), "0.(1)");
+
+    assert_eq!(create_int("-2").pow_signed(&create_int("-1")).unwrap(), create_float("-0.5"));
+    assert_eq!(create_int("-2").pow_signed(&create_int("-2")).unwrap(), create_float("0.25"));
+    assert_eq!(create_int("10").pow_signed(&create_int("-40")).unwrap(), create_float("1e-40"));
+    // the reciprocal times the power is exactly one
+    let seven = create_int("7").pow_signed(&create_int("-3")).unwrap();
+    assert_eq!(seven._mul(&create_float("343")).unwrap(), create_float("1"));
+}
+
+#[test]
+fn zero_to_a_negative_power_fails() {
+    assert_eq!(create_int("0").pow_signed(&create_int("-1")), Err(errors::DIV_BY_ZERO));
+    assert_eq!(create_int("2").pow(&create_int("-1")), Err(errors::INVALID_FORMAT));
+}
@@ -0,0 +1,39 @@
+use imagnum::config::{precision, set_precision, DEFAULT_PRECISION, MAX_PRECISION};
+use imagnum::{create_float, Float};
+
+fn significant_digits(f: &Float) -> u64 {
+    match f {
+        Float::Irrational(bd) => bd.digits(),
+        _ => panic!("expected an irrational result, got {:?}", f),
+    }
+}
+
+// one test, since the setting is process-wide
+#[test]
+fn precision_context_controls_rounded_results() {
+    assert_eq!(precision(), DEFAULT_PRECISION);
+    let one = create_float("1");
+    assert_eq!(significant_digits(&one.hypot(&one).unwrap()), DEFAULT_PRECISION);
+
+    set_precision(40);
+    assert_eq!(precision(), 40);
+    let root2 = one.hypot(&one).unwrap();
+    // 1.414213562373095048801688724209698078569(67...) rounds up to a trailing zero
+    assert_eq!(significant_digits(&root2), 39);
+    assert!(root2.to_str().starts_with("1.41421356237309504880168872420969807857"));
+    assert_eq!(significant_digits(&create_float("0.001").exp_m1().unwrap()), 40);
+    assert_eq!(significant_digits(&root2.powi(3).unwrap()), 40);
+    // Γ(1/2) = √π
+    let gamma = create_float("0.5").gamma().unwrap();
+    assert_eq!(gamma.to_str(), "1.772453850905516027298167483341145182798");
+    // exact results are not rounded
+    assert_eq!(create_float("1.5").powi(20).unwrap(), create_float("3325.256730079650878906250"));
+
+    set_precision(0);
+    assert_eq!(precision(), 1);
+    set_precision(u64::MAX);
+    assert_eq!(precision(), MAX_PRECISION);
+
+    set_precision(DEFAULT_PRECISION);
+    assert_eq!(significant_digits(&one.hypot(&one).unwrap()), DEFAULT_PRECISION);
+}
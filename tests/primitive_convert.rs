@@ -0,0 +1,122 @@
+use bigdecimal::BigDecimal;
+use imagnum::format::{FormatOptions, Notation};
+use imagnum::{create_float, create_int, errors, Float, Int};
+use std::str::FromStr;
+
+#[test]
+fn int_to_unsigned() {
+    assert_eq!(u64::try_from(create_int("18446744073709551615")), Ok(u64::MAX));
+    assert_eq!(u64::try_from(create_int("18446744073709551616")), Err(errors::INVALID_FORMAT));
+    assert_eq!(u8::try_from(&create_int("-1")), Err(errors::NEGATIVE_RESULT));
+    assert_eq!(u128::try_from(Int::from(u128::MAX)), Ok(u128::MAX));
+    assert_eq!(create_int("300").to_u16(), Ok(300));
+    let small: Result<u32, i8> = create_int("7").try_into();
+    assert_eq!(small, Ok(7));
+}
+
+#[test]
+fn int_to_signed_and_float() {
+    assert_eq!(i8::try_from(create_int("-128")), Ok(-128));
+    assert_eq!(i8::try_from(create_int("128")), Err(errors::INVALID_FORMAT));
+    assert_eq!(i128::try_from(Int::from(i128::MIN)), Ok(i128::MIN));
+    assert_eq!(f64::try_from(create_int("-12345")), Ok(-12345.0));
+    assert_eq!(create_int("16777217").to_f32(), Ok(16777216.0));
+    let huge = create_int(&format!("1{}", "0".repeat(400)));
+    assert_eq!(f64::try_from(&huge), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn float_to_primitives() {
+    assert_eq!(i32::try_from(create_float("-42.0")), Ok(-42));
+    assert_eq!(u64::try_from(&create_float("1e3")), Ok(1000));
+    assert_eq!(i32::try_from(create_float("2.5")), Err(errors::INVALID_FORMAT));
+    assert_eq!(u8::try_from(create_float("-3")), Err(errors::NEGATIVE_RESULT));
+    assert_eq!(i64::try_from(Float::Infinity), Err(errors::INFINITE_RESULT));
+    assert_eq!(f32::try_from(create_float("0.5")), Ok(0.5));
+    assert_eq!(create_float("1.25").to_f32(), Ok(1.25));
+}
+
+#[test]
+fn primitives_to_float_and_int() {
+    assert_eq!(Float::from(7u8), create_float("7"));
+    assert_eq!(Float::from(-3i64), create_float("-3"));
+    assert_eq!(Float::from(u128::MAX).to_u128(), Ok(u128::MAX));
+    assert_eq!(Int::try_from(3.0f64), Ok(create_int("3")));
+    assert_eq!(Int::try_from(-2.0f32), Ok(create_int("-2")));
+    assert_eq!(Int::try_from(0.5f64), Err(errors::INVALID_FORMAT));
+    assert_eq!(Int::try_from(f64::NAN), Err(errors::INVALID_FORMAT));
+    assert_eq!(Int::try_from(f64::INFINITY), Err(errors::INFINITE_RESULT));
+}
+
+#[test]
+fn float_to_f64_rounds_to_nearest_even() {
+    let cases = [
+        "9007199254740993",
+        "9007199254740993.0000000000000000000000000001",
+        "9007199254740995",
+        "1.7976931348623157e308",
+        "1.7976931348623158e308",
+        "1.797693134862315807e308",
+        "4.9406564584124654e-324",
+        "2.4703282292062327e-324",
+        "2.4703282292062328e-324",
+        "2.2250738585072011e-308",
+        "-0.1",
+        "1e-400",
+        "-1e-400",
+        "1e400",
+        "-1e400",
+        "123456789012345678901234567890e-40",
+    ];
+    for s in cases {
+        let expected: f64 = s.parse().unwrap();
+        let got = create_float(s).to_f64().unwrap();
+        assert_eq!(got.to_bits(), expected.to_bits(), "{s}");
+        let expected: f32 = s.parse().unwrap();
+        let got = create_float(s).to_f32().unwrap();
+        assert_eq!(got.to_bits(), expected.to_bits(), "{s} as f32");
+    }
+}
+
+#[test]
+fn recurring_and_irrational_to_f64() {
+    assert_eq!(create_float("0.(3)").to_f64(), Ok(1.0 / 3.0));
+    assert_eq!(create_float("-2.1(6)").to_f64(), Ok(-13.0 / 6.0));
+    assert_eq!(create_float("0.(142857)").to_f32(), Ok(1.0f32 / 7.0));
+    let root = create_float("2").sqrt().unwrap();
+    assert_eq!(root.to_f64(), Ok(2f64.sqrt()));
+    assert_eq!(Float::Irrational(BigDecimal::from_str("1e999999999").unwrap()).to_f64(), Ok(f64::INFINITY));
+    assert_eq!(Float::Irrational(BigDecimal::from_str("-1e-999999999").unwrap()).to_f64(), Ok(-0.0));
+}
+
+#[test]
+fn from_f64_exact_and_shortest() {
+    assert_eq!(Float::from_f64_shortest(0.1).to_string(), "0.1");
+    let fixed = |f: Float| f.format(&FormatOptions::new().notation(Notation::Fixed));
+    assert_eq!(
+        fixed(Float::from_f64_exact(0.1)),
+        "0.1000000000000000055511151231257827021181583404541015625"
+    );
+    assert_eq!(fixed(Float::from_f32_exact(0.1)), "0.100000001490116119384765625");
+    assert_eq!(fixed(Float::from_f64_exact(-2.5)), "-2.5");
+    assert_eq!(Float::from_f64_exact(1e22), create_float("1e22"));
+    assert_eq!(Float::from_f64_exact(2f64.powi(70)), create_float("1180591620717411303424"));
+    assert_eq!(Float::from_f64_exact(0.0), create_float("0"));
+    assert!(Float::from_f64_exact(f64::NAN).is_nan());
+    assert!(matches!(Float::from_f64_exact(f64::NEG_INFINITY), Float::NegInfinity));
+    for v in [0.1, -1.0 / 3.0, f64::MAX, f64::MIN_POSITIVE, f64::from_bits(1), 123.456e-300] {
+        assert_eq!(Float::from_f64_exact(v).to_f64(), Ok(v));
+    }
+}
+
+#[test]
+fn primitives_to_int_and_interned() {
+    assert_eq!(Int::from(u128::MAX), create_int("340282366920938463463374607431768211455"));
+    assert_eq!(Int::from(i64::MIN), create_int("-9223372036854775808"));
+    assert_eq!(Int::from(-7i8), create_int("-7"));
+
+    for v in [i8::MIN, -1, 0, 1, 42, i8::MAX] {
+        assert_eq!(*Int::interned(v), Int::from(v));
+    }
+    assert!(std::ptr::eq(Int::interned(-3), Int::interned(-3)));
+}
@@ -0,0 +1,122 @@
+//! Algebraic invariants over random values of every kind, by `proptest`.
+
+use arbitrary::{Arbitrary, Unstructured};
+use imagnum::{create_complex, create_float, create_int, ApproxEq, Float, Int};
+use proptest::prelude::*;
+
+fn int() -> impl Strategy<Value = Int> {
+    prop_oneof![
+        any::<i64>().prop_map(Int::from),
+        "-?[1-9][0-9]{0,80}".prop_map(|s| create_int(&s)),
+    ]
+}
+
+/// Finite decimal floats: `Big` with any exponent and `Recurring`.
+fn float() -> impl Strategy<Value = Float> {
+    prop_oneof![
+        "-?[0-9]{1,30}\\.[0-9]{1,30}(e-?[0-9]{1,2})?".prop_map(|s| create_float(&s)),
+        "-?[0-9]{1,4}\\.[0-9]{0,3}\\([0-9]{1,3}\\)".prop_map(|s| create_float(&s)),
+    ]
+}
+
+/// `Small` floats, whose arithmetic is `f64` arithmetic.
+fn small_float() -> impl Strategy<Value = Float> {
+    (-1e12..1e12f64).prop_map(Float::from)
+}
+
+fn complex() -> impl Strategy<Value = Float> {
+    ("-?[0-9]{1,6}\\.[0-9]{1,6}", "-?[0-9]{1,6}\\.[0-9]{1,6}").prop_map(|(re, im)| create_complex(&re, &im))
+}
+
+fn zero() -> Float {
+    create_float("0")
+}
+
+/// Relative tolerance for results that may be rounded to `precision()` digits.
+fn tol() -> Float {
+    create_float("1e-100")
+}
+
+proptest! {
+    #[test]
+    fn int_ring_laws(a in int(), b in int(), c in int()) {
+        prop_assert_eq!(a._add(&b).unwrap(), b._add(&a).unwrap());
+        prop_assert_eq!(a._mul(&b).unwrap(), b._mul(&a).unwrap());
+        prop_assert_eq!(a._add(&b).unwrap()._add(&c).unwrap(), a._add(&b._add(&c).unwrap()).unwrap());
+        prop_assert_eq!(a._mul(&b).unwrap()._mul(&c).unwrap(), a._mul(&b._mul(&c).unwrap()).unwrap());
+        prop_assert_eq!(a._mul(&b._add(&c).unwrap()).unwrap(), a._mul(&b).unwrap()._add(&a._mul(&c).unwrap()).unwrap());
+        prop_assert!(a._sub(&a).unwrap().is_zero());
+    }
+
+    #[test]
+    fn int_division_identity(a in int(), b in int()) {
+        prop_assume!(!b.is_zero());
+        let q = a.div_floor(&b).unwrap();
+        let r = a._sub(&q._mul(&b).unwrap()).unwrap();
+        prop_assert!(r.abs() < b.abs());
+        prop_assert!(r.is_zero() || r.is_negative() == b.is_negative());
+    }
+
+    #[test]
+    fn int_parse_format_round_trip(a in int()) {
+        prop_assert_eq!(create_int(&a.to_string()), a.clone());
+        prop_assert_eq!(Int::from_str(&a.to_string()).unwrap(), a);
+    }
+
+    #[test]
+    fn float_field_laws(a in float(), b in float(), c in float()) {
+        prop_assert_eq!(a._add(&b).unwrap(), b._add(&a).unwrap());
+        prop_assert_eq!(a._mul(&b).unwrap(), b._mul(&a).unwrap());
+        let left = a._add(&b).unwrap()._add(&c).unwrap();
+        let right = a._add(&b._add(&c).unwrap()).unwrap();
+        prop_assert!(left.approx_eq_rel(&right, &tol(), &tol()), "{} != {}", left, right);
+        let left = a._mul(&b).unwrap()._mul(&c).unwrap();
+        let right = a._mul(&b._mul(&c).unwrap()).unwrap();
+        prop_assert!(left.approx_eq_rel(&right, &tol(), &zero()), "{} != {}", left, right);
+        prop_assert!(a._sub(&a).unwrap().is_zero());
+    }
+
+    #[test]
+    fn small_float_laws(a in small_float(), b in small_float(), c in small_float()) {
+        let tol = create_float("1e-12");
+        prop_assert_eq!(a._add(&b).unwrap(), b._add(&a).unwrap());
+        prop_assert_eq!(a._mul(&b).unwrap(), b._mul(&a).unwrap());
+        let left = a._add(&b).unwrap()._add(&c).unwrap();
+        let right = a._add(&b._add(&c).unwrap()).unwrap();
+        prop_assert!(left.approx_eq_rel(&right, &tol, &create_float("1e-3")), "{} != {}", left, right);
+        let left = a._mul(&b).unwrap()._mul(&c).unwrap();
+        let right = a._mul(&b._mul(&c).unwrap()).unwrap();
+        prop_assert!(left.approx_eq_rel(&right, &tol, &zero()), "{} != {}", left, right);
+        prop_assert!(a._sub(&a).unwrap().is_zero());
+    }
+
+    #[test]
+    fn float_parse_format_round_trip(a in float()) {
+        prop_assert_eq!(create_float(&a.to_string()), a.clone());
+        prop_assert_eq!(Float::from_str(&a.to_string()).unwrap(), a);
+    }
+
+    #[test]
+    fn complex_laws(a in complex(), b in complex()) {
+        prop_assert_eq!(a._add(&b).unwrap(), b._add(&a).unwrap());
+        prop_assert_eq!(a._mul(&b).unwrap(), b._mul(&a).unwrap());
+        prop_assert!(a._sub(&a).unwrap().is_zero());
+    }
+
+    /// The `arbitrary` instances build sound values: NaN is the only value unequal to itself,
+    /// and everything displays and subtracts without panicking.
+    #[test]
+    fn arbitrary_values_are_well_formed(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let mut u = Unstructured::new(&bytes);
+        let (Ok(i), Ok(f)) = (Int::arbitrary(&mut u), Float::arbitrary(&mut u)) else {
+            return Ok(());
+        };
+        prop_assert_eq!(&i, &i);
+        prop_assert!(i._sub(&i).unwrap().is_zero());
+        prop_assert_eq!(f == f, !f.is_nan());
+        let _ = f.to_string();
+        if let Ok(d) = f._sub(&f) {
+            prop_assert!(d.is_nan() || d.is_zero());
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use imagnum::{create_float, errors, Float, Quaternion};
+
+fn q(w: &str, x: &str, y: &str, z: &str) -> Quaternion {
+    Quaternion::new(create_float(w), create_float(x), create_float(y), create_float(z))
+}
+
+fn close(a: &Quaternion, b: &Quaternion) -> bool {
+    let d = (a.clone() - b).unwrap().norm_sqr().unwrap();
+    d.to_f64().unwrap() < 1e-20
+}
+
+#[test]
+fn test_hamilton_product() {
+    let i = q("0", "1", "0", "0");
+    let j = q("0", "0", "1", "0");
+    let k = q("0", "0", "0", "1");
+    let minus_one = q("-1", "0", "0", "0");
+    assert_eq!((i.clone() * &i).unwrap(), minus_one);
+    assert_eq!((i.clone() * &j).unwrap(), k);
+    assert_eq!((j.clone() * &k).unwrap(), i);
+    assert_eq!((j.clone() * &i).unwrap(), -k.clone());
+
+    let a = q("1", "2", "3", "4");
+    let b = q("5", "6", "7", "8");
+    assert_eq!(a.clone() * &b, Ok(q("-60", "12", "30", "24")));
+    assert_eq!(a.clone() + b, Ok(q("6", "8", "10", "12")));
+    assert_eq!(a.to_string(), "1.0 + 2.0i + 3.0j + 4.0k");
+    assert_eq!(a.conj().to_string(), "1.0 - 2.0i - 3.0j - 4.0k");
+}
+
+#[test]
+fn test_norm_and_inverse() {
+    let a = q("1", "2", "3", "4");
+    assert_eq!(a.norm_sqr(), Ok(create_float("30")));
+    assert_eq!(q("1", "1", "1", "1").norm(), Ok(create_float("2")));
+
+    let b = q("1", "-1", "1", "1");
+    let inv = b.inverse().unwrap();
+    assert_eq!(inv, q("0.25", "0.25", "-0.25", "-0.25"));
+    assert!(close(&(b.clone() * &inv).unwrap(), &Quaternion::identity()));
+    assert!(close(&(inv * &b).unwrap(), &Quaternion::identity()));
+    assert_eq!(Quaternion::new(Float::new(), Float::new(), Float::new(), Float::new()).inverse(), Err(errors::DIV_BY_ZERO));
+
+    let unit = q("0", "3", "0", "4").normalize().unwrap();
+    assert_eq!(unit, q("0", "0.6", "0", "0.8"));
+}
+
+#[test]
+fn test_slerp() {
+    let a = Quaternion::identity();
+    // 90 degrees about z
+    let half = create_float("0.5").sqrt().unwrap();
+    let b = Quaternion::new(half.clone(), create_float("0"), create_float("0"), half);
+
+    assert!(close(&a.slerp(&b, &create_float("0")).unwrap(), &a));
+    assert!(close(&a.slerp(&b, &create_float("1")).unwrap(), &b));
+
+    // halfway is 45 degrees: cos(22.5°) + sin(22.5°)k
+    let mid = a.slerp(&b, &create_float("0.5")).unwrap();
+    let angle = std::f64::consts::PI / 8.0;
+    assert!((mid.w.to_f64().unwrap() - angle.cos()).abs() < 1e-10);
+    assert!((mid.z.to_f64().unwrap() - angle.sin()).abs() < 1e-10);
+    assert!((mid.norm().unwrap().to_f64().unwrap() - 1.0).abs() < 1e-10);
+
+    // opposite signs describe the same rotation; slerp takes the short way
+    let short = a.slerp(&-b.clone(), &create_float("0.5")).unwrap();
+    assert!(close(&short, &mid) || close(&-short, &mid));
+}
+
+#[test]
+fn slerp_keeps_the_working_precision() {
+    // a third of the way from 1 to k is a 30 degree turn: cos(15°) + sin(15°)k
+    let a = Quaternion::identity();
+    let half = create_float("0.5").sqrt().unwrap();
+    let b = Quaternion::new(half.clone(), create_float("0"), create_float("0"), half);
+    let third = create_float("1")._div(&create_float("3")).unwrap();
+    let mid = a.slerp(&b, &third).unwrap();
+
+    let angle = Float::Irrational(imagnum::consts::pi(60))._div(&create_float("12")).unwrap();
+    let tolerance = create_float("1e-40");
+    assert!(mid.w._sub(&angle.cos().unwrap()).unwrap().abs() < tolerance);
+    assert!(mid.z._sub(&angle.sin().unwrap()).unwrap().abs() < tolerance);
+}
@@ -1,4 +1,6 @@
-use imagnum::Int;
+use imagnum::foundation::{SmallFloat, SmallInt};
+use imagnum::{create_float, create_int, Float, Int};
+use num_bigint::BigInt;
 
 #[test]
 fn test_radix_binary_and_octal() {
@@ -37,3 +39,112 @@ fn test_radix_with_underscores() {
     let i = Int::from_str_radix("DE_AD_BE_EF", 16).unwrap();
     assert_eq!(i.to_str_radix(16).unwrap().to_lowercase(), "deadbeef");
 }
+
+#[test]
+fn test_int_radix_formatting_beyond_i128() {
+    let big = create_int("123456789012345678901234567890123456789012345");
+    let expected = BigInt::parse_bytes(b"123456789012345678901234567890123456789012345", 10).unwrap();
+    assert_eq!(format!("{:x}", big), format!("{:x}", expected));
+    assert_eq!(format!("{:#b}", big), format!("{:#b}", expected));
+    assert_eq!(format!("{:o}", create_int("-8")), "-10");
+    assert_eq!(format!("{:#06X}", create_int("255")), "0x00FF");
+}
+
+#[test]
+fn test_int_radix_formatter_flags() {
+    let n = create_int("-255");
+    assert_eq!(format!("{:*^12x}", n), "****-ff*****");
+    assert_eq!(format!("{:<8o}|", n), "-377    |");
+    assert_eq!(format!("{:#012b}", n), "-0b011111111");
+    assert_eq!(format!("{:+x}", create_int("255")), "+ff");
+    let huge = create_int(&format!("-{}", "9".repeat(60)));
+    assert_eq!(format!("{:X}", huge), format!("{:X}", BigInt::parse_bytes(format!("-{}", "9".repeat(60)).as_bytes(), 10).unwrap()));
+    assert_eq!(format!("{:#x}", Int::Small(SmallInt::U8(200))), "0xc8");
+    assert_eq!(format!("{:b}", Int::Small(SmallInt::I8(-5))), "-101");
+}
+
+#[test]
+fn test_float_radix_formatting() {
+    let f = create_float;
+    assert_eq!(format!("{:x}", f("12")), "1.8p+3");
+    assert_eq!(format!("{:#X}", f("12.5")), "0x1.9P+3");
+    assert_eq!(format!("{:x}", f("-0.75")), "-1.8p-1");
+    assert_eq!(format!("{:x}", f("0")), "0p+0");
+    assert_eq!(format!("{:x}", f("255.99609375")), "1.fffep+7");
+    // a precision rounds half-even, carrying into the exponent
+    assert_eq!(format!("{:.3x}", f("0.1")), "1.99ap-4");
+    assert_eq!(format!("{:.0x}", f("1.96875")), "1p+1");
+    assert_eq!(format!("{:>8x}", f("1")), "    1p+0");
+    // small floats show their exact binary value
+    assert_eq!(format!("{:x}", Float::Small(SmallFloat::F64(0.1))), "1.999999999999ap-4");
+
+    assert_eq!(format!("{:b}", f("12.5")), "1100.1");
+    assert_eq!(format!("{:#o}", f("12.5")), "0o14.4");
+    assert_eq!(format!("{:.4b}", f("-0.75")), "-0.1100");
+    assert_eq!(format!("{:.6b}", f("0.(3)")), "0.010101");
+    assert_eq!(format!("{:b}", f("3+4.5i")), "11.0 + 100.1i");
+    assert_eq!(format!("{:x}", Float::NaN), "NaN");
+}
+
+#[test]
+fn test_hex_float_parsing() {
+    let f = create_float;
+    assert_eq!(f("0x1.8p3"), f("12"));
+    assert_eq!(f("-0x.4p-2"), f("-0.0625"));
+    assert_eq!(f("0X1F"), f("31"));
+    assert_eq!(f("0x1.Fp+1"), f("3.875"));
+    assert_eq!(f("0x1p-1074").to_f64().unwrap(), f64::from_bits(1));
+    for bad in ["0xp1", "0x1.8p", "0x1g", "0x-1", "0x1p999999999"] {
+        assert!(f(bad).is_nan(), "{} should not parse", bad);
+    }
+    // dyadic values round-trip exactly
+    for s in ["12.5", "-0.0625", "1048576.125", "0.0009765625"] {
+        assert_eq!(f(&format!("{:#x}", f(s))), f(s));
+    }
+}
+
+#[test]
+fn test_radix_above_36() {
+    let i = Int::from_str_radix("zZ", 62).unwrap();
+    assert_eq!(i, create_int("3817"));
+    assert_eq!(i.to_str_radix(62).unwrap(), "zZ");
+    // case matters above 36: 'Z' is 35, 'z' is 61
+    assert_eq!(Int::from_str_radix("Z", 62).unwrap(), create_int("35"));
+    assert_eq!(Int::from_str_radix("z", 62).unwrap(), create_int("61"));
+    assert_eq!(Int::from_str_radix("-1_0", 37).unwrap(), create_int("-37"));
+    assert!(Int::from_str_radix("b", 37).is_err());
+    assert!(Int::from_str_radix("1", 63).is_err());
+    assert!(create_int("1").to_str_radix(63).is_err());
+    assert!(Int::from_str_radix("_", 62).is_err());
+
+    let big = create_int(&format!("-{}", "9876543210".repeat(20)));
+    for radix in 2..=62 {
+        let s = big.to_str_radix(radix).unwrap();
+        assert_eq!(Int::from_str_radix(&s, radix).unwrap(), big, "radix {}", radix);
+    }
+    assert_eq!(create_int("0").to_str_radix(62).unwrap(), "0");
+}
+
+#[test]
+fn test_custom_alphabet() {
+    let base64url = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    for s in ["0", "63", "64", "4096", "123456789012345678901234567890"] {
+        let n = create_int(s);
+        let text = n.to_str_alphabet(base64url).unwrap();
+        assert_eq!(Int::from_str_alphabet(&text, base64url).unwrap(), n, "{}", s);
+    }
+    assert_eq!(create_int("64").to_str_alphabet(base64url).unwrap(), "BA");
+    // '-' is a digit here, so it can't be a sign
+    assert_eq!(Int::from_str_alphabet("-", base64url).unwrap(), create_int("62"));
+    assert!(create_int("-1").to_str_alphabet(base64url).is_err());
+
+    let binary = "ox";
+    assert_eq!(create_int("-6").to_str_alphabet(binary).unwrap(), "-xxo");
+    assert_eq!(Int::from_str_alphabet("-xxo", binary).unwrap(), create_int("-6"));
+    assert_eq!(Int::from_str_alphabet("αβ", "αβγ").unwrap(), create_int("1"));
+
+    assert!(Int::from_str_alphabet("12", "0").is_err());
+    assert!(Int::from_str_alphabet("12", "0121").is_err());
+    assert!(Int::from_str_alphabet("", "01").is_err());
+    assert!(Int::from_str_alphabet("2", "01").is_err());
+}
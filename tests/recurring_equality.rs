@@ -1,4 +1,4 @@
-use imagnum::{create_float, create_int};
+use imagnum::{create_float, create_int, Float};
 use std::ops::{Div, Mul};
 
 #[test]
@@ -45,4 +45,76 @@ fn one_divided_by_three() {
 
     let c = a.mul(&create_float("3.0")).expect("Multiplication by 3 failed");
     assert_eq!(c, create_float("1.0"), "0.(3) * 3 should equal 1");
-}
\ No newline at end of file
+}
+
+#[test]
+fn recurring_arithmetic_is_exact() {
+    let f = create_float;
+    let sum = f("0.(3)")._add(&f("0.(6)")).unwrap();
+    assert_eq!(sum, f("1"));
+    assert!(matches!(sum, Float::Big(_)), "0.(3) + 0.(6) should terminate");
+    assert_eq!(f("0.(3)")._mul(&f("0.(3)")).unwrap(), f("0.(1)"));
+    assert_eq!(f("0.1(6)")._sub(&f("0.(1)")).unwrap(), f("0.0(5)"));
+    assert_eq!(f("0.(3)")._div(&f("0.(6)")).unwrap(), f("0.5"));
+    assert_eq!(f("0.(142857)")._mul(&f("7")).unwrap(), f("1"));
+    assert_eq!(f("-0.(3)")._add(&f("0.5")).unwrap(), f("0.1(6)"));
+    assert_eq!(f("0.(3)").pow_int(&create_int("3")).unwrap(), f("0.(037)"));
+    assert_eq!(f("0.(3)").pow_int(&create_int("-2")).unwrap(), f("9"));
+    // past MAX_DIGITS either way the power is rounded instead
+    assert!(matches!(f("0.(3)").powi(1_000_000_000_000).unwrap(), Float::Irrational(_)));
+    let wide = f(&format!("1{}.(3)", "0".repeat(50)));
+    assert!(matches!(wide.powi(10_000).unwrap(), Float::Irrational(_)));
+    assert_eq!(f("0.(3)")._div(&f("0")), Err(imagnum::errors::DIV_BY_ZERO));
+}
+
+#[test]
+fn recurring_to_ratio() {
+    let ratio = |s: &str| create_float(s).to_ratio().unwrap();
+    assert_eq!(ratio("0.(3)"), (create_int("1"), create_int("3")));
+    assert_eq!(ratio("-1.1(6)"), (create_int("-7"), create_int("6")));
+    assert_eq!(ratio("2.5"), (create_int("5"), create_int("2")));
+    // a shorter period in the last digits is not mistaken for the repetend
+    assert_eq!(ratio("0.(21111)"), (create_int("7037"), create_int("33333")));
+    assert_eq!(create_float("1").div(&create_float("4")).unwrap(), create_float("0.25"));
+    assert!(create_float("2").sqrt().unwrap().to_ratio().is_none());
+}
+
+#[test]
+fn recurring_parts_round_trip() {
+    let parts = |s: &str| create_float(s).recurring_parts().unwrap();
+    let owned = |i: &str, n: &str, r: &str| (i.to_string(), n.to_string(), r.to_string());
+    assert_eq!(parts("0.(3)"), owned("0", "", "3"));
+    assert_eq!(parts("-1.1(6)"), owned("-1", "1", "6"));
+    assert_eq!(parts("-0.(142857)"), owned("-0", "", "142857"));
+    assert_eq!(parts("0.(21111)"), owned("0", "", "21111"));
+    assert_eq!(create_float("0.(21111)").to_string(), "0.(21111)");
+    assert_eq!(create_float("0.5").recurring_parts(), None);
+
+    for s in ["0.(3)", "-1.1(6)", "12.34(56)", "0.00(714285)"] {
+        let (i, n, r) = parts(s);
+        assert_eq!(Float::from_recurring_parts(&i, &n, &r).unwrap(), create_float(s));
+        assert_eq!(create_float(s).to_string(), s);
+    }
+    assert_eq!(Float::from_recurring_parts("1", "25", "").unwrap(), create_float("1.25"));
+    assert_eq!(Float::from_recurring_parts("+2", "", "9").unwrap(), create_float("3"));
+    assert_eq!(Float::from_recurring_parts("1", "2x", "3"), Err(imagnum::errors::INVALID_FORMAT));
+}
+
+#[test]
+fn recurring_values_are_exact_fractions() {
+    let frac = |n: &str, d: &str| Float::from_fraction(&create_int(n), &create_int(d)).unwrap();
+    // a repetend of 10 006 digits still multiplies back exactly
+    let back = frac("1", "10007").mul(&create_float("10007")).unwrap();
+    assert_eq!(back, create_float("1"));
+    assert!(matches!(back, Float::Big(_)));
+    assert!(frac("1", "10007").to_string().starts_with("0.0000999300489657"));
+
+    assert_ne!(create_float("0.(3)"), create_float("0.3333333333"));
+    assert!(create_float("0.(3)") > create_float("0.3333333333"));
+    assert_eq!(frac("2", "6"), create_float("0.(3)"));
+    assert_eq!(frac("3", "-9").to_ratio(), Some((create_int("-1"), create_int("3"))));
+
+    for f in [frac("1", "7"), frac("-7", "6"), frac("1", "10007")] {
+        assert_eq!(Float::from_bytes(&f.to_bytes()).unwrap(), f);
+    }
+}
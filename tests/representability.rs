@@ -0,0 +1,36 @@
+use imagnum::{Float, create_float};
+
+#[test]
+fn exact_f64() {
+    assert!(create_float("0.5").is_exact_f64());
+    assert!(create_float("-1234.125").is_exact_f64());
+    assert!(create_float("9007199254740992").is_exact_f64());
+    assert!(!create_float("9007199254740993").is_exact_f64());
+    assert!(!create_float("0.1").is_exact_f64());
+    assert!(create_float("0.1000000000000000055511151231257827021181583404541015625").is_exact_f64());
+    assert!(!create_float("1e400").is_exact_f64());
+    assert!(Float::new_small(0.1f64).is_exact_f64());
+    assert!(Float::Infinity.is_exact_f64());
+    assert!(!Float::complex(create_float("1"), create_float("0")).is_exact_f64());
+}
+
+#[test]
+fn exact_f32() {
+    assert!(create_float("0.75").is_exact_f32());
+    assert!(create_float("16777216").is_exact_f32());
+    assert!(!create_float("16777217").is_exact_f32());
+    assert!(create_float("16777217").is_exact_f64());
+    assert!(!Float::new_small(0.1f64).is_exact_f32());
+    assert!(Float::new_small(0.25f64).is_exact_f32());
+    assert!(Float::new_small(0.1f32).is_exact_f32());
+}
+
+#[test]
+fn subnormal() {
+    assert!(create_float("1e-310").is_subnormal_f64());
+    assert!(create_float("-1e-310").is_subnormal_f64());
+    assert!(!create_float("1e-300").is_subnormal_f64());
+    assert!(!create_float("0").is_subnormal_f64());
+    assert!(!create_float("1e-400").is_exact_f64());
+    assert!(Float::new_small(f64::MIN_POSITIVE / 2.0).is_subnormal_f64());
+}
@@ -0,0 +1,85 @@
+use imagnum::compat::{float_to_parts, round_float_parts};
+use imagnum::{create_float, create_int, RoundingMode};
+
+#[test]
+fn round_decimal_places() {
+    assert_eq!(create_float("1.2345").round(2).to_string(), "1.23");
+    assert_eq!(create_float("-9.995").round(2).to_string(), "-10.0");
+    assert_eq!(create_float("2.5").round(0).to_string(), "3.0");
+    // digits past the requested precision beyond the leading zeros
+    assert_eq!(create_float("0.0049").round(3).to_string(), "0.005");
+    assert_eq!(create_float("0.0049").round(1).to_string(), "0.0");
+    assert_eq!(create_float("0.005").round(2).to_string(), "0.01");
+    // already short enough: unchanged
+    assert_eq!(create_float("1.5").round(4).to_string(), "1.5");
+}
+
+#[test]
+fn round_to_multiple_of_power_of_ten() {
+    let f = create_float("12345.678");
+    assert_eq!(f.round_to_multiple_of_power_of_ten(-1).to_string(), "12345.7");
+    assert_eq!(f.round_to_multiple_of_power_of_ten(0).to_string(), "12346.0");
+    assert_eq!(f.round_to_multiple_of_power_of_ten(2), create_float("12300"));
+    assert_eq!(create_float("-250").round_to_multiple_of_power_of_ten(2), create_float("-300"));
+    assert!(create_float("nan").round_to_multiple_of_power_of_ten(1).is_nan());
+}
+
+#[test]
+fn bigdecimal_and_digit_string_paths_agree() {
+    for s in ["1.2345", "-9.995", "0.0049", "0.005", "99.9996", "123456789.987654321", "-0.00000149"] {
+        let x = create_float(s);
+        for places in [0, 1, 2, 3, 5, 8] {
+            let (mantissa, exponent, neg, _) = float_to_parts(&x);
+            assert_eq!(x.round(places), round_float_parts(mantissa, exponent, neg, places), "{} to {}", s, places);
+        }
+    }
+}
+
+#[test]
+fn binary_floats_below_one_round_past_leading_zeros() {
+    assert_eq!(imagnum::Float::from(0.0049f64).round(1), create_float("0"));
+    assert_eq!(imagnum::Float::from(0.0049f64).round(3), create_float("0.005"));
+    assert_eq!(imagnum::Float::from(-0.25f64).round(0), create_float("0"));
+}
+
+#[test]
+fn round_with_modes() {
+    use RoundingMode::*;
+    let cases = [
+        ("2.345", [("2.35", HalfUp), ("2.34", HalfEven), ("2.34", HalfDown), ("2.34", Floor), ("2.35", Ceiling), ("2.34", TowardZero), ("2.35", AwayFromZero)]),
+        ("-2.355", [("-2.36", HalfUp), ("-2.36", HalfEven), ("-2.35", HalfDown), ("-2.36", Floor), ("-2.35", Ceiling), ("-2.35", TowardZero), ("-2.36", AwayFromZero)]),
+        ("1.2341", [("1.23", HalfUp), ("1.23", HalfEven), ("1.23", HalfDown), ("1.23", Floor), ("1.24", Ceiling), ("1.23", TowardZero), ("1.24", AwayFromZero)]),
+    ];
+    for (value, expected) in cases {
+        for (want, mode) in expected {
+            assert_eq!(create_float(value).round_with(2, mode), create_float(want), "{} {:?}", value, mode);
+        }
+    }
+    assert_eq!(create_float("2.5").round_with(0, HalfEven), create_float("2"));
+    assert_eq!(create_float("1.5").round_with(4, Floor).to_string(), "1.5");
+    assert!(create_float("nan").round_with(1, Ceiling).is_nan());
+    assert_eq!(
+        imagnum::create_complex("1.25", "-1.25").round_with(1, HalfEven),
+        imagnum::create_complex("1.2", "-1.2")
+    );
+}
+
+#[test]
+fn int_div_round() {
+    use RoundingMode::*;
+    let int = create_int;
+    let q = |a: &str, b: &str, mode| int(a).div_round(&int(b), mode).unwrap();
+    assert_eq!(q("5", "2", HalfEven), int("2"));
+    assert_eq!(q("7", "2", HalfEven), int("4"));
+    assert_eq!(q("-5", "2", HalfEven), int("-2"));
+    assert_eq!(q("5", "2", HalfDown), int("2"));
+    assert_eq!(q("-5", "2", HalfUp), int("-3"));
+    assert_eq!(q("7", "3", HalfDown), int("2"));
+    assert_eq!(q("8", "3", HalfDown), int("3"));
+    assert_eq!(q("-7", "2", Floor), int("-4"));
+    assert_eq!(q("-7", "2", Ceiling), int("-3"));
+    assert_eq!(q("7", "-3", AwayFromZero), int("-3"));
+    assert_eq!(q("7", "-3", TowardZero), int("-2"));
+    assert_eq!(q("6", "3", AwayFromZero), int("2"));
+    assert!(int("1").div_round(&int("0"), Floor).is_err());
+}
@@ -0,0 +1,58 @@
+use imagnum::config::{auto_shrink, set_auto_shrink};
+use imagnum::create_int;
+use imagnum::foundation::{Int, SmallInt};
+
+#[test]
+fn shrink_picks_smallest_of_i64_i128() {
+    assert!(matches!(create_int("42").shrink(), Int::Small(SmallInt::I64(42))));
+    assert!(matches!(create_int("-9223372036854775808").shrink(), Int::Small(SmallInt::I64(i64::MIN))));
+    assert!(matches!(create_int("9223372036854775808").shrink(), Int::Small(SmallInt::I128(_))));
+    assert!(matches!(create_int("340282366920938463463374607431768211456").shrink(), Int::Big(_)));
+    assert_eq!(create_int("123456").shrink(), create_int("123456"));
+}
+
+#[test]
+fn small_and_big_compare_by_value() {
+    assert_eq!(Int::Small(SmallInt::U8(7)), create_int("7"));
+    assert_eq!(Int::Small(SmallInt::I8(7)), Int::Small(SmallInt::I128(7)));
+    assert!(Int::Small(SmallInt::I64(-5)) > Int::Small(SmallInt::I128(-(1i128 << 100))));
+    assert!(Int::Small(SmallInt::U8(200)) > Int::Small(SmallInt::I64(3)));
+}
+
+#[test]
+fn auto_shrink_applies_to_arithmetic() {
+    assert!(!auto_shrink());
+    set_auto_shrink(true);
+    let sum = create_int("40")._add(&create_int("2")).unwrap();
+    let prod = create_int("18446744073709551616")._mul(&create_int("18446744073709551616")).unwrap();
+    let pow = create_int("2").pow(&create_int("70")).unwrap();
+    set_auto_shrink(false);
+
+    assert!(matches!(sum, Int::Small(SmallInt::I64(42))));
+    assert!(matches!(prod, Int::Big(_)));
+    assert!(matches!(pow, Int::Small(SmallInt::I128(_))));
+    assert!(matches!(create_int("40")._add(&create_int("2")).unwrap(), Int::Big(_)));
+}
+
+#[test]
+fn to_small_with_explicit_kind() {
+    use imagnum::errors::{NEGATIVE_RESULT, NUMBER_TOO_LARGE};
+    use imagnum::foundation::SmallIntKind;
+
+    assert!(matches!(create_int("200").to_small(SmallIntKind::U8), Ok(Int::Small(SmallInt::U8(200)))));
+    assert_eq!(create_int("200").to_small(SmallIntKind::I8), Err(NUMBER_TOO_LARGE));
+    assert_eq!(create_int("-1").to_small(SmallIntKind::U64), Err(NEGATIVE_RESULT));
+    assert!(matches!(create_int("-1").to_small(SmallIntKind::ISize), Ok(Int::Small(SmallInt::ISize(-1)))));
+}
+
+#[test]
+fn smallest_fitting_variant() {
+    use imagnum::foundation::SmallIntKind;
+
+    assert_eq!(create_int("0").smallest_fitting_variant(), Some(SmallIntKind::U8));
+    assert_eq!(create_int("256").smallest_fitting_variant(), Some(SmallIntKind::U16));
+    assert_eq!(create_int("-129").smallest_fitting_variant(), Some(SmallIntKind::I16));
+    assert_eq!(create_int("18446744073709551616").smallest_fitting_variant(), Some(SmallIntKind::U128));
+    assert_eq!(create_int("-340282366920938463463374607431768211456").smallest_fitting_variant(), None);
+    assert_eq!(SmallInt::I32(1).kind(), SmallIntKind::I32);
+}
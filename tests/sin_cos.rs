@@ -0,0 +1,30 @@
+use imagnum::create_float;
+use imagnum::foundation::Float;
+
+#[test]
+fn sin_cos_matches_separate_calls() {
+    for v in ["0", "0.5", "-1.25", "3", "100"] {
+        let x = create_float(v);
+        let (s, c) = x.sin_cos().unwrap();
+        assert_eq!(s, x.sin().unwrap());
+        assert_eq!(c, x.cos().unwrap());
+    }
+}
+
+#[test]
+fn complex_sin_cos_and_exp() {
+    let z = Float::complex(create_float("0.5"), create_float("-1.5"));
+    let (s, c) = z.sin_cos().unwrap();
+    assert_eq!(s, z.sin().unwrap());
+    assert_eq!(c, z.cos().unwrap());
+    assert_eq!(z.tan().unwrap(), s._div(&c).unwrap());
+
+    // e^(i*pi/2) ~= i
+    let half_pi = Float::complex(create_float("0"), create_float("1.5707963267948966"));
+    if let Float::Complex(re, im) = half_pi.exp().unwrap() {
+        assert!(re.to_f64().unwrap().abs() < 1e-15);
+        assert!((im.to_f64().unwrap() - 1.0).abs() < 1e-15);
+    } else {
+        panic!("expected complex result");
+    }
+}
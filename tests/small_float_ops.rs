@@ -0,0 +1,58 @@
+use imagnum::create_float;
+use imagnum::foundation::{Float, SmallFloat};
+
+fn f64_of(v: f64) -> Float {
+    Float::Small(SmallFloat::F64(v))
+}
+
+fn is_small(f: &Float) -> bool {
+    matches!(f, Float::Small(SmallFloat::F64(_)))
+}
+
+#[test]
+fn exact_results_stay_small() {
+    let r = f64_of(0.5)._add(&f64_of(0.25)).unwrap();
+    assert!(is_small(&r));
+    assert_eq!(r, create_float("0.75"));
+
+    let r = f64_of(3.0)._sub(&f64_of(5.5)).unwrap();
+    assert!(is_small(&r));
+    assert_eq!(r, create_float("-2.5"));
+
+    let r = f64_of(1.5)._mul(&f64_of(-4.0)).unwrap();
+    assert!(is_small(&r));
+    assert_eq!(r, create_float("-6"));
+
+    let r = f64_of(7.0)._div(&f64_of(2.0)).unwrap();
+    assert!(is_small(&r));
+    assert_eq!(r, create_float("3.5"));
+}
+
+#[test]
+fn inexact_results_promote_to_big() {
+    let r = f64_of(0.1)._add(&f64_of(0.2)).unwrap();
+    assert!(matches!(r, Float::Big(_)));
+    assert_ne!(r, f64_of(0.1 + 0.2));
+
+    let r = f64_of(1.0)._div(&f64_of(3.0)).unwrap();
+    assert!(!is_small(&r));
+
+    let r = f64_of(1.0 + f64::EPSILON)._mul(&f64_of(1.0 + f64::EPSILON)).unwrap();
+    assert!(!is_small(&r));
+}
+
+#[test]
+fn overflow_and_underflow_promote_to_big() {
+    let r = f64_of(f64::MAX)._mul(&f64_of(2.0)).unwrap();
+    assert!(matches!(r, Float::Big(_)));
+    assert!(r > create_float("1e308"));
+
+    let r = f64_of(f64::MAX)._add(&f64_of(f64::MAX)).unwrap();
+    assert!(matches!(r, Float::Big(_)));
+
+    let r = f64_of(1e-200)._mul(&f64_of(1e-200)).unwrap();
+    assert!(!is_small(&r));
+    assert!(!r.is_zero());
+
+    assert!(f64_of(1.0)._div(&f64_of(0.0)).is_err());
+}
@@ -0,0 +1,151 @@
+use imagnum::eval::{eval, Value};
+use imagnum::special::{bessel_j, bessel_y, beta, elliptic_e, elliptic_k};
+use imagnum::{create_float, create_int, errors, Float};
+
+fn f(s: &str) -> Float {
+    create_float(s)
+}
+
+/// The first `n` characters of the displayed value.
+fn prefix(x: &Float, n: usize) -> String {
+    x.to_str().chars().take(n).collect()
+}
+
+#[test]
+fn test_gamma() {
+    assert_eq!(f("5").gamma().unwrap(), f("24"));
+    assert_eq!(f("1").gamma().unwrap(), f("1"));
+    assert_eq!(f("21").gamma().unwrap(), Float::Big(create_int("2432902008176640000").to_string().parse().unwrap()));
+
+    // Γ(1/2) = √π, Γ(-1/2) = -2√π
+    let sqrt_pi = "1.7724538509055160272981674833411451827975494561223871282138077898529112845910";
+    let g = f("0.5").gamma().unwrap();
+    assert!(matches!(g, Float::Irrational(_)));
+    assert_eq!(prefix(&g, sqrt_pi.len()), sqrt_pi);
+    assert_eq!(prefix(&f("-0.5").gamma().unwrap(), 20), "-3.54490770181103205");
+    assert_eq!(prefix(&f("-2.5").gamma().unwrap(), 20), "-0.94530872048294188");
+    assert_eq!(prefix(&f("-1.5").gamma().unwrap(), 20), "2.363271801207354703");
+    assert_eq!(prefix(&f("0.1").gamma().unwrap(), 20), "9.513507698668731836");
+
+    assert_eq!(f("0").gamma(), Err(errors::DIV_BY_ZERO));
+    assert_eq!(f("-3").gamma(), Err(errors::DIV_BY_ZERO));
+    assert_eq!(f("nan").gamma(), Err(errors::INVALID_FORMAT));
+    assert_eq!(create_float("1+2i").gamma(), Err(errors::INVALID_FORMAT));
+    assert_eq!(f("inf").gamma().unwrap(), Float::Infinity);
+}
+
+#[test]
+fn test_ln_gamma() {
+    assert_eq!(f("1").ln_gamma().unwrap(), f("0"));
+    assert_eq!(f("2").ln_gamma().unwrap(), f("0"));
+    // ln Γ(1/2) = ln √π
+    assert_eq!(prefix(&f("0.5").ln_gamma().unwrap(), 20), "0.572364942924700087");
+    // ln 99! overflows f64's gamma but not ln_gamma
+    assert_eq!(prefix(&f("100").ln_gamma().unwrap(), 20), "359.1342053695753987");
+    assert_eq!(prefix(&f("1e6").ln_gamma().unwrap(), 20), "12815504.56914761165");
+    // |Γ(-1/2)| = 2√π
+    assert_eq!(prefix(&f("-0.5").ln_gamma().unwrap(), 20), "1.265512123484645396");
+    assert_eq!(f("-1").ln_gamma(), Err(errors::DIV_BY_ZERO));
+}
+
+#[test]
+fn test_erf() {
+    assert_eq!(f("0").erf().unwrap(), f("0"));
+    assert_eq!(f("inf").erf().unwrap(), f("1"));
+    assert_eq!(f("-inf").erf().unwrap(), f("-1"));
+    assert_eq!(prefix(&f("1").erf().unwrap(), 20), "0.842700792949714869");
+    assert_eq!(prefix(&f("-0.5").erf().unwrap(), 20), "-0.52049987781304653");
+    assert_eq!(f("0.3").erf().unwrap(), -f("-0.3").erf().unwrap());
+    // past the working precision erf rounds to 1
+    assert_eq!(f("30").erf().unwrap().to_f64().unwrap(), 1.0);
+
+    assert_eq!(f("0").erfc().unwrap(), f("1"));
+    assert_eq!(f("inf").erfc().unwrap(), f("0"));
+    assert_eq!(f("-inf").erfc().unwrap(), f("2"));
+    assert_eq!(prefix(&f("1").erfc().unwrap(), 20), "0.157299207050285130");
+    assert_eq!(prefix(&f("-1").erfc().unwrap(), 20), "1.842700792949714869");
+    // no cancellation in the tail
+    assert_eq!(prefix(&f("5").erfc().unwrap(), 20), "1.537459794428034850");
+    assert!(f("5").erfc().unwrap().to_str().ends_with("E-12"));
+    assert_eq!(prefix(&f("20").erfc().unwrap(), 11), "5.395865611");
+    assert!(f("20").erfc().unwrap().to_str().ends_with("E-176"));
+
+    assert_eq!(f("nan").erf(), Err(errors::INVALID_FORMAT));
+    assert_eq!(create_float("2i").erfc(), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_beta() {
+    assert_eq!(beta(&f("1"), &f("4")).unwrap(), f("0.25"));
+    assert_eq!(beta(&f("2"), &f("3")).unwrap().to_f64().unwrap(), 1.0 / 12.0);
+    assert!(matches!(beta(&f("2"), &f("3")).unwrap(), Float::Irrational(_)));
+    // B(1/2, 1/2) = π
+    let pi = "3.14159265358979323846264338327950288419716939937510";
+    assert_eq!(prefix(&beta(&f("0.5"), &f("0.5")).unwrap(), pi.len()), pi);
+    assert_eq!(prefix(&beta(&f("2.5"), &f("-1.5")).unwrap(), pi.len()), pi);
+    assert_eq!(beta(&f("0.5"), &f("-0.5")).unwrap(), f("0"));
+    assert_eq!(beta(&f("-1"), &f("2")), Err(errors::DIV_BY_ZERO));
+    assert_eq!(beta(&f("inf"), &f("2")), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_bessel() {
+    assert_eq!(prefix(&bessel_j(0, &f("1")).unwrap(), 20), "0.765197686557966551");
+    assert_eq!(prefix(&bessel_j(1, &f("2.5")).unwrap(), 20), "0.497094102464274038");
+    assert_eq!(prefix(&bessel_j(3, &f("-4")).unwrap(), 20), "-0.43017147387562194");
+    assert_eq!(bessel_j(-3, &f("4")).unwrap(), bessel_j(3, &f("-4")).unwrap());
+    // the series cancels about 13 digits here
+    assert_eq!(prefix(&bessel_j(0, &f("30")).unwrap(), 20), "-0.08636798358104021");
+    assert_eq!(bessel_j(0, &f("0")).unwrap(), f("1"));
+    assert_eq!(bessel_j(2, &f("0")).unwrap(), f("0"));
+
+    assert_eq!(prefix(&bessel_y(0, &f("1")).unwrap(), 20), "0.088256964215676957");
+    assert_eq!(prefix(&bessel_y(2, &f("100")).unwrap(), 20), "0.076836867125027956");
+    assert_eq!(prefix(&bessel_y(5, &f("0.01")).unwrap(), 20), "-2444635204829.71142");
+    assert_eq!(bessel_y(-1, &f("2.5")).unwrap(), -bessel_y(1, &f("2.5")).unwrap());
+    assert_eq!(bessel_y(0, &f("0")), Err(errors::DIV_BY_ZERO));
+    assert_eq!(bessel_y(0, &f("-1")), Err(errors::INVALID_FORMAT));
+    assert_eq!(bessel_j(0, &f("nan")), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_bessel_limits() {
+    assert_eq!(bessel_j(0, &f("1e5")), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(bessel_j(1000000, &f("1")), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(bessel_j(-1001, &f("1")), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(bessel_y(0, &f("1000.5")), Err(errors::NUMBER_TOO_LARGE));
+    assert_eq!(bessel_y(1000000, &f("1")), Err(errors::NUMBER_TOO_LARGE));
+    assert!(bessel_j(1000, &f("1")).unwrap() > 0);
+}
+
+#[test]
+fn test_elliptic() {
+    // K(0) = E(0) = π/2
+    let half_pi = "1.5707963267948966192313216916397514420985846996875529104874722961";
+    assert_eq!(prefix(&elliptic_k(&f("0")).unwrap(), half_pi.len()), half_pi);
+    assert_eq!(prefix(&elliptic_e(&f("0")).unwrap(), half_pi.len()), half_pi);
+    assert_eq!(prefix(&elliptic_k(&f("0.5")).unwrap(), 20), "1.854074677301371918");
+    assert_eq!(prefix(&elliptic_e(&f("0.5")).unwrap(), 20), "1.350643881047675502");
+    assert_eq!(prefix(&elliptic_k(&f("-3")).unwrap(), 20), "1.078257823749821617");
+    assert_eq!(prefix(&elliptic_e(&f("0.999999")).unwrap(), 20), "1.000003897026172061");
+
+    assert_eq!(elliptic_k(&f("1")).unwrap(), Float::Infinity);
+    assert_eq!(elliptic_e(&f("1")).unwrap(), f("1"));
+    assert_eq!(elliptic_k(&f("1.5")), Err(errors::INVALID_FORMAT));
+    assert_eq!(elliptic_e(&f("inf")), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn test_eval_functions() {
+    let near = |expr: &str, expected: f64| match eval(expr).unwrap() {
+        Value::Float(x) => assert!((x.to_f64().unwrap() - expected).abs() < 1e-12, "{}", expr),
+        other => panic!("unexpected {:?}", other),
+    };
+    near("gamma(4.5)", 11.631728396567448);
+    near("lgamma(10)", 12.801827480081469);
+    near("erf(1) + erfc(1)", 1.0);
+    near("beta(2.5, 1.5)", 0.19634954084936207);
+    near("besselj(1, 2.5) + bessely(0, 1)", 0.585351066679951);
+    near("ellipk(0.5) - ellipe(0.5)", 0.5034307962536964);
+    assert_eq!(eval("besselj(0.5, 1)"), Err(errors::INVALID_FORMAT));
+}
@@ -0,0 +1,41 @@
+use imagnum::{create_complex, create_float, errors, Float};
+
+fn starts(f: &Float, prefix: &str) {
+    let s = f.to_str();
+    assert!(s.starts_with(prefix), "{} does not start with {}", s, prefix);
+}
+
+#[test]
+fn hypot_is_exact_for_perfect_squares() {
+    assert_eq!(create_float("3").hypot(&create_float("-4")), Ok(create_float("5")));
+    assert_eq!(create_float("3e400").hypot(&create_float("4e400")), Ok(create_float("5e400")));
+    assert_eq!(create_float("3e-400").hypot(&create_float("4e-400")), Ok(create_float("5e-400")));
+    let r = create_float("1").hypot(&create_float("1")).unwrap();
+    assert!(r.is_irrational());
+    starts(&r, "1.41421356237309504880168872420969807856967187537694807317667973799");
+    assert_eq!(Float::NaN.hypot(&Float::Infinity), Ok(Float::Infinity));
+    assert_eq!(Float::NaN.hypot(&create_float("1")), Err(errors::INVALID_FORMAT));
+}
+
+#[test]
+fn exp_m1_keeps_small_arguments() {
+    // exp(1e-30) - 1 is 1e-30 + 5e-61 + ...; exp() - 1 would give 0
+    let r = create_float("1e-30").exp_m1().unwrap();
+    starts(&r, "1.00000000000000000000000000000050000000000000000000000000000016666");
+    starts(&create_float("0.5").exp_m1().unwrap(), "0.648721270700128146848650787814163571653776100710148011575079");
+    starts(&create_float("-0.5").exp_m1().unwrap(), "-0.3934693402873665763962004650088195465580818645128130443171");
+    assert!(create_float("0").exp_m1().unwrap().is_zero());
+    assert_eq!(Float::NegInfinity.exp_m1(), Ok(create_float("-1")));
+    assert!((create_float("2").exp_m1().unwrap().to_f64().unwrap() - 2f64.exp_m1()).abs() < 1e-12);
+}
+
+#[test]
+fn ln_1p_keeps_small_arguments() {
+    starts(&create_float("1e-30").ln_1p().unwrap(), "9.99999999999999999999999999999500000000000000000000000000000333333");
+    starts(&create_float("0.5").ln_1p().unwrap(), "0.405465108108164381978013115464349136571990423462494197614");
+    starts(&create_float("-0.25").ln_1p().unwrap(), "-0.287682072451780927439219005993827431");
+    assert!((create_float("9").ln_1p().unwrap().to_f64().unwrap() - 10f64.ln()).abs() < 1e-12);
+    assert!(create_float("-1").ln_1p().is_err());
+    assert!(create_float("-2").ln_1p().is_err());
+    assert!(create_complex("0", "1").ln_1p().is_ok());
+}
@@ -0,0 +1,29 @@
+use imagnum::{create_float, create_int, errors, Float, Int};
+
+#[test]
+fn int_sum_and_product() {
+    let ints = vec![create_int("3"), create_int("-5"), create_int("7")];
+    assert_eq!(ints.iter().sum::<Int>(), create_int("5"));
+    assert_eq!(ints.iter().product::<Int>(), create_int("-105"));
+    assert_eq!(ints.into_iter().sum::<Result<Int, i8>>(), Ok(create_int("5")));
+    assert_eq!(Vec::<Int>::new().into_iter().sum::<Int>(), create_int("0"));
+    assert_eq!(Vec::<Int>::new().iter().product::<Int>(), create_int("1"));
+}
+
+#[test]
+fn float_sum_and_product() {
+    let floats = vec![create_float("0.5"), create_float("1.25"), create_float("-2")];
+    assert_eq!(floats.iter().sum::<Float>(), create_float("-0.25"));
+    assert_eq!(floats.iter().product::<Float>(), create_float("-1.25"));
+    assert_eq!(floats.into_iter().product::<Result<Float, i8>>(), Ok(create_float("-1.25")));
+    assert_eq!(Vec::<Float>::new().iter().sum::<Float>(), create_float("0"));
+    assert_eq!(Vec::<Float>::new().iter().product::<Float>(), create_float("1"));
+}
+
+#[test]
+fn errors_propagate_or_give_nan() {
+    // the first error is returned, or turns the plain sum into NaN
+    let bad = [Float::Infinity, Float::NegInfinity, create_float("1")];
+    assert_eq!(bad.iter().sum::<Result<Float, i8>>(), Err(errors::INFINITE_RESULT));
+    assert!(bad.iter().sum::<Float>().is_nan());
+}
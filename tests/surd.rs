@@ -0,0 +1,81 @@
+use imagnum::{create_float, create_int, errors, Float};
+
+fn surd(c: &str, r: &str) -> Float {
+    Float::surd(&create_float(c), &create_int(r)).unwrap()
+}
+
+#[test]
+fn test_construction_and_display() {
+    assert_eq!(surd("1", "8").to_string(), "2√2");
+    assert_eq!(surd("-1", "2").to_string(), "-√2");
+    assert_eq!(surd("0.5", "12").to_string(), "√3");
+    assert_eq!(surd("20", "3").to_string(), "20√3");
+    assert!(matches!(surd("3", "9"), Float::Big(_)));
+    assert_eq!(surd("3", "9"), create_float("9"));
+    assert_eq!(surd("0", "5"), create_float("0"));
+    assert_eq!(surd("1", "0"), create_float("0"));
+
+    assert_eq!(Float::surd(&create_float("1"), &create_int("-2")), Err(errors::NEGATIVE_SQRT));
+    let root2 = create_int("2").sqrt().unwrap();
+    assert_eq!(Float::surd(&root2, &create_int("2")), Err(errors::INVALID_FORMAT));
+
+    // the display form parses back
+    for s in ["√2", "-√7", "2√3", "-0.5√6"] {
+        let f = create_float(s);
+        assert!(f.is_surd() && f.is_exact() && f.is_irrational(), "{}", s);
+        assert_eq!(f.to_string(), s);
+        assert_eq!(Float::from_str(s).unwrap().to_canonical_string(), s);
+    }
+    assert_eq!(create_float("√20").to_string(), "2√5");
+    assert!(matches!(create_float("√x"), Float::NaN));
+}
+
+#[test]
+fn test_exact_arithmetic() {
+    let r2 = surd("1", "2");
+    let r3 = surd("1", "3");
+    assert_eq!(r2._mul(&r2).unwrap(), create_float("2"));
+    assert!(r2._mul(&r2).unwrap().is_exact());
+    assert_eq!(r2._mul(&r3).unwrap().to_string(), "√6");
+    assert_eq!(surd("1", "6")._mul(&surd("1", "10")).unwrap().to_string(), "2√15");
+    assert_eq!(r2._mul(&create_float("1.5")).unwrap().to_string(), "1.5√2");
+
+    assert_eq!(surd("1", "8")._div(&r2).unwrap(), create_float("2"));
+    assert_eq!(create_float("1")._div(&r2).unwrap().to_string(), "0.5√2");
+    assert_eq!(r2._div(&create_float("4")).unwrap().to_string(), "0.25√2");
+    assert_eq!(r3._div(&r2).unwrap().to_string(), "0.5√6");
+
+    assert_eq!(r2._add(&surd("2", "2")).unwrap().to_string(), "3√2");
+    assert_eq!(r2._sub(&r2).unwrap(), create_float("0"));
+    assert!(r2._sub(&r2).unwrap().is_exact());
+    assert_eq!((-r2.clone()).to_string(), "-√2");
+    assert_eq!((-r2.clone()).abs().to_string(), "√2");
+
+    assert_eq!(r2.powi(5).unwrap().to_string(), "4√2");
+    assert_eq!(r2.powi(4).unwrap(), create_float("4"));
+    assert_eq!(r2.powi(-3).unwrap().to_string(), "0.25√2");
+    // too many digits to stay exact: rounded like any irrational power
+    let huge = r2.powi(1_000_000_000_000).unwrap();
+    assert!(matches!(huge, Float::Irrational(_)));
+    assert!(huge.to_canonical_string().starts_with("978582864733117186457827213950710587821"));
+    assert_eq!(surd("10", "2").powi(i64::MAX), Err(errors::NUMBER_TOO_LARGE));
+}
+
+#[test]
+fn test_expansion() {
+    let r2 = surd("1", "2");
+    let digits = "1.41421356237309504880168872420969807856967187537694807317667973799";
+    assert!(r2.expand().is_irrational() && !r2.expand().is_exact());
+    assert!(r2.expand().to_str().starts_with(digits));
+    assert!((r2.to_f64().unwrap() - 2f64.sqrt()).abs() < 1e-15);
+    assert!(r2 > create_float("1.414") && r2 < create_float("1.415"));
+
+    // unlike surds and a 1/3 coefficient fall back to the expansion
+    let sum = r2._add(&surd("1", "3")).unwrap();
+    assert!(!sum.is_surd() && !sum.is_exact());
+    assert!(sum.to_str().starts_with("3.14626436994197234232913506571557"));
+    assert!(!create_float("1")._div(&surd("1", "3")).unwrap().is_exact());
+
+    let bytes = surd("-3", "7").to_bytes();
+    assert_eq!(Float::from_bytes(&bytes).unwrap().to_string(), "-3√7");
+}
@@ -65,3 +65,20 @@ fn test_ln_of_1_is_zero() {
         _ => panic!("expected zero for ln(1)"),
     }
 }
+
+#[test]
+fn test_ln_and_exp_beyond_f64_range() {
+    // reference values from mpmath at 300 digits
+    let big = create_int("10").pow(&create_int("400")).unwrap();
+    assert!(big.ln().unwrap().to_str().starts_with("921.03403719761827360719658187374568304044059545150919"));
+    assert!(create_float("1e-500").ln().unwrap().to_str().starts_with("-1151.2925464970228420089957273421821038005507"));
+    assert!(create_float("2.5e800").log10().unwrap().to_str().starts_with("800.3979400086720376095725222105510139464636"));
+    assert_eq!(create_float("1e-30").log10().unwrap(), create_float("-30"));
+
+    let e1000 = create_int("1000").exp().unwrap();
+    assert!(e1000.to_str().starts_with("19700711140170469938888793522433231253169379853238"));
+    assert!(e1000.log10().unwrap().to_str().starts_with("434.29448190325182765112891891660508229439700580366"));
+    let e_minus = create_int("-1000").exp().unwrap().to_str();
+    assert!(e_minus.starts_with("5.07595889754945676529180947957433691930559928289") && e_minus.ends_with("E-435"));
+    assert!(create_int("1000000000000000000").exp().is_err());
+}
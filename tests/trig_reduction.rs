@@ -0,0 +1,85 @@
+use imagnum::consts::pi;
+use imagnum::{create_float, create_int};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-12
+}
+
+#[test]
+fn pi_digits() {
+    assert_eq!(pi(10).to_string(), "3.1415926535");
+    assert_eq!(
+        pi(50).to_string(),
+        "3.14159265358979323846264338327950288419716939937510"
+    );
+    // served from the cache after a larger computation
+    assert_eq!(pi(5).to_string(), "3.14159");
+}
+
+#[test]
+fn huge_arguments() {
+    // reference values computed with 100-digit arithmetic
+    let s = create_int("1000000000000000000000000000000").sin().unwrap().to_f64().unwrap();
+    assert!(close(s, -0.09011690191213805), "{}", s);
+    let c = create_float("1e22").cos().unwrap().to_f64().unwrap();
+    assert!(close(c, 0.5232147853951389), "{}", c);
+    let t = create_float("1e22").tan().unwrap().to_f64().unwrap();
+    assert!(close(t, -1.628778225606898), "{}", t);
+}
+
+#[test]
+fn moderate_arguments_agree_with_f64() {
+    for v in [1.0f64, 2.5, -3.0, 10.0, 123.456, -1000.0] {
+        let x = create_float(&v.to_string());
+        assert!(close(x.sin().unwrap().to_f64().unwrap(), v.sin()), "sin {}", v);
+        assert!(close(x.cos().unwrap().to_f64().unwrap(), v.cos()), "cos {}", v);
+    }
+}
+
+fn prefix(s: String, n: usize) -> String {
+    s.chars().take(n).collect()
+}
+
+#[test]
+fn huge_arguments_to_full_precision() {
+    // reference values from mpmath at 400 digits
+    assert_eq!(
+        prefix(create_float("1e40").sin().unwrap().to_str(), 62),
+        "-0.56963340095363632730803418157356872313292131914786845085382"
+    );
+    let googol = format!("1{}", "0".repeat(100));
+    assert_eq!(
+        prefix(create_int(&googol).cos().unwrap().to_str(), 62),
+        "-0.92808190507465534345619464377695592818318207643905039332511"
+    );
+    assert_eq!(
+        prefix(create_float("1e22").tan().unwrap().to_str(), 50),
+        "-1.62877822560689887854937593693954851354515116817"
+    );
+}
+
+#[test]
+fn arguments_near_multiples_of_half_pi() {
+    // 833719 is within 2.4e-6 of 265381π
+    assert_eq!(
+        prefix(create_int("833719").sin().unwrap().to_str(), 60),
+        "0.0000023129194164527015006004800390682306360209931878241486"
+    );
+    // 6381956970095103·2^797 lies within 5e-19 of an odd multiple of π/2
+    let x = create_int("5319372648326541416707296656673541083813475031793921822105998164685326343987747477646239125204069843392466931105720371047561653378447496736288905533500277726150903890962697774418679535123008556835980236851047840822029788166318932319835828816270258618761216");
+    let c = x.cos().unwrap().to_str();
+    assert!(c.ends_with("E-19"), "{}", c);
+    assert_eq!(prefix(c, 40), "-4.6871659242546276111225828019638843987");
+}
+
+#[test]
+fn arguments_past_the_cap_fail() {
+    let too_large = Some(imagnum::errors::NUMBER_TOO_LARGE);
+    assert_eq!(create_float("1e999999999").sin().err(), too_large);
+    assert_eq!(create_float("-1e100000").cos().err(), too_large);
+    assert_eq!(create_float("1e100000").tan().err(), too_large);
+    assert_eq!(create_float("1e100000").sin_cos().err(), too_large);
+    let huge = create_int(&format!("1{}", "0".repeat(10_000)));
+    assert_eq!(huge.sin().err(), too_large);
+    assert!(create_float("1e5000").cos().is_ok());
+}
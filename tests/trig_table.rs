@@ -0,0 +1,39 @@
+use imagnum::consts::{trig_lookup, trig_table, ExactValue};
+use imagnum::{create_float, ApproxEq};
+
+#[test]
+fn table_matches_f64_trig() {
+    for e in trig_table() {
+        let angle = std::f64::consts::PI * e.num as f64 / e.den as f64;
+        let sin = e.sin.to_float(30).to_f64().unwrap();
+        let cos = e.cos.to_float(30).to_f64().unwrap();
+        assert!((sin - angle.sin()).abs() < 1e-12, "sin({}/{} pi)", e.num, e.den);
+        assert!((cos - angle.cos()).abs() < 1e-12, "cos({}/{} pi)", e.num, e.den);
+        match e.tan {
+            Some(t) => assert!((t.to_float(30).to_f64().unwrap() - angle.tan()).abs() < 1e-9),
+            None => assert!(e.cos.is_zero()),
+        }
+    }
+}
+
+#[test]
+fn lookup_reduces_angle() {
+    let e = trig_lookup(2, 6).unwrap();
+    assert_eq!((e.num, e.den), (1, 3));
+    assert_eq!(e.sin, ExactValue::new(1, 2, 3));
+
+    let e = trig_lookup(-1, 2).unwrap();
+    assert_eq!((e.num, e.den), (3, 2));
+    assert!(e.tan.is_none());
+
+    assert!(trig_lookup(1, 5).is_none());
+    assert!(trig_lookup(1, 0).is_none());
+}
+
+#[test]
+fn exact_value_to_float() {
+    assert_eq!(ExactValue::new(-1, 2, 1).to_float(10), create_float("-0.5"));
+    let half_sqrt3 = ExactValue::new(1, 2, 3).to_float(40);
+    assert!(half_sqrt3.is_irrational());
+    assert!(half_sqrt3.approx_eq(&create_float("0.8660254037844386467637231707529361834714"), 1e-30));
+}
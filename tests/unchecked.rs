@@ -0,0 +1,52 @@
+use imagnum::errors::DIV_BY_ZERO;
+use imagnum::{create_float, create_int, Float, Unchecked};
+
+#[test]
+fn int_chains_without_question_marks() {
+    let a = create_int("7");
+    let b = create_int("5");
+    let r = (a.clone().unchecked() + &b) * create_int("3") - create_int("1");
+    assert_eq!(r.value(), Ok(create_int("35")));
+    let r = -(Unchecked::from(a) % b) / create_int("2");
+    assert_eq!(r.value(), Ok(create_int("-1")));
+}
+
+#[test]
+fn int_errors_poison_the_chain() {
+    let r = create_int("1").unchecked() / create_int("0");
+    assert_eq!(r.error(), Some(DIV_BY_ZERO));
+    assert_eq!(r.to_string(), "NaN");
+    let r = r + create_int("5");
+    assert_eq!(r.value(), Err(DIV_BY_ZERO));
+
+    let mut acc = create_int("10").unchecked();
+    acc -= create_int("10");
+    acc = create_int("1").unchecked() / acc;
+    acc += create_int("1");
+    assert_eq!(acc.error(), Some(DIV_BY_ZERO));
+}
+
+#[test]
+fn float_errors_become_values() {
+    let one = create_float("1");
+    let zero = create_float("0");
+    assert_eq!((one.clone().unchecked() / zero.clone()).value(), Ok(Float::Infinity));
+    assert_eq!((create_float("-2").unchecked() / zero.clone()).value(), Ok(Float::NegInfinity));
+    assert!((zero.clone().unchecked() / zero.clone()).value().unwrap().is_nan());
+
+    let nan = Float::NaN.unchecked() + one.clone();
+    assert!(nan.get().unwrap().is_nan());
+    assert_eq!(nan.error(), None);
+
+    // indeterminate forms are NaN, whichever way round
+    assert!((Float::NegInfinity.unchecked() + Float::Infinity).value().unwrap().is_nan());
+    assert!((Float::Infinity.unchecked() + Float::NegInfinity).value().unwrap().is_nan());
+    assert!((Float::Infinity.unchecked() - Float::Infinity).value().unwrap().is_nan());
+    assert!((Float::NegInfinity.unchecked() - Float::NegInfinity).value().unwrap().is_nan());
+    assert_eq!((Float::NegInfinity.unchecked() + one.clone()).value(), Ok(Float::NegInfinity));
+
+    let mut x = create_float("0.5").unchecked();
+    x *= create_float("4");
+    x += create_float("0.25").unchecked();
+    assert_eq!(x.value(), Ok(create_float("2.25")));
+}
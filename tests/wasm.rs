@@ -0,0 +1,40 @@
+//! The JavaScript-facing wrappers, called from Rust. Error paths build a JavaScript
+//! `Error`, which only exists on wasm32, so only successes are checked here.
+
+use imagnum::wasm::{JsFloat, JsInt};
+use imagnum::{create_float, create_int, Float, Int};
+
+fn int(s: &str) -> JsInt {
+    JsInt::new(s).unwrap()
+}
+
+fn float(s: &str) -> JsFloat {
+    JsFloat::new(s).unwrap()
+}
+
+#[test]
+fn int_arithmetic_through_strings() {
+    let a = int("12345678901234567890");
+    assert_eq!(a.mul(&int("3")).unwrap().to_js_string(), "37037036703703703670");
+    assert_eq!(a.add(&int("10")).unwrap().to_js_string(), "12345678901234567900");
+    assert_eq!(int("-7").div(&int("2")).unwrap().to_js_string(), "-3");
+    assert_eq!(int("2").pow(&int("100")).unwrap().to_js_string(), "1267650600228229401496703205376");
+    assert_eq!(int("5").neg().abs(), int("5"));
+    assert_eq!(int("3").compare(&int("4")), -1);
+    assert!(int("0").is_zero() && int("-1").is_negative());
+    assert_eq!(Int::from(int("42")), create_int("42"));
+}
+
+#[test]
+fn float_arithmetic_through_strings() {
+    assert_eq!(float("1").div(&float("3")).unwrap().to_js_string(), "0.(3)");
+    assert_eq!(float("0.1").add(&float("0.2")).unwrap().to_js_string(), "0.3");
+    assert_eq!(float("2.5").mul(&float("-4")).unwrap().to_number().unwrap(), -10.0);
+    assert_eq!(JsFloat::from_number(0.1).to_js_string(), "0.1");
+    assert!(JsFloat::from_number(f64::NAN).is_nan());
+    assert!(JsFloat::from_number(f64::NAN).to_number().unwrap().is_nan());
+    assert_eq!(float("1").compare(&float("2")), Some(-1));
+    assert_eq!(JsFloat::from_number(f64::NAN).compare(&float("2")), None);
+    assert_eq!(int("7").to_float().unwrap(), float("7"));
+    assert_eq!(Float::from(float("1.5")), create_float("1.5"));
+}
@@ -0,0 +1,57 @@
+use imagnum::foundation::{Float, SmallFloat};
+use imagnum::{create_float, floats_from_f64};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash(f: &Float) -> u64 {
+    let mut h = DefaultHasher::new();
+    f.hash(&mut h);
+    h.finish()
+}
+
+fn is_positive_zero(f: &Float) -> bool {
+    match f {
+        Float::Small(SmallFloat::F64(v)) => *v == 0.0 && v.is_sign_positive(),
+        Float::Small(SmallFloat::F32(v)) => *v == 0.0 && v.is_sign_positive(),
+        other => other.is_zero() && !other.is_negative(),
+    }
+}
+
+#[test]
+fn negative_zero_inputs_become_positive_zero() {
+    assert!(is_positive_zero(&Float::from(-0.0f64)));
+    assert!(is_positive_zero(&Float::from(-0.0f32)));
+    assert!(is_positive_zero(&Float::from_bits((-0.0f64).to_bits())));
+    assert!(is_positive_zero(&floats_from_f64(&[-0.0])[0]));
+    assert_eq!(Float::from(-0.0f64).to_bits(), Ok(0));
+    assert_eq!(Float::from(-0.0f64).to_f64().map(f64::is_sign_positive), Ok(true));
+}
+
+#[test]
+fn arithmetic_never_yields_negative_zero() {
+    let zero = create_float("-1.5")._add(&create_float("1.5")).unwrap();
+    assert!(is_positive_zero(&zero));
+    assert_eq!(zero.to_string(), "0.0");
+
+    let product = Float::from(-1.0f64)._mul(&Float::from(0.0f64)).unwrap();
+    assert!(is_positive_zero(&product));
+    assert!(is_positive_zero(&-Float::from(0.0f64)));
+    assert!(is_positive_zero(&Float::from(-0.4f64).round(0)));
+}
+
+#[test]
+fn every_zero_is_the_same_zero() {
+    let zeros = [create_float("0"), create_float("-0.0"), Float::from(0.0f64), Float::from(-0.0f64), -create_float("0")];
+    for z in &zeros {
+        assert_eq!(z, &zeros[0]);
+        assert_eq!(z.to_string(), "0.0");
+        assert!(z.is_zero() && !z.is_negative());
+    }
+    assert_eq!(hash(&Float::from(-0.0f64)), hash(&Float::from(0.0f64)));
+}
+
+#[test]
+fn tiny_negative_values_still_convert_to_negative_zero() {
+    assert_eq!(create_float("-1e-400").to_f64().map(f64::is_sign_negative), Ok(true));
+    assert!(create_float("-1e-400").is_negative());
+}